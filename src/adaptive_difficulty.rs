@@ -0,0 +1,124 @@
+/*!
+🎚️ ADAPTIVE DIFFICULTY
+Opção de acessibilidade (desligada por padrão): a cada sala de combate vencida mede quanto
+da vida o grupo perdeu e se passou perto de morrer. Quem está sofrendo enfrenta inimigos um
+pouco mais fracos; quem está dominando, um pouco mais fortes. O ajuste anda aos poucos,
+olha só as últimas salas e fica sempre dentro de ±15% de vida e dano dos inimigos
+*/
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use crate::settings::GameSettings;
+use crate::{Player, Room, RoomClearedEvent, RoomEnteredEvent, RoomType, Stats};
+
+// Teto do ajuste nos dois sentidos
+const MAX_ADJUSTMENT: f32 = 0.15;
+// Perda de vida por sala considerada "na medida": menos que isso é dominar
+const TARGET_HEALTH_LOSS: f32 = 0.2;
+// Abaixo desta fração da vida a sala conta como quase-morte
+const CLOSE_CALL_HEALTH: f32 = 0.2;
+// Salas lembradas e quanto a nota pode andar por sala
+const RECENT_ROOMS: usize = 4;
+const MAX_RATING_STEP: f32 = 0.25;
+
+#[derive(Resource, Default)]
+pub struct AdaptiveDifficulty {
+    enabled: bool,
+    rating: f32, // -1 = sofrendo muito, 0 = neutro, 1 = dominando
+    recent: VecDeque<f32>,
+    tracked_room: Option<usize>,
+    start_health: f32,  // fração da vida do grupo ao entrar
+    lowest_health: f32, // menor fração durante a sala
+}
+
+impl AdaptiveDifficulty {
+    fn multiplier(&self) -> f32 {
+        if self.enabled { 1.0 + self.rating * MAX_ADJUSTMENT } else { 1.0 }
+    }
+
+    pub fn enemy_health_multiplier(&self) -> f32 {
+        self.multiplier()
+    }
+
+    pub fn enemy_damage_multiplier(&self) -> f32 {
+        self.multiplier()
+    }
+
+    // Nota da sala: 1 sem perder vida, 0 na perda alvo, -1 perdendo o dobro ou quase morrendo
+    fn record_room(&mut self, end_health: f32) {
+        let lost = (self.start_health - end_health).max(0.0);
+        let mut score = ((TARGET_HEALTH_LOSS - lost) / TARGET_HEALTH_LOSS).clamp(-1.0, 1.0);
+        if self.lowest_health < CLOSE_CALL_HEALTH {
+            score = -1.0;
+        }
+        self.recent.push_back(score);
+        if self.recent.len() > RECENT_ROOMS {
+            self.recent.pop_front();
+        }
+        let average = self.recent.iter().sum::<f32>() / self.recent.len() as f32;
+        self.rating += (average - self.rating).clamp(-MAX_RATING_STEP, MAX_RATING_STEP);
+    }
+}
+
+pub struct AdaptiveDifficultyPlugin;
+
+impl Plugin for AdaptiveDifficultyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AdaptiveDifficulty>()
+            .add_systems(Update, (
+                sync_adaptive_setting,
+                track_room_performance,
+            ).chain());
+    }
+}
+
+fn sync_adaptive_setting(settings: Res<GameSettings>, mut difficulty: ResMut<AdaptiveDifficulty>) {
+    if settings.is_changed() && difficulty.enabled != settings.adaptive_difficulty {
+        difficulty.enabled = settings.adaptive_difficulty;
+        info!("🎚️ Adaptive difficulty {}", if difficulty.enabled { "enabled" } else { "disabled" });
+    }
+}
+
+// Fração somada da vida dos jogadores (caídos no co-op contam com zero)
+fn party_health(players: &Query<&Stats, With<Player>>) -> Option<f32> {
+    let (current, max) = players.iter()
+        .fold((0.0, 0.0), |(current, max), stats| (current + stats.current_health, max + stats.max_health));
+    (max > 0.0).then(|| current / max)
+}
+
+fn track_room_performance(
+    mut entered_events: EventReader<RoomEnteredEvent>,
+    mut cleared_events: EventReader<RoomClearedEvent>,
+    rooms: Query<&Room>,
+    players: Query<&Stats, With<Player>>,
+    mut difficulty: ResMut<AdaptiveDifficulty>,
+) {
+    let health = party_health(&players);
+
+    for event in entered_events.read() {
+        let combat_room = rooms.iter()
+            .any(|room| room.id == event.room && matches!(room.room_type, RoomType::Combat) && !room.cleared);
+        difficulty.tracked_room = combat_room.then_some(event.room);
+        difficulty.start_health = health.unwrap_or(1.0);
+        difficulty.lowest_health = difficulty.start_health;
+    }
+
+    if difficulty.tracked_room.is_some() {
+        if let Some(health) = health {
+            difficulty.lowest_health = difficulty.lowest_health.min(health);
+        }
+    }
+
+    for event in cleared_events.read() {
+        if difficulty.tracked_room != Some(event.room) {
+            continue;
+        }
+        difficulty.tracked_room = None;
+        difficulty.record_room(health.unwrap_or(0.0));
+        info!(
+            "🎚️ Room performance recorded: rating {:.2} (enemy health/damage x{:.2})",
+            difficulty.rating,
+            1.0 + difficulty.rating * MAX_ADJUSTMENT
+        );
+    }
+}
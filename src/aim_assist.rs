@@ -0,0 +1,73 @@
+/*!
+🎯 AIM ASSIST
+Trava suave: a mira de cada jogador puxa para o inimigo mais próximo/centralizado dentro de
+um cone em volta da direção mirada. Roda sobre a entrada já copiada para a simulação
+(FixedInput), então Q, avanço do secundário e mira do R usam a direção corrigida
+*/
+
+use bevy::prelude::*;
+use crate::settings::GameSettings;
+use crate::{Enemy, FixedInput, Player, PlayerId, Spawning};
+
+// Meio-ângulo do cone de busca em volta da mira
+const AIM_ASSIST_CONE: f32 = 0.6; // ~35°
+const AIM_ASSIST_RANGE: f32 = 12.0;
+// Peso da distância contra o desvio angular na escolha do alvo
+const AIM_ASSIST_DISTANCE_WEIGHT: f32 = 0.5;
+
+// Direção do jogador até o inimigo escolhido, se algum estiver no cone
+fn assisted_target(player_pos: Vec3, aim: Vec3, enemies: impl Iterator<Item = Vec3>) -> Option<Vec3> {
+    enemies
+        .filter_map(|enemy_pos| {
+            let offset = Vec3::new(enemy_pos.x - player_pos.x, 0.0, enemy_pos.z - player_pos.z);
+            let distance = offset.length();
+            if !(0.01..=AIM_ASSIST_RANGE).contains(&distance) {
+                return None;
+            }
+            let angle = aim.angle_between(offset);
+            if angle > AIM_ASSIST_CONE {
+                return None;
+            }
+            // Mais perto e mais centralizado = menor pontuação
+            let score = angle / AIM_ASSIST_CONE + AIM_ASSIST_DISTANCE_WEIGHT * distance / AIM_ASSIST_RANGE;
+            Some((offset / distance, score))
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(direction, _)| direction)
+}
+
+/// Corrige a mira da entrada da simulação; refeito a cada quadro a partir da entrada ao vivo
+pub fn apply_aim_assist(
+    settings: Res<GameSettings>,
+    mut fixed_input: ResMut<FixedInput>,
+    players: Query<(&PlayerId, &Transform), With<Player>>,
+    enemies: Query<&Transform, (With<Enemy>, Without<Spawning>)>,
+) {
+    let strength = settings.aim_assist_strength.clamp(0.0, 1.0);
+    if strength <= 0.0 {
+        return;
+    }
+
+    for (id, player_transform) in &players {
+        // O mouse só recebe assistência se o jogador pedir
+        if id.is_primary() && !settings.aim_assist_mouse {
+            continue;
+        }
+        let player_pos = player_transform.translation;
+        let input = &mut fixed_input.0[id.index()];
+        let raw = Vec3::new(input.mouse_world_pos.x - player_pos.x, 0.0, input.mouse_world_pos.z - player_pos.z);
+        let reach = raw.length();
+        if reach < 0.01 {
+            continue;
+        }
+
+        let aim = raw / reach;
+        let Some(target) = assisted_target(player_pos, aim, enemies.iter().map(|transform| transform.translation)) else {
+            continue;
+        };
+        // Mantém a distância mirada (alcance do R e do avanço), só gira a direção
+        let direction = aim.lerp(target, strength).normalize_or_zero();
+        let assisted = player_pos + direction * reach;
+        input.mouse_world_pos = Vec3::new(assisted.x, input.mouse_world_pos.y, assisted.z);
+    }
+}
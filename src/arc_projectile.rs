@@ -0,0 +1,174 @@
+/*!
+💣 ARC PROJECTILE SYSTEM
+Projéteis lançados em arco parabólico (bombas do jogador, jarros das múmias)
+Passam por cima de pilares/paredes e explodem ao pousar
+*/
+
+use bevy::prelude::*;
+use crate::ui::AppState;
+use crate::coop_system::Downed;
+use crate::damage_indicator::PlayerDamagedEvent;
+use crate::debug_tools::{godmode_enabled, DebugSettings};
+use crate::{Player, Stats, Dash, HitEffect, SpawnParticlesEvent, AudioEvent, AoeExplosionEvent, AoeFalloff};
+
+#[derive(Component)]
+pub struct ArcProjectile {
+    pub start: Vec3,
+    pub target: Vec3,
+    pub elapsed: f32,
+    pub flight_time: f32,
+    pub arc_height: f32,
+    pub fuse: f32, // tempo no chão antes de explodir
+    pub radius: f32,
+    pub damage: f32,
+    pub from_enemy: bool,
+    pub shadow: Option<Entity>, // sombra de aviso no ponto de impacto
+}
+
+// Sombra no chão que telegrafa onde o projétil vai cair
+#[derive(Component)]
+pub struct LandingShadow;
+
+pub struct ArcProjectilePlugin;
+
+impl Plugin for ArcProjectilePlugin {
+    fn build(&self, app: &mut App) {
+        // Simulação em passo fixo, junto com os projéteis comuns
+        app.add_systems(FixedUpdate, arc_projectile_system.run_if(in_state(AppState::InGame)))
+            // Run nova: bombas e jarros ainda no ar (e suas sombras) não caem na run seguinte
+            .add_systems(OnEnter(AppState::Loading), cleanup_arc_projectiles);
+    }
+}
+
+/// Spawns an arc projectile; enemy lobs also get a landing shadow telegraph
+pub fn spawn_arc_projectile(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    mut projectile: ArcProjectile,
+    size: f32,
+    color: Color,
+) {
+    if projectile.from_enemy {
+        let shadow = commands.spawn((
+            PbrBundle {
+                mesh: meshes.add(Circle::new(projectile.radius)),
+                material: materials.add(StandardMaterial {
+                    base_color: Color::rgba(0.05, 0.0, 0.0, 0.55),
+                    alpha_mode: AlphaMode::Blend,
+                    unlit: true,
+                    ..default()
+                }),
+                transform: Transform::from_xyz(projectile.target.x, 0.03, projectile.target.z)
+                    .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+                ..default()
+            },
+            LandingShadow,
+        )).id();
+        projectile.shadow = Some(shadow);
+    }
+
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Sphere::new(size)),
+            material: materials.add(StandardMaterial {
+                base_color: color,
+                emissive: color * 3.0,
+                ..default()
+            }),
+            transform: Transform::from_translation(projectile.start),
+            ..default()
+        },
+        projectile,
+    ));
+}
+
+// Integra o arco parabólico; ao pousar conta o pavio e detona
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn arc_projectile_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut projectiles: Query<(Entity, &mut Transform, &mut ArcProjectile), Without<Player>>,
+    mut player_query: Query<(Entity, &Transform, &mut Stats, &Dash), (With<Player>, Without<Downed>)>,
+    mut aoe_events: EventWriter<AoeExplosionEvent>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+    mut audio_events: EventWriter<AudioEvent>,
+    mut damaged_events: EventWriter<PlayerDamagedEvent>,
+    debug: Option<Res<DebugSettings>>,
+) {
+    let dt = time.delta_seconds();
+    let godmode = godmode_enabled(debug.as_deref());
+
+    for (entity, mut transform, mut projectile) in &mut projectiles {
+        projectile.elapsed += dt;
+
+        if projectile.elapsed < projectile.flight_time {
+            let t = projectile.elapsed / projectile.flight_time;
+            let ground = projectile.start.lerp(projectile.target, t);
+            transform.translation = ground + Vec3::Y * (4.0 * projectile.arc_height * t * (1.0 - t));
+            continue;
+        }
+
+        transform.translation = projectile.target;
+        projectile.fuse -= dt;
+
+        // Fuse sparks while waiting to blow
+        if projectile.fuse > 0.0 {
+            if (projectile.fuse / 0.1) as i32 != ((projectile.fuse + dt) / 0.1) as i32 {
+                particle_events.send(SpawnParticlesEvent {
+                    position: projectile.target,
+                    color: Color::rgb(1.0, 0.8, 0.3),
+                    count: 2,
+                });
+            }
+            continue;
+        }
+
+        if projectile.from_enemy {
+            particle_events.send(SpawnParticlesEvent {
+                position: projectile.target,
+                color: Color::rgb(0.5, 0.8, 0.3),
+                count: 14,
+            });
+
+            for (player_entity, player_transform, mut player_stats, dash) in &mut player_query {
+                let offset = player_transform.translation - projectile.target;
+                let in_blast = Vec3::new(offset.x, 0.0, offset.z).length() <= projectile.radius;
+                if in_blast && dash.i_timer <= 0.0 && !godmode {
+                    player_stats.current_health = (player_stats.current_health - projectile.damage).max(0.0);
+                    damaged_events.send(PlayerDamagedEvent { target: player_entity, source: projectile.start });
+                    commands.entity(player_entity).insert(HitEffect {
+                        timer: 0.0,
+                        duration: 0.2,
+                        original_scale: player_transform.scale,
+                    });
+                }
+            }
+            audio_events.send(AudioEvent::ProjectileHit);
+        } else {
+            aoe_events.send(AoeExplosionEvent {
+                center: projectile.target,
+                radius: projectile.radius,
+                damage: projectile.damage,
+                on_hit: None,
+                falloff: AoeFalloff::None,
+            });
+            audio_events.send(AudioEvent::AbilityR);
+        }
+
+        if let Some(shadow) = projectile.shadow {
+            commands.entity(shadow).despawn_recursive();
+        }
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn cleanup_arc_projectiles(
+    mut commands: Commands,
+    projectiles: Query<Entity, Or<(With<ArcProjectile>, With<LandingShadow>)>>,
+) {
+    for entity in &projectiles {
+        commands.entity(entity).despawn_recursive();
+    }
+}
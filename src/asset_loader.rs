@@ -1,4 +1,11 @@
 use bevy::prelude::*;
+use bevy::asset::AssetLoadFailedEvent;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use crate::ui::RequiredAssets;
+
+// Cor das texturas substitutas (arquivo ausente): neutra, mas visível
+const PLACEHOLDER_TEXTURE_COLOR: [u8; 4] = [64, 52, 38, 255];
 
 #[derive(Resource)]
 pub struct GameAssets {
@@ -47,7 +54,8 @@ pub struct AssetLoaderPlugin;
 
 impl Plugin for AssetLoaderPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(PreStartup, load_game_assets);
+        app.add_systems(PreStartup, load_game_assets)
+            .add_systems(Update, (fallback_missing_fonts, fallback_missing_images));
     }
 }
 
@@ -55,6 +63,7 @@ fn load_game_assets(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    mut required: ResMut<RequiredAssets>,
 ) {
     info!("Loading RTX-generated 3D isometric game assets...");
     
@@ -105,6 +114,67 @@ fn load_game_assets(
         anubis_guardian_statue: asset_server.load("environment_3d/anubis_guardian_statue_alpha.png"),
     };
     
+    // Layouts dos atlas são criados em memória; só as imagens vêm do disco
+    for image in [
+        &game_assets.pharaoh_warrior_atlas,
+        &game_assets.anubis_judge_atlas,
+        &game_assets.isis_mother_atlas,
+        &game_assets.pharaoh_warrior,
+        &game_assets.anubis_judge,
+        &game_assets.isis_mother,
+        &game_assets.ra_sun_god,
+        &game_assets.set_chaos,
+        &game_assets.egyptian_warrior,
+        &game_assets.mummy_guardian,
+        &game_assets.sphinx_guardian,
+        &game_assets.pyramid_interior,
+        &game_assets.tomb_chamber,
+        &game_assets.temple_halls,
+        &game_assets.desert_oasis,
+        &game_assets.ankh_health,
+        &game_assets.scarab_energy,
+        &game_assets.eye_of_horus,
+        &game_assets.khopesh_sword,
+        &game_assets.ankh_artifact,
+        &game_assets.canopic_jar,
+        &game_assets.egyptian_wall_section,
+        &game_assets.stone_pillar_ornate,
+        &game_assets.torch_brazier,
+        &game_assets.anubis_guardian_statue,
+    ] {
+        required.track(image);
+    }
     commands.insert_resource(game_assets);
     info!("✅ All RTX 5070 generated 3D assets loaded successfully!");
-}
\ No newline at end of file
+}
+
+// Fonte ausente: o próprio handle passa a conter a fonte padrão do Bevy, então
+// todo texto que já usa (ou vai usar) esse caminho continua legível
+fn fallback_missing_fonts(
+    mut failures: EventReader<AssetLoadFailedEvent<Font>>,
+    mut fonts: ResMut<Assets<Font>>,
+) {
+    for failure in failures.read() {
+        warn!("⚠️ Missing font '{}', using Bevy's default font: {}", failure.path, failure.error);
+        if let Some(default_font) = fonts.get(Handle::<Font>::default()).cloned() {
+            fonts.insert(failure.id, default_font);
+        }
+    }
+}
+
+// Textura ausente: 1x1 de cor sólida no lugar, em vez de imagem invisível
+fn fallback_missing_images(
+    mut failures: EventReader<AssetLoadFailedEvent<Image>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    for failure in failures.read() {
+        warn!("⚠️ Missing texture '{}', using a solid placeholder: {}", failure.path, failure.error);
+        images.insert(failure.id, Image::new_fill(
+            Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
+            TextureDimension::D2,
+            &PLACEHOLDER_TEXTURE_COLOR,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        ));
+    }
+}
@@ -0,0 +1,128 @@
+/*!
+📜 BESTIARY
+Tabelas de inimigos por bioma: os nomes de inimigos dos templates de sala (Desert_Scorpion,
+Stone_Guardian, Lost_Soul...) viram arquétipos concretos em cima dos tipos base de IA, com vida,
+velocidade e cor do bioma. Escorpiões e múmias no deserto, guardiões e sacerdotes no templo,
+espectros e almas no submundo. Elites ganham uma variante com o sabor do bioma
+*/
+
+use bevy::prelude::*;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use std::f32::consts::TAU;
+use crate::procedural::{BiomeType, RoomTemplate};
+use crate::EnemyType;
+
+// Anel onde os inimigos da sala surgem, em volta do centro
+const SPAWN_RING_MIN: f32 = 3.0;
+const SPAWN_RING_MAX: f32 = 6.0;
+
+pub struct EnemyArchetype {
+    pub name: &'static str,
+    pub biome: BiomeType,
+    pub enemy_type: EnemyType,
+    template_ids: &'static [&'static str],
+    pub health: f32, // multiplicadores sobre os stats do tipo base
+    pub speed: f32,
+    pub tint: Color,
+}
+
+// Um arquétipo por (bioma, tipo base)
+const BESTIARY: [EnemyArchetype; 15] = [
+    // Deserto: escorpiões, múmias e bandidos das dunas
+    EnemyArchetype { name: "Escorpião do Deserto", biome: BiomeType::Desert, enemy_type: EnemyType::Chaser,
+        template_ids: &["Desert_Scorpion", "Poisonous_Snake", "Desert_Warrior"], health: 0.9, speed: 1.1, tint: Color::rgb(0.95, 0.7, 0.4) },
+    EnemyArchetype { name: "Bandido do Deserto", biome: BiomeType::Desert, enemy_type: EnemyType::Shooter,
+        template_ids: &["Desert_Bandit"], health: 1.0, speed: 1.0, tint: Color::rgb(0.9, 0.75, 0.55) },
+    EnemyArchetype { name: "Elemental de Areia", biome: BiomeType::Desert, enemy_type: EnemyType::Tank,
+        template_ids: &["Sand_Elemental", "Sand_Pharaoh", "Royal_Guard"], health: 1.0, speed: 1.0, tint: Color::rgb(1.0, 0.85, 0.5) },
+    EnemyArchetype { name: "Múmia das Areias", biome: BiomeType::Desert, enemy_type: EnemyType::LobberMummy,
+        template_ids: &["Sand_Mummy"], health: 1.0, speed: 1.0, tint: Color::rgb(0.9, 0.85, 0.7) },
+    EnemyArchetype { name: "Chacal das Areias", biome: BiomeType::Desert, enemy_type: EnemyType::SandJackal,
+        template_ids: &["Sand_Jackal"], health: 1.0, speed: 1.0, tint: Color::WHITE },
+    // Templo: guardiões de pedra e sacerdotes, mais resistentes e mais lentos
+    EnemyArchetype { name: "Campeão do Templo", biome: BiomeType::Temple, enemy_type: EnemyType::Chaser,
+        template_ids: &["Temple_Champion"], health: 1.2, speed: 0.95, tint: Color::rgb(1.0, 0.9, 0.6) },
+    EnemyArchetype { name: "Sacerdote do Templo", biome: BiomeType::Temple, enemy_type: EnemyType::Shooter,
+        template_ids: &["Temple_Priest", "Sacrificial_Priest", "High_Priest"], health: 1.1, speed: 1.0, tint: Color::rgb(1.0, 0.8, 0.45) },
+    EnemyArchetype { name: "Guardião de Pedra", biome: BiomeType::Temple, enemy_type: EnemyType::Tank,
+        template_ids: &["Stone_Guardian"], health: 1.3, speed: 0.85, tint: Color::rgb(0.75, 0.72, 0.65) },
+    EnemyArchetype { name: "Escriba Amaldiçoado", biome: BiomeType::Temple, enemy_type: EnemyType::LobberMummy,
+        template_ids: &["Cursed_Scribe"], health: 1.1, speed: 1.0, tint: Color::rgb(0.8, 0.9, 0.55) },
+    EnemyArchetype { name: "Espectro dos Hieróglifos", biome: BiomeType::Temple, enemy_type: EnemyType::SandJackal,
+        template_ids: &["Hieroglyph_Specter", "Blood_Wraith"], health: 1.0, speed: 1.0, tint: Color::rgb(0.95, 0.5, 0.45) },
+    // Submundo: almas e espectros, frágeis porém rápidos
+    EnemyArchetype { name: "Alma Perdida", biome: BiomeType::Underworld, enemy_type: EnemyType::Chaser,
+        template_ids: &["Lost_Soul"], health: 0.8, speed: 1.25, tint: Color::rgb(0.6, 0.7, 1.0) },
+    EnemyArchetype { name: "Sombra do Barqueiro", biome: BiomeType::Underworld, enemy_type: EnemyType::Shooter,
+        template_ids: &["Ferryman_Shadow"], health: 0.9, speed: 1.1, tint: Color::rgb(0.5, 0.45, 0.8) },
+    EnemyArchetype { name: "Guardião do Submundo", biome: BiomeType::Underworld, enemy_type: EnemyType::Tank,
+        template_ids: &["Underworld_Guardian", "Shadow_Lord", "Death_Knight"], health: 1.1, speed: 1.0, tint: Color::rgb(0.55, 0.35, 0.75) },
+    EnemyArchetype { name: "Fantasma da Memória", biome: BiomeType::Underworld, enemy_type: EnemyType::LobberMummy,
+        template_ids: &["Memory_Phantom"], health: 0.9, speed: 1.1, tint: Color::rgb(0.7, 0.8, 0.95) },
+    EnemyArchetype { name: "Espreitador de Ossos", biome: BiomeType::Underworld, enemy_type: EnemyType::SandJackal,
+        template_ids: &["Bone_Stalker", "Judgment_Wraith", "Sand_Jackal"], health: 0.9, speed: 1.15, tint: Color::rgb(0.85, 0.85, 0.8) },
+];
+
+/// Criatura local do bioma para um tipo base de IA
+pub fn biome_archetype(biome: BiomeType, enemy_type: EnemyType) -> &'static EnemyArchetype {
+    BESTIARY.iter()
+        .find(|archetype| archetype.biome == biome && archetype.enemy_type == enemy_type)
+        .expect("bestiary covers every biome and enemy type")
+}
+
+// Nome do template para o arquétipo do bioma; nomes de outro bioma viram o equivalente local
+fn template_archetype(id: &str, biome: BiomeType) -> Option<&'static EnemyArchetype> {
+    let listed = |archetype: &&EnemyArchetype| archetype.template_ids.contains(&id);
+    BESTIARY.iter()
+        .filter(|archetype| archetype.biome == biome)
+        .find(listed)
+        .or_else(|| BESTIARY.iter().find(listed).map(|archetype| biome_archetype(biome, archetype.enemy_type)))
+}
+
+/// Variante de elite com o sabor do bioma (por cima do dobro de vida de todo elite)
+pub struct EliteVariant {
+    pub title: &'static str,
+    pub health: f32,
+    pub speed: f32,
+    pub tint: Color,
+}
+
+pub fn elite_variant(biome: BiomeType) -> EliteVariant {
+    match biome {
+        BiomeType::Desert => EliteVariant { title: "Faraônico", health: 1.1, speed: 1.1, tint: Color::rgb(1.0, 0.8, 0.3) },
+        BiomeType::Temple => EliteVariant { title: "Consagrado", health: 1.3, speed: 0.9, tint: Color::rgb(1.0, 0.95, 0.7) },
+        BiomeType::Underworld => EliteVariant { title: "Sombrio", health: 1.0, speed: 1.25, tint: Color::rgb(0.6, 0.3, 0.9) },
+    }
+}
+
+/// Inimigos de uma sala de combate a partir do template: quantidade entre o mínimo e o máximo
+/// e cada um sorteado entre os nomes listados (sem nenhum conhecido, o bestiário do bioma inteiro)
+pub fn roll_room_spawns(template: &RoomTemplate, biome: BiomeType, center: Vec2, rng: &mut impl Rng) -> Vec<(Vec3, EnemyType)> {
+    let mut pool: Vec<EnemyType> = template.enemy_types.iter()
+        .filter_map(|id| template_archetype(id, biome))
+        .map(|archetype| archetype.enemy_type)
+        .collect();
+    if pool.is_empty() {
+        pool = BESTIARY.iter()
+            .filter(|archetype| archetype.biome == biome)
+            .map(|archetype| archetype.enemy_type)
+            .collect();
+    }
+
+    let count = rng.gen_range(template.min_enemies..=template.max_enemies.max(template.min_enemies)).max(1);
+    let start = rng.gen::<f32>() * TAU;
+    (0..count)
+        .filter_map(|index| {
+            let enemy_type = *pool.choose(rng)?;
+            let angle = start + index as f32 * TAU / count as f32;
+            let radius = rng.gen_range(SPAWN_RING_MIN..SPAWN_RING_MAX);
+            Some((Vec3::new(center.x + angle.cos() * radius, 0.5, center.y + angle.sin() * radius), enemy_type))
+        })
+        .collect()
+}
+
+/// Cor base multiplicada pelo tom da criatura (mantém o alfa da base)
+pub fn tint(base: Color, tint: Color) -> Color {
+    Color::rgba(base.r() * tint.r(), base.g() * tint.g(), base.b() * tint.b(), base.a())
+}
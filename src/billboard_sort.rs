@@ -0,0 +1,58 @@
+/*!
+🎴 BILLBOARD SORT
+Quads com textura (sprites dos inimigos no fallback, tochas, estátuas, pilares, paredes) usam
+recorte (AlphaMode::Mask) em vez de mistura: bordas duras, escrevem profundidade e não dependem
+da ordem de desenho entre si. Cada quad marcado com BillboardSort ganha ainda um viés de
+profundidade pela altura, para não brigar com o chão nem com marcas no chão e para os que
+voltam a misturar (dissolvendo ao morrer) desenharem na ordem certa
+*/
+
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+
+// Texels abaixo desta opacidade são descartados
+const BILLBOARD_ALPHA_CUTOFF: f32 = 0.5;
+// Viés base (na frente do chão) e extra por unidade de altura (mais alto desenha por cima)
+const BILLBOARD_BASE_BIAS: f32 = 1.0;
+const BILLBOARD_HEIGHT_BIAS: f32 = 0.5;
+
+#[derive(Component)]
+pub struct BillboardSort;
+
+/// Material de sprite recortado: mesmo visual das texturas com fundo transparente, sem os
+/// artefatos de ordenação do AlphaMode::Blend
+pub fn billboard_material(texture: Handle<Image>, base_color: Color, unlit: bool) -> StandardMaterial {
+    StandardMaterial {
+        base_color_texture: Some(texture),
+        base_color,
+        alpha_mode: AlphaMode::Mask(BILLBOARD_ALPHA_CUTOFF),
+        unlit,
+        double_sided: true,
+        cull_mode: None,
+        ..default()
+    }
+}
+
+pub struct BillboardSortPlugin;
+
+impl Plugin for BillboardSortPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, apply_billboard_depth_bias.after(TransformSystem::TransformPropagate));
+    }
+}
+
+// Só escreve no material quando o viés muda (não marca o asset como alterado todo quadro)
+fn apply_billboard_depth_bias(
+    billboards: Query<(&GlobalTransform, &Handle<StandardMaterial>), With<BillboardSort>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (transform, handle) in &billboards {
+        let bias = BILLBOARD_BASE_BIAS + transform.translation().y.max(0.0) * BILLBOARD_HEIGHT_BIAS;
+        let outdated = materials.get(handle).is_some_and(|material| (material.depth_bias - bias).abs() > 0.01);
+        if outdated {
+            if let Some(material) = materials.get_mut(handle) {
+                material.depth_bias = bias;
+            }
+        }
+    }
+}
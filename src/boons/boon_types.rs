@@ -17,6 +17,20 @@ pub fn create_ra_boons() -> Vec<Boon> {
             synergy_tags: vec!["fire".to_string(), "burn".to_string()],
             level: 1,
             max_level: 3,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "ra_blazing_might".to_string(),
+            god: EgyptianGod::Ra,
+            rarity: BoonRarity::Common,
+            name: "Força Ardente".to_string(),
+            description: "Todos os seus golpes e habilidades causam 20% a mais de dano".to_string(),
+            effects: vec![BoonEffect::DamageBonus { percentage: 0.2 }],
+            synergy_tags: vec!["fire".to_string(), "damage".to_string()],
+            level: 1,
+            max_level: 3,
+            conflicts_with: Vec::new(),
         },
         
         Boon {
@@ -32,6 +46,7 @@ pub fn create_ra_boons() -> Vec<Boon> {
             synergy_tags: vec!["fire".to_string(), "aura".to_string()],
             level: 1,
             max_level: 2,
+            conflicts_with: Vec::new(),
         },
         
         Boon {
@@ -47,6 +62,7 @@ pub fn create_ra_boons() -> Vec<Boon> {
             synergy_tags: vec!["fire".to_string(), "area".to_string(), "r_ability".to_string()],
             level: 1,
             max_level: 1,
+            conflicts_with: Vec::new(),
         },
         
         Boon {
@@ -62,6 +78,80 @@ pub fn create_ra_boons() -> Vec<Boon> {
             synergy_tags: vec!["fire".to_string(), "legendary".to_string(), "heal".to_string()],
             level: 1,
             max_level: 1,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "ra_twin_suns".to_string(),
+            god: EgyptianGod::Ra,
+            rarity: BoonRarity::Common,
+            name: "Sóis Gêmeos".to_string(),
+            description: "Seu Q dispara 1 projétil extra em leque".to_string(),
+            effects: vec![BoonEffect::ProjectileCount { extra: 1 }],
+            synergy_tags: vec!["fire".to_string(), "q_ability".to_string(), "multishot".to_string()],
+            level: 1,
+            max_level: 2,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "ra_expanding_corona".to_string(),
+            god: EgyptianGod::Ra,
+            rarity: BoonRarity::Rare,
+            name: "Coroa Expansiva".to_string(),
+            description: "Sua habilidade R e suas áreas ficam 25% maiores".to_string(),
+            effects: vec![BoonEffect::AreaSize { multiplier: 1.25 }],
+            synergy_tags: vec!["fire".to_string(), "area".to_string(), "r_ability".to_string()],
+            level: 1,
+            max_level: 3,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "ra_solar_stride".to_string(),
+            god: EgyptianGod::Ra,
+            rarity: BoonRarity::Common,
+            name: "Passos do Sol".to_string(),
+            description: "Você se move 10% mais rápido".to_string(),
+            effects: vec![BoonEffect::MovementSpeed { multiplier: 1.1 }],
+            synergy_tags: vec!["fire".to_string(), "speed".to_string()],
+            level: 1,
+            max_level: 3,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "ra_blinding_dawn".to_string(),
+            god: EgyptianGod::Ra,
+            rarity: BoonRarity::Rare,
+            name: "Aurora Ofuscante".to_string(),
+            description: "Seu Q cega os inimigos, deixando-os 35% mais lentos por 2 segundos".to_string(),
+            effects: vec![BoonEffect::AbilityEnhancement {
+                ability: "Q".to_string(),
+                enhancement: Box::new(BoonEffect::ApplySlow { slow_percentage: 0.35, duration: 2.0 }),
+            }],
+            synergy_tags: vec!["fire".to_string(), "q_ability".to_string(), "frost".to_string()],
+            level: 1,
+            max_level: 2,
+            conflicts_with: vec!["thoth_frozen_script".to_string()], // Os dois trocam o efeito do Q
+        },
+        
+        Boon {
+            id: "ra_sun_obelisk".to_string(),
+            god: EgyptianGod::Ra,
+            rarity: BoonRarity::Epic,
+            name: "Obelisco Solar".to_string(),
+            description: "Aperte T para erguer um obelisco que dispara raios de sol no inimigo mais próximo por 8 segundos (2 por sala)".to_string(),
+            effects: vec![BoonEffect::SummonTotem {
+                duration: 8.0,
+                damage: 8.0,
+                fire_interval: 0.7,
+                charges: 2,
+            }],
+            synergy_tags: vec!["fire".to_string(), "summon".to_string(), "ranged".to_string()],
+            level: 1,
+            max_level: 3,
+            conflicts_with: Vec::new(),
         },
     ]
 }
@@ -79,6 +169,7 @@ pub fn create_anubis_boons() -> Vec<Boon> {
             synergy_tags: vec!["death".to_string(), "execute".to_string()],
             level: 1,
             max_level: 3,
+            conflicts_with: Vec::new(),
         },
         
         Boon {
@@ -91,6 +182,7 @@ pub fn create_anubis_boons() -> Vec<Boon> {
             synergy_tags: vec!["death".to_string(), "heal".to_string()],
             level: 1,
             max_level: 2,
+            conflicts_with: Vec::new(),
         },
         
         Boon {
@@ -106,6 +198,40 @@ pub fn create_anubis_boons() -> Vec<Boon> {
             synergy_tags: vec!["death".to_string(), "mobility".to_string(), "dash".to_string()],
             level: 1,
             max_level: 1,
+            conflicts_with: vec!["set_chaos_dash".to_string()], // Teleporte e rastro mudam o dash de formas incompatíveis
+        },
+        
+        Boon {
+            id: "anubis_embalmers_venom".to_string(),
+            god: EgyptianGod::Anubis,
+            rarity: BoonRarity::Common,
+            name: "Veneno do Embalsamador".to_string(),
+            description: "Seus ataques envenenam: 3 de dano por segundo por acúmulo, até 5 acúmulos".to_string(),
+            effects: vec![BoonEffect::ApplyPoison {
+                damage_per_second: 3.0,
+                duration: 4.0,
+            }],
+            synergy_tags: vec!["death".to_string(), "poison".to_string(), "dot".to_string()],
+            level: 1,
+            max_level: 3,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "anubis_jackal_pack".to_string(),
+            god: EgyptianGod::Anubis,
+            rarity: BoonRarity::Rare,
+            name: "Chacal de Anúbis".to_string(),
+            description: "Invoca um chacal espectral que caça inimigos por 12 segundos".to_string(),
+            effects: vec![BoonEffect::SummonAlly {
+                duration: 12.0,
+                damage: 8.0,
+                cooldown: 15.0,
+            }],
+            synergy_tags: vec!["death".to_string(), "summon".to_string()],
+            level: 1,
+            max_level: 2,
+            conflicts_with: Vec::new(),
         },
         
         Boon {
@@ -120,6 +246,50 @@ pub fn create_anubis_boons() -> Vec<Boon> {
             synergy_tags: vec!["death".to_string(), "legendary".to_string(), "resurrection".to_string()],
             level: 1,
             max_level: 1,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "anubis_wide_grave".to_string(),
+            god: EgyptianGod::Anubis,
+            rarity: BoonRarity::Rare,
+            name: "Túmulo Amplo".to_string(),
+            description: "Sua habilidade R e suas áreas ficam 20% maiores".to_string(),
+            effects: vec![BoonEffect::AreaSize { multiplier: 1.2 }],
+            synergy_tags: vec!["death".to_string(), "area".to_string(), "r_ability".to_string()],
+            level: 1,
+            max_level: 2,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "anubis_jackal_stride".to_string(),
+            god: EgyptianGod::Anubis,
+            rarity: BoonRarity::Common,
+            name: "Passo do Chacal".to_string(),
+            description: "Você se move 12% mais rápido".to_string(),
+            effects: vec![BoonEffect::MovementSpeed { multiplier: 1.12 }],
+            synergy_tags: vec!["death".to_string(), "speed".to_string(), "mobility".to_string()],
+            level: 1,
+            max_level: 2,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "anubis_restless_dead".to_string(),
+            god: EgyptianGod::Anubis,
+            rarity: BoonRarity::Epic,
+            name: "Mortos Inquietos".to_string(),
+            description: "Inimigos mortos deixam um cadáver instável por 2.5s que explode se outro inimigo morrer perto ou se você atravessá-lo com o dash".to_string(),
+            effects: vec![BoonEffect::VolatileCorpse {
+                window: 2.5,
+                damage: 30.0,
+                radius: 2.5,
+            }],
+            synergy_tags: vec!["death".to_string(), "area".to_string(), "on_kill".to_string()],
+            level: 1,
+            max_level: 3,
+            conflicts_with: Vec::new(),
         },
     ]
 }
@@ -137,6 +307,7 @@ pub fn create_isis_boons() -> Vec<Boon> {
             synergy_tags: vec!["heal".to_string(), "regeneration".to_string()],
             level: 1,
             max_level: 3,
+            conflicts_with: Vec::new(),
         },
         
         Boon {
@@ -153,6 +324,7 @@ pub fn create_isis_boons() -> Vec<Boon> {
             synergy_tags: vec!["protection".to_string(), "shield".to_string()],
             level: 1,
             max_level: 2,
+            conflicts_with: Vec::new(),
         },
         
         Boon {
@@ -169,6 +341,7 @@ pub fn create_isis_boons() -> Vec<Boon> {
             synergy_tags: vec!["heal".to_string(), "protection".to_string(), "emergency".to_string()],
             level: 1,
             max_level: 1,
+            conflicts_with: Vec::new(),
         },
         
         Boon {
@@ -184,6 +357,69 @@ pub fn create_isis_boons() -> Vec<Boon> {
             synergy_tags: vec!["heal".to_string(), "legendary".to_string(), "resurrection".to_string()],
             level: 1,
             max_level: 1,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "isis_nile_current".to_string(),
+            god: EgyptianGod::Isis,
+            rarity: BoonRarity::Common,
+            name: "Corrente do Nilo".to_string(),
+            description: "Você se move 10% mais rápido".to_string(),
+            effects: vec![BoonEffect::MovementSpeed { multiplier: 1.1 }],
+            synergy_tags: vec!["heal".to_string(), "speed".to_string()],
+            level: 1,
+            max_level: 3,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "isis_wide_embrace".to_string(),
+            god: EgyptianGod::Isis,
+            rarity: BoonRarity::Rare,
+            name: "Abraço Amplo".to_string(),
+            description: "Suas áreas ficam 20% maiores e você regenera 1 de vida por segundo".to_string(),
+            effects: vec![
+                BoonEffect::AreaSize { multiplier: 1.2 },
+                BoonEffect::HealthRegen { health_per_second: 1.0 },
+            ],
+            synergy_tags: vec!["heal".to_string(), "area".to_string(), "regeneration".to_string()],
+            level: 1,
+            max_level: 2,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "isis_twin_tears".to_string(),
+            god: EgyptianGod::Isis,
+            rarity: BoonRarity::Rare,
+            name: "Lágrimas Gêmeas".to_string(),
+            description: "Seu Q dispara 1 projétil extra em leque".to_string(),
+            effects: vec![BoonEffect::ProjectileCount { extra: 1 }],
+            synergy_tags: vec!["heal".to_string(), "q_ability".to_string(), "multishot".to_string()],
+            level: 1,
+            max_level: 2,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "isis_mothers_veil".to_string(),
+            god: EgyptianGod::Isis,
+            rarity: BoonRarity::Epic,
+            name: "Véu da Mãe".to_string(),
+            description: "Ganha um escudo de 30 que se regenera e suas áreas ficam 15% maiores".to_string(),
+            effects: vec![
+                BoonEffect::Shield {
+                    max_shield: 30.0,
+                    regen_rate: 6.0,
+                    regen_delay: 4.0,
+                },
+                BoonEffect::AreaSize { multiplier: 1.15 },
+            ],
+            synergy_tags: vec!["heal".to_string(), "protection".to_string(), "shield".to_string(), "area".to_string()],
+            level: 1,
+            max_level: 1,
+            conflicts_with: Vec::new(),
         },
     ]
 }
@@ -204,6 +440,36 @@ pub fn create_set_boons() -> Vec<Boon> {
             synergy_tags: vec!["lightning".to_string(), "chain".to_string()],
             level: 1,
             max_level: 3,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "set_rending_claws".to_string(),
+            god: EgyptianGod::Set,
+            rarity: BoonRarity::Common,
+            name: "Garras Dilacerantes".to_string(),
+            description: "Seus ataques causam sangramento (5 de dano por segundo), mais forte enquanto o inimigo foge".to_string(),
+            effects: vec![BoonEffect::ApplyBleed {
+                damage_per_second: 5.0,
+                duration: 3.0,
+            }],
+            synergy_tags: vec!["chaos".to_string(), "bleed".to_string(), "dot".to_string()],
+            level: 1,
+            max_level: 3,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "set_chaos_edge".to_string(),
+            god: EgyptianGod::Set,
+            rarity: BoonRarity::Common,
+            name: "Fio do Caos".to_string(),
+            description: "Seus ataques têm 10% de chance de acerto crítico".to_string(),
+            effects: vec![BoonEffect::CritChance { chance: 0.1 }],
+            synergy_tags: vec!["chaos".to_string(), "critical".to_string()],
+            level: 1,
+            max_level: 3,
+            conflicts_with: Vec::new(),
         },
         
         Boon {
@@ -221,6 +487,7 @@ pub fn create_set_boons() -> Vec<Boon> {
             synergy_tags: vec!["speed".to_string(), "stacking".to_string()],
             level: 1,
             max_level: 2,
+            conflicts_with: Vec::new(),
         },
         
         Boon {
@@ -237,6 +504,7 @@ pub fn create_set_boons() -> Vec<Boon> {
             synergy_tags: vec!["lightning".to_string(), "dash".to_string(), "stun".to_string()],
             level: 1,
             max_level: 1,
+            conflicts_with: vec!["anubis_shadow_step".to_string()],
         },
         
         Boon {
@@ -254,6 +522,78 @@ pub fn create_set_boons() -> Vec<Boon> {
             synergy_tags: vec!["lightning".to_string(), "legendary".to_string(), "r_ability".to_string()],
             level: 1,
             max_level: 1,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "set_sandstorm_stride".to_string(),
+            god: EgyptianGod::Set,
+            rarity: BoonRarity::Rare,
+            name: "Passo da Tempestade".to_string(),
+            description: "Você se move 20% mais rápido".to_string(),
+            effects: vec![BoonEffect::MovementSpeed { multiplier: 1.2 }],
+            synergy_tags: vec!["lightning".to_string(), "speed".to_string(), "chaos".to_string()],
+            level: 1,
+            max_level: 2,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "set_forked_bolts".to_string(),
+            god: EgyptianGod::Set,
+            rarity: BoonRarity::Epic,
+            name: "Raios Bifurcados".to_string(),
+            description: "Seu Q dispara 2 projéteis extras em leque".to_string(),
+            effects: vec![BoonEffect::ProjectileCount { extra: 2 }],
+            synergy_tags: vec!["lightning".to_string(), "q_ability".to_string(), "multishot".to_string()],
+            level: 1,
+            max_level: 1,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "set_chaos_rift".to_string(),
+            god: EgyptianGod::Set,
+            rarity: BoonRarity::Common,
+            name: "Fenda do Caos".to_string(),
+            description: "Suas áreas ficam 15% maiores e seus ataques causam sangramento leve".to_string(),
+            effects: vec![
+                BoonEffect::AreaSize { multiplier: 1.15 },
+                BoonEffect::ApplyBleed { damage_per_second: 2.0, duration: 3.0 },
+            ],
+            synergy_tags: vec!["lightning".to_string(), "area".to_string(), "bleed".to_string()],
+            level: 1,
+            max_level: 3,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "set_desert_updraft".to_string(),
+            god: EgyptianGod::Set,
+            rarity: BoonRarity::Rare,
+            name: "Redemoinho do Deserto".to_string(),
+            description: "Sua habilidade R arremessa os inimigos para o alto; no ar eles não agem e sofrem 50% a mais de dano".to_string(),
+            effects: vec![BoonEffect::AbilityEnhancement {
+                ability: "R".to_string(),
+                enhancement: Box::new(BoonEffect::Knockup { height: 2.5, duration: 0.9 }),
+            }],
+            synergy_tags: vec!["lightning".to_string(), "r_ability".to_string(), "chaos".to_string()],
+            level: 1,
+            max_level: 3,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "set_thunder_fist".to_string(),
+            god: EgyptianGod::Set,
+            rarity: BoonRarity::Common,
+            name: "Punho do Trovão".to_string(),
+            description: "Golpes carregados atordoam 0.4s a mais e seu dash-strike também atordoa".to_string(),
+            effects: vec![BoonEffect::StunOnHeavy { bonus_duration: 0.4 }],
+            synergy_tags: vec!["lightning".to_string(), "stun".to_string()],
+            level: 1,
+            max_level: 2,
+            conflicts_with: Vec::new(),
         },
     ]
 }
@@ -274,6 +614,7 @@ pub fn create_thoth_boons() -> Vec<Boon> {
             synergy_tags: vec!["magic".to_string(), "cooldown".to_string()],
             level: 1,
             max_level: 3,
+            conflicts_with: Vec::new(),
         },
         
         Boon {
@@ -288,6 +629,26 @@ pub fn create_thoth_boons() -> Vec<Boon> {
             synergy_tags: vec!["magic".to_string(), "stamina".to_string()],
             level: 1,
             max_level: 2,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "thoth_frozen_script".to_string(),
+            god: EgyptianGod::Thoth,
+            rarity: BoonRarity::Rare,
+            name: "Escrita Gélida".to_string(),
+            description: "Seu Q deixa os inimigos atingidos 50% mais lentos por 2 segundos".to_string(),
+            effects: vec![BoonEffect::AbilityEnhancement {
+                ability: "Q".to_string(),
+                enhancement: Box::new(BoonEffect::ApplySlow {
+                    slow_percentage: 0.5,
+                    duration: 2.0,
+                }),
+            }],
+            synergy_tags: vec!["magic".to_string(), "q_ability".to_string(), "frost".to_string()],
+            level: 1,
+            max_level: 3,
+            conflicts_with: Vec::new(),
         },
         
         Boon {
@@ -304,6 +665,7 @@ pub fn create_thoth_boons() -> Vec<Boon> {
             synergy_tags: vec!["magic".to_string(), "q_ability".to_string(), "echo".to_string()],
             level: 1,
             max_level: 1,
+            conflicts_with: Vec::new(),
         },
         
         Boon {
@@ -319,6 +681,63 @@ pub fn create_thoth_boons() -> Vec<Boon> {
             synergy_tags: vec!["magic".to_string(), "legendary".to_string(), "vision".to_string()],
             level: 1,
             max_level: 1,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "thoth_scribe_volley".to_string(),
+            god: EgyptianGod::Thoth,
+            rarity: BoonRarity::Rare,
+            name: "Rajada do Escriba".to_string(),
+            description: "Seu Q dispara 1 projétil extra em leque".to_string(),
+            effects: vec![BoonEffect::ProjectileCount { extra: 1 }],
+            synergy_tags: vec!["magic".to_string(), "q_ability".to_string(), "multishot".to_string()],
+            level: 1,
+            max_level: 2,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "thoth_glyph_expansion".to_string(),
+            god: EgyptianGod::Thoth,
+            rarity: BoonRarity::Rare,
+            name: "Glifos Ampliados".to_string(),
+            description: "Sua habilidade R e suas áreas ficam 30% maiores".to_string(),
+            effects: vec![BoonEffect::AreaSize { multiplier: 1.3 }],
+            synergy_tags: vec!["magic".to_string(), "area".to_string(), "r_ability".to_string()],
+            level: 1,
+            max_level: 2,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "thoth_ibis_flight".to_string(),
+            god: EgyptianGod::Thoth,
+            rarity: BoonRarity::Common,
+            name: "Voo do Íbis".to_string(),
+            description: "Você se move 10% mais rápido".to_string(),
+            effects: vec![BoonEffect::MovementSpeed { multiplier: 1.1 }],
+            synergy_tags: vec!["magic".to_string(), "speed".to_string()],
+            level: 1,
+            max_level: 3,
+            conflicts_with: Vec::new(),
+        },
+        
+        Boon {
+            id: "thoth_mirage".to_string(),
+            god: EgyptianGod::Thoth,
+            rarity: BoonRarity::Epic,
+            name: "Miragem de Thoth".to_string(),
+            description: "Ao usar o dash você deixa uma ilusão que atrai os inimigos próximos por 4 segundos".to_string(),
+            effects: vec![BoonEffect::SpawnDecoy {
+                duration: 4.0,
+                health: 40.0,
+                cooldown: 6.0,
+            }],
+            synergy_tags: vec!["magic".to_string(), "dash".to_string(), "illusion".to_string()],
+            level: 1,
+            max_level: 3,
+            conflicts_with: Vec::new(),
         },
     ]
 }
\ No newline at end of file
@@ -0,0 +1,157 @@
+use bevy::prelude::*;
+use std::fmt;
+use std::fs;
+use crate::procedural::{GeneratedDungeon, RunConfig};
+use crate::ui::AppState;
+use super::{recalculate_synergies, ActiveBoons, Boon, BoonRegistry};
+
+// Código de build compartilhável: "DUAT1.<semente>.<id>*<nível>+<id>*<nível>.<verificação>"
+// Legível o bastante para colar em discussões de balanceamento e relatórios de bug
+const BUILD_CODE_PREFIX: &str = "DUAT1";
+const BUILD_CODE_SAVE_PATH: &str = "saves/build_code.txt";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BuildCodeError {
+    Malformed,
+    Checksum,
+    UnknownBoon(String),
+    InvalidLevel(String),
+    Duplicate(String),
+    Conflict(String, String),
+}
+
+impl fmt::Display for BuildCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BuildCodeError::Malformed => write!(f, "Código de build inválido"),
+            BuildCodeError::Checksum => write!(f, "Código de build corrompido (verificação falhou)"),
+            BuildCodeError::UnknownBoon(id) => write!(f, "Bênção desconhecida: {}", id),
+            BuildCodeError::InvalidLevel(id) => write!(f, "Nível inválido para {}", id),
+            BuildCodeError::Duplicate(id) => write!(f, "Bênção repetida: {}", id),
+            BuildCodeError::Conflict(a, b) => write!(f, "Bênçãos incompatíveis: {} e {}", a, b),
+        }
+    }
+}
+
+/// Build reconstruída de um código: bênçãos do registro já no nível certo
+pub struct ImportedBuild {
+    pub seed: u64,
+    pub boons: Vec<Boon>,
+}
+
+impl ImportedBuild {
+    /// Substitui as bênçãos atuais pelas da build e recalcula as sinergias
+    pub fn apply(self, active_boons: &mut ActiveBoons) {
+        active_boons.player_boons = self.boons;
+        recalculate_synergies(active_boons);
+    }
+}
+
+// FNV-1a dobrado em 16 bits: pega erros de digitação, não é segurança
+fn checksum(payload: &str) -> u16 {
+    let hash = payload.bytes().fold(0x811c9dc5u32, |hash, byte| (hash ^ byte as u32).wrapping_mul(0x01000193));
+    ((hash >> 16) ^ (hash & 0xffff)) as u16
+}
+
+pub fn export_build_code(active_boons: &ActiveBoons, seed: u64) -> String {
+    let entries: Vec<String> = active_boons.player_boons.iter()
+        .map(|boon| format!("{}*{}", boon.id, boon.level))
+        .collect();
+    let payload = format!("{}.{:x}.{}", BUILD_CODE_PREFIX, seed, entries.join("+"));
+    format!("{}.{:04x}", payload, checksum(&payload))
+}
+
+/// Valida cada id contra o registro; qualquer entrada desconhecida rejeita o código inteiro
+pub fn import_build_code(code: &str, registry: &BoonRegistry) -> Result<ImportedBuild, BuildCodeError> {
+    let code = code.trim();
+    let (payload, check) = code.rsplit_once('.').ok_or(BuildCodeError::Malformed)?;
+    let check = u16::from_str_radix(check, 16).map_err(|_| BuildCodeError::Malformed)?;
+    let mut parts = payload.splitn(3, '.');
+    let (Some(BUILD_CODE_PREFIX), Some(seed), Some(entries)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(BuildCodeError::Malformed);
+    };
+    if checksum(payload) != check {
+        return Err(BuildCodeError::Checksum);
+    }
+    let seed = u64::from_str_radix(seed, 16).map_err(|_| BuildCodeError::Malformed)?;
+
+    let mut boons: Vec<Boon> = Vec::new();
+    for entry in entries.split('+').filter(|entry| !entry.is_empty()) {
+        let (id, level) = entry.split_once('*').ok_or(BuildCodeError::Malformed)?;
+        let mut boon = registry.find_boon(id).cloned().ok_or_else(|| BuildCodeError::UnknownBoon(id.to_string()))?;
+        boon.level = match level.parse::<u32>() {
+            Ok(level) if (1..=boon.max_level).contains(&level) => level,
+            _ => return Err(BuildCodeError::InvalidLevel(id.to_string())),
+        };
+        if boons.iter().any(|owned| owned.id == boon.id) {
+            return Err(BuildCodeError::Duplicate(boon.id));
+        }
+        if let Some(owned) = boons.iter().find(|owned| owned.conflicts(&boon)) {
+            return Err(BuildCodeError::Conflict(owned.id.clone(), boon.id));
+        }
+        boons.push(boon);
+    }
+    Ok(ImportedBuild { seed, boons })
+}
+
+/// Último código exportado (ou colado à mão) em saves/build_code.txt
+pub fn save_build_code(code: &str) {
+    if let Some(parent) = std::path::Path::new(BUILD_CODE_SAVE_PATH).parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    if let Err(err) = fs::write(BUILD_CODE_SAVE_PATH, code) {
+        warn!("⚠️ Failed to save build code: {}", err);
+    }
+}
+
+pub fn load_saved_build_code() -> Option<String> {
+    fs::read_to_string(BUILD_CODE_SAVE_PATH).ok().map(|code| code.trim().to_string())
+}
+
+pub struct BuildCodePlugin;
+
+impl Plugin for BuildCodePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (export_build_shortcut, import_build_shortcut).run_if(in_state(AppState::InGame)));
+    }
+}
+
+// F8 durante a run: código no log e em saves/build_code.txt para colar onde quiser
+fn export_build_shortcut(
+    keys: Res<ButtonInput<KeyCode>>,
+    active_boons: Res<ActiveBoons>,
+    dungeon: Option<Res<GeneratedDungeon>>,
+) {
+    if !keys.just_pressed(KeyCode::F8) {
+        return;
+    }
+    let seed = dungeon.map_or(0, |dungeon| dungeon.generation_seed);
+    let code = export_build_code(&active_boons, seed);
+    info!("📜 Build code: {}", code);
+    save_build_code(&code);
+}
+
+// F9 (só em builds de debug): carrega a build de saves/build_code.txt na run atual;
+// a semente do código vale para a próxima run (mesma dungeon de quem mandou o código)
+fn import_build_shortcut(
+    keys: Res<ButtonInput<KeyCode>>,
+    registry: Res<BoonRegistry>,
+    mut active_boons: ResMut<ActiveBoons>,
+    mut run_config: ResMut<RunConfig>,
+) {
+    if !cfg!(debug_assertions) || !keys.just_pressed(KeyCode::F9) {
+        return;
+    }
+    let Some(code) = load_saved_build_code() else {
+        warn!("⚠️ No build code at {}", BUILD_CODE_SAVE_PATH);
+        return;
+    };
+    match import_build_code(&code, &registry) {
+        Ok(build) => {
+            info!("📜 Imported build with {} boons (seed {:x} on the next run)", build.boons.len(), build.seed);
+            run_config.fixed_seed = Some(build.seed);
+            build.apply(&mut active_boons);
+        }
+        Err(err) => warn!("⚠️ {}", err),
+    }
+}
@@ -1,4 +1,13 @@
 use bevy::prelude::*;
+use super::ActiveBoons;
+
+// Tetos dos modificadores passivos somados de todas as bênçãos
+const MAX_MOVEMENT_SPEED_MULT: f32 = 1.6;
+const MAX_AREA_MULT: f32 = 2.0;
+const MAX_EXTRA_PROJECTILES: u32 = 4;
+const MAX_DAMAGE_MULT: f32 = 2.5;
+const MAX_CRIT_CHANCE: f32 = 0.75;
+const MAX_COOLDOWN_REDUCTION: f32 = 0.6;
 
 #[derive(Debug, Clone)]
 pub enum BoonEffect {
@@ -7,6 +16,13 @@ pub enum BoonEffect {
     BurnDamage { damage_per_second: f32, duration: f32 },
     ChainLightning { damage: f32, chains: u32, range: f32 },
     ExecuteThreshold { threshold: f32, damage_multiplier: f32 },
+    DamageBonus { percentage: f32 }, // Dano de todos os golpes e habilidades
+    CritChance { chance: f32 },      // Chance somada de acerto crítico
+    ApplySlow { slow_percentage: f32, duration: f32 },
+    ApplyPoison { damage_per_second: f32, duration: f32 },
+    ApplyBleed { damage_per_second: f32, duration: f32 },
+    Knockup { height: f32, duration: f32 }, // Arremessa para cima; no ar não age e sofre mais dano
+    StunOnHeavy { bonus_duration: f32 },    // Golpes pesados atordoam por mais tempo (e o dash-strike também)
     
     // Area Effects
     AuraDamage { radius: f32, damage_per_second: f32 },
@@ -24,6 +40,11 @@ pub enum BoonEffect {
     // Movement and Utility
     DashEnhancement { shadow_damage: f32, teleport: bool },
     DashTrail { damage: f32, stun_duration: f32, trail_duration: f32 },
+    MovementSpeed { multiplier: f32 },
+    
+    // Modificadores de habilidade
+    ProjectileCount { extra: u32 },   // Projéteis a mais no Q, em leque
+    AreaSize { multiplier: f32 },     // Raio do R e das áreas
     
     // Ability Enhancements
     AbilityEnhancement { ability: String, enhancement: Box<BoonEffect> },
@@ -41,6 +62,10 @@ pub enum BoonEffect {
     SummonStorm { duration: f32, lightning_damage: f32, strikes_per_second: f32, tracking: bool },
     ResurrectAllies,
     WallHack,
+    SummonAlly { duration: f32, damage: f32, cooldown: f32 },
+    SpawnDecoy { duration: f32, health: f32, cooldown: f32 }, // Ilusão no início do dash que atrai os inimigos
+    SummonTotem { duration: f32, damage: f32, fire_interval: f32, charges: u32 }, // Obelisco parado que atira (cargas por sala)
+    VolatileCorpse { window: f32, damage: f32, radius: f32 }, // Mortos explodem se outro morrer perto ou o dash passar por cima
 }
 
 #[derive(Component)]
@@ -90,6 +115,138 @@ pub struct ElectricTrail {
     pub remaining_duration: f32,
 }
 
+// Modificadores passivos: cada nível extra repete o bônus da bênção (somado, com teto)
+fn summed_bonus(active_boons: &ActiveBoons, bonus: impl Fn(&BoonEffect) -> Option<f32>) -> f32 {
+    active_boons.player_boons.iter()
+        .flat_map(|boon| boon.effects.iter().map(move |effect| (boon.level, effect)))
+        .filter_map(|(level, effect)| bonus(effect).map(|value| value * level as f32))
+        .sum()
+}
+
+pub fn movement_speed_multiplier(active_boons: &ActiveBoons) -> f32 {
+    let bonus = summed_bonus(active_boons, |effect| match effect {
+        BoonEffect::MovementSpeed { multiplier } => Some(multiplier - 1.0),
+        _ => None,
+    });
+    (1.0 + bonus).clamp(0.5, MAX_MOVEMENT_SPEED_MULT)
+}
+
+pub fn area_multiplier(active_boons: &ActiveBoons) -> f32 {
+    let bonus = summed_bonus(active_boons, |effect| match effect {
+        BoonEffect::AreaSize { multiplier } => Some(multiplier - 1.0),
+        _ => None,
+    });
+    (1.0 + bonus).clamp(0.5, MAX_AREA_MULT)
+}
+
+pub fn extra_projectiles(active_boons: &ActiveBoons) -> u32 {
+    let extra = summed_bonus(active_boons, |effect| match effect {
+        BoonEffect::ProjectileCount { extra } => Some(*extra as f32),
+        _ => None,
+    });
+    (extra as u32).min(MAX_EXTRA_PROJECTILES)
+}
+
+// Cura por segundo: bênçãos (por nível) mais as sinergias ativas do panteão
+pub fn health_regen_per_second(active_boons: &ActiveBoons) -> f32 {
+    let regen = |effect: &BoonEffect| match effect {
+        BoonEffect::HealthRegen { health_per_second } => Some(*health_per_second),
+        _ => None,
+    };
+    let synergy: f32 = active_boons.synergy_bonuses.iter()
+        .filter(|synergy| synergy.active)
+        .flat_map(|synergy| synergy.bonus_effects.iter())
+        .filter_map(regen)
+        .sum();
+    summed_bonus(active_boons, regen) + synergy
+}
+
+/// Números do combate vindos das bênçãos, recalculados só quando a lista de bênçãos muda
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct CombatModifiers {
+    pub damage_multiplier: f32,
+    pub crit_chance: f32,
+    // Multiplicadores de recarga por habilidade (1 = sem redução)
+    pub attack_cooldown: f32,
+    pub special_cooldown: f32,
+    pub q_cooldown: f32,
+    pub r_cooldown: f32,
+}
+
+impl Default for CombatModifiers {
+    fn default() -> Self {
+        Self {
+            damage_multiplier: 1.0,
+            crit_chance: 0.0,
+            attack_cooldown: 1.0,
+            special_cooldown: 1.0,
+            q_cooldown: 1.0,
+            r_cooldown: 1.0,
+        }
+    }
+}
+
+impl CombatModifiers {
+    pub fn from_boons(active_boons: &ActiveBoons) -> Self {
+        let damage = summed_bonus(active_boons, |effect| match effect {
+            BoonEffect::DamageBonus { percentage } => Some(*percentage),
+            _ => None,
+        });
+        let crit = summed_bonus(active_boons, |effect| match effect {
+            BoonEffect::CritChance { chance } => Some(*chance),
+            _ => None,
+        });
+        // Redução de recarga de cada habilidade citada na bênção ("Attack", "Special", "Q", "R")
+        let cooldown = |ability: &str| {
+            let reduction = summed_bonus(active_boons, |effect| match effect {
+                BoonEffect::CooldownReduction { abilities, reduction_percentage }
+                    if abilities.iter().any(|name| name.eq_ignore_ascii_case(ability)) => Some(*reduction_percentage),
+                _ => None,
+            });
+            1.0 - reduction.clamp(0.0, MAX_COOLDOWN_REDUCTION)
+        };
+        Self {
+            damage_multiplier: (1.0 + damage).clamp(0.5, MAX_DAMAGE_MULT),
+            crit_chance: crit.clamp(0.0, MAX_CRIT_CHANCE),
+            attack_cooldown: cooldown("Attack"),
+            special_cooldown: cooldown("Special"),
+            q_cooldown: cooldown("Q"),
+            r_cooldown: cooldown("R"),
+        }
+    }
+}
+
+// Ímpeto (OnKillBuff) de uma fonte: bênção ou sinergia ativa
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnKillMomentum {
+    pub source: String,
+    pub speed_bonus: f32,
+    pub attack_speed_bonus: f32,
+    pub duration: f32,
+    pub max_stacks: u32,
+}
+
+// Cada nível extra da bênção permite mais um acúmulo
+pub fn on_kill_momentum(active_boons: &ActiveBoons) -> Vec<OnKillMomentum> {
+    let from_boons = active_boons.player_boons.iter()
+        .flat_map(|boon| boon.effects.iter().map(move |effect| (boon.id.as_str(), boon.level, effect)));
+    let from_synergies = active_boons.synergy_bonuses.iter()
+        .filter(|synergy| synergy.active)
+        .flat_map(|synergy| synergy.bonus_effects.iter().map(move |effect| (synergy.id.as_str(), 1, effect)));
+    from_boons.chain(from_synergies)
+        .filter_map(|(source, level, effect)| match effect {
+            BoonEffect::OnKillBuff { speed_bonus, attack_speed_bonus, duration, max_stacks } => Some(OnKillMomentum {
+                source: source.to_string(),
+                speed_bonus: *speed_bonus,
+                attack_speed_bonus: *attack_speed_bonus,
+                duration: *duration,
+                max_stacks: max_stacks + (level - 1),
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
 // Effect application systems
 pub fn apply_burn_effects(
     time: Res<Time>,
@@ -127,6 +284,7 @@ pub fn update_shield_systems(
     }
 }
 
+#[allow(clippy::type_complexity)]
 pub fn update_storm_entities(
     time: Res<Time>,
     mut storm_query: Query<(Entity, &mut StormEntity, &Transform)>,
@@ -263,6 +421,10 @@ impl BoonEffect {
             BoonEffect::DashTrail { trail_duration, .. } => Some(*trail_duration),
             BoonEffect::OnKillBuff { duration, .. } => Some(*duration),
             BoonEffect::SummonStorm { duration, .. } => Some(*duration),
+            BoonEffect::SummonAlly { duration, .. } => Some(*duration),
+            BoonEffect::SpawnDecoy { duration, .. } => Some(*duration),
+            BoonEffect::SummonTotem { duration, .. } => Some(*duration),
+            BoonEffect::VolatileCorpse { window, .. } => Some(*window),
             _ => None, // Permanent effects
         }
     }
@@ -274,4 +436,27 @@ impl BoonEffect {
             _ => false,
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::boons::create_ra_boons;
+
+    fn with_boon(id: &str, level: u32) -> ActiveBoons {
+        let mut boon = create_ra_boons().into_iter().find(|boon| boon.id == id).unwrap();
+        boon.level = level;
+        ActiveBoons { player_boons: vec![boon], synergy_bonuses: Vec::new() }
+    }
+
+    #[test]
+    fn damage_bonus_raises_the_damage_multiplier() {
+        let none = ActiveBoons { player_boons: Vec::new(), synergy_bonuses: Vec::new() };
+        assert_eq!(CombatModifiers::from_boons(&none), CombatModifiers::default());
+
+        // Força Ardente: +20% por nível
+        let modifiers = CombatModifiers::from_boons(&with_boon("ra_blazing_might", 1));
+        assert!((modifiers.damage_multiplier - 1.2).abs() < 1e-5);
+        let modifiers = CombatModifiers::from_boons(&with_boon("ra_blazing_might", 3));
+        assert!((modifiers.damage_multiplier - 1.6).abs() < 1e-5);
+    }
+}
@@ -1,14 +1,16 @@
 use bevy::prelude::*;
-use rand::{Rng, thread_rng, seq::SliceRandom};
+use rand::{Rng, seq::SliceRandom};
 use std::collections::HashMap;
 
 pub mod boon_types;
 pub mod synergy_system;
 pub mod effects;
+pub mod build_code;
 
 pub use boon_types::*;
 pub use synergy_system::*;
 pub use effects::*;
+pub use build_code::*;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EgyptianGod {
@@ -38,6 +40,15 @@ pub struct Boon {
     pub synergy_tags: Vec<String>,
     pub level: u32,
     pub max_level: u32,
+    pub conflicts_with: Vec<String>, // Ids de bênçãos que não podem coexistir com esta
+}
+
+impl Boon {
+    /// Conflito vale nos dois sentidos: basta uma das duas declarar
+    pub fn conflicts(&self, other: &Boon) -> bool {
+        self.id != other.id
+            && (self.conflicts_with.contains(&other.id) || other.conflicts_with.contains(&self.id))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -75,6 +86,7 @@ impl Plugin for BoonSystemPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<BoonRegistry>()
             .init_resource::<ActiveBoons>()
+            .init_resource::<CombatModifiers>()
             .add_event::<BoonSelectedEvent>()
             .add_event::<BoonOfferEvent>()
             .add_systems(Startup, initialize_boon_registry)
@@ -82,7 +94,7 @@ impl Plugin for BoonSystemPlugin {
                 handle_boon_selection,
                 update_synergies,
                 apply_boon_effects,
-            ));
+            ).chain());
     }
 }
 
@@ -107,6 +119,17 @@ impl EgyptianGod {
         }
     }
 
+    /// Fala do deus ao aparecer num encontro
+    pub fn encounter_line(&self) -> &str {
+        match self {
+            EgyptianGod::Ra => "Minha luz queima por você, pequeno. Escolha qual chama levar.",
+            EgyptianGod::Anubis => "Pesei seu coração e ele ainda bate. Por enquanto.",
+            EgyptianGod::Isis => "Nenhum filho meu caminha sozinho pelas areias.",
+            EgyptianGod::Set => "Caos! Finalmente alguém digno da tempestade.",
+            EgyptianGod::Thoth => "Está escrito que você chegaria aqui. O que vem depois, não.",
+        }
+    }
+
     pub fn get_domain(&self) -> &str {
         match self {
             EgyptianGod::Ra => "Sol e Fogo",
@@ -138,6 +161,16 @@ impl BoonRarity {
         }
     }
 
+    // Nível 0 (comum) a 3 (lendária), usado pela paleta daltônica
+    pub fn tier(&self) -> usize {
+        match self {
+            BoonRarity::Common => 0,
+            BoonRarity::Rare => 1,
+            BoonRarity::Epic => 2,
+            BoonRarity::Legendary => 3,
+        }
+    }
+
     pub fn get_spawn_weight(&self) -> f32 {
         match self {
             BoonRarity::Common => 0.6,
@@ -175,6 +208,21 @@ impl Default for ActiveBoons {
     }
 }
 
+impl ActiveBoons {
+    /// Sobe um nível de uma bênção que o jogador já tem; false se já está no máximo
+    pub fn upgrade_boon(&mut self, index: usize) -> bool {
+        let Some(boon) = self.player_boons.get_mut(index) else {
+            return false;
+        };
+        if boon.level >= boon.max_level {
+            return false;
+        }
+        boon.level += 1;
+        info!("📈 Upgraded {} to level {}", boon.name, boon.level);
+        true
+    }
+}
+
 fn initialize_boon_registry(mut commands: Commands) {
     info!("🌟 Initializing Egyptian Boon Registry...");
     
@@ -219,6 +267,15 @@ fn handle_boon_selection(
                 info!("📈 Upgraded {} to level {}", existing_boon.name, existing_boon.level);
             }
         } else {
+            // Bênçãos incompatíveis saem: a nova substitui
+            active_boons.player_boons.retain(|owned| {
+                let conflicting = owned.conflicts(&event.boon);
+                if conflicting {
+                    info!("🔁 {} replaces {}", event.boon.name, owned.name);
+                }
+                !conflicting
+            });
+            
             // Add new boon
             active_boons.player_boons.push(event.boon.clone());
             info!("✨ Added new boon: {}", event.boon.name);
@@ -240,62 +297,94 @@ fn update_synergies(
     }
 }
 
+// Soma dano, crítico e redução de recarga das bênçãos no CombatModifiers que o combate lê
 fn apply_boon_effects(
     active_boons: Res<ActiveBoons>,
-    // Add queries for entities that need boon effects applied
-    // This would integrate with combat system, stats, etc.
+    mut modifiers: ResMut<CombatModifiers>,
 ) {
-    // This system would apply active boon effects to relevant entities
-    // For now, it's a placeholder for the actual implementation
-    if !active_boons.player_boons.is_empty() {
-        // Apply effects would go here
+    if !active_boons.is_changed() {
+        return;
+    }
+    let updated = CombatModifiers::from_boons(&active_boons);
+    if *modifiers != updated {
+        *modifiers = updated;
+        info!("⚔️ Boon modifiers: dano x{:.2}, crítico {:.0}%", updated.damage_multiplier, updated.crit_chance * 100.0);
     }
 }
 
 // Public API for generating boon offers
 impl BoonRegistry {
-    pub fn generate_offer(&self, god_preferences: Option<Vec<EgyptianGod>>, count: u32) -> BoonOffer {
-        let mut rng = thread_rng();
+    pub fn find_boon(&self, id: &str) -> Option<&Boon> {
+        self.available_boons.values().flatten().find(|boon| boon.id == id)
+    }
+
+    /// Oferta de bênçãos; nunca inclui algo que conflite com o que o jogador já tem (`owned`)
+    pub fn generate_offer(&self, god_preferences: Option<Vec<EgyptianGod>>, count: u32, owned: &[Boon], rng: &mut impl Rng) -> BoonOffer {
         let mut offers = Vec::new();
+        let allowed = |boon: &Boon| !owned.iter().any(|owned_boon| owned_boon.conflicts(boon));
         
-        let gods_to_offer = god_preferences.unwrap_or_else(|| {
-            // Weighted selection based on god favor
-            let mut weighted_gods = Vec::new();
-            for (god, favor) in &self.god_favor {
-                let weight = (1.0 + favor) * 10.0;
-                for _ in 0..(weight as u32) {
-                    weighted_gods.push(*god);
-                }
+        // Encontro com deuses escolhidos: só bênçãos deles, sem repetir, puxando para raridades altas
+        if let Some(gods) = god_preferences.filter(|gods| !gods.is_empty()) {
+            let mut pool: Vec<&Boon> = gods.iter()
+                .filter_map(|god| self.available_boons.get(god))
+                .flatten()
+                .filter(|boon| allowed(boon))
+                .collect();
+            while offers.len() < count as usize {
+                let Ok(boon) = pool.choose_weighted(rng, |boon| (boon.rarity.tier() + 1) as f32).cloned() else {
+                    break;
+                };
+                pool.retain(|other| other.id != boon.id);
+                offers.push(boon.clone());
+            }
+            return BoonOffer {
+                boons: offers,
+                source: "God Encounter".to_string(),
+            };
+        }
+        
+        // Weighted selection based on god favor
+        let mut weighted_gods = Vec::new();
+        for (god, favor) in &self.god_favor {
+            let weight = (1.0 + favor) * 10.0;
+            for _ in 0..(weight as u32) {
+                weighted_gods.push(*god);
             }
-            weighted_gods.shuffle(&mut rng);
-            weighted_gods.into_iter().take(count as usize).collect()
-        });
+        }
+        weighted_gods.shuffle(rng);
+        let gods_to_offer: Vec<EgyptianGod> = weighted_gods.into_iter().take(count as usize).collect();
         
         for god in gods_to_offer.iter().take(count as usize) {
             if let Some(god_boons) = self.available_boons.get(god) {
-                if let Some(boon) = god_boons.choose(&mut rng) {
-                    offers.push(boon.clone());
+                let candidates: Vec<&Boon> = god_boons.iter().filter(|boon| allowed(boon)).collect();
+                if let Some(boon) = candidates.choose(rng) {
+                    offers.push((*boon).clone());
                 }
             }
         }
         
         // Ensure we have the requested count
+        let fallback: Vec<&Boon> = self.available_boons.values().flatten().filter(|boon| allowed(boon)).collect();
         while offers.len() < count as usize {
-            let all_gods = EgyptianGod::get_all();
-            let random_god = all_gods.choose(&mut rng).unwrap();
-            if let Some(god_boons) = self.available_boons.get(random_god) {
-                if let Some(boon) = god_boons.choose(&mut rng) {
-                    offers.push(boon.clone());
-                }
-            }
+            let Some(boon) = fallback.choose(rng) else {
+                break;
+            };
+            offers.push((*boon).clone());
         }
         
         BoonOffer {
             boons: offers,
-            source: "God Encounter".to_string(),
+            source: "Random".to_string(),
         }
     }
     
+    /// Deus que aparece num encontro, com peso pelo favor acumulado
+    pub fn choose_encounter_god(&self, rng: &mut impl Rng) -> EgyptianGod {
+        let gods = EgyptianGod::get_all();
+        *gods.choose_weighted(rng, |god| 1.0 + 2.0 * self.get_god_favor(*god))
+            .unwrap_or(&EgyptianGod::Ra)
+    }
+    
     pub fn get_god_favor(&self, god: EgyptianGod) -> f32 {
         *self.god_favor.get(&god).unwrap_or(&0.0)
     }
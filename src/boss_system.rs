@@ -0,0 +1,343 @@
+/*!
+👑 BOSS SYSTEM
+Chefe da sala final: barra de vida própria, timer de fúria (enrage) contra kiting infinito
+(opcional, ligado pelo modificador de calor Ampulheta do Chefe) e portais de reforço que trazem inimigos até serem destruídos
+*/
+
+use bevy::prelude::*;
+use crate::adaptive_difficulty::AdaptiveDifficulty;
+use crate::asset_loader::GameAssets;
+use crate::hades_3d_system::Hades3DAssets;
+use crate::meta_progression::HeatModifiers;
+use crate::procedural::CurrentBiomeSettings;
+use crate::ui::AppState;
+use crate::spawn_governor::{QueuedSpawn, SpawnGovernor, SpawnSource};
+use crate::{Enemy, EnemyType, Stats, AI, SpawnParticlesEvent, spawn_room_enemy};
+
+// Tempo limite antes da fúria e intervalo entre cada escalada
+const ENRAGE_TIME_LIMIT: f32 = 90.0;
+const ENRAGE_STEP_INTERVAL: f32 = 10.0;
+const ENRAGE_MAX_STAGE: u32 = 5;
+// Aviso visual na barra quando faltar este tempo
+const ENRAGE_WARNING_TIME: f32 = 20.0;
+
+// Portais: intervalo entre reforços, limite de reforços vivos e fração de vida do chefe que fecha os portais
+const PORTAL_SPAWN_INTERVAL: f32 = 8.0;
+const PORTAL_MAX_LIVE_ADDS: usize = 4;
+const PORTAL_CLOSE_BOSS_HEALTH: f32 = 0.5;
+const PORTAL_HEALTH: f32 = 60.0;
+
+#[derive(Component)]
+pub struct Boss;
+
+// Timer de fúria, iniciado quando o chefe surge (entrada na sala do chefe) se a Ampulheta estiver ativa
+#[derive(Component)]
+pub struct BossEnrage {
+    pub timer: Timer,
+    pub step_timer: Timer,
+    pub stage: u32,
+}
+
+impl Default for BossEnrage {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(ENRAGE_TIME_LIMIT, TimerMode::Once),
+            step_timer: Timer::from_seconds(ENRAGE_STEP_INTERVAL, TimerMode::Repeating),
+            stage: 0,
+        }
+    }
+}
+
+impl BossEnrage {
+    pub fn is_enraged(&self) -> bool {
+        self.stage > 0
+    }
+
+    // +25% de dano por estágio de fúria
+    pub fn damage_multiplier(&self) -> f32 {
+        1.0 + 0.25 * self.stage as f32
+    }
+}
+
+// Portal de sombras: também é um Enemy (pode ser atacado e segura a sala até ser destruído)
+#[derive(Component)]
+pub struct ReinforcementPortal {
+    pub spawn_timer: Timer,
+    pub wave: usize,
+    // Portais da sala do chefe fecham com a fase do chefe; os de elite só quando destruídos
+    pub tied_to_boss: bool,
+}
+
+// Inimigo trazido por um portal - conta para o limite de reforços vivos
+#[derive(Component)]
+pub struct PortalAdd;
+
+#[derive(Component)]
+struct BossHealthBar;
+
+#[derive(Component)]
+struct BossHealthFill;
+
+pub struct BossPlugin;
+
+impl Plugin for BossPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (
+                boss_enrage_system,
+                portal_reinforcement_system,
+                portal_close_system,
+                boss_health_bar_system,
+            ).chain().run_if(in_state(AppState::InGame)))
+            .add_systems(OnExit(AppState::InGame), cleanup_boss_health_bar);
+    }
+}
+
+fn boss_enrage_system(
+    time: Res<Time>,
+    mut bosses: Query<(&Transform, &mut BossEnrage, &mut Stats, &mut AI), With<Boss>>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+) {
+    for (transform, mut enrage, mut stats, mut ai) in &mut bosses {
+        enrage.timer.tick(time.delta());
+        if !enrage.timer.finished() {
+            continue;
+        }
+
+        // Primeira escalada assim que o tempo acaba, depois uma a cada intervalo
+        let escalate = if enrage.stage == 0 {
+            true
+        } else {
+            enrage.step_timer.tick(time.delta()).just_finished()
+        };
+        if !escalate || enrage.stage >= ENRAGE_MAX_STAGE {
+            continue;
+        }
+
+        enrage.stage += 1;
+        stats.speed *= 1.15;
+        ai.chase_speed *= 1.15;
+        ai.attack_cooldown *= 0.85;
+
+        particle_events.send(SpawnParticlesEvent {
+            position: transform.translation,
+            color: Color::rgb(1.0, 0.1, 0.05),
+            count: 24,
+        });
+        info!("😡 Boss enraged! Stage {}", enrage.stage);
+    }
+}
+
+pub fn spawn_reinforcement_portal(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    position: Vec3,
+    tied_to_boss: bool,
+) {
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Torus::new(0.6, 1.0)),
+            material: materials.add(StandardMaterial {
+                base_color: Color::rgb(0.3, 0.05, 0.4),
+                emissive: Color::rgb(1.2, 0.2, 1.8),
+                ..default()
+            }),
+            // De pé, virado para a câmera
+            transform: Transform::from_translation(Vec3::new(position.x, 1.2, position.z))
+                .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+            ..default()
+        },
+        Enemy,
+        Stats {
+            max_health: PORTAL_HEALTH,
+            current_health: PORTAL_HEALTH,
+            max_stamina: 0.0,
+            current_stamina: 0.0,
+            speed: 0.0,
+            stamina_regen_rate: 0.0,
+            poise: 0.0,
+            knockback_multiplier: 0.0, // Portal não sai do lugar
+        },
+        ReinforcementPortal {
+            spawn_timer: Timer::from_seconds(PORTAL_SPAWN_INTERVAL, TimerMode::Repeating),
+            wave: 0,
+            tied_to_boss,
+        },
+    ));
+    info!("🌀 Reinforcement portal opened");
+}
+
+#[allow(clippy::too_many_arguments)]
+fn portal_reinforcement_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    time: Res<Time>,
+    game_assets: Option<Res<GameAssets>>,
+    hades_3d_assets: Option<Res<Hades3DAssets>>,
+    heat: Res<HeatModifiers>,
+    difficulty: Res<AdaptiveDifficulty>,
+    biome: Res<CurrentBiomeSettings>,
+    mut portals: Query<(&Transform, &mut ReinforcementPortal)>,
+    adds: Query<(), With<PortalAdd>>,
+    mut governor: ResMut<SpawnGovernor>,
+) {
+    // Reforços esperando vaga no governor também contam, senão a fila cresce sem parar
+    let mut live_adds = adds.iter().count() + governor.queued_from(SpawnSource::Portal);
+
+    for (transform, mut portal) in &mut portals {
+        if !portal.spawn_timer.tick(time.delta()).just_finished() {
+            continue;
+        }
+        // Sala cheia - o portal espera o próximo ciclo
+        if live_adds >= PORTAL_MAX_LIVE_ADDS {
+            continue;
+        }
+
+        // Alterna entre corpo a corpo e atirador a cada onda
+        let enemy_type = if portal.wave % 2 == 0 { EnemyType::Chaser } else { EnemyType::Shooter };
+        portal.wave += 1;
+
+        let pos = Vec3::new(transform.translation.x, 0.5, transform.translation.z + 1.5);
+        live_adds += 1;
+        if !governor.try_reserve() {
+            governor.enqueue(QueuedSpawn { position: pos, enemy_type, affix: None, source: SpawnSource::Portal });
+            continue;
+        }
+        let enemy_entity = spawn_room_enemy(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            game_assets.as_deref(),
+            hades_3d_assets.as_deref(),
+            &heat,
+            &difficulty,
+            biome.biome_type,
+            pos,
+            enemy_type,
+            None,
+        );
+        commands.entity(enemy_entity).insert(PortalAdd);
+    }
+}
+
+// Portais fecham quando o chefe entra na segunda fase (metade da vida) ou morre
+fn portal_close_system(
+    mut commands: Commands,
+    bosses: Query<&Stats, With<Boss>>,
+    portals: Query<(Entity, &Transform, &ReinforcementPortal)>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+) {
+    if portals.is_empty() {
+        return;
+    }
+    let boss_phase_reached = bosses.iter()
+        .all(|stats| stats.current_health <= stats.max_health * PORTAL_CLOSE_BOSS_HEALTH);
+    if !boss_phase_reached {
+        return;
+    }
+
+    for (entity, transform, portal) in &portals {
+        if !portal.tied_to_boss {
+            continue;
+        }
+        commands.entity(entity).despawn_recursive();
+        particle_events.send(SpawnParticlesEvent {
+            position: transform.translation,
+            color: Color::rgb(0.7, 0.2, 1.0),
+            count: 16,
+        });
+        info!("🌀 Reinforcement portal closed");
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn boss_health_bar_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    bosses: Query<(&Stats, Option<&BossEnrage>), (With<Boss>, With<Enemy>)>,
+    bars: Query<Entity, With<BossHealthBar>>,
+    mut frames: Query<&mut BorderColor, With<BossHealthBar>>,
+    mut fills: Query<(&mut Style, &mut BackgroundColor), With<BossHealthFill>>,
+) {
+    let Some((stats, enrage)) = bosses.iter().next() else {
+        // Chefe derrotado - remove a barra
+        for bar in &bars {
+            commands.entity(bar).despawn_recursive();
+        }
+        return;
+    };
+
+    if bars.is_empty() {
+        spawn_boss_health_bar(&mut commands);
+        return;
+    }
+
+    let percent = (stats.current_health / stats.max_health).clamp(0.0, 1.0) * 100.0;
+    let enraged = enrage.is_some_and(BossEnrage::is_enraged);
+    // Sem a Ampulheta não há fúria: a moldura fica sempre dourada
+    let remaining = enrage.map_or(f32::INFINITY, |enrage| enrage.timer.remaining_secs());
+
+    // Indicador sutil: moldura pulsa em vermelho perto do limite, vermelho fixo em fúria
+    let frame_color = if enraged {
+        Color::rgb(0.9, 0.1, 0.05)
+    } else if remaining <= ENRAGE_WARNING_TIME {
+        let pulse = (time.elapsed_seconds() * 4.0).sin() * 0.5 + 0.5;
+        Color::rgb(0.8 + 0.2 * pulse, 0.6 * (1.0 - pulse), 0.2 * (1.0 - pulse))
+    } else {
+        Color::rgb(0.8, 0.6, 0.2)
+    };
+    let fill_color = if enraged {
+        Color::rgb(0.9, 0.15, 0.1)
+    } else {
+        Color::rgb(0.6, 0.1, 0.5)
+    };
+
+    for mut border in &mut frames {
+        *border = frame_color.into();
+    }
+    for (mut style, mut background) in &mut fills {
+        style.width = Val::Percent(percent);
+        *background = fill_color.into();
+    }
+}
+
+fn spawn_boss_health_bar(commands: &mut Commands) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(50.0),
+                bottom: Val::Px(40.0),
+                width: Val::Px(500.0),
+                height: Val::Px(18.0),
+                margin: UiRect::left(Val::Px(-250.0)), // Center it
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            border_color: Color::rgb(0.8, 0.6, 0.2).into(),
+            background_color: Color::rgba(0.1, 0.08, 0.05, 0.8).into(),
+            ..default()
+        },
+        BossHealthBar,
+    )).with_children(|parent| {
+        parent.spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                background_color: Color::rgb(0.6, 0.1, 0.5).into(),
+                ..default()
+            },
+            BossHealthFill,
+        ));
+    });
+}
+
+fn cleanup_boss_health_bar(mut commands: Commands, bars: Query<Entity, With<BossHealthBar>>) {
+    for bar in &bars {
+        commands.entity(bar).despawn_recursive();
+    }
+}
@@ -0,0 +1,226 @@
+/*!
+🧰 CHEST SYSTEM
+Baú das salas do tesouro: abre com interagir (E / botão do controle), a tampa gira e o
+saque é sorteado pelo reward_multiplier do template - moedas, uma oferta de bênçãos ou
+consumíveis. Baús armadilhados (Trapped_Chest) ainda acordam guardiões ao abrir
+*/
+
+use bevy::prelude::*;
+use rand::Rng;
+use crate::adaptive_difficulty::AdaptiveDifficulty;
+use crate::asset_loader::GameAssets;
+use crate::boons::{ActiveBoons, BoonRegistry};
+use crate::consumable_system::spawn_consumable_pickup;
+use crate::hades_3d_system::Hades3DAssets;
+use crate::interact_highlight::Interactable;
+use crate::inventory::ConsumableKind;
+use crate::meta_progression::HeatModifiers;
+use crate::procedural::{CurrentBiomeSettings, GeneratedDungeon, RoomId, RunRng, RunRngStream};
+use crate::ui::{AppState, BoonData, CurrentBoonOffer};
+use crate::{spawn_room_enemy, AudioEvent, EnemyType, FixedInput, Player, PlayerId, SimulationSet, SpawnParticlesEvent};
+
+const CHEST_INTERACT_RADIUS: f32 = 1.8;
+const CHEST_OPEN_TIME: f32 = 0.5;
+const CHEST_LID_MAX_ANGLE: f32 = 1.9; // ~110°
+const CHEST_BASE_COINS: f32 = 30.0;
+// Pesos do sorteio: moedas, consumíveis, bênção
+const CHEST_REWARD_WEIGHTS: [f32; 3] = [0.5, 0.3, 0.2];
+
+#[derive(Component)]
+pub struct Chest {
+    room: RoomId,
+    reward_multiplier: f32,
+    trapped: bool,
+    lid_pivot: Entity,
+    // None = fechado; Some = abrindo/aberto (tempo desde a abertura)
+    opened: Option<f32>,
+}
+
+pub struct ChestPlugin;
+
+impl Plugin for ChestPlugin {
+    fn build(&self, app: &mut App) {
+        // Abrir lê a entrada da simulação (replays); a tampa e o saque seguem no quadro
+        app.add_systems(FixedUpdate, open_chests.in_set(SimulationSet).run_if(in_state(AppState::InGame)))
+            .add_systems(Update, animate_chest_lids.run_if(in_state(AppState::InGame)));
+    }
+}
+
+/// Baú fechado no chão; a tampa gira numa dobradiça na borda de trás
+pub fn spawn_chest(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    room: RoomId,
+    position: Vec3,
+    reward_multiplier: f32,
+    trapped: bool,
+) {
+    let wood = materials.add(StandardMaterial {
+        base_color: Color::rgb(0.45, 0.3, 0.15),
+        ..default()
+    });
+    let gold = materials.add(StandardMaterial {
+        base_color: Color::rgb(0.9, 0.7, 0.25),
+        emissive: Color::rgb(0.3, 0.2, 0.05),
+        metallic: 0.8,
+        ..default()
+    });
+
+    let lid = commands.spawn(PbrBundle {
+        mesh: meshes.add(Cuboid::new(1.2, 0.25, 0.8)),
+        material: gold,
+        transform: Transform::from_xyz(0.0, 0.125, 0.4),
+        ..default()
+    }).id();
+    let lid_pivot = commands.spawn(SpatialBundle::from_transform(Transform::from_xyz(0.0, 0.35, -0.4)))
+        .add_child(lid)
+        .id();
+
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Cuboid::new(1.2, 0.7, 0.8)),
+            material: wood,
+            transform: Transform::from_xyz(position.x, 0.35, position.z),
+            ..default()
+        },
+        Chest {
+            room,
+            reward_multiplier,
+            trapped,
+            lid_pivot,
+            opened: None,
+        },
+        Interactable::new("Abrir baú", CHEST_INTERACT_RADIUS),
+        Name::new("Treasure Chest"),
+    )).add_child(lid_pivot);
+}
+
+// Qualquer jogador perto abre; a sala fica marcada como saqueada na hora
+fn open_chests(
+    fixed_input: Res<FixedInput>,
+    players: Query<(&PlayerId, &Transform), With<Player>>,
+    mut chests: Query<(&Transform, &mut Chest, &mut Interactable)>,
+    mut dungeon: ResMut<GeneratedDungeon>,
+    mut audio_events: EventWriter<AudioEvent>,
+) {
+    let interacting: Vec<Vec3> = players.iter()
+        .filter(|(id, _)| fixed_input.get(**id).interact)
+        .map(|(_, transform)| transform.translation)
+        .collect();
+    if interacting.is_empty() {
+        return;
+    }
+
+    for (transform, mut chest, mut interactable) in &mut chests {
+        if chest.opened.is_some() {
+            continue;
+        }
+        let in_reach = interacting.iter().any(|position| {
+            let offset = *position - transform.translation;
+            Vec3::new(offset.x, 0.0, offset.z).length() <= CHEST_INTERACT_RADIUS
+        });
+        if in_reach {
+            chest.opened = Some(0.0);
+            interactable.enabled = false;
+            dungeon.room_state_mut(chest.room).loot_taken = true;
+            audio_events.send(AudioEvent::ChestOpen);
+            info!("🧰 Opening chest...");
+        }
+    }
+}
+
+// Tampa abre; no fim da animação o saque é sorteado (uma vez)
+#[allow(clippy::too_many_arguments)]
+fn animate_chest_lids(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut chests: Query<(&Transform, &mut Chest)>,
+    mut pivots: Query<&mut Transform, Without<Chest>>,
+    mut boon_data: ResMut<BoonData>,
+    mut current_offer: ResMut<CurrentBoonOffer>,
+    // Oferta de bênçãos do baú
+    (boon_registry, active_boons): (Res<BoonRegistry>, Res<ActiveBoons>),
+    mut run_rng: ResMut<RunRng>,
+    mut app_state: ResMut<NextState<AppState>>,
+    game_assets: Option<Res<GameAssets>>,
+    hades_3d_assets: Option<Res<Hades3DAssets>>,
+    // Escala dos guardiões (agrupados: o sistema já está no limite de parâmetros)
+    (heat, difficulty): (Res<HeatModifiers>, Res<AdaptiveDifficulty>),
+    biome: Res<CurrentBiomeSettings>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+) {
+    for (transform, mut chest) in &mut chests {
+        let Some(elapsed) = chest.opened else {
+            continue;
+        };
+        if elapsed >= CHEST_OPEN_TIME {
+            continue;
+        }
+        let elapsed = (elapsed + time.delta_seconds()).min(CHEST_OPEN_TIME);
+        chest.opened = Some(elapsed);
+        if let Ok(mut pivot) = pivots.get_mut(chest.lid_pivot) {
+            let progress = elapsed / CHEST_OPEN_TIME;
+            pivot.rotation = Quat::from_rotation_x(-CHEST_LID_MAX_ANGLE * (1.0 - (1.0 - progress).powi(2)));
+        }
+        if elapsed < CHEST_OPEN_TIME {
+            continue;
+        }
+
+        let position = transform.translation;
+        particle_events.send(SpawnParticlesEvent {
+            position: position + Vec3::Y * 0.5,
+            color: Color::rgb(1.0, 0.85, 0.3),
+            count: 24,
+        });
+
+        let rng = run_rng.stream(RunRngStream::Chests);
+        let roll = rng.gen::<f32>() * CHEST_REWARD_WEIGHTS.iter().sum::<f32>();
+        if roll < CHEST_REWARD_WEIGHTS[0] {
+            let coins = (CHEST_BASE_COINS * chest.reward_multiplier).round() as u32;
+            boon_data.coins += coins;
+            info!("💰 Chest: {} coins", coins);
+        } else if roll < CHEST_REWARD_WEIGHTS[0] + CHEST_REWARD_WEIGHTS[1] {
+            let count = chest.reward_multiplier.round().max(1.0) as usize;
+            for index in 0..count {
+                let kind = match rng.gen_range(0..3) {
+                    0 => ConsumableKind::HealthPotion,
+                    1 => ConsumableKind::StaminaElixir,
+                    _ => ConsumableKind::Bomb,
+                };
+                // Em leque na frente do baú
+                let angle = (index as f32 - (count - 1) as f32 / 2.0) * 0.6;
+                let offset = Vec3::new(angle.sin(), 0.0, angle.cos()) * 1.3;
+                spawn_consumable_pickup(&mut commands, &mut meshes, &mut materials, kind, Vec3::new(position.x, 0.5, position.z) + offset);
+            }
+            info!("🧪 Chest: {} consumables", count);
+        } else {
+            current_offer.boons = boon_registry.generate_offer(None, 3, &active_boons.player_boons, rng).boons;
+            current_offer.selected = false;
+            app_state.set(AppState::BoonSelection);
+            info!("🌟 Chest: boon offer");
+        }
+
+        // Armadilha: guardiões acordam em volta do baú
+        if chest.trapped {
+            for (offset, enemy_type) in [(Vec3::new(2.5, 0.0, 1.5), EnemyType::Tank), (Vec3::new(-2.5, 0.0, 1.5), EnemyType::Chaser)] {
+                spawn_room_enemy(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    game_assets.as_deref(),
+                    hades_3d_assets.as_deref(),
+                    &heat,
+                    &difficulty,
+                    biome.biome_type,
+                    Vec3::new(position.x, 0.5, position.z) + offset,
+                    enemy_type,
+                    None,
+                );
+            }
+            warn!("🪤 Trapped chest! Guardians awaken");
+        }
+    }
+}
@@ -0,0 +1,213 @@
+/*!
+⏱️ CLEAR TIMER
+Cronômetro das salas de combate: começa ao atravessar a porta e para quando a sala é limpa.
+Abaixo do tempo par (por bioma, um pouco mais folgado a cada sala vencida) o grupo ganha
+moedas extras e uma pequena cura, com o destaque "Limpeza Veloz!"
+*/
+
+use bevy::prelude::*;
+use crate::coop_system::Downed;
+use crate::meta_progression::HeatModifiers;
+use crate::procedural::{BiomeType, CurrentBiomeSettings};
+use crate::ui::{AppState, BoonData};
+use crate::{GameState, Player, Room, RoomClearedEvent, RoomEnteredEvent, RoomType, Stats};
+
+// Par: base do bioma + folga por sala já vencida, até o teto
+const PAR_PER_CLEARED_ROOM: f32 = 1.5;
+const PAR_MAX: f32 = 60.0;
+// Recompensa da limpeza veloz
+const SWIFT_CLEAR_COINS: u32 = 15;
+const SWIFT_CLEAR_HEAL_FRACTION: f32 = 0.1;
+const SWIFT_FLOURISH_DURATION: f32 = 2.5;
+
+#[derive(Resource, Default)]
+struct RoomClearTimer {
+    room: Option<usize>, // Sala de combate sendo cronometrada
+    elapsed: f32,
+    par: f32,
+}
+
+#[derive(Component)]
+struct ClearTimerText;
+
+#[derive(Component)]
+struct SwiftClearFlourish {
+    timer: f32,
+}
+
+pub struct ClearTimerPlugin;
+
+impl Plugin for ClearTimerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RoomClearTimer>()
+            .add_systems(OnEnter(AppState::InGame), setup_clear_timer_display)
+            .add_systems(OnExit(AppState::InGame), cleanup_clear_timer_display)
+            .add_systems(Update, (
+                start_clear_timer,
+                tick_clear_timer.run_if(in_state(AppState::InGame)),
+                update_clear_timer_display.run_if(in_state(AppState::InGame)),
+                animate_swift_flourish.run_if(in_state(AppState::InGame)),
+            ).chain())
+            // A limpeza abre a escolha de recompensa no mesmo quadro: o evento é lido em qualquer estado
+            .add_systems(Update, finish_clear_timer.after(tick_clear_timer))
+            .add_systems(OnEnter(AppState::Loading), reset_clear_timer);
+    }
+}
+
+fn biome_par(biome: BiomeType) -> f32 {
+    match biome {
+        BiomeType::Desert => 30.0,
+        BiomeType::Temple => 35.0,
+        BiomeType::Underworld => 40.0,
+    }
+}
+
+fn start_clear_timer(
+    mut entered_events: EventReader<RoomEnteredEvent>,
+    rooms: Query<&Room>,
+    game_state: Res<GameState>,
+    biome: Res<CurrentBiomeSettings>,
+    mut timer: ResMut<RoomClearTimer>,
+) {
+    for event in entered_events.read() {
+        let combat_room = rooms.iter()
+            .find(|room| room.id == event.room)
+            .filter(|room| matches!(room.room_type, RoomType::Combat) && !room.cleared);
+        *timer = match combat_room {
+            Some(room) => RoomClearTimer {
+                room: Some(room.id),
+                elapsed: 0.0,
+                par: (biome_par(biome.biome_type) + PAR_PER_CLEARED_ROOM * game_state.rooms_cleared as f32).min(PAR_MAX),
+            },
+            None => RoomClearTimer::default(),
+        };
+    }
+}
+
+fn tick_clear_timer(time: Res<Time>, mut timer: ResMut<RoomClearTimer>) {
+    if timer.room.is_some() {
+        timer.elapsed += time.delta_seconds();
+    }
+}
+
+fn finish_clear_timer(
+    mut commands: Commands,
+    mut cleared_events: EventReader<RoomClearedEvent>,
+    mut timer: ResMut<RoomClearTimer>,
+    mut boon_data: ResMut<BoonData>,
+    heat: Res<HeatModifiers>,
+    mut players: Query<&mut Stats, (With<Player>, Without<Downed>)>,
+) {
+    for event in cleared_events.read() {
+        if !matches!(event.room_type, RoomType::Combat) || timer.room != Some(event.room) {
+            continue;
+        }
+        let (elapsed, par) = (timer.elapsed, timer.par);
+        timer.room = None;
+        if elapsed > par {
+            info!("⏱️ Room cleared in {:.1}s (par {:.0}s)", elapsed, par);
+            continue;
+        }
+
+        boon_data.coins += SWIFT_CLEAR_COINS;
+        for mut stats in &mut players {
+            let heal = stats.max_health * SWIFT_CLEAR_HEAL_FRACTION * heat.healing_multiplier();
+            stats.current_health = (stats.current_health + heal).min(stats.max_health);
+        }
+        spawn_swift_flourish(&mut commands, elapsed, par);
+        info!("⏱️ Swift clearance: {:.1}s under par {:.0}s (+{} coins)", elapsed, par, SWIFT_CLEAR_COINS);
+    }
+}
+
+fn spawn_swift_flourish(commands: &mut Commands, elapsed: f32, par: f32) {
+    commands.spawn((
+        TextBundle::from_section(
+            format!("Limpeza Veloz!\n{:.1}s (par {:.0}s)  +{} moedas", elapsed, par, SWIFT_CLEAR_COINS),
+            TextStyle {
+                font_size: 34.0,
+                color: Color::rgb(1.0, 0.85, 0.3),
+                ..default()
+            },
+        ).with_text_justify(JustifyText::Center)
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(22.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        }),
+        SwiftClearFlourish { timer: SWIFT_FLOURISH_DURATION },
+    ));
+}
+
+// Segura e depois esmaece (só corre em jogo; a escolha de recompensa fica por cima)
+fn animate_swift_flourish(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut flourishes: Query<(Entity, &mut SwiftClearFlourish, &mut Text)>,
+) {
+    for (entity, mut flourish, mut text) in &mut flourishes {
+        flourish.timer -= time.delta_seconds();
+        if flourish.timer <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        let alpha = (flourish.timer / 0.8).min(1.0);
+        for section in &mut text.sections {
+            section.style.color.set_a(alpha);
+        }
+    }
+}
+
+fn setup_clear_timer_display(mut commands: Commands) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 18.0,
+                color: Color::rgba(0.9, 0.85, 0.7, 0.6),
+                ..default()
+            },
+        ).with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(44.0),
+            left: Val::Percent(47.0),
+            ..default()
+        }),
+        ClearTimerText,
+    ));
+}
+
+// Discreto: some fora das salas de combate e fica vermelho depois do par
+fn update_clear_timer_display(timer: Res<RoomClearTimer>, mut texts: Query<&mut Text, With<ClearTimerText>>) {
+    for mut text in &mut texts {
+        if timer.room.is_none() {
+            text.sections[0].value.clear();
+            continue;
+        }
+        text.sections[0].value = format!("{:.1}s / {:.0}s", timer.elapsed, timer.par);
+        text.sections[0].style.color = if timer.elapsed <= timer.par {
+            Color::rgba(0.9, 0.85, 0.7, 0.6)
+        } else {
+            Color::rgba(1.0, 0.4, 0.3, 0.6)
+        };
+    }
+}
+
+fn cleanup_clear_timer_display(mut commands: Commands, texts: Query<Entity, With<ClearTimerText>>) {
+    for entity in &texts {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// Run nova: sem cronômetro nem destaque da run anterior
+fn reset_clear_timer(
+    mut commands: Commands,
+    mut timer: ResMut<RoomClearTimer>,
+    flourishes: Query<Entity, With<SwiftClearFlourish>>,
+) {
+    *timer = RoomClearTimer::default();
+    for entity in &flourishes {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,409 @@
+/*!
+⚖️ COMBAT TUNING
+Valores de balanceamento (dano, recargas, alcances, stamina, velocidades) num arquivo RON.
+Os valores compilados são o fallback; em builds de debug o arquivo é recarregado ao ser salvo
+*/
+
+use bevy::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fs;
+use std::time::SystemTime;
+use crate::{AoeFalloff, Combat, Dash, Player, Stats, WeaponKind};
+
+const TUNING_PATH: &str = "assets/config/combat_tuning.ron";
+// Intervalo entre verificações do arquivo (hot-reload)
+const RELOAD_POLL_INTERVAL: f32 = 0.5;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayerTuning {
+    pub max_health: f32,
+    pub max_stamina: f32,
+    pub speed: f32,
+    pub stamina_regen_rate: f32,
+}
+
+impl Default for PlayerTuning {
+    fn default() -> Self {
+        Self {
+            max_health: 100.0,
+            max_stamina: 100.0,
+            speed: 8.0,
+            stamina_regen_rate: 50.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DashTuning {
+    pub cooldown: f32,
+    pub distance: f32,
+    pub i_frames: f32,
+    pub stamina_cost: f32,
+}
+
+impl Default for DashTuning {
+    fn default() -> Self {
+        let dash = Dash::default();
+        Self {
+            cooldown: dash.cooldown,
+            distance: dash.distance,
+            i_frames: dash.i_frames,
+            stamina_cost: dash.stamina_cost,
+        }
+    }
+}
+
+// Valores por arma: primário e projétil do Q. No arquivo cada bloco pode ser parcial:
+// o que faltar vem dos valores compilados daquela arma (ver WeaponTuningPatch)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WeaponTuning {
+    pub base_damage: i32,
+    pub atk_cd: f32,
+    pub hit_range: f32,
+    pub q_cd: f32,
+    pub q_damage: i32,
+    pub q_speed: f32,
+    pub q_ttl: f32,
+}
+
+impl WeaponTuning {
+    fn compiled(weapon: WeaponKind) -> Self {
+        let (q_damage, q_speed, q_ttl) = match weapon {
+            WeaponKind::Khopesh => (12, 20.0, 2.5),
+            WeaponKind::Spear => (18, 28.0, 1.2),
+            WeaponKind::WasScepter => (22, 14.0, 3.0),
+            WeaponKind::ShieldKhopesh => (10, 18.0, 2.0),
+        };
+        Self {
+            base_damage: weapon.base_damage(),
+            atk_cd: weapon.atk_cd(),
+            hit_range: weapon.hit_range(),
+            q_cd: weapon.q_cd(),
+            q_damage,
+            q_speed,
+            q_ttl,
+        }
+    }
+}
+
+// Bloco de arma lido do RON: campos ausentes ficam None e são preenchidos pela arma certa
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct WeaponTuningPatch {
+    base_damage: Option<i32>,
+    atk_cd: Option<f32>,
+    hit_range: Option<f32>,
+    q_cd: Option<f32>,
+    q_damage: Option<i32>,
+    q_speed: Option<f32>,
+    q_ttl: Option<f32>,
+}
+
+impl WeaponTuningPatch {
+    fn over(self, weapon: WeaponKind) -> WeaponTuning {
+        let compiled = WeaponTuning::compiled(weapon);
+        WeaponTuning {
+            base_damage: self.base_damage.unwrap_or(compiled.base_damage),
+            atk_cd: self.atk_cd.unwrap_or(compiled.atk_cd),
+            hit_range: self.hit_range.unwrap_or(compiled.hit_range),
+            q_cd: self.q_cd.unwrap_or(compiled.q_cd),
+            q_damage: self.q_damage.unwrap_or(compiled.q_damage),
+            q_speed: self.q_speed.unwrap_or(compiled.q_speed),
+            q_ttl: self.q_ttl.unwrap_or(compiled.q_ttl),
+        }
+    }
+}
+
+fn khopesh_tuning<'de, D: Deserializer<'de>>(deserializer: D) -> Result<WeaponTuning, D::Error> {
+    WeaponTuningPatch::deserialize(deserializer).map(|patch| patch.over(WeaponKind::Khopesh))
+}
+
+fn spear_tuning<'de, D: Deserializer<'de>>(deserializer: D) -> Result<WeaponTuning, D::Error> {
+    WeaponTuningPatch::deserialize(deserializer).map(|patch| patch.over(WeaponKind::Spear))
+}
+
+fn was_scepter_tuning<'de, D: Deserializer<'de>>(deserializer: D) -> Result<WeaponTuning, D::Error> {
+    WeaponTuningPatch::deserialize(deserializer).map(|patch| patch.over(WeaponKind::WasScepter))
+}
+
+fn shield_khopesh_tuning<'de, D: Deserializer<'de>>(deserializer: D) -> Result<WeaponTuning, D::Error> {
+    WeaponTuningPatch::deserialize(deserializer).map(|patch| patch.over(WeaponKind::ShieldKhopesh))
+}
+
+// Golpes compartilhados por todas as armas
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AttackTuning {
+    pub chain_bonus_damage: i32, // Dano extra por passo da sequência de 3
+    pub chain_window: f32, // Tempo para acertar o próximo golpe antes da sequência recomeçar
+    pub finisher_damage_mult: f32, // Terceiro golpe da sequência
+    pub finisher_knockback: f32,
+    pub charge_threshold: f32,
+    pub heavy_damage_mult: f32,
+    pub heavy_range_mult: f32,
+    pub heavy_knockback: f32, // Empurrões: distância percorrida pelo inimigo até parar
+    pub primary_knockback: f32,
+    pub dash_strike_knockback: f32,
+    pub projectile_knockback: f32,
+    pub aoe_knockback: f32,
+    pub knockback_decay: f32, // Desaceleração do empurrão (1/s)
+    pub special_cd: f32,
+    pub dash_strike_damage_mult: f32,
+    pub dash_strike_range_mult: f32,
+    pub dash_strike_max_distance: f32,
+    pub parry_window: f32,
+    pub r_cd: f32,
+    pub aoe_damage_mult: f32,
+    pub aoe_radius: f32,
+    pub aoe_max_cast_range: f32,
+    pub aoe_falloff: AoeFalloff, // None / Linear / Quadratic
+    pub blink_cd: f32,
+    pub blink_distance: f32,
+    pub blink_stamina_cost: f32,
+    pub blink_i_frames: f32, // Invulnerável logo ao reaparecer
+    pub block_damage_taken: f32, // Fração do dano frontal que passa pelo escudo
+    pub perfect_block_window: f32,
+    pub block_stamina_drain: f32, // Por segundo com o escudo erguido
+    pub perfect_block_stagger: f32, // Atordoamento de quem bate num bloqueio perfeito
+}
+
+impl Default for AttackTuning {
+    fn default() -> Self {
+        let combat = Combat::default();
+        Self {
+            chain_bonus_damage: 2,
+            chain_window: 1.2,
+            finisher_damage_mult: 1.5,
+            finisher_knockback: 1.2,
+            charge_threshold: combat.charge_threshold,
+            heavy_damage_mult: 2.5,
+            heavy_range_mult: 1.5,
+            heavy_knockback: 2.5,
+            primary_knockback: 0.35,
+            dash_strike_knockback: 1.0,
+            projectile_knockback: 0.25,
+            aoe_knockback: 3.0,
+            knockback_decay: 10.0,
+            special_cd: combat.special_cd,
+            dash_strike_damage_mult: 1.8,
+            dash_strike_range_mult: 1.35,
+            dash_strike_max_distance: 4.0,
+            parry_window: 0.25,
+            r_cd: combat.r_cd,
+            aoe_damage_mult: 2.4,
+            aoe_radius: 2.6,
+            aoe_max_cast_range: 8.0,
+            aoe_falloff: combat.aoe_falloff,
+            blink_cd: combat.blink_cd,
+            blink_distance: 6.0,
+            blink_stamina_cost: 30.0,
+            blink_i_frames: 0.3,
+            block_damage_taken: 0.3,
+            perfect_block_window: 0.2,
+            block_stamina_drain: 20.0,
+            perfect_block_stagger: 0.8,
+        }
+    }
+}
+
+/// Botões de balanceamento do combate do jogador
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CombatTuning {
+    pub player: PlayerTuning,
+    pub dash: DashTuning,
+    #[serde(deserialize_with = "khopesh_tuning")]
+    pub khopesh: WeaponTuning,
+    #[serde(deserialize_with = "spear_tuning")]
+    pub spear: WeaponTuning,
+    #[serde(deserialize_with = "was_scepter_tuning")]
+    pub was_scepter: WeaponTuning,
+    #[serde(deserialize_with = "shield_khopesh_tuning")]
+    pub shield_khopesh: WeaponTuning,
+    pub attacks: AttackTuning,
+}
+
+impl Default for CombatTuning {
+    fn default() -> Self {
+        Self {
+            player: PlayerTuning::default(),
+            dash: DashTuning::default(),
+            khopesh: WeaponTuning::compiled(WeaponKind::Khopesh),
+            spear: WeaponTuning::compiled(WeaponKind::Spear),
+            was_scepter: WeaponTuning::compiled(WeaponKind::WasScepter),
+            shield_khopesh: WeaponTuning::compiled(WeaponKind::ShieldKhopesh),
+            attacks: AttackTuning::default(),
+        }
+    }
+}
+
+impl CombatTuning {
+    pub fn load() -> Self {
+        match fs::read_to_string(TUNING_PATH) {
+            Ok(contents) => Self::parse(&contents).unwrap_or_else(|err| {
+                warn!("⚠️ Invalid combat tuning file, using compiled defaults: {}", err);
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    // Campos opcionais dos blocos de arma aceitam o valor direto (sem Some(...))
+    fn parse(contents: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(contents)
+    }
+
+    pub fn weapon(&self, weapon: WeaponKind) -> &WeaponTuning {
+        match weapon {
+            WeaponKind::Khopesh => &self.khopesh,
+            WeaponKind::Spear => &self.spear,
+            WeaponKind::WasScepter => &self.was_scepter,
+            WeaponKind::ShieldKhopesh => &self.shield_khopesh,
+        }
+    }
+
+    pub fn player_stats(&self) -> Stats {
+        Stats {
+            max_health: self.player.max_health,
+            current_health: self.player.max_health,
+            max_stamina: self.player.max_stamina,
+            current_stamina: self.player.max_stamina,
+            speed: self.player.speed,
+            stamina_regen_rate: self.player.stamina_regen_rate,
+            poise: 0.0,
+            knockback_multiplier: 1.0,
+        }
+    }
+
+    pub fn dash(&self) -> Dash {
+        let mut dash = Dash::default();
+        self.apply_to_dash(&mut dash);
+        dash
+    }
+
+    pub fn combat_for(&self, weapon: WeaponKind) -> Combat {
+        let mut combat = Combat::for_weapon(weapon);
+        self.apply_to_combat(&mut combat);
+        combat
+    }
+
+    // Só os valores ajustáveis: timers e estado do combate são preservados
+    fn apply_to_combat(&self, combat: &mut Combat) {
+        let weapon = self.weapon(combat.weapon);
+        combat.base_damage = weapon.base_damage;
+        combat.atk_cd = weapon.atk_cd;
+        combat.q_cd = weapon.q_cd;
+        combat.charge_threshold = self.attacks.charge_threshold;
+        combat.special_cd = self.attacks.special_cd;
+        combat.r_cd = self.attacks.r_cd;
+        combat.blink_cd = self.attacks.blink_cd;
+        combat.aoe_falloff = self.attacks.aoe_falloff;
+    }
+
+    fn apply_to_dash(&self, dash: &mut Dash) {
+        dash.cooldown = self.dash.cooldown;
+        dash.distance = self.dash.distance;
+        dash.i_frames = self.dash.i_frames;
+        dash.stamina_cost = self.dash.stamina_cost;
+    }
+
+    // Mantém a fração de vida/stamina atual ao mudar os máximos
+    fn apply_to_stats(&self, stats: &mut Stats) {
+        let health_fraction = stats.current_health / stats.max_health.max(1.0);
+        let stamina_fraction = stats.current_stamina / stats.max_stamina.max(1.0);
+        stats.max_health = self.player.max_health;
+        stats.max_stamina = self.player.max_stamina;
+        stats.current_health = stats.max_health * health_fraction;
+        stats.current_stamina = stats.max_stamina * stamina_fraction;
+        stats.speed = self.player.speed;
+        stats.stamina_regen_rate = self.player.stamina_regen_rate;
+    }
+}
+
+pub struct CombatTuningPlugin;
+
+impl Plugin for CombatTuningPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(CombatTuning::load());
+        if cfg!(debug_assertions) {
+            app.add_systems(Update, (hot_reload_tuning, apply_tuning_to_players).chain());
+        }
+    }
+}
+
+// Debug: relê o arquivo quando a data de modificação muda
+fn hot_reload_tuning(
+    time: Res<Time<Real>>,
+    mut poll_timer: Local<f32>,
+    mut last_modified: Local<Option<SystemTime>>,
+    mut tuning: ResMut<CombatTuning>,
+) {
+    *poll_timer -= time.delta_seconds();
+    if *poll_timer > 0.0 {
+        return;
+    }
+    *poll_timer = RELOAD_POLL_INTERVAL;
+
+    let Ok(modified) = fs::metadata(TUNING_PATH).and_then(|metadata| metadata.modified()) else {
+        return;
+    };
+    // Primeira leitura: o arquivo já foi carregado na inicialização
+    let Some(previous) = last_modified.replace(modified) else {
+        return;
+    };
+    if previous == modified {
+        return;
+    }
+
+    match fs::read_to_string(TUNING_PATH).map_err(|err| err.to_string())
+        .and_then(|contents| CombatTuning::parse(&contents).map_err(|err| err.to_string()))
+    {
+        Ok(reloaded) => {
+            *tuning = reloaded;
+            info!("⚖️ Combat tuning reloaded from {}", TUNING_PATH);
+        }
+        // Arquivo inválido no meio da edição: mantém os valores atuais
+        Err(err) => warn!("⚠️ Combat tuning not reloaded: {}", err),
+    }
+}
+
+// Aplica os valores recarregados aos jogadores já em jogo
+fn apply_tuning_to_players(
+    tuning: Res<CombatTuning>,
+    mut players: Query<(&mut Combat, &mut Dash, &mut Stats), With<Player>>,
+) {
+    if !tuning.is_changed() || tuning.is_added() {
+        return;
+    }
+    for (mut combat, mut dash, mut stats) in &mut players {
+        tuning.apply_to_combat(&mut combat);
+        tuning.apply_to_dash(&mut dash);
+        tuning.apply_to_stats(&mut stats);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partial_weapon_block_keeps_that_weapons_defaults() {
+        let tuning = CombatTuning::parse("(spear: (base_damage: 40), khopesh: ())").unwrap();
+        let compiled = WeaponTuning::compiled(WeaponKind::Spear);
+        assert_eq!(tuning.spear, WeaponTuning { base_damage: 40, ..compiled });
+        // Bloco vazio ou ausente: valores compilados da própria arma
+        assert_eq!(tuning.khopesh, WeaponTuning::compiled(WeaponKind::Khopesh));
+        assert_eq!(tuning.was_scepter, WeaponTuning::compiled(WeaponKind::WasScepter));
+    }
+
+    #[test]
+    fn shipped_tuning_file_parses() {
+        let contents = fs::read_to_string(TUNING_PATH).unwrap();
+        let tuning = CombatTuning::parse(&contents).unwrap();
+        assert!(tuning.spear.base_damage > 0);
+    }
+}
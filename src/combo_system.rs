@@ -0,0 +1,176 @@
+/*!
+🔥 COMBO SYSTEM
+Contador de golpes/abates em sequência: decai sem dano e concede buffs temporários por patamar
+*/
+
+use bevy::prelude::*;
+use crate::ui::{AppState, DamageEvent, DamageType};
+use crate::{EnemyKilledEvent, SimulationSet};
+
+// Sem causar dano por este tempo, o combo zera
+const COMBO_DECAY_TIME: f32 = 3.0;
+// Abates valem mais que golpes
+const COMBO_KILL_BONUS: u32 = 2;
+// Patamares: (combo mínimo, bônus de dano, bônus de velocidade de recarga)
+const COMBO_TIERS: [(u32, f32, f32); 3] = [
+    (10, 0.10, 0.10),
+    (25, 0.20, 0.20),
+    (50, 0.35, 0.30),
+];
+const COMBO_BUFF_DURATION: f32 = 6.0;
+const COMBO_FLOURISH_DURATION: f32 = 0.4;
+
+#[derive(Resource, Default)]
+pub struct ComboMeter {
+    pub count: u32,
+    pub idle_time: f32,
+    // Buff do último patamar atingido (0 = nenhum), dura mesmo se o combo cair
+    pub buff_tier: usize,
+    pub buff_timer: f32,
+    flourish_timer: f32,
+}
+
+impl ComboMeter {
+    fn register(&mut self, amount: u32) {
+        self.count += amount;
+        self.idle_time = 0.0;
+
+        let tier = COMBO_TIERS.iter()
+            .filter(|(threshold, _, _)| self.count >= *threshold)
+            .count();
+        // Manter o patamar renova o buff; subir de patamar dispara o destaque visual
+        if tier > 0 && tier >= self.buff_tier {
+            if tier > self.buff_tier {
+                self.flourish_timer = COMBO_FLOURISH_DURATION;
+                info!("🔥 Combo tier {} reached ({} hits)", tier, self.count);
+            }
+            self.buff_tier = tier;
+            self.buff_timer = COMBO_BUFF_DURATION;
+        }
+    }
+
+    pub fn damage_multiplier(&self) -> f32 {
+        match self.buff_tier {
+            0 => 1.0,
+            tier => 1.0 + COMBO_TIERS[tier - 1].1,
+        }
+    }
+
+    // Multiplica a velocidade com que as recargas descem
+    pub fn cooldown_rate(&self) -> f32 {
+        match self.buff_tier {
+            0 => 1.0,
+            tier => 1.0 + COMBO_TIERS[tier - 1].2,
+        }
+    }
+}
+
+#[derive(Component)]
+struct ComboText;
+
+pub struct ComboPlugin;
+
+impl Plugin for ComboPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ComboMeter>()
+            .add_systems(OnEnter(AppState::InGame), setup_combo_display)
+            .add_systems(OnExit(AppState::InGame), cleanup_combo_display)
+            // O combo multiplica o dano dos golpes: conta no passo fixo, depois das mortes do passo
+            .add_systems(FixedUpdate, combo_tracking_system
+                .after(crate::enemy_death_system)
+                .in_set(SimulationSet)
+                .run_if(in_state(AppState::InGame)))
+            .add_systems(Update, update_combo_display.run_if(in_state(AppState::InGame)))
+            .add_systems(OnEnter(AppState::Loading), reset_combo);
+    }
+}
+
+// Run nova: combo e buff do patamar zerados
+fn reset_combo(mut combo: ResMut<ComboMeter>) {
+    *combo = ComboMeter::default();
+}
+
+fn combo_tracking_system(
+    time: Res<Time>,
+    mut combo: ResMut<ComboMeter>,
+    mut damage_events: EventReader<DamageEvent>,
+    mut kill_events: EventReader<EnemyKilledEvent>,
+) {
+    let dt = time.delta_seconds();
+
+    // Só os nossos golpes contam (dano recebido/cura não)
+    let hits = damage_events.read()
+        .filter(|event| matches!(event.damage_type, DamageType::Enemy | DamageType::Critical))
+        .count() as u32;
+    let kills = kill_events.read().count() as u32;
+    if hits + kills > 0 {
+        combo.register(hits + kills * COMBO_KILL_BONUS);
+    } else {
+        combo.idle_time += dt;
+        if combo.idle_time >= COMBO_DECAY_TIME && combo.count > 0 {
+            combo.count = 0;
+        }
+    }
+
+    if combo.buff_tier > 0 {
+        combo.buff_timer -= dt;
+        if combo.buff_timer <= 0.0 {
+            combo.buff_tier = 0;
+        }
+    }
+    combo.flourish_timer = (combo.flourish_timer - dt).max(0.0);
+}
+
+fn setup_combo_display(mut commands: Commands) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 28.0,
+                color: Color::rgb(0.9, 0.8, 0.4),
+                ..default()
+            },
+        ).with_style(Style {
+            position_type: PositionType::Absolute,
+            right: Val::Px(60.0),
+            top: Val::Percent(40.0),
+            ..default()
+        }),
+        ComboText,
+    ));
+}
+
+fn cleanup_combo_display(mut commands: Commands, texts: Query<Entity, With<ComboText>>) {
+    for entity in &texts {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn update_combo_display(
+    combo: Res<ComboMeter>,
+    mut texts: Query<&mut Text, With<ComboText>>,
+) {
+    for mut text in &mut texts {
+        if combo.count < 2 && combo.buff_tier == 0 {
+            text.sections[0].value.clear();
+            continue;
+        }
+
+        let mut label = format!("Combo x{}", combo.count);
+        if combo.buff_tier > 0 {
+            label.push_str(&format!("\n+{:.0}% dano", (combo.damage_multiplier() - 1.0) * 100.0));
+        }
+        text.sections[0].value = label;
+
+        // Cada patamar deixa o texto maior e mais quente; o pulso marca a subida de patamar
+        let (color, size) = match combo.buff_tier {
+            0 => (Color::rgb(0.9, 0.8, 0.4), 28.0),
+            1 => (Color::rgb(1.0, 0.6, 0.2), 34.0),
+            2 => (Color::rgb(1.0, 0.35, 0.1), 40.0),
+            _ => (Color::rgb(1.0, 0.15, 0.3), 48.0),
+        };
+        let pulse = 1.0 + 0.5 * (combo.flourish_timer / COMBO_FLOURISH_DURATION);
+        text.sections[0].style.color = color;
+        text.sections[0].style.font_size = size * pulse;
+    }
+}
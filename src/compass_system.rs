@@ -0,0 +1,136 @@
+/*!
+🧭 COMPASS
+Marcador na borda da tela apontando para a porta do próximo passo rumo à sala do chefe
+*/
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use crate::settings::GameSettings;
+use crate::ui::AppState;
+use crate::{GameState, Room, RoomTransition, RoomType};
+
+// Distância mínima das bordas da tela (px)
+const COMPASS_EDGE_MARGIN: f32 = 60.0;
+const COMPASS_SIZE: f32 = 22.0;
+
+#[derive(Component)]
+struct CompassMarker;
+
+pub struct CompassPlugin;
+
+impl Plugin for CompassPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::InGame), setup_compass)
+            .add_systems(OnExit(AppState::InGame), cleanup_compass)
+            .add_systems(Update, update_compass.run_if(in_state(AppState::InGame)));
+    }
+}
+
+// BFS pelas transições a partir da sala atual: devolve a porta (posição, desbloqueada) do
+// primeiro passo no caminho mais curto até uma sala de chefe
+fn next_door_towards_boss(current: usize, rooms: &[&Room], transitions: &[&RoomTransition]) -> Option<(Vec3, bool)> {
+    let is_boss = |id: usize| rooms.iter().any(|room| room.id == id && matches!(room.room_type, RoomType::Boss));
+    if is_boss(current) {
+        return None;
+    }
+
+    // Cada nó guarda a primeira porta usada para chegar nele
+    let mut visited = vec![current];
+    let mut queue: VecDeque<(usize, Option<&RoomTransition>)> = VecDeque::from([(current, None)]);
+    while let Some((room_id, first_door)) = queue.pop_front() {
+        for transition in transitions.iter().filter(|transition| transition.from_room == room_id) {
+            if visited.contains(&transition.to_room) {
+                continue;
+            }
+            let door = first_door.unwrap_or(transition);
+            if is_boss(transition.to_room) {
+                return Some((door.position, door.active));
+            }
+            visited.push(transition.to_room);
+            queue.push_back((transition.to_room, Some(door)));
+        }
+    }
+    None
+}
+
+fn setup_compass(mut commands: Commands) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Px(COMPASS_SIZE),
+                height: Val::Px(COMPASS_SIZE),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            border_color: Color::rgb(0.2, 0.1, 0.05).into(),
+            background_color: Color::rgb(1.0, 0.8, 0.3).into(),
+            transform: Transform::from_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        CompassMarker,
+    ));
+}
+
+fn cleanup_compass(mut commands: Commands, markers: Query<Entity, With<CompassMarker>>) {
+    for entity in &markers {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn update_compass(
+    settings: Res<GameSettings>,
+    game_state: Res<GameState>,
+    rooms: Query<&Room>,
+    transitions: Query<&RoomTransition>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    ui_scale: Res<UiScale>,
+    mut markers: Query<(&mut Style, &mut Visibility, &mut BackgroundColor), With<CompassMarker>>,
+) {
+    let Ok((mut style, mut visibility, mut color)) = markers.get_single_mut() else {
+        return;
+    };
+
+    let room_list: Vec<&Room> = rooms.iter().collect();
+    let transition_list: Vec<&RoomTransition> = transitions.iter().collect();
+    let door = if settings.show_compass {
+        next_door_towards_boss(game_state.current_room, &room_list, &transition_list)
+    } else {
+        None
+    };
+    let camera = cameras.iter()
+        .filter(|(camera, _)| camera.is_active)
+        .max_by_key(|(camera, _)| camera.order);
+
+    let (Some((door_position, unlocked)), Some((camera, camera_transform))) = (door, camera) else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+    let (Some(viewport), Some(screen_pos)) = (
+        camera.logical_viewport_size(),
+        camera.world_to_viewport(camera_transform, door_position),
+    ) else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+
+    // Porta visível: marcador sobre ela; fora da tela: preso à borda na direção da porta
+    let center = viewport / 2.0;
+    let half_extent = center - Vec2::splat(COMPASS_EDGE_MARGIN);
+    let offset = screen_pos - center;
+    let overshoot = (offset.abs() / half_extent).max_element();
+    let marker_pos = if overshoot > 1.0 { center + offset / overshoot } else { screen_pos };
+    // Pixels da viewport -> unidades da UI (multiplicadas pelo UiScale no layout)
+    let marker_pos = marker_pos / ui_scale.0;
+
+    style.left = Val::Px(marker_pos.x - COMPASS_SIZE / 2.0);
+    style.top = Val::Px(marker_pos.y - COMPASS_SIZE / 2.0);
+    *visibility = Visibility::Visible;
+    // Porta ainda trancada (sala não limpa): marcador apagado
+    *color = if unlocked {
+        Color::rgb(1.0, 0.8, 0.3).into()
+    } else {
+        Color::rgba(0.6, 0.5, 0.3, 0.5).into()
+    };
+}
@@ -1,8 +1,30 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 #[derive(Component)]
 pub struct Player;
 
+// Jogadores locais suportados (co-op no mesmo computador)
+pub const MAX_PLAYERS: usize = 2;
+
+// Qual jogador local controla a entidade: P1 no teclado/mouse, P2 no gamepad
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PlayerId(pub u8);
+
+impl PlayerId {
+    pub const P1: PlayerId = PlayerId(0);
+    pub const P2: PlayerId = PlayerId(1);
+
+    pub fn index(self) -> usize {
+        (self.0 as usize).min(MAX_PLAYERS - 1)
+    }
+
+    // HUD, câmera e mira do mouse acompanham o P1
+    pub fn is_primary(self) -> bool {
+        self == Self::P1
+    }
+}
+
 #[derive(Component)]
 pub struct Stats {
     pub max_health: f32,
@@ -11,6 +33,8 @@ pub struct Stats {
     pub current_stamina: f32,
     pub speed: f32,
     pub stamina_regen_rate: f32,
+    pub poise: f32, // golpe com dano >= poise interrompe o ataque em preparação (stagger)
+    pub knockback_multiplier: f32, // fração do empurrão sofrida (Tanks resistem; 0 = imóvel)
 }
 
 impl Default for Stats {
@@ -22,10 +46,23 @@ impl Default for Stats {
             current_stamina: 100.0,
             speed: 9.5, // Faster like Hades
             stamina_regen_rate: 25.0, // Stamina per second
+            poise: 0.0,
+            knockback_multiplier: 1.0,
         }
     }
 }
 
+/// Jogador caído no co-op: não se move, não ataca e não é alvo até ser revivido
+#[derive(Component)]
+pub struct Downed {
+    pub revive_progress: f32,
+    pub standing_rotation: Quat, // Volta a ficar de pé com a rotação de antes da queda
+}
+
+// Velocidade atual (unidades/s), escrita pelo movimento do jogador a cada quadro
+#[derive(Component, Default, Clone, Copy)]
+pub struct Velocity(pub Vec3);
+
 #[derive(Component)]
 pub struct Dash {
     pub cooldown: f32,
@@ -55,37 +92,191 @@ impl Default for Dash {
     }
 }
 
+// Arquétipos de arma escolhidos no início da run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum WeaponKind {
+    #[default]
+    Khopesh,    // Lâmina curva - corpo a corpo rápido
+    Spear,      // Lança - alcance longo, golpes lentos
+    WasScepter, // Cetro Was - conjurador, Q poderoso
+    ShieldKhopesh, // Khopesh e escudo - segura o secundário para bloquear
+}
+
+impl WeaponKind {
+    pub fn get_all() -> Vec<Self> {
+        vec![WeaponKind::Khopesh, WeaponKind::Spear, WeaponKind::WasScepter, WeaponKind::ShieldKhopesh]
+    }
+
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            WeaponKind::Khopesh => "Khopesh",
+            WeaponKind::Spear => "Lança de Hórus",
+            WeaponKind::WasScepter => "Cetro Was",
+            WeaponKind::ShieldKhopesh => "Khopesh e Escudo",
+        }
+    }
+
+    pub fn base_damage(&self) -> i32 {
+        match self {
+            WeaponKind::Khopesh => 10,
+            WeaponKind::Spear => 12,
+            WeaponKind::WasScepter => 7,
+            WeaponKind::ShieldKhopesh => 9,
+        }
+    }
+
+    pub fn atk_cd(&self) -> f32 {
+        match self {
+            WeaponKind::Khopesh => 0.25,
+            WeaponKind::Spear => 0.4,
+            WeaponKind::WasScepter => 0.3,
+            WeaponKind::ShieldKhopesh => 0.3,
+        }
+    }
+
+    pub fn hit_range(&self) -> f32 {
+        match self {
+            WeaponKind::Khopesh => 1.6,
+            WeaponKind::Spear => 2.6,
+            WeaponKind::WasScepter => 1.4,
+            WeaponKind::ShieldKhopesh => 1.5,
+        }
+    }
+
+    pub fn q_cd(&self) -> f32 {
+        match self {
+            WeaponKind::WasScepter => 0.8,
+            _ => 1.2,
+        }
+    }
+
+    // Custo em essência para desbloquear (0 = inicial)
+    pub fn unlock_cost(&self) -> u32 {
+        match self {
+            WeaponKind::Khopesh => 0,
+            WeaponKind::Spear => 30,
+            WeaponKind::WasScepter => 60,
+            WeaponKind::ShieldKhopesh => 45,
+        }
+    }
+
+    // Armas com escudo trocam o golpe com avanço do secundário pelo bloqueio
+    pub fn has_shield(&self) -> bool {
+        matches!(self, WeaponKind::ShieldKhopesh)
+    }
+}
+
+// Dano que sobra na borda de uma área (o centro sempre recebe o dano cheio)
+pub const AOE_EDGE_DAMAGE: f32 = 0.5;
+
+// Curva de queda do dano em área conforme a distância do centro
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AoeFalloff {
+    None,      // Dano cheio em todo o raio
+    #[default]
+    Linear,
+    Quadratic, // Cai devagar perto do centro e rápido perto da borda
+}
+
+impl AoeFalloff {
+    // Multiplicador de dano a `distance` do centro de uma área de raio `radius`
+    pub fn damage_multiplier(&self, distance: f32, radius: f32) -> f32 {
+        let t = (distance / radius.max(0.01)).clamp(0.0, 1.0);
+        let drop = match self {
+            AoeFalloff::None => 0.0,
+            AoeFalloff::Linear => t,
+            AoeFalloff::Quadratic => t * t,
+        };
+        1.0 - (1.0 - AOE_EDGE_DAMAGE) * drop
+    }
+}
+
 #[derive(Component)]
 pub struct Combat {
+    pub weapon: WeaponKind,
     pub base_damage: i32,
+    // crítico: chance por acerto (vinda das bênçãos) e multiplicador do dano
+    pub crit_chance: f32,
+    pub crit_multiplier: f32,
     // primário (mouse esq) – chain de 3
     pub atk_cd: f32,
     pub atk_timer: f32,
     pub chain_step: u8,
+    pub chain_timer: f32, // janela para o próximo golpe da sequência; acabou, volta ao primeiro
+    // ataque carregado (segurar o primário)
+    pub charge_time: f32,
+    pub charge_threshold: f32,
     // secundário (mouse dir) – especial leve
     pub special_cd: f32,
     pub special_timer: f32,
+    pub dash_strike_pending: bool, // golpe aguardando o fim do avanço
+    pub parry_timer: f32, // janela em que projéteis inimigos são rebatidos
+    // bloqueio (armas com escudo: segurar o secundário)
+    pub blocking: bool,
+    pub block_direction: Vec3, // para onde o escudo aponta (mira)
+    pub perfect_block_timer: f32, // logo ao erguer o escudo: bloqueio total que atordoa
     // Q – cast/projétil
     pub q_cd: f32,
     pub q_timer: f32,
+    pub projectile_count: u32, // projéteis por disparo, em leque (bênçãos de múltiplos tiros)
     // R – habilidade principal (AoE)
     pub r_cd: f32,
     pub r_timer: f32,
+    pub r_aim_time: f32, // tempo segurando R (mira no chão)
+    pub aoe_falloff: AoeFalloff, // queda do dano do R e do dash-strike até a borda
+    pub area_multiplier: f32, // escala de todos os raios (R, dash-strike, explosões) vinda dos boons de área
+    // Blink (Shift) – teleporte curto em direção à mira
+    pub blink_cd: f32,
+    pub blink_timer: f32,
 }
 
 impl Default for Combat {
     fn default() -> Self {
         Self {
+            weapon: WeaponKind::Khopesh,
             base_damage: 10,
+            crit_chance: 0.0,
+            crit_multiplier: 2.0,
             atk_cd: 0.25,
             atk_timer: 0.0,
             chain_step: 0,
+            chain_timer: 0.0,
+            charge_time: 0.0,
+            charge_threshold: 0.6,
             special_cd: 3.0,
             special_timer: 0.0,
+            dash_strike_pending: false,
+            parry_timer: 0.0,
+            blocking: false,
+            block_direction: Vec3::NEG_Z,
+            perfect_block_timer: 0.0,
             q_cd: 1.2,
             q_timer: 0.0,
+            projectile_count: 1,
             r_cd: 8.0,
             r_timer: 0.0,
+            r_aim_time: 0.0,
+            aoe_falloff: AoeFalloff::default(),
+            area_multiplier: 1.0,
+            blink_cd: 2.5,
+            blink_timer: 0.0,
+        }
+    }
+}
+
+impl Combat {
+    /// Multiplicador de dano de um acerto (1 quando não foi crítico)
+    pub fn critical_multiplier(&self, critical: bool) -> f32 {
+        if critical { self.crit_multiplier } else { 1.0 }
+    }
+
+    pub fn for_weapon(weapon: WeaponKind) -> Self {
+        Self {
+            weapon,
+            base_damage: weapon.base_damage(),
+            atk_cd: weapon.atk_cd(),
+            q_cd: weapon.q_cd(),
+            ..default()
         }
     }
-}
\ No newline at end of file
+}
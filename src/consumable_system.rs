@@ -0,0 +1,193 @@
+/*!
+🧪 CONSUMABLE SYSTEM
+Drops sorteados na tabela de saque dos inimigos, coleta e uso de itens do inventário (tecla F)
+Bombas são arremessadas em arco até o cursor e explodem após um pavio curto
+*/
+
+use bevy::prelude::*;
+use rand::Rng;
+use crate::inventory::{ConsumableKind, Inventory};
+use crate::meta_progression::HeatModifiers;
+use crate::ui::AppState;
+use crate::arc_projectile::{ArcProjectile, spawn_arc_projectile};
+use crate::procedural::{RunRng, RunRngStream};
+use crate::{Combat, Player, PlayerId, Stats, FixedInput, EnemyKilledEvent, SimulationSet, SpawnParticlesEvent};
+
+const PICKUP_RADIUS: f32 = 1.0;
+
+const POTION_HEAL: f32 = 35.0;
+const BOMB_RADIUS: f32 = 3.0;
+const BOMB_DAMAGE: f32 = 40.0;
+const BOMB_MAX_THROW_RANGE: f32 = 9.0;
+const BOMB_FLIGHT_TIME: f32 = 0.7;
+const BOMB_ARC_HEIGHT: f32 = 3.0;
+const BOMB_FUSE: f32 = 0.5;
+
+#[derive(Component)]
+pub struct ConsumablePickup {
+    pub kind: ConsumableKind,
+}
+
+pub struct ConsumablePlugin;
+
+impl Plugin for ConsumablePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Inventory>()
+            .add_systems(Update, (
+                drop_consumables_on_kill,
+                pickup_consumables,
+            ).run_if(in_state(AppState::InGame)))
+            // Usar lê a entrada da simulação (replays)
+            .add_systems(FixedUpdate, use_consumable.in_set(SimulationSet).run_if(in_state(AppState::InGame)))
+            // Run nova começa de bolsos vazios
+            .add_systems(OnEnter(AppState::Loading), empty_inventory);
+    }
+}
+
+fn empty_inventory(mut inventory: ResMut<Inventory>) {
+    inventory.items.clear();
+}
+
+/// Spawns a pickup in the world (drops, chests, shops)
+pub fn spawn_consumable_pickup(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    kind: ConsumableKind,
+    position: Vec3,
+) {
+    let color = kind.get_color();
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Sphere::new(0.25)),
+            material: materials.add(StandardMaterial {
+                base_color: color,
+                emissive: color * 2.0,
+                ..default()
+            }),
+            transform: Transform::from_translation(Vec3::new(position.x, 0.3, position.z)),
+            ..default()
+        },
+        ConsumablePickup { kind },
+        Name::new(format!("Pickup: {}", kind.get_display_name())),
+    ));
+}
+
+fn drop_consumables_on_kill(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut kill_events: EventReader<EnemyKilledEvent>,
+    mut run_rng: ResMut<RunRng>,
+) {
+    let rng = run_rng.stream(RunRngStream::ConsumableDrops);
+
+    for event in kill_events.read() {
+        // Vários drops se espalham em volta do ponto da morte
+        for index in 0..event.consumable_drops {
+            let kind = match rng.gen_range(0..3) {
+                0 => ConsumableKind::HealthPotion,
+                1 => ConsumableKind::StaminaElixir,
+                _ => ConsumableKind::Bomb,
+            };
+            let angle = index as f32 * std::f32::consts::TAU / 3.0;
+            let offset = if index == 0 { Vec3::ZERO } else { Vec3::new(angle.cos(), 0.0, angle.sin()) * 0.8 };
+            spawn_consumable_pickup(&mut commands, &mut meshes, &mut materials, kind, event.position + offset);
+            info!("🧪 Enemy dropped {}", kind.get_display_name());
+        }
+    }
+}
+
+fn pickup_consumables(
+    mut commands: Commands,
+    mut inventory: ResMut<Inventory>,
+    player_query: Query<&Transform, With<Player>>,
+    pickups: Query<(Entity, &Transform, &ConsumablePickup)>,
+) {
+    // Inventário compartilhado: qualquer jogador recolhe
+    for (entity, transform, pickup) in &pickups {
+        let in_reach = player_query.iter().any(|player_transform| {
+            let offset = transform.translation - player_transform.translation;
+            Vec3::new(offset.x, 0.0, offset.z).length() <= PICKUP_RADIUS
+        });
+        if in_reach && inventory.add(pickup.kind) {
+            commands.entity(entity).despawn_recursive();
+            info!("🎒 Picked up {}", pickup.kind.get_display_name());
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn use_consumable(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    fixed_input: Res<FixedInput>,
+    heat: Res<HeatModifiers>,
+    mut inventory: ResMut<Inventory>,
+    mut player_query: Query<(&PlayerId, &Transform, &mut Stats, &Combat), With<Player>>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+) {
+    // Inventário compartilhado: o item vai para quem apertou (P1 primeiro no mesmo passo)
+    let mut users: Vec<_> = player_query.iter_mut()
+        .filter(|(id, ..)| fixed_input.get(**id).use_item)
+        .collect();
+    users.sort_by_key(|(id, ..)| id.index());
+
+    for (id, player_transform, mut player_stats, combat) in users {
+        let input = fixed_input.get(*id);
+        let Some(item) = inventory.take_next() else {
+            return;
+        };
+
+        match item {
+            ConsumableKind::HealthPotion => {
+                let heal = POTION_HEAL * heat.healing_multiplier();
+                player_stats.current_health = (player_stats.current_health + heal).min(player_stats.max_health);
+                particle_events.send(SpawnParticlesEvent {
+                    position: player_transform.translation,
+                    color: item.get_color(),
+                    count: 10,
+                });
+            }
+            ConsumableKind::StaminaElixir => {
+                player_stats.current_stamina = player_stats.max_stamina;
+                particle_events.send(SpawnParticlesEvent {
+                    position: player_transform.translation,
+                    color: item.get_color(),
+                    count: 10,
+                });
+            }
+            ConsumableKind::Bomb => {
+                // Lob toward the cursor, clamped to the max throw range
+                let start = player_transform.translation;
+                let offset = Vec3::new(input.mouse_world_pos.x - start.x, 0.0, input.mouse_world_pos.z - start.z);
+                let target = start + offset.clamp_length_max(BOMB_MAX_THROW_RANGE);
+
+                spawn_arc_projectile(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    ArcProjectile {
+                        start,
+                        target: Vec3::new(target.x, start.y, target.z),
+                        elapsed: 0.0,
+                        flight_time: BOMB_FLIGHT_TIME,
+                        arc_height: BOMB_ARC_HEIGHT,
+                        fuse: BOMB_FUSE,
+                        // Boons de área também aumentam a explosão da bomba
+                        radius: BOMB_RADIUS * combat.area_multiplier,
+                        damage: BOMB_DAMAGE,
+                        from_enemy: false,
+                        shadow: None,
+                    },
+                    0.2,
+                    item.get_color(),
+                );
+            }
+        }
+
+        info!("🧪 P{} used {}", id.0 + 1, item.get_display_name());
+    }
+}
+
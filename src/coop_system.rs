@@ -0,0 +1,365 @@
+/*!
+🎮 LOCAL CO-OP
+Segundo jogador no gamepad: entra com Start, mira no analógico direito,
+cai em vez de morrer enquanto o parceiro estiver de pé e pode ser revivido
+*/
+
+use bevy::prelude::*;
+pub use crate::components::Downed;
+use crate::combat_tuning::CombatTuning;
+use crate::meta_progression::RunLoadout;
+use crate::ui::AppState;
+use crate::slow_motion::{SlowMotion, SlowMotionMoment};
+use crate::{FixedInput, GameState, InputState, Player, PlayerId, Room, SimulationSet, SpawnParticlesEvent, Stats, Velocity};
+
+// Distância entre jogadores ao entrar numa sala
+pub const COOP_SPAWN_SPACING: f32 = 1.5;
+const STICK_DEADZONE: f32 = 0.3;
+// Distância do ponto de mira à frente do P2 (equivalente à posição do mouse do P1)
+const GAMEPAD_AIM_DISTANCE: f32 = 6.0;
+const REVIVE_RADIUS: f32 = 1.5;
+const REVIVE_TIME: f32 = 3.0;
+const REVIVE_HEALTH_FRACTION: f32 = 0.3;
+// Distância entre jogadores que cabe na tela sem afastar a câmera
+const CAMERA_COMFORT_SPREAD: f32 = 8.0;
+const CAMERA_MAX_ZOOM: f32 = 1.8;
+// Velocidade de suavização (1/s) do enquadramento e do zoom
+const CAMERA_PAN_RATE: f32 = 5.0;
+const CAMERA_ZOOM_RATE: f32 = 3.0;
+// Raio em que o jogador anda sem arrastar a câmera (passos curtos não tremem a tela)
+const CAMERA_DEAD_ZONE: f32 = 1.0;
+
+/// Entrada do P2, lida do primeiro gamepad conectado
+#[derive(Resource, Default)]
+pub struct GamepadInputState {
+    pub input: InputState,
+    last_aim: Vec2,
+}
+
+#[derive(Component)]
+struct CoopHudText;
+
+/// Câmera compartilhada: enquadra o ponto médio dos jogadores e se afasta
+/// (até um limite) quando eles se separam
+#[derive(Resource)]
+pub struct CoopCamera {
+    focus: Vec3,
+    anchor: Vec3, // Ponto que a câmera persegue: só anda quando o grupo sai da zona morta
+    zoom: f32,
+    pub follow_speed: f32, // Suavização (1/s) do enquadramento
+    pub dead_zone: f32,
+}
+
+impl Default for CoopCamera {
+    fn default() -> Self {
+        Self {
+            focus: Vec3::ZERO,
+            anchor: Vec3::ZERO,
+            zoom: 1.0,
+            follow_speed: CAMERA_PAN_RATE,
+            dead_zone: CAMERA_DEAD_ZONE,
+        }
+    }
+}
+
+// Deslocamento original de cada câmera em relação ao ponto que ela olha
+#[derive(Component)]
+struct CameraOffset(Vec3);
+
+/// Ordenação para efeitos que mexem na câmera depois do enquadramento
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CoopCameraSet;
+
+pub struct CoopPlugin;
+
+impl Plugin for CoopPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GamepadInputState>()
+            .init_resource::<CoopCamera>()
+            .add_systems(Update, read_gamepad_input)
+            .add_systems(FixedUpdate, (
+                coop_join_system.in_set(SimulationSet),
+                (player_downed_system, revive_system).chain().after(SimulationSet),
+            ).run_if(in_state(AppState::InGame)))
+            .add_systems(OnEnter(AppState::InGame), setup_coop_hud)
+            .add_systems(OnExit(AppState::InGame), cleanup_coop_hud)
+            .add_systems(Update, update_coop_hud.run_if(in_state(AppState::InGame)))
+            .add_systems(Update, coop_camera_system
+                .in_set(CoopCameraSet)
+                // O tremor soma por cima do enquadramento do quadro (nunca é sobrescrito por ele)
+                .before(crate::ui::combat_feedback::apply_screen_shake)
+                .run_if(in_state(AppState::InGame)))
+            .add_systems(OnEnter(AppState::MainMenu), despawn_guest_players);
+    }
+}
+
+fn read_gamepad_input(
+    gamepads: Res<Gamepads>,
+    buttons: Res<ButtonInput<GamepadButton>>,
+    axes: Res<Axis<GamepadAxis>>,
+    player_query: Query<(&PlayerId, &Transform), With<Player>>,
+    mut gamepad_input: ResMut<GamepadInputState>,
+) {
+    let Some(gamepad) = gamepads.iter().next() else {
+        gamepad_input.input = InputState::default();
+        return;
+    };
+    let button = |kind| GamepadButton::new(gamepad, kind);
+    let axis = |kind| axes.get(GamepadAxis::new(gamepad, kind)).unwrap_or(0.0);
+
+    let stick = Vec2::new(axis(GamepadAxisType::LeftStickX), axis(GamepadAxisType::LeftStickY));
+    let aim = Vec2::new(axis(GamepadAxisType::RightStickX), axis(GamepadAxisType::RightStickY));
+    // Sem analógico direito, mira para onde está andando
+    if aim.length() > STICK_DEADZONE {
+        gamepad_input.last_aim = aim.normalize();
+    } else if stick.length() > STICK_DEADZONE {
+        gamepad_input.last_aim = stick.normalize();
+    }
+    let last_aim = if gamepad_input.last_aim == Vec2::ZERO { Vec2::Y } else { gamepad_input.last_aim };
+
+    let input = &mut gamepad_input.input;
+    // Analógico para cima = -Z no mundo (mesmo sentido do W)
+    input.up = stick.y > STICK_DEADZONE;
+    input.down = stick.y < -STICK_DEADZONE;
+    input.left = stick.x < -STICK_DEADZONE;
+    input.right = stick.x > STICK_DEADZONE;
+
+    input.dash = buttons.just_pressed(button(GamepadButtonType::South));
+    input.interact = buttons.just_pressed(button(GamepadButtonType::East));
+    input.use_item = buttons.just_pressed(button(GamepadButtonType::LeftTrigger));
+    input.place_totem = buttons.just_pressed(button(GamepadButtonType::LeftTrigger2));
+    input.blink = buttons.just_pressed(button(GamepadButtonType::LeftThumb));
+    input.join = buttons.just_pressed(button(GamepadButtonType::Start));
+
+    input.primary = buttons.just_pressed(button(GamepadButtonType::West));
+    input.primary_held = buttons.pressed(button(GamepadButtonType::West));
+    input.primary_released = buttons.just_released(button(GamepadButtonType::West));
+    input.secondary = buttons.just_pressed(button(GamepadButtonType::North));
+    input.secondary_held = buttons.pressed(button(GamepadButtonType::North));
+    input.ability_q = buttons.just_pressed(button(GamepadButtonType::RightTrigger));
+    input.ability_r = buttons.just_pressed(button(GamepadButtonType::RightTrigger2));
+    input.ability_r_held = buttons.pressed(button(GamepadButtonType::RightTrigger2));
+    input.ability_r_released = buttons.just_released(button(GamepadButtonType::RightTrigger2));
+
+    if let Some((_, transform)) = player_query.iter().find(|(id, _)| **id == PlayerId::P2) {
+        input.mouse_world_pos = transform.translation + Vec3::new(last_aim.x, 0.0, -last_aim.y) * GAMEPAD_AIM_DISTANCE;
+    }
+}
+
+// Start no gamepad durante a run: o P2 entra ao lado do P1
+fn coop_join_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    fixed_input: Res<FixedInput>,
+    loadout: Res<RunLoadout>,
+    tuning: Res<CombatTuning>,
+    player_query: Query<(&PlayerId, &Transform), With<Player>>,
+) {
+    if !fixed_input.get(PlayerId::P2).join || player_query.iter().any(|(id, _)| *id == PlayerId::P2) {
+        return;
+    }
+    let Some((_, host_transform)) = player_query.iter().find(|(id, _)| id.is_primary()) else {
+        return;
+    };
+
+    let position = host_transform.translation + Vec3::new(0.0, 0.0, COOP_SPAWN_SPACING);
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Capsule3d::new(0.4, 1.0)),
+            material: materials.add(StandardMaterial {
+                base_color: Color::rgb(0.2, 0.7, 0.7),
+                emissive: Color::rgb(0.1, 0.4, 0.4),
+                ..default()
+            }),
+            transform: Transform::from_xyz(position.x, 0.5, position.z),
+            ..default()
+        },
+        Player,
+        PlayerId::P2,
+        tuning.player_stats(),
+        tuning.dash(),
+        Velocity::default(),
+        tuning.combat_for(loadout.weapon),
+        Name::new("Player 2"),
+    ));
+    info!("🎮 Player 2 joined");
+}
+
+// Sem vida com o parceiro de pé: cai em vez de encerrar a run
+#[allow(clippy::type_complexity)]
+fn player_downed_system(
+    mut commands: Commands,
+    mut players: Query<(Entity, &PlayerId, &Stats, &mut Transform, &mut Velocity), (With<Player>, Without<Downed>)>,
+    all_players: Query<&Stats, With<Player>>,
+) {
+    let anyone_standing = all_players.iter().any(|stats| stats.current_health > 0.0);
+    if all_players.iter().count() < 2 || !anyone_standing {
+        return;
+    }
+
+    for (entity, id, stats, mut transform, mut velocity) in &mut players {
+        if stats.current_health > 0.0 {
+            continue;
+        }
+        commands.entity(entity).insert(Downed {
+            revive_progress: 0.0,
+            standing_rotation: transform.rotation,
+        });
+        // Deitado no chão
+        transform.rotation = Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2) * transform.rotation;
+        velocity.0 = Vec3::ZERO;
+        info!("💀 Player {} is down", id.0 + 1);
+    }
+}
+
+// Parceiro de pé ao lado do caído enche a barra de reviver; sozinho ela esvazia
+#[allow(clippy::type_complexity)]
+fn revive_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut downed_players: Query<(Entity, &PlayerId, &mut Downed, &mut Stats, &mut Transform), With<Player>>,
+    helpers: Query<(&Transform, &Stats), (With<Player>, Without<Downed>)>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+    mut slow_motion: ResMut<SlowMotion>,
+) {
+    let dt = time.delta_seconds();
+
+    for (entity, id, mut downed, mut stats, mut transform) in &mut downed_players {
+        let helped = helpers.iter().any(|(helper_transform, helper_stats)| {
+            helper_stats.current_health > 0.0
+                && helper_transform.translation.distance(transform.translation) <= REVIVE_RADIUS
+        });
+        downed.revive_progress = if helped {
+            downed.revive_progress + dt
+        } else {
+            (downed.revive_progress - dt).max(0.0)
+        };
+
+        if downed.revive_progress >= REVIVE_TIME {
+            stats.current_health = stats.max_health * REVIVE_HEALTH_FRACTION;
+            transform.rotation = downed.standing_rotation;
+            commands.entity(entity).remove::<Downed>();
+            particle_events.send(SpawnParticlesEvent {
+                position: transform.translation,
+                color: Color::rgb(1.0, 0.9, 0.5),
+                count: 16,
+            });
+            slow_motion.trigger(SlowMotionMoment::Revive);
+            info!("✨ Player {} revived", id.0 + 1);
+        }
+    }
+}
+
+// Um jogador: segue ele; dois: ponto médio com zoom pela distância, presos à sala atual
+fn coop_camera_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    mut coop_camera: ResMut<CoopCamera>,
+    players: Query<&Transform, (With<Player>, Without<Camera3d>)>,
+    rooms: Query<&Room>,
+    mut cameras: Query<(Entity, &mut Transform, Option<&CameraOffset>), With<Camera3d>>,
+) {
+    let positions: Vec<Vec3> = players.iter().map(|transform| transform.translation).collect();
+    if positions.is_empty() {
+        return;
+    }
+
+    let mut focus = positions.iter().sum::<Vec3>() / positions.len() as f32;
+    focus.y = 0.0;
+    if let Some(room) = rooms.iter().find(|room| room.id == game_state.current_room) {
+        let half = room.size / 2.0;
+        focus.x = focus.x.clamp(room.center.x - half.x, room.center.x + half.x);
+        focus.z = focus.z.clamp(room.center.y - half.y, room.center.y + half.y);
+    }
+    let spread = positions.iter()
+        .flat_map(|a| positions.iter().map(move |b| a.distance(*b)))
+        .fold(0.0, f32::max);
+    let target_zoom = (spread / CAMERA_COMFORT_SPREAD).clamp(1.0, CAMERA_MAX_ZOOM);
+
+    // Zona morta: o alvo só é arrastado pela parte do movimento que passa do raio
+    let drift = focus - coop_camera.anchor;
+    if drift.length() > coop_camera.dead_zone {
+        coop_camera.anchor = focus - drift.normalize() * coop_camera.dead_zone;
+    }
+
+    // Suavização independente da taxa de quadros
+    let dt = time.delta_seconds();
+    let anchor = coop_camera.anchor;
+    coop_camera.focus = coop_camera.focus.lerp(anchor, 1.0 - (-coop_camera.follow_speed * dt).exp());
+    coop_camera.zoom += (target_zoom - coop_camera.zoom) * (1.0 - (-CAMERA_ZOOM_RATE * dt).exp());
+
+    for (entity, mut transform, offset) in &mut cameras {
+        // Câmeras nascem olhando para a origem: a posição inicial é o deslocamento
+        let offset = match offset {
+            Some(offset) => offset.0,
+            None => {
+                commands.entity(entity).insert(CameraOffset(transform.translation));
+                transform.translation
+            }
+        };
+        transform.translation = coop_camera.focus + offset * coop_camera.zoom;
+        transform.look_at(coop_camera.focus, Vec3::Y);
+    }
+}
+
+fn despawn_guest_players(mut commands: Commands, players: Query<(Entity, &PlayerId), With<Player>>) {
+    for (entity, id) in &players {
+        if !id.is_primary() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+fn setup_coop_hud(mut commands: Commands) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 22.0,
+                color: Color::rgb(0.4, 0.9, 0.9),
+                ..default()
+            },
+        ).with_style(Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(50.0),
+            top: Val::Px(160.0),
+            ..default()
+        }),
+        CoopHudText,
+    ));
+}
+
+fn cleanup_coop_hud(mut commands: Commands, texts: Query<Entity, With<CoopHudText>>) {
+    for entity in &texts {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// O HUD principal mostra o P1; aqui aparecem o P2 e quem estiver caído
+fn update_coop_hud(
+    players: Query<(&PlayerId, &Stats, Option<&Downed>), With<Player>>,
+    mut texts: Query<&mut Text, With<CoopHudText>>,
+) {
+    let mut lines: Vec<(PlayerId, String)> = players.iter()
+        .filter(|(id, _, downed)| !id.is_primary() || downed.is_some())
+        .map(|(id, stats, downed)| {
+            let line = match downed {
+                Some(downed) => format!(
+                    "P{} CAÍDO - reviver {:.0}%",
+                    id.0 + 1,
+                    downed.revive_progress / REVIVE_TIME * 100.0
+                ),
+                None => format!("P{} ♥ {:.0}/{:.0}", id.0 + 1, stats.current_health, stats.max_health),
+            };
+            (*id, line)
+        })
+        .collect();
+    lines.sort_by_key(|(id, _)| id.0);
+    let label = lines.into_iter().map(|(_, line)| line).collect::<Vec<_>>().join("\n");
+
+    for mut text in &mut texts {
+        text.sections[0].value.clone_from(&label);
+    }
+}
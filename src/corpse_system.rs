@@ -0,0 +1,178 @@
+/*!
+💀 CORPSE SYSTEM
+Cadáveres Instáveis de Anúbis: cada inimigo morto deixa um cadáver volátil por alguns
+segundos. Se outro inimigo morrer perto, ou o jogador atravessar o cadáver com o dash,
+ele explode - a explosão é um AoeExplosionEvent do jogador (mesmo dano/partículas do R),
+arma os cadáveres vizinhos e as mortes que causar deixam novos cadáveres: limpa grupos em cadeia
+*/
+
+use bevy::prelude::*;
+use crate::boons::{self, ActiveBoons, BoonEffect};
+use crate::ui::AppState;
+use crate::{AoeExplosionEvent, AoeFalloff, Dash, EnemyKilledEvent, Player, SpawnParticlesEvent};
+
+// Atravessar com o dash a esta distância detona o cadáver
+const CORPSE_DASH_TRIGGER_RADIUS: f32 = 1.0;
+// Atraso entre um cadáver explodir e o vizinho armado explodir (a cadeia fica legível)
+const CORPSE_CHAIN_DELAY: f32 = 0.15;
+
+#[derive(Component)]
+pub struct VolatileCorpse {
+    pub timer: f32,
+    pub damage: f32,
+    pub radius: f32,
+    pub fuse: Option<f32>, // Armado: explode quando zerar
+}
+
+pub struct CorpsePlugin;
+
+impl Plugin for CorpsePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(FixedUpdate, (
+            corpse_kill_system,
+            corpse_dash_trigger,
+            corpse_detonation_system,
+        ).chain().run_if(in_state(AppState::InGame)))
+            .add_systems(OnEnter(AppState::Loading), cleanup_corpses);
+    }
+}
+
+// Boon de cadáver ativo: (janela, dano, raio); cada nível extra aumenta o dano em 50%
+// e o raio cresce com os boons de área, como o R
+fn active_corpse_boon(active_boons: &ActiveBoons) -> Option<(f32, f32, f32)> {
+    let area = boons::area_multiplier(active_boons);
+    active_boons.player_boons.iter()
+        .flat_map(|boon| boon.effects.iter().map(move |effect| (boon.level, effect)))
+        .find_map(|(level, effect)| match effect {
+            BoonEffect::VolatileCorpse { window, damage, radius } => {
+                Some((*window, damage * (1.0 + 0.5 * (level - 1) as f32), radius * area))
+            }
+            _ => None,
+        })
+}
+
+// Mortes armam os cadáveres próximos e deixam um cadáver novo onde o inimigo caiu
+fn corpse_kill_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    active_boons: Res<ActiveBoons>,
+    mut kill_events: EventReader<EnemyKilledEvent>,
+    mut corpses: Query<(&Transform, &mut VolatileCorpse)>,
+) {
+    let boon = active_corpse_boon(&active_boons);
+
+    for kill in kill_events.read() {
+        for (transform, mut corpse) in &mut corpses {
+            if corpse.fuse.is_none() && transform.translation.distance(kill.position) <= corpse.radius {
+                corpse.fuse = Some(CORPSE_CHAIN_DELAY);
+            }
+        }
+
+        let Some((window, damage, radius)) = boon else {
+            continue;
+        };
+        commands.spawn((
+            PbrBundle {
+                mesh: meshes.add(Circle::new(0.6)),
+                material: materials.add(StandardMaterial {
+                    base_color: Color::rgba(0.45, 0.15, 0.7, 0.7),
+                    emissive: Color::rgb(0.9, 0.3, 1.4),
+                    alpha_mode: AlphaMode::Blend,
+                    unlit: true,
+                    ..default()
+                }),
+                transform: Transform::from_xyz(kill.position.x, 0.06, kill.position.z)
+                    .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+                ..default()
+            },
+            VolatileCorpse { timer: window, damage, radius, fuse: None },
+            Name::new("Volatile Corpse"),
+        ));
+    }
+}
+
+// O jogador detona o cadáver atravessando-o com o dash
+fn corpse_dash_trigger(
+    players: Query<(&Transform, &Dash), With<Player>>,
+    mut corpses: Query<(&Transform, &mut VolatileCorpse), Without<Player>>,
+) {
+    for (player_transform, dash) in &players {
+        if !dash.is_dashing {
+            continue;
+        }
+        let player_pos = Vec3::new(player_transform.translation.x, 0.0, player_transform.translation.z);
+        for (transform, mut corpse) in &mut corpses {
+            let corpse_pos = Vec3::new(transform.translation.x, 0.0, transform.translation.z);
+            if corpse.fuse.is_none() && corpse_pos.distance(player_pos) <= CORPSE_DASH_TRIGGER_RADIUS {
+                corpse.fuse = Some(0.0);
+            }
+        }
+    }
+}
+
+fn corpse_detonation_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut corpses: Query<(Entity, &mut Transform, &mut VolatileCorpse)>,
+    mut aoe_events: EventWriter<AoeExplosionEvent>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+) {
+    let dt = time.delta_seconds();
+    let mut detonated = Vec::new();
+
+    for (entity, mut transform, mut corpse) in &mut corpses {
+        corpse.timer -= dt;
+        match corpse.fuse {
+            Some(fuse) if fuse - dt <= 0.0 => {
+                detonated.push((transform.translation, corpse.radius));
+                aoe_events.send(AoeExplosionEvent {
+                    center: Vec3::new(transform.translation.x, 0.5, transform.translation.z),
+                    radius: corpse.radius,
+                    damage: corpse.damage,
+                    on_hit: None,
+                    falloff: AoeFalloff::Linear,
+                });
+                commands.entity(entity).despawn_recursive();
+            }
+            Some(fuse) => {
+                corpse.fuse = Some(fuse - dt);
+                // Armado: pulsa rápido até explodir
+                transform.scale = Vec3::splat(1.0 + 0.4 * (fuse * 40.0).sin().abs());
+            }
+            None if corpse.timer <= 0.0 => {
+                // Janela acabou sem explodir: some em fumaça
+                particle_events.send(SpawnParticlesEvent {
+                    position: transform.translation,
+                    color: Color::rgb(0.4, 0.2, 0.5),
+                    count: 6,
+                });
+                commands.entity(entity).despawn_recursive();
+            }
+            None => {}
+        }
+    }
+
+    if detonated.is_empty() {
+        return;
+    }
+    info!("💀 {} volatile corpse(s) detonated", detonated.len());
+    // A explosão arma os cadáveres vizinhos: a cadeia atravessa o grupo
+    for (_, transform, mut corpse) in &mut corpses {
+        if corpse.fuse.is_some() {
+            continue;
+        }
+        let in_blast = detonated.iter()
+            .any(|(center, radius)| center.distance(transform.translation) <= *radius);
+        if in_blast {
+            corpse.fuse = Some(CORPSE_CHAIN_DELAY);
+        }
+    }
+}
+
+// Run nova: cadáveres da run anterior não sobrevivem
+fn cleanup_corpses(mut commands: Commands, corpses: Query<Entity, With<VolatileCorpse>>) {
+    for entity in &corpses {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,167 @@
+/*!
+🎯 DAMAGE DIRECTION INDICATORS
+Arcos vermelhos na borda da tela apontando de onde veio o dano recebido pelo jogador
+*/
+
+use bevy::prelude::*;
+use crate::ui::AppState;
+use crate::{Player, PlayerId};
+
+const INDICATOR_LIFETIME: f32 = 0.8;
+// Golpes vindos de direções mais próximas que isso renovam o indicador existente
+const INDICATOR_MERGE_ANGLE: f32 = 0.35; // ~20°
+// Distância do centro da tela, como fração da meia-altura/meia-largura
+const INDICATOR_RADIUS: f32 = 0.8;
+const INDICATOR_LENGTH: f32 = 90.0;
+const INDICATOR_THICKNESS: f32 = 10.0;
+
+// Jogador `target` recebeu dano vindo de `source` (atacante, origem do projétil, ponto de impacto)
+#[derive(Event)]
+pub struct PlayerDamagedEvent {
+    pub target: Entity,
+    pub source: Vec3,
+}
+
+#[derive(Component)]
+struct DamageIndicator {
+    source: Vec3,
+    timer: f32,
+}
+
+pub struct DamageIndicatorPlugin;
+
+impl Plugin for DamageIndicatorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PlayerDamagedEvent>()
+            .add_systems(Update, (
+                spawn_damage_indicators,
+                update_damage_indicators,
+            ).chain().run_if(in_state(AppState::InGame)))
+            .add_systems(OnExit(AppState::InGame), cleanup_damage_indicators);
+    }
+}
+
+// Direção na tela (y para baixo) do jogador até a origem do dano
+fn screen_direction(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    player: Vec3,
+    source: Vec3,
+) -> Option<Vec2> {
+    let from = camera.world_to_viewport(camera_transform, player)?;
+    let to = camera.world_to_viewport(camera_transform, source)?;
+    let direction = to - from;
+    (direction.length_squared() > 1.0).then(|| direction.normalize())
+}
+
+fn active_camera<'a>(cameras: &'a Query<(&Camera, &GlobalTransform), With<Camera3d>>) -> Option<(&'a Camera, &'a GlobalTransform)> {
+    cameras.iter()
+        .filter(|(camera, _)| camera.is_active)
+        .max_by_key(|(camera, _)| camera.order)
+}
+
+fn spawn_damage_indicators(
+    mut commands: Commands,
+    mut damage_events: EventReader<PlayerDamagedEvent>,
+    mut indicators: Query<&mut DamageIndicator>,
+    player_query: Query<(Entity, &PlayerId, &Transform), With<Player>>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+) {
+    // Indicadores só para o P1 (a câmera o acompanha)
+    let primary = player_query.iter().find(|(_, id, _)| id.is_primary());
+    let (Some((player_entity, _, player_transform)), Some((camera, camera_transform))) = (primary, active_camera(&cameras)) else {
+        damage_events.clear();
+        return;
+    };
+    let player = player_transform.translation;
+
+    for event in damage_events.read().filter(|event| event.target == player_entity) {
+        let Some(direction) = screen_direction(camera, camera_transform, player, event.source) else {
+            continue;
+        };
+
+        // Mesma direção de um indicador ativo: só renova (dano contínuo não empilha)
+        let merged = indicators.iter_mut().find(|indicator| {
+            screen_direction(camera, camera_transform, player, indicator.source)
+                .is_some_and(|existing| existing.angle_between(direction).abs() < INDICATOR_MERGE_ANGLE)
+        });
+        if let Some(mut indicator) = merged {
+            indicator.source = event.source;
+            indicator.timer = INDICATOR_LIFETIME;
+            continue;
+        }
+
+        commands.spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Px(INDICATOR_LENGTH),
+                    height: Val::Px(INDICATOR_THICKNESS),
+                    ..default()
+                },
+                background_color: Color::rgba(1.0, 0.15, 0.1, 0.9).into(),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            DamageIndicator {
+                source: event.source,
+                timer: INDICATOR_LIFETIME,
+            },
+        ));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_damage_indicators(
+    mut commands: Commands,
+    time: Res<Time>,
+    player_query: Query<(&PlayerId, &Transform), (With<Player>, Without<DamageIndicator>)>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    ui_scale: Res<UiScale>,
+    mut indicators: Query<(Entity, &mut DamageIndicator, &mut Style, &mut Transform, &mut BackgroundColor, &mut Visibility)>,
+) {
+    let dt = time.delta_seconds();
+    let player = player_query.iter()
+        .find(|(id, _)| id.is_primary())
+        .map(|(_, transform)| transform.translation);
+    let camera = active_camera(&cameras);
+    // Em unidades da UI, que o layout multiplica pelo UiScale
+    let viewport = camera.and_then(|(camera, _)| camera.logical_viewport_size()).map(|size| size / ui_scale.0);
+
+    for (entity, mut indicator, mut style, mut transform, mut color, mut visibility) in &mut indicators {
+        indicator.timer -= dt;
+        if indicator.timer <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        // Recalcula a cada quadro: o jogador continua se movendo
+        let direction = match (player, camera) {
+            (Some(player), Some((camera, camera_transform))) => {
+                screen_direction(camera, camera_transform, player, indicator.source)
+            }
+            _ => None,
+        };
+        let (Some(direction), Some(viewport)) = (direction, viewport) else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        // Arco numa elipse ao redor do centro, perpendicular à direção do golpe
+        let center = viewport / 2.0;
+        let position = center + direction * center * INDICATOR_RADIUS;
+        style.left = Val::Px(position.x - INDICATOR_LENGTH / 2.0);
+        style.top = Val::Px(position.y - INDICATOR_THICKNESS / 2.0);
+        transform.rotation = Quat::from_rotation_z(direction.y.atan2(direction.x) + std::f32::consts::FRAC_PI_2);
+
+        let alpha = 0.9 * (indicator.timer / INDICATOR_LIFETIME);
+        *color = Color::rgba(1.0, 0.15, 0.1, alpha).into();
+        *visibility = Visibility::Visible;
+    }
+}
+
+fn cleanup_damage_indicators(mut commands: Commands, indicators: Query<Entity, With<DamageIndicator>>) {
+    for entity in &indicators {
+        commands.entity(entity).despawn_recursive();
+    }
+}
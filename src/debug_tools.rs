@@ -0,0 +1,281 @@
+/*!
+🐞 DEBUG TOOLS
+Ferramentas de balanceamento, só em builds de debug (no release o plugin não registra nada).
+F3 liga/desliga o desenho de hitboxes e hurtboxes com gizmos.
+F4 godmode (jogador não toma dano), F5 noclip (voa: PageUp sobe, PageDown desce),
+F6/F7 diminuem/aumentam o multiplicador de velocidade do jogador.
+F10 prende a semente da dungeon atual para as próximas runs (de novo, volta a sortear).
+O overlay também mostra os inimigos vivos contra o teto do SpawnGovernor
+*/
+
+use bevy::prelude::*;
+use crate::arc_projectile::ArcProjectile;
+use crate::combat_tuning::CombatTuning;
+use crate::enemy_formation::EnemyFormation;
+use crate::procedural::{GeneratedDungeon, RunConfig};
+use crate::spawn_governor::SpawnGovernor;
+use crate::ui::AppState;
+use crate::{
+    aoe_target_position, melee_strike, AoeExplosionEvent, Combat, Enemy, EnemyType, FixedInput, Player, PlayerId, Projectile, AI,
+    AOE_AIM_THRESHOLD, ENEMY_HURT_RADIUS, PLAYER_HURT_RADIUS,
+};
+
+const PLAYER_ATTACK_COLOR: Color = Color::rgb(0.2, 1.0, 0.3);
+const HURTBOX_COLOR: Color = Color::rgb(1.0, 0.9, 0.2);
+const ENEMY_ATTACK_COLOR: Color = Color::rgb(1.0, 0.2, 0.2);
+const AGGRO_COLOR: Color = Color::rgba(1.0, 0.5, 0.2, 0.3);
+const AOE_COLOR: Color = Color::rgb(1.0, 0.5, 0.1);
+
+const SPEED_MULTIPLIERS: [f32; 5] = [0.5, 1.0, 2.0, 4.0, 8.0];
+const NOCLIP_VERTICAL_SPEED: f32 = 8.0;
+
+#[derive(Resource)]
+pub struct DebugSettings {
+    pub show_hitboxes: bool,
+    pub godmode: bool,
+    pub noclip: bool,
+    speed_level: usize,
+}
+
+impl Default for DebugSettings {
+    fn default() -> Self {
+        Self {
+            show_hitboxes: false,
+            godmode: false,
+            noclip: false,
+            speed_level: 1,
+        }
+    }
+}
+
+impl DebugSettings {
+    pub fn speed_multiplier(&self) -> f32 {
+        SPEED_MULTIPLIERS[self.speed_level]
+    }
+}
+
+// O recurso só existe em builds de debug; no release tudo isso vira constante
+pub fn godmode_enabled(settings: Option<&DebugSettings>) -> bool {
+    cfg!(debug_assertions) && settings.is_some_and(|settings| settings.godmode)
+}
+
+pub fn noclip_enabled(settings: Option<&DebugSettings>) -> bool {
+    cfg!(debug_assertions) && settings.is_some_and(|settings| settings.noclip)
+}
+
+pub fn player_speed_multiplier(settings: Option<&DebugSettings>) -> f32 {
+    match settings {
+        Some(settings) if cfg!(debug_assertions) => settings.speed_multiplier(),
+        _ => 1.0,
+    }
+}
+
+#[derive(Component)]
+struct DebugOverlayText;
+
+pub struct DebugToolsPlugin;
+
+impl Plugin for DebugToolsPlugin {
+    fn build(&self, app: &mut App) {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+        app.init_resource::<DebugSettings>()
+            .add_systems(Startup, setup_debug_overlay)
+            .add_systems(Update, (
+                toggle_debug_settings,
+                update_debug_overlay,
+                noclip_flight
+                    .run_if(|settings: Res<DebugSettings>| settings.noclip)
+                    .run_if(in_state(AppState::InGame)),
+                (draw_player_hitboxes, draw_enemy_hitboxes, draw_projectile_hitboxes)
+                    .run_if(|settings: Res<DebugSettings>| settings.show_hitboxes)
+                    .run_if(in_state(AppState::InGame)),
+            ).chain());
+    }
+}
+
+fn toggle_debug_settings(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<DebugSettings>,
+    mut run_config: ResMut<RunConfig>,
+    dungeon: Option<Res<GeneratedDungeon>>,
+) {
+    if keys.just_pressed(KeyCode::F3) {
+        settings.show_hitboxes = !settings.show_hitboxes;
+        info!("🐞 Hitbox gizmos {}", if settings.show_hitboxes { "on" } else { "off" });
+    }
+    if keys.just_pressed(KeyCode::F4) {
+        settings.godmode = !settings.godmode;
+        info!("🐞 Godmode {}", if settings.godmode { "on" } else { "off" });
+    }
+    if keys.just_pressed(KeyCode::F5) {
+        settings.noclip = !settings.noclip;
+        info!("🐞 Noclip {}", if settings.noclip { "on" } else { "off" });
+    }
+    if keys.just_pressed(KeyCode::F6) && settings.speed_level > 0 {
+        settings.speed_level -= 1;
+        info!("🐞 Player speed x{}", settings.speed_multiplier());
+    }
+    if keys.just_pressed(KeyCode::F7) && settings.speed_level + 1 < SPEED_MULTIPLIERS.len() {
+        settings.speed_level += 1;
+        info!("🐞 Player speed x{}", settings.speed_multiplier());
+    }
+    if keys.just_pressed(KeyCode::F10) {
+        if run_config.keep_seed {
+            run_config.keep_seed = false;
+            run_config.fixed_seed = None;
+            info!("🐞 Dungeon seed unlocked (random every run)");
+        } else if let Some(dungeon) = dungeon {
+            run_config.keep_seed = true;
+            run_config.fixed_seed = Some(dungeon.generation_seed);
+            info!("🐞 Dungeon seed locked to {}", dungeon.generation_seed);
+        }
+    }
+}
+
+// Noclip: o movimento normal não prende mais o jogador no chão, a altura fica por conta daqui
+fn noclip_flight(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<DebugSettings>,
+    mut players: Query<&mut Transform, With<Player>>,
+) {
+    let mut vertical = 0.0;
+    if keys.pressed(KeyCode::PageUp) { vertical += 1.0; }
+    if keys.pressed(KeyCode::PageDown) { vertical -= 1.0; }
+    if vertical == 0.0 {
+        return;
+    }
+    let step = vertical * NOCLIP_VERTICAL_SPEED * settings.speed_multiplier() * time.delta_seconds();
+    for mut transform in &mut players {
+        transform.translation.y = (transform.translation.y + step).max(0.5);
+    }
+}
+
+fn setup_debug_overlay(mut commands: Commands) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 16.0,
+                color: Color::rgb(0.6, 1.0, 0.6),
+                ..default()
+            },
+        ).with_style(Style {
+            position_type: PositionType::Absolute,
+            right: Val::Px(10.0),
+            bottom: Val::Px(10.0),
+            ..default()
+        }),
+        DebugOverlayText,
+    ));
+}
+
+fn update_debug_overlay(
+    settings: Res<DebugSettings>,
+    governor: Res<SpawnGovernor>,
+    formation: Res<EnemyFormation>,
+    run_config: Res<RunConfig>,
+    mut texts: Query<&mut Text, With<DebugOverlayText>>,
+) {
+    if !settings.is_changed() && !governor.is_changed() && !formation.is_changed() && !run_config.is_changed() {
+        return;
+    }
+    for mut text in &mut texts {
+        let mut flags = Vec::new();
+        if settings.show_hitboxes { flags.push("hitboxes [F3]".to_string()); }
+        if settings.godmode { flags.push("godmode [F4]".to_string()); }
+        if settings.noclip { flags.push("noclip [F5]".to_string()); }
+        if settings.speed_level != 1 { flags.push(format!("speed x{} [F6/F7]", settings.speed_multiplier())); }
+        match (run_config.keep_seed, run_config.fixed_seed) {
+            (true, Some(seed)) => flags.push(format!("seed {} [F10]", seed)),
+            (false, Some(seed)) => flags.push(format!("next run seed {} [F9]", seed)),
+            _ => {}
+        }
+        if governor.live_enemies > 0 || governor.queued() > 0 {
+            flags.push(format!("enemies {}/{} (+{} queued)", governor.live_enemies, governor.max_live_enemies, governor.queued()));
+            flags.push(format!("formation {} engage / {} surround / {} hold", formation.engaged, formation.surrounding, formation.holding));
+        }
+        text.sections[0].value = if flags.is_empty() {
+            String::new()
+        } else {
+            format!("DEBUG: {}", flags.join(" | "))
+        };
+    }
+}
+
+// Círculo no chão (os alcances são todos medidos no plano XZ)
+fn ground_circle(gizmos: &mut Gizmos, center: Vec3, radius: f32, color: Color) {
+    gizmos.circle(Vec3::new(center.x, 0.05, center.z), Direction3d::Y, radius, color);
+}
+
+// Alcance do primário, do pesado e do golpe do secundário; hurtbox; alvo do R
+fn draw_player_hitboxes(
+    mut gizmos: Gizmos,
+    fixed_input: Res<FixedInput>,
+    tuning: Res<CombatTuning>,
+    players: Query<(&PlayerId, &Transform, &Combat), With<Player>>,
+) {
+    for (id, transform, combat) in &players {
+        let position = transform.translation;
+        let attacks = &tuning.attacks;
+        let hit_range = tuning.weapon(combat.weapon).hit_range;
+        ground_circle(&mut gizmos, position, hit_range, PLAYER_ATTACK_COLOR);
+        ground_circle(&mut gizmos, position, hit_range * attacks.heavy_range_mult, PLAYER_ATTACK_COLOR.with_a(0.5));
+        ground_circle(&mut gizmos, position, hit_range * attacks.dash_strike_range_mult * combat.area_multiplier, Color::rgba(0.8, 0.3, 1.0, 0.6));
+        ground_circle(&mut gizmos, position, PLAYER_HURT_RADIUS, HURTBOX_COLOR);
+
+        // R: no alvo enquanto mira, senão em volta do jogador
+        let aoe_center = if combat.r_aim_time >= AOE_AIM_THRESHOLD {
+            aoe_target_position(position, fixed_input.get(*id).mouse_world_pos, attacks.aoe_max_cast_range)
+        } else {
+            position
+        };
+        ground_circle(&mut gizmos, aoe_center, attacks.aoe_radius * combat.area_multiplier, AOE_COLOR.with_a(0.6));
+    }
+}
+
+// Hurtbox, raio de perseguição e alcance do golpe corpo a corpo
+fn draw_enemy_hitboxes(
+    mut gizmos: Gizmos,
+    enemies: Query<(&Transform, &AI, &EnemyType), With<Enemy>>,
+) {
+    for (transform, ai, enemy_type) in &enemies {
+        let position = transform.translation;
+        ground_circle(&mut gizmos, position, ENEMY_HURT_RADIUS, HURTBOX_COLOR);
+        ground_circle(&mut gizmos, position, ai.target_range, AGGRO_COLOR);
+        if matches!(enemy_type, EnemyType::Chaser | EnemyType::Tank) {
+            let (reach, ..) = melee_strike(*enemy_type);
+            let color = if ai.windup.is_some() { ENEMY_ATTACK_COLOR } else { ENEMY_ATTACK_COLOR.with_a(0.4) };
+            ground_circle(&mut gizmos, position, reach, color);
+        }
+    }
+}
+
+// Projéteis comuns, ponto de queda dos lançados em arco e explosões do R
+fn draw_projectile_hitboxes(
+    mut gizmos: Gizmos,
+    projectiles: Query<(&Transform, &Projectile)>,
+    arc_projectiles: Query<&ArcProjectile>,
+    mut explosions: EventReader<AoeExplosionEvent>,
+) {
+    for (transform, projectile) in &projectiles {
+        // Projétil inimigo colide com o raio do jogador; o do jogador, com o raio do inimigo
+        let (radius, color) = if projectile.from_enemy {
+            (PLAYER_HURT_RADIUS, ENEMY_ATTACK_COLOR)
+        } else {
+            (ENEMY_HURT_RADIUS, PLAYER_ATTACK_COLOR)
+        };
+        gizmos.sphere(transform.translation, Quat::IDENTITY, radius, color);
+    }
+
+    for arc in &arc_projectiles {
+        let color = if arc.from_enemy { ENEMY_ATTACK_COLOR } else { PLAYER_ATTACK_COLOR };
+        ground_circle(&mut gizmos, arc.target, arc.radius, color);
+    }
+
+    for explosion in explosions.read() {
+        ground_circle(&mut gizmos, explosion.center, explosion.radius, AOE_COLOR);
+    }
+}
@@ -0,0 +1,166 @@
+/*!
+🪞 DECOY SYSTEM
+Miragem de Thoth: ao usar o dash o jogador deixa uma ilusão parada no ponto de partida.
+Inimigos perto dela trocam de alvo no ai_system (perseguem e atacam a ilusão) até ela
+expirar ou ser destruída - abre espaço para fugir e se reposicionar
+*/
+
+use bevy::prelude::*;
+use std::collections::HashSet;
+use crate::boons::{ActiveBoons, BoonEffect};
+use crate::projectile_pool::{reclaim_projectile, ProjectilePool};
+use crate::ui::AppState;
+use crate::{AudioEvent, Dash, EnemyProjectile, Player, Projectile, SimulationSet, SpawnParticlesEvent};
+
+// Inimigos a esta distância da ilusão preferem ela ao jogador
+pub const DECOY_AGGRO_RADIUS: f32 = 8.0;
+const DECOY_HIT_RADIUS: f32 = 0.8;
+
+#[derive(Component)]
+pub struct Decoy {
+    pub health: f32,
+    pub remaining: f32,
+}
+
+// Recarga entre ilusões (o dash é frequente demais para uma por dash)
+#[derive(Resource, Default)]
+struct DecoyCooldown {
+    timer: f32,
+}
+
+pub struct DecoyPlugin;
+
+impl Plugin for DecoyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DecoyCooldown>()
+            // Ilusões desviam a IA e seguram tiros: simulação em passo fixo, antes da IA e dos golpes
+            .add_systems(FixedUpdate, (
+                spawn_decoy_on_dash,
+                decoy_projectile_hits,
+                update_decoys,
+            ).chain()
+                .after(crate::player_movement_system)
+                .before(crate::ai_system)
+                .before(crate::hades_combat_system)
+                .in_set(SimulationSet)
+                .run_if(in_state(AppState::InGame)))
+            .add_systems(OnEnter(AppState::Loading), cleanup_decoys);
+    }
+}
+
+// Boon de ilusão ativo: (duração, vida, recarga); cada nível extra dura 1s a mais
+fn active_decoy_boon(active_boons: &ActiveBoons) -> Option<(f32, f32, f32)> {
+    active_boons.player_boons.iter()
+        .flat_map(|boon| boon.effects.iter().map(move |effect| (boon.level, effect)))
+        .find_map(|(level, effect)| match effect {
+            BoonEffect::SpawnDecoy { duration, health, cooldown } => {
+                Some((duration + (level - 1) as f32, *health, *cooldown))
+            }
+            _ => None,
+        })
+}
+
+// Dispara no quadro em que o dash começa, deixando a ilusão onde o jogador estava
+#[allow(clippy::too_many_arguments)]
+fn spawn_decoy_on_dash(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    time: Res<Time>,
+    active_boons: Res<ActiveBoons>,
+    mut cooldown: ResMut<DecoyCooldown>,
+    players: Query<(Entity, &Transform, &Dash), With<Player>>,
+    mut dashing_last_frame: Local<HashSet<Entity>>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+) {
+    cooldown.timer = (cooldown.timer - time.delta_seconds()).max(0.0);
+    let boon = active_decoy_boon(&active_boons);
+
+    for (entity, transform, dash) in &players {
+        let started_dash = dash.is_dashing && !dashing_last_frame.contains(&entity);
+        if dash.is_dashing {
+            dashing_last_frame.insert(entity);
+        } else {
+            dashing_last_frame.remove(&entity);
+        }
+
+        let Some((duration, health, recharge)) = boon else {
+            continue;
+        };
+        if !started_dash || cooldown.timer > 0.0 {
+            continue;
+        }
+
+        commands.spawn((
+            PbrBundle {
+                mesh: meshes.add(Capsule3d::new(0.35, 1.0)),
+                material: materials.add(StandardMaterial {
+                    base_color: Color::rgba(0.3, 0.8, 0.9, 0.45),
+                    emissive: Color::rgb(0.2, 0.6, 0.8),
+                    alpha_mode: AlphaMode::Blend,
+                    ..default()
+                }),
+                transform: Transform::from_translation(transform.translation),
+                ..default()
+            },
+            Decoy { health, remaining: duration },
+            Name::new("Decoy: Thoth Mirage"),
+        ));
+        particle_events.send(SpawnParticlesEvent {
+            position: transform.translation,
+            color: Color::rgb(0.4, 0.9, 1.0),
+            count: 12,
+        });
+        cooldown.timer = recharge;
+        info!("🪞 Thoth mirage left behind for {:.0}s", duration);
+    }
+}
+
+// Tiros inimigos acertam a ilusão no caminho (ataques corpo a corpo vêm do ai_system)
+fn decoy_projectile_hits(
+    mut commands: Commands,
+    projectiles: Query<(Entity, &Transform, &Projectile), With<EnemyProjectile>>,
+    mut decoys: Query<(&Transform, &mut Decoy)>,
+    mut projectile_pool: ResMut<ProjectilePool>,
+) {
+    for (projectile_entity, projectile_transform, projectile) in &projectiles {
+        let hit = decoys.iter_mut()
+            .find(|(decoy_transform, _)| decoy_transform.translation.distance(projectile_transform.translation) <= DECOY_HIT_RADIUS);
+        if let Some((_, mut decoy)) = hit {
+            decoy.health -= projectile.damage as f32;
+            reclaim_projectile(&mut commands, &mut projectile_pool, projectile_entity);
+        }
+    }
+}
+
+fn update_decoys(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut decoys: Query<(Entity, &Transform, &mut Decoy)>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+    mut audio_events: EventWriter<AudioEvent>,
+) {
+    for (entity, transform, mut decoy) in &mut decoys {
+        decoy.remaining -= time.delta_seconds();
+        if decoy.remaining > 0.0 && decoy.health > 0.0 {
+            continue;
+        }
+        // Some numa nuvem de areia
+        particle_events.send(SpawnParticlesEvent {
+            position: transform.translation,
+            color: Color::rgb(0.5, 0.85, 0.95),
+            count: 16,
+        });
+        if decoy.health <= 0.0 {
+            audio_events.send(AudioEvent::ShieldBreak);
+        }
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// Run nova: ilusões da run anterior não sobrevivem (menus por cima do jogo não limpam)
+fn cleanup_decoys(mut commands: Commands, decoys: Query<Entity, With<Decoy>>) {
+    for entity in &decoys {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,151 @@
+/*!
+🚪 DOOR PREVIEW
+Símbolo girando acima de cada porta mostrando a recompensa da sala do outro lado (bênção,
+moedas, cura, loja, tesouro, chefe), lido do modelo da sala de destino. Só aparece nas portas
+abertas da sala atual; portas trancadas e salas secretas não revelam nada
+*/
+
+use bevy::prelude::*;
+use crate::procedural::{RoomTemplate, RoomType as TemplateRoomType};
+use crate::ui::AppState;
+use crate::{GameState, Room, RoomTransition};
+
+const PREVIEW_HEIGHT: f32 = 2.2; // acima do centro da porta
+const PREVIEW_SPIN_SPEED: f32 = 1.5;
+const PREVIEW_BOB_SPEED: f32 = 2.0;
+const PREVIEW_BOB_HEIGHT: f32 = 0.12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DoorReward {
+    Boon,
+    Coins,
+    Heal,
+    Shop,
+    Treasure,
+    Boss,
+}
+
+impl DoorReward {
+    // Salas secretas ficam sem símbolo
+    pub fn from_template(template: &RoomTemplate) -> Option<Self> {
+        match template.room_type {
+            TemplateRoomType::Combat | TemplateRoomType::Elite => Some(DoorReward::Boon),
+            TemplateRoomType::Event => Some(DoorReward::Coins),
+            TemplateRoomType::Rest => Some(DoorReward::Heal),
+            TemplateRoomType::Shop => Some(DoorReward::Shop),
+            TemplateRoomType::Treasure => Some(DoorReward::Treasure),
+            TemplateRoomType::Boss => Some(DoorReward::Boss),
+            TemplateRoomType::Secret => None,
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            DoorReward::Boon => Color::rgb(0.9, 0.8, 0.4),
+            DoorReward::Coins => Color::rgb(1.0, 0.65, 0.2),
+            DoorReward::Heal => Color::rgb(0.9, 0.25, 0.3),
+            DoorReward::Shop => Color::rgb(0.4, 0.9, 0.5),
+            DoorReward::Treasure => Color::rgb(1.0, 0.85, 0.3),
+            DoorReward::Boss => Color::rgb(0.7, 0.2, 0.9),
+        }
+    }
+
+    // Forma do símbolo: disco solar, moeda, coração, balança, baú, chifres do chefe
+    fn mesh(&self) -> Mesh {
+        match self {
+            DoorReward::Boon => Sphere::new(0.3).into(),
+            DoorReward::Coins => Cylinder::new(0.3, 0.08).into(),
+            DoorReward::Heal => Capsule3d::new(0.15, 0.25).into(),
+            DoorReward::Shop => Cuboid::new(0.5, 0.08, 0.3).into(),
+            DoorReward::Treasure => Cuboid::new(0.45, 0.35, 0.3).into(),
+            DoorReward::Boss => Torus::new(0.18, 0.32).into(),
+        }
+    }
+
+    // Moeda e chifres ficam de pé para o giro aparecer na câmera de cima
+    fn tilt(&self) -> Quat {
+        match self {
+            DoorReward::Coins | DoorReward::Boss => Quat::from_rotation_x(std::f32::consts::FRAC_PI_2),
+            _ => Quat::IDENTITY,
+        }
+    }
+}
+
+#[derive(Component)]
+struct DoorPreview {
+    door: Entity,
+    tilt: Quat,
+    age: f32,
+}
+
+// Porta que já ganhou (ou não precisa de) símbolo
+#[derive(Component)]
+struct DoorPreviewed;
+
+pub struct DoorPreviewPlugin;
+
+impl Plugin for DoorPreviewPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (
+            spawn_door_previews,
+            update_door_previews,
+        ).chain().run_if(in_state(AppState::InGame)));
+    }
+}
+
+// Filho da porta: some junto quando a fase é descarregada
+fn spawn_door_previews(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    doors: Query<(Entity, &RoomTransition), Without<DoorPreviewed>>,
+    rooms: Query<&Room>,
+) {
+    for (door, transition) in &doors {
+        let Some(room) = rooms.iter().find(|room| room.id == transition.to_room) else {
+            continue;
+        };
+        commands.entity(door).insert(DoorPreviewed);
+        let Some(reward) = DoorReward::from_template(&room.template) else {
+            continue;
+        };
+        let color = reward.color();
+        let preview = commands.spawn((
+            PbrBundle {
+                mesh: meshes.add(reward.mesh()),
+                material: materials.add(StandardMaterial {
+                    base_color: color,
+                    emissive: color * 1.5,
+                    unlit: true,
+                    ..default()
+                }),
+                transform: Transform::from_xyz(0.0, PREVIEW_HEIGHT, 0.0),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            DoorPreview { door, tilt: reward.tilt(), age: 0.0 },
+            Name::new(format!("Door Preview: {:?}", reward)),
+        )).id();
+        commands.entity(door).add_child(preview);
+    }
+}
+
+// Atualiza a visibilidade conforme as portas destrancam e a sala atual muda
+fn update_door_previews(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    doors: Query<&RoomTransition>,
+    mut previews: Query<(&mut DoorPreview, &mut Transform, &mut Visibility)>,
+) {
+    for (mut preview, mut transform, mut visibility) in &mut previews {
+        let open = doors.get(preview.door)
+            .is_ok_and(|transition| transition.active && transition.from_room == game_state.current_room);
+        *visibility = if open { Visibility::Inherited } else { Visibility::Hidden };
+        if !open {
+            continue;
+        }
+        preview.age += time.delta_seconds();
+        transform.translation.y = PREVIEW_HEIGHT + (preview.age * PREVIEW_BOB_SPEED).sin() * PREVIEW_BOB_HEIGHT;
+        transform.rotation = Quat::from_rotation_y(preview.age * PREVIEW_SPIN_SPEED) * preview.tilt;
+    }
+}
@@ -0,0 +1,347 @@
+/*!
+🗺️ DUNGEON MAP
+Mapa completo da masmorra gerada (Tab): pausa o jogo e mostra todas as salas com nome,
+tipo e bioma do template, estado (limpa/bloqueada/secreta), trancas das conexões e a sala atual.
+WASD/setas ou arrastar com o mouse movem o mapa; a roda do mouse dá zoom
+*/
+
+use bevy::input::mouse::{MouseMotion, MouseWheel};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use std::collections::HashSet;
+use crate::procedural::{GeneratedDungeon, RoomId};
+use crate::procedural::room_types::RoomType;
+use crate::ui::AppState;
+use crate::GameState;
+
+const ROOM_NODE_SIZE: Vec2 = Vec2::new(170.0, 70.0);
+const LINK_THICKNESS: f32 = 3.0;
+// Margem da tela ao encaixar o mapa inteiro
+const MAP_MARGIN: f32 = 120.0;
+const MAP_PAN_SPEED: f32 = 600.0; // px/s
+const MAP_MIN_ZOOM: f32 = 0.4;
+const MAP_MAX_ZOOM: f32 = 3.0;
+
+const CURRENT_BORDER: Color = Color::rgb(1.0, 0.85, 0.3);
+const ROOM_BORDER: Color = Color::rgb(0.6, 0.45, 0.2);
+const LOCKED_LINK: Color = Color::rgba(0.7, 0.2, 0.15, 0.7);
+const OPEN_LINK: Color = Color::rgba(0.9, 0.75, 0.4, 0.8);
+
+#[derive(Component)]
+struct DungeonMapUI;
+
+#[derive(Component)]
+struct MapRoomNode {
+    position: Vec2,
+}
+
+#[derive(Component)]
+struct MapLink {
+    from: Vec2,
+    to: Vec2,
+}
+
+// Enquadramento atual: `pan` em pixels de tela, `zoom` sobre a escala que encaixa o mapa
+#[derive(Resource, Default)]
+struct MapView {
+    zoom: f32,
+    pan: Vec2,
+    bounds_min: Vec2,
+    bounds_max: Vec2,
+}
+
+impl MapView {
+    // Posição do gerador -> pixel da tela (y do gerador cresce para baixo no mapa)
+    // Tamanho da janela em unidades da UI (o layout multiplica tudo pelo UiScale)
+    fn screen_size(window: &Window, ui_scale: &UiScale) -> Vec2 {
+        Vec2::new(window.width(), window.height()) / ui_scale.0
+    }
+
+    fn to_screen(&self, position: Vec2, screen: Vec2) -> Vec2 {
+        let size = (self.bounds_max - self.bounds_min).max(Vec2::ONE);
+        let available = (screen - Vec2::splat(MAP_MARGIN * 2.0)).max(Vec2::ONE);
+        let fit = (available / size).min_element();
+        let center = (self.bounds_min + self.bounds_max) / 2.0;
+        screen / 2.0 + (position - center) * fit * self.zoom + self.pan
+    }
+}
+
+pub struct DungeonMapPlugin;
+
+impl Plugin for DungeonMapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MapView>()
+            .add_systems(Update, toggle_dungeon_map)
+            .add_systems(OnEnter(AppState::DungeonMap), setup_dungeon_map)
+            .add_systems(OnExit(AppState::DungeonMap), cleanup_dungeon_map)
+            .add_systems(Update, (
+                navigate_dungeon_map,
+                layout_dungeon_map,
+            ).chain().run_if(in_state(AppState::DungeonMap)));
+    }
+}
+
+fn toggle_dungeon_map(
+    keys: Res<ButtonInput<KeyCode>>,
+    current_state: Res<State<AppState>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if !keys.just_pressed(KeyCode::Tab) {
+        return;
+    }
+    match current_state.get() {
+        AppState::InGame => next_state.set(AppState::DungeonMap),
+        AppState::DungeonMap => next_state.set(AppState::InGame),
+        _ => {}
+    }
+}
+
+fn room_status(dungeon: &GeneratedDungeon, room: RoomId, current: RoomId) -> (&'static str, Color) {
+    if room == current {
+        ("Você está aqui", Color::rgba(0.35, 0.25, 0.1, 0.95))
+    } else if dungeon.room_state(room).cleared {
+        ("Limpa", Color::rgba(0.15, 0.3, 0.15, 0.9))
+    } else if dungeon.unlocked_rooms.contains(&room) {
+        ("Aberta", Color::rgba(0.2, 0.15, 0.1, 0.9))
+    } else {
+        ("Bloqueada", Color::rgba(0.1, 0.08, 0.08, 0.85))
+    }
+}
+
+fn setup_dungeon_map(
+    mut commands: Commands,
+    dungeon: Option<Res<GeneratedDungeon>>,
+    game_state: Res<GameState>,
+    mut view: ResMut<MapView>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+) {
+    let root = commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            background_color: Color::rgba(0.03, 0.02, 0.02, 0.92).into(),
+            z_index: ZIndex::Global(50),
+            ..default()
+        },
+        DungeonMapUI,
+    )).id();
+
+    let Some(dungeon) = dungeon else {
+        return;
+    };
+    let layout = &dungeon.layout;
+    // As salas do jogo usam o próprio RoomId do gerador como índice
+    let current = RoomId(game_state.current_room as u32);
+
+    let positions: Vec<Vec2> = layout.rooms.values().map(|room| room.position).collect();
+    view.bounds_min = positions.iter().copied().reduce(Vec2::min).unwrap_or_default();
+    view.bounds_max = positions.iter().copied().reduce(Vec2::max).unwrap_or_default();
+    view.zoom = 1.0;
+    view.pan = Vec2::ZERO;
+    // Abre centrado na sala atual
+    if let (Some(room), Ok(window)) = (layout.rooms.get(&current), windows.get_single()) {
+        let screen = MapView::screen_size(window, &ui_scale);
+        view.pan = screen / 2.0 - view.to_screen(room.position, screen);
+    }
+
+    commands.entity(root).with_children(|parent| {
+        // Conexões primeiro (ficam atrás das salas), uma linha por par
+        let mut drawn = HashSet::new();
+        for connection in layout.connections.values().flatten() {
+            let key = (connection.from_room.0.min(connection.to_room.0), connection.from_room.0.max(connection.to_room.0));
+            let (Some(from), Some(to)) = (layout.rooms.get(&connection.from_room), layout.rooms.get(&connection.to_room)) else {
+                continue;
+            };
+            if !drawn.insert(key) {
+                continue;
+            }
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        height: Val::Px(LINK_THICKNESS),
+                        ..default()
+                    },
+                    background_color: if connection.is_locked { LOCKED_LINK } else { OPEN_LINK }.into(),
+                    ..default()
+                },
+                MapLink { from: from.position, to: to.position },
+            ));
+        }
+
+        let mut rooms: Vec<_> = layout.rooms.values().collect();
+        rooms.sort_by_key(|room| room.id.0);
+        for room in rooms {
+            let (status, background) = room_status(&dungeon, room.id, current);
+            let template = &room.template;
+            // Câmaras secretas só se revelam depois de abertas
+            let hidden = template.room_type == RoomType::Secret && !dungeon.unlocked_rooms.contains(&room.id);
+            let (title, subtitle) = if hidden {
+                ("???".to_string(), "Câmara Secreta".to_string())
+            } else {
+                (
+                    template.name.clone(),
+                    format!("{} · {}", template.room_type.get_display_name(), template.biome.get_display_name()),
+                )
+            };
+            let is_boss = layout.boss_rooms.contains(&room.id);
+
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        width: Val::Px(ROOM_NODE_SIZE.x),
+                        height: Val::Px(ROOM_NODE_SIZE.y),
+                        flex_direction: FlexDirection::Column,
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(if room.id == current { 3.0 } else { 1.0 })),
+                        ..default()
+                    },
+                    border_color: if room.id == current { CURRENT_BORDER } else { ROOM_BORDER }.into(),
+                    background_color: background.into(),
+                    ..default()
+                },
+                MapRoomNode { position: room.position },
+            )).with_children(|parent| {
+                parent.spawn(TextBundle::from_section(
+                    if is_boss { format!("👑 {}", title) } else { title },
+                    TextStyle {
+                        font_size: 15.0,
+                        color: Color::rgb(0.95, 0.85, 0.55),
+                        ..default()
+                    },
+                ));
+                parent.spawn(TextBundle::from_section(
+                    subtitle,
+                    TextStyle {
+                        font_size: 11.0,
+                        color: Color::rgb(0.75, 0.7, 0.6),
+                        ..default()
+                    },
+                ));
+                parent.spawn(TextBundle::from_section(
+                    status,
+                    TextStyle {
+                        font_size: 12.0,
+                        color: if room.id == current { CURRENT_BORDER } else { Color::rgb(0.6, 0.6, 0.55) },
+                        ..default()
+                    },
+                ));
+            });
+        }
+
+        parent.spawn(TextBundle::from_section(
+            format!("Mapa do Duat - semente {}", dungeon.generation_seed),
+            TextStyle {
+                font_size: 28.0,
+                color: Color::rgb(0.95, 0.8, 0.4),
+                ..default()
+            },
+        ).with_style(Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(30.0),
+            top: Val::Px(20.0),
+            ..default()
+        }));
+        parent.spawn(TextBundle::from_section(
+            "Tab/Esc: fechar   WASD/arrastar: mover   Roda do mouse: zoom",
+            TextStyle {
+                font_size: 16.0,
+                color: Color::rgb(0.7, 0.65, 0.55),
+                ..default()
+            },
+        ).with_style(Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(30.0),
+            bottom: Val::Px(20.0),
+            ..default()
+        }));
+    });
+}
+
+fn cleanup_dungeon_map(mut commands: Commands, maps: Query<Entity, With<DungeonMapUI>>) {
+    for entity in &maps {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// Usa o relógio real: o tempo virtual do jogo não importa aqui
+fn navigate_dungeon_map(
+    time: Res<Time<Real>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut wheel_events: EventReader<MouseWheel>,
+    mut motion_events: EventReader<MouseMotion>,
+    ui_scale: Res<UiScale>,
+    mut view: ResMut<MapView>,
+) {
+    let mut direction = Vec2::ZERO;
+    if keys.any_pressed([KeyCode::KeyW, KeyCode::ArrowUp]) {
+        direction.y += 1.0;
+    }
+    if keys.any_pressed([KeyCode::KeyS, KeyCode::ArrowDown]) {
+        direction.y -= 1.0;
+    }
+    if keys.any_pressed([KeyCode::KeyA, KeyCode::ArrowLeft]) {
+        direction.x += 1.0;
+    }
+    if keys.any_pressed([KeyCode::KeyD, KeyCode::ArrowRight]) {
+        direction.x -= 1.0;
+    }
+    // Mover a câmera para cima = desenhar o mapa mais para baixo
+    let mut pan = direction * MAP_PAN_SPEED * time.delta_seconds();
+    let dragged: Vec2 = motion_events.read().map(|motion| motion.delta).sum();
+    if mouse_buttons.pressed(MouseButton::Left) {
+        pan += dragged / ui_scale.0;
+    }
+    if pan != Vec2::ZERO {
+        view.pan += pan;
+    }
+
+    let scroll: f32 = wheel_events.read().map(|wheel| wheel.y).sum();
+    if scroll != 0.0 {
+        let zoom = (view.zoom * 1.1_f32.powf(scroll)).clamp(MAP_MIN_ZOOM, MAP_MAX_ZOOM);
+        // Zoom em volta do centro da tela
+        let ratio = zoom / view.zoom;
+        view.pan *= ratio;
+        view.zoom = zoom;
+    }
+}
+
+fn layout_dungeon_map(
+    view: Res<MapView>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+    mut rooms: Query<(&MapRoomNode, &mut Style), Without<MapLink>>,
+    mut links: Query<(&MapLink, &mut Style, &mut Transform), Without<MapRoomNode>>,
+) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let screen = MapView::screen_size(window, &ui_scale);
+
+    for (room, mut style) in &mut rooms {
+        let position = view.to_screen(room.position, screen) - ROOM_NODE_SIZE / 2.0;
+        style.left = Val::Px(position.x);
+        style.top = Val::Px(position.y);
+    }
+
+    for (link, mut style, mut transform) in &mut links {
+        let from = view.to_screen(link.from, screen);
+        let to = view.to_screen(link.to, screen);
+        let length = from.distance(to);
+        let middle = (from + to) / 2.0;
+        style.width = Val::Px(length);
+        style.left = Val::Px(middle.x - length / 2.0);
+        style.top = Val::Px(middle.y - LINK_THICKNESS / 2.0);
+        let offset = to - from;
+        transform.rotation = Quat::from_rotation_z(offset.y.atan2(offset.x));
+    }
+}
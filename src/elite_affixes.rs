@@ -0,0 +1,165 @@
+/*!
+👑 ELITE AFFIXES
+Cada elite sorteia um afixo no spawn: Volátil (explode ao morrer), Blindado (escudo extra),
+Veloz (anda e ataca mais rápido) ou Vampírico (cura ao acertar o jogador).
+O afixo fica no inimigo como componente e os sistemas de IA, projéteis e morte consultam;
+uma joia flutuante na cor do afixo identifica o elite, e matá-lo rende recompensa extra
+*/
+
+use bevy::prelude::*;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use crate::arc_projectile::{spawn_arc_projectile, ArcProjectile};
+use crate::EnemyType;
+
+// Veloz: multiplicadores de movimento e cadência
+pub const SWIFT_SPEED_MULT: f32 = 1.5;
+pub const SWIFT_ATTACK_RATE_MULT: f32 = 1.3;
+// Vampírico: fração do dano causado que volta como vida
+pub const VAMPIRIC_HEAL_FRACTION: f32 = 0.5;
+// Blindado: escudo somado ao de base do inimigo
+pub const SHIELDED_EXTRA_SHIELD: f32 = 40.0;
+// Romãs do Poder por elite abatido (qualquer afixo)
+pub const ELITE_POM_REWARD: u32 = 1;
+// Volátil: explosão com pavio no ponto da morte
+const VOLATILE_FUSE: f32 = 0.8;
+const VOLATILE_RADIUS: f32 = 2.5;
+const VOLATILE_DAMAGE: f32 = 20.0;
+
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EliteAffix {
+    Volatile,
+    Shielded,
+    Swift,
+    Vampiric,
+}
+
+// Projétil de um elite vampírico: cura o dono ao acertar
+#[derive(Component)]
+pub struct VampiricShot {
+    pub owner: Entity,
+}
+
+// Joia flutuante acima do elite (filha do inimigo)
+#[derive(Component)]
+pub struct AffixIcon;
+
+impl EliteAffix {
+    const ALL: [EliteAffix; 4] = [EliteAffix::Volatile, EliteAffix::Shielded, EliteAffix::Swift, EliteAffix::Vampiric];
+
+    /// Sorteia um afixo que faça sentido para o tipo (jarros em arco não têm dono para curar)
+    pub fn roll(enemy_type: EnemyType, rng: &mut impl Rng) -> EliteAffix {
+        let allowed: Vec<EliteAffix> = Self::ALL.iter()
+            .copied()
+            .filter(|affix| !(*affix == EliteAffix::Vampiric && enemy_type == EnemyType::LobberMummy))
+            .collect();
+        *allowed.choose(rng).unwrap_or(&EliteAffix::Shielded)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            EliteAffix::Volatile => "Volátil",
+            EliteAffix::Shielded => "Blindado",
+            EliteAffix::Swift => "Veloz",
+            EliteAffix::Vampiric => "Vampírico",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            EliteAffix::Volatile => Color::rgb(1.0, 0.45, 0.1),
+            EliteAffix::Shielded => Color::rgb(0.3, 0.7, 1.0),
+            EliteAffix::Swift => Color::rgb(0.9, 0.95, 0.3),
+            EliteAffix::Vampiric => Color::rgb(0.75, 0.05, 0.2),
+        }
+    }
+
+    /// Moedas extras ao matar o elite; afixos mais perigosos pagam mais
+    pub fn bonus_coins(&self) -> u32 {
+        match self {
+            EliteAffix::Volatile | EliteAffix::Swift => 15,
+            EliteAffix::Shielded => 20,
+            EliteAffix::Vampiric => 25,
+        }
+    }
+
+    pub fn speed_multiplier(&self) -> f32 {
+        if *self == EliteAffix::Swift { SWIFT_SPEED_MULT } else { 1.0 }
+    }
+
+    pub fn attack_rate_multiplier(&self) -> f32 {
+        if *self == EliteAffix::Swift { SWIFT_ATTACK_RATE_MULT } else { 1.0 }
+    }
+}
+
+/// Joia do afixo acima da cabeça; `height` já no espaço local do inimigo
+pub fn spawn_affix_icon(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    enemy: Entity,
+    affix: EliteAffix,
+    height: f32,
+    size: f32,
+) {
+    let color = affix.color();
+    let icon = commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Cuboid::new(size, size, size)),
+            material: materials.add(StandardMaterial {
+                base_color: color,
+                emissive: color * 2.0,
+                unlit: true,
+                ..default()
+            }),
+            transform: Transform::from_xyz(0.0, height, 0.0)
+                .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4) * Quat::from_rotation_x(std::f32::consts::FRAC_PI_4)),
+            ..default()
+        },
+        AffixIcon,
+    )).id();
+    commands.entity(enemy).add_child(icon);
+}
+
+/// Volátil: deixa uma carga com pavio (e sombra de aviso) onde o elite caiu
+pub fn spawn_volatile_blast(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    position: Vec3,
+) {
+    let ground = Vec3::new(position.x, 0.5, position.z);
+    spawn_arc_projectile(
+        commands,
+        meshes,
+        materials,
+        ArcProjectile {
+            start: ground,
+            target: ground,
+            elapsed: 0.0,
+            flight_time: 0.0,
+            arc_height: 0.0,
+            fuse: VOLATILE_FUSE,
+            radius: VOLATILE_RADIUS,
+            damage: VOLATILE_DAMAGE,
+            from_enemy: true,
+            shadow: None,
+        },
+        0.35,
+        EliteAffix::Volatile.color(),
+    );
+}
+
+pub struct EliteAffixPlugin;
+
+impl Plugin for EliteAffixPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, spin_affix_icons);
+    }
+}
+
+fn spin_affix_icons(time: Res<Time>, mut icons: Query<&mut Transform, With<AffixIcon>>) {
+    for mut transform in &mut icons {
+        transform.rotate_y(2.0 * time.delta_seconds());
+    }
+}
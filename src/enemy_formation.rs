@@ -0,0 +1,156 @@
+/*!
+🛡️ ENEMY FORMATION
+Coordenador da sala: em vez de todo inimigo correr em linha reta para o jogador, cada passo
+fixo distribui papéis antes do ai_system:
+- Engajar: poucos corpo a corpo (os mais próximos) atacam de verdade
+- Cercar: os demais corpo a corpo esperam num anel em volta do jogador até abrir vaga
+- Segurar distância: atiradores e múmias se espalham em ângulos diferentes no seu anel
+
+O jogador enfrenta uma formação legível em vez de uma pilha
+*/
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::f32::consts::TAU;
+use crate::coop_system::Downed;
+use crate::{Enemy, EnemyType, Player, SimulationSet, Spawning, AI};
+
+// Corpo a corpo atacando ao mesmo tempo, por jogador de pé
+const MELEE_ENGAGE_PER_PLAYER: usize = 2;
+// Quem já estava engajado leva vantagem na disputa (evita trocar de papel a cada passo)
+const ENGAGE_STICKINESS: f32 = 2.0;
+// Chegou perto o bastante do lugar no anel
+const FORMATION_SLOT_TOLERANCE: f32 = 0.4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CombatRole {
+    Engage,
+    Surround { angle: f32 },
+    HoldRange { angle: f32 },
+}
+
+// Papéis da sala atual (só a sala atual tem inimigos vivos)
+#[derive(Resource, Default)]
+pub struct EnemyFormation {
+    roles: HashMap<Entity, CombatRole>,
+    pub engaged: usize,
+    pub surrounding: usize,
+    pub holding: usize,
+}
+
+impl EnemyFormation {
+    // Sem papel ainda (acabou de surgir): age como antes, direto no alvo
+    pub fn role(&self, entity: Entity) -> CombatRole {
+        self.roles.get(&entity).copied().unwrap_or(CombatRole::Engage)
+    }
+}
+
+// Raio do anel de cada tipo: chacais cercam fora do alcance da investida
+pub fn formation_radius(enemy_type: EnemyType) -> f32 {
+    match enemy_type {
+        EnemyType::SandJackal => 7.5,
+        EnemyType::Shooter => 5.0,
+        EnemyType::LobberMummy => 7.5,
+        EnemyType::Chaser | EnemyType::Tank => 4.5,
+    }
+}
+
+// Lugar no anel em volta do alvo, no plano do chão
+pub fn formation_slot(target: Vec3, angle: f32, radius: f32) -> Vec3 {
+    Vec3::new(target.x + angle.cos() * radius, 0.5, target.z + angle.sin() * radius)
+}
+
+// Um passo em direção ao lugar no anel, sem passar dele; já no lugar, fica parado
+pub fn step_toward_slot(position: Vec3, slot: Vec3, step: f32) -> Vec3 {
+    let offset = Vec3::new(slot.x - position.x, 0.0, slot.z - position.z);
+    let remaining = offset.length();
+    if remaining <= FORMATION_SLOT_TOLERANCE {
+        return position;
+    }
+    let moved = position + offset / remaining * step.min(remaining);
+    Vec3::new(moved.x, 0.5, moved.z)
+}
+
+pub struct FormationPlugin;
+
+impl Plugin for FormationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EnemyFormation>()
+            .add_systems(FixedUpdate, assign_combat_roles.in_set(SimulationSet).before(crate::ai_system));
+    }
+}
+
+// Ângulos igualmente espaçados no anel, na mesma ordem em que os inimigos já estão em
+// volta do alvo (assim os caminhos até o lugar de cada um quase não se cruzam)
+fn spread_angles(mut members: Vec<(Entity, f32)>) -> Vec<(Entity, f32)> {
+    members.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let Some(&(_, start)) = members.first() else {
+        return members;
+    };
+    let step = TAU / members.len() as f32;
+    members.into_iter()
+        .enumerate()
+        .map(|(index, (entity, _))| (entity, start + step * index as f32))
+        .collect()
+}
+
+#[allow(clippy::type_complexity)]
+fn assign_combat_roles(
+    mut formation: ResMut<EnemyFormation>,
+    players: Query<&Transform, (With<Player>, Without<Downed>)>,
+    enemies: Query<(Entity, &Transform, &EnemyType, &AI), (With<Enemy>, Without<Spawning>)>,
+) {
+    let positions: Vec<Vec3> = players.iter().map(|transform| transform.translation).collect();
+    let previous = std::mem::take(&mut formation.roles);
+    if positions.is_empty() {
+        *formation = EnemyFormation::default();
+        return;
+    }
+
+    // Cada inimigo se organiza em volta do jogador mais próximo
+    let mut melee = Vec::new();
+    let mut ranged = Vec::new();
+    for (entity, transform, enemy_type, ai) in &enemies {
+        let position = transform.translation;
+        let target = positions.iter()
+            .copied()
+            .min_by(|a, b| a.distance_squared(position).total_cmp(&b.distance_squared(position)))
+            .unwrap_or(position);
+        let bearing = (position.z - target.z).atan2(position.x - target.x);
+        match enemy_type {
+            EnemyType::Shooter | EnemyType::LobberMummy => ranged.push((entity, bearing)),
+            EnemyType::Chaser | EnemyType::Tank | EnemyType::SandJackal => {
+                // Golpe ou investida já em andamento não é interrompido pela troca de papel
+                let committed = ai.windup.is_some() || ai.lunge.is_some();
+                let mut score = position.distance(target);
+                if previous.get(&entity) == Some(&CombatRole::Engage) {
+                    score -= ENGAGE_STICKINESS;
+                }
+                melee.push((entity, bearing, committed, score));
+            }
+        }
+    }
+
+    let mut roles = HashMap::new();
+    melee.sort_by(|a, b| b.2.cmp(&a.2).then(a.3.total_cmp(&b.3)));
+    let slots = (MELEE_ENGAGE_PER_PLAYER * positions.len()).max(melee.iter().filter(|member| member.2).count());
+    let mut surrounders = Vec::new();
+    for (index, (entity, bearing, _, _)) in melee.into_iter().enumerate() {
+        if index < slots {
+            roles.insert(entity, CombatRole::Engage);
+        } else {
+            surrounders.push((entity, bearing));
+        }
+    }
+    let engaged = roles.len();
+    let surrounding = surrounders.len();
+    let holding = ranged.len();
+    for (entity, angle) in spread_angles(surrounders) {
+        roles.insert(entity, CombatRole::Surround { angle });
+    }
+    for (entity, angle) in spread_angles(ranged) {
+        roles.insert(entity, CombatRole::HoldRange { angle });
+    }
+
+    *formation = EnemyFormation { roles, engaged, surrounding, holding };
+}
@@ -0,0 +1,139 @@
+/*!
+❤️ ENEMY HEALTH BAR
+Barrinha de vida flutuando acima de cada inimigo comum (o chefe tem a barra própria na tela):
+fundo escuro e preenchimento proporcional a current_health / max_health, sempre virada para a
+câmera. Fica escondida com a vida cheia e aparece suavemente no primeiro dano. A barra segue o
+inimigo de fora (não é filha dele) e some junto quando ele morre ou é removido
+*/
+
+use bevy::prelude::*;
+use crate::boss_system::Boss;
+use crate::ui::AppState;
+use crate::{Dying, Enemy, Stats};
+
+const BAR_WIDTH: f32 = 0.9;
+const BAR_HEIGHT: f32 = 0.1;
+const BAR_OFFSET: f32 = 1.3; // acima do centro do inimigo, escalado pelo tamanho dele
+const BAR_FADE_SPEED: f32 = 4.0; // alfa por segundo
+const BAR_BACKGROUND: Color = Color::rgb(0.08, 0.05, 0.04);
+const BAR_FILL: Color = Color::rgb(0.85, 0.15, 0.1);
+
+#[derive(Component)]
+struct EnemyHealthBar {
+    enemy: Entity,
+    fill: Entity,
+    alpha: f32,
+}
+
+// Inimigo que já ganhou a barra
+#[derive(Component)]
+struct HasHealthBar;
+
+#[derive(Resource)]
+struct EnemyHealthBarMesh(Handle<Mesh>);
+
+impl FromWorld for EnemyHealthBarMesh {
+    fn from_world(world: &mut World) -> Self {
+        let mut meshes = world.resource_mut::<Assets<Mesh>>();
+        Self(meshes.add(Rectangle::new(BAR_WIDTH, BAR_HEIGHT)))
+    }
+}
+
+pub struct EnemyHealthBarPlugin;
+
+impl Plugin for EnemyHealthBarPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EnemyHealthBarMesh>()
+            .add_systems(Update, (
+                spawn_enemy_health_bars,
+                enemy_health_bar_system,
+            ).chain().run_if(in_state(AppState::InGame)));
+    }
+}
+
+fn bar_material(color: Color) -> StandardMaterial {
+    StandardMaterial {
+        base_color: color.with_a(0.0),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    }
+}
+
+// Material próprio por barra: o fade mexe no alfa de cada uma separadamente
+#[allow(clippy::type_complexity)]
+fn spawn_enemy_health_bars(
+    mut commands: Commands,
+    bar_mesh: Res<EnemyHealthBarMesh>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    enemies: Query<Entity, (With<Enemy>, Without<HasHealthBar>, Without<Boss>, Without<Dying>)>,
+) {
+    for enemy in &enemies {
+        let fill = commands.spawn(PbrBundle {
+            mesh: bar_mesh.0.clone(),
+            material: materials.add(bar_material(BAR_FILL)),
+            transform: Transform::from_xyz(0.0, 0.0, 0.01),
+            ..default()
+        }).id();
+        commands.spawn((
+            PbrBundle {
+                mesh: bar_mesh.0.clone(),
+                material: materials.add(bar_material(BAR_BACKGROUND)),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            EnemyHealthBar { enemy, fill, alpha: 0.0 },
+            Name::new("Enemy Health Bar"),
+        )).add_child(fill);
+        commands.entity(enemy).insert(HasHealthBar);
+    }
+}
+
+/// Posiciona, vira para a câmera e preenche cada barra; inimigo morrendo ou removido leva a barra junto
+#[allow(clippy::type_complexity)]
+fn enemy_health_bar_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    enemies: Query<(&Transform, &Stats), (With<Enemy>, Without<Dying>, Without<EnemyHealthBar>)>,
+    mut bars: Query<(Entity, &mut EnemyHealthBar, &mut Transform, &mut Visibility, &Handle<StandardMaterial>), Without<Enemy>>,
+    mut fills: Query<(&mut Transform, &Handle<StandardMaterial>), (Without<EnemyHealthBar>, Without<Enemy>)>,
+) {
+    let camera_rotation = cameras.iter()
+        .filter(|(camera, _)| camera.is_active)
+        .max_by_key(|(camera, _)| camera.order)
+        .map_or(Quat::IDENTITY, |(_, transform)| transform.compute_transform().rotation);
+
+    for (bar_entity, mut bar, mut transform, mut visibility, background) in &mut bars {
+        let Ok((enemy_transform, stats)) = enemies.get(bar.enemy) else {
+            commands.entity(bar_entity).despawn_recursive();
+            continue;
+        };
+        let ratio = (stats.current_health / stats.max_health.max(1.0)).clamp(0.0, 1.0);
+
+        // Vida cheia: some aos poucos; qualquer dano traz de volta
+        let target = if ratio < 1.0 { 1.0 } else { 0.0 };
+        let step = BAR_FADE_SPEED * time.delta_seconds();
+        bar.alpha = if bar.alpha < target { (bar.alpha + step).min(target) } else { (bar.alpha - step).max(target) };
+        *visibility = if bar.alpha > 0.0 { Visibility::Visible } else { Visibility::Hidden };
+        if bar.alpha <= 0.0 {
+            continue;
+        }
+
+        transform.translation = enemy_transform.translation + Vec3::Y * BAR_OFFSET * enemy_transform.scale.y;
+        transform.rotation = camera_rotation;
+        if let Some(material) = materials.get_mut(background) {
+            material.base_color.set_a(bar.alpha * 0.8);
+        }
+
+        // Preenchimento encolhe da direita para a esquerda
+        if let Ok((mut fill_transform, fill_material)) = fills.get_mut(bar.fill) {
+            fill_transform.scale.x = ratio.max(0.001);
+            fill_transform.translation.x = -(1.0 - ratio) * BAR_WIDTH / 2.0;
+            if let Some(material) = materials.get_mut(fill_material) {
+                material.base_color.set_a(bar.alpha);
+            }
+        }
+    }
+}
@@ -0,0 +1,292 @@
+/*!
+🧭 ENEMY PATHFINDING
+Paredes da sala atual viram obstáculos no plano do chão (caixas alargadas pelo corpo do inimigo).
+O ai_system pergunta o próximo ponto do caminho até o alvo: com a linha reta livre (o caso
+comum, sala aberta) volta o próprio alvo sem custo extra; com parede no meio roda um A* na
+grade de células bloqueadas da sala (montada uma vez por sala) e devolve o ponto mais distante
+do caminho que já dá para ver em linha reta. O caminho de cada inimigo vale por alguns passos
+*/
+
+use bevy::prelude::*;
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap};
+use crate::{GameState, SimulationSet};
+
+// Folga em volta das paredes (raio aproximado de um inimigo)
+const NAV_CLEARANCE: f32 = 0.5;
+// Grade do A*: tamanho da célula, margem em volta de origem/alvo e limite de células por eixo
+const NAV_CELL: f32 = 0.5;
+const NAV_SEARCH_MARGIN: f32 = 6.0;
+const NAV_MAX_CELLS: usize = 96;
+// Custos inteiros do A* (reto e diagonal)
+const STEP_COST: u32 = 10;
+const DIAGONAL_COST: u32 = 14;
+// Caminho guardado por inimigo: vale alguns passos, ou até o alvo se afastar do ponto usado
+const PATH_CACHE_TICKS: u32 = 6;
+const PATH_CACHE_GOAL_DRIFT: f32 = 1.0;
+
+/// Parede que bloqueia o caminho dos inimigos (meia largura em X e meia profundidade em Z)
+#[derive(Component)]
+pub struct WallObstacle {
+    pub half_extents: Vec2,
+    pub room: usize, // Só as paredes da sala atual entram no caminho
+}
+
+// Caixa no plano XZ
+#[derive(Clone, Copy)]
+struct NavBox {
+    min: Vec2,
+    max: Vec2,
+}
+
+impl NavBox {
+    fn contains(&self, point: Vec2) -> bool {
+        point.cmpge(self.min).all() && point.cmple(self.max).all()
+    }
+
+    // Teste de slab do segmento contra a caixa
+    fn blocks(&self, from: Vec2, to: Vec2) -> bool {
+        let delta = to - from;
+        let mut enter = 0.0_f32;
+        let mut exit = 1.0_f32;
+        for axis in 0..2 {
+            let (start, step, low, high) = (from[axis], delta[axis], self.min[axis], self.max[axis]);
+            if step.abs() < f32::EPSILON {
+                if start < low || start > high {
+                    return false;
+                }
+                continue;
+            }
+            let (a, b) = ((low - start) / step, (high - start) / step);
+            enter = enter.max(a.min(b));
+            exit = exit.min(a.max(b));
+            if enter > exit {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Obstáculos da sala atual, atualizados a cada passo antes da IA
+#[derive(Resource, Default)]
+pub struct NavObstacles {
+    room: Option<usize>,
+    walls: Vec<NavBox>,
+    boxes: Vec<NavBox>, // As mesmas paredes alargadas pela folga
+    // Células bloqueadas de cada sala já visitada (as paredes não mudam durante a run)
+    grids: HashMap<usize, NavGrid>,
+    // Último caminho de cada inimigo, reaproveitado por alguns passos
+    paths: HashMap<Entity, CachedPath>,
+}
+
+struct CachedPath {
+    goal: Vec2,
+    path: Vec<Vec2>, // Vazio: não havia caminho (persegue reto)
+    ticks_left: u32,
+}
+
+impl NavObstacles {
+    // Com a origem ou o alvo encostado na parede (dentro da folga) vale só a parede em si:
+    // senão quem encosta nunca sai dali e o jogador colado na parede vira caminho bloqueado
+    fn line_clear(&self, from: Vec2, to: Vec2) -> bool {
+        !self.walls.iter().zip(&self.boxes).any(|(wall, nav_box)| {
+            if nav_box.contains(from) || nav_box.contains(to) {
+                wall.blocks(from, to)
+            } else {
+                nav_box.blocks(from, to)
+            }
+        })
+    }
+
+    /// Próximo ponto para onde `enemy` deve andar indo de `from` até `to`. Linha reta livre ou
+    /// sem caminho encontrado: o próprio `to` (persegue reto como antes)
+    pub fn waypoint(&mut self, enemy: Entity, from: Vec3, to: Vec3) -> Vec3 {
+        let (start, goal) = (from.xz(), to.xz());
+        if self.line_clear(start, goal) {
+            return to;
+        }
+        // O A* só roda de novo quando o caminho guardado venceu ou o alvo andou demais
+        let fresh = self.paths.get(&enemy)
+            .is_some_and(|cached| cached.goal.distance(goal) <= PATH_CACHE_GOAL_DRIFT);
+        if !fresh {
+            let path = self.find_path(start, goal).unwrap_or_default();
+            self.paths.insert(enemy, CachedPath { goal, path, ticks_left: PATH_CACHE_TICKS });
+        }
+        // Corta cantos: o ponto mais adiantado do caminho que já está à vista
+        let next = self.paths[&enemy].path.iter()
+            .rev()
+            .find(|point| self.line_clear(start, **point))
+            .copied()
+            .unwrap_or(goal);
+        Vec3::new(next.x, to.y, next.y)
+    }
+
+    // Dentro da sala usa a grade guardada; fora dela (porta, corredor) monta uma em volta dos dois pontos
+    fn find_path(&self, start: Vec2, goal: Vec2) -> Option<Vec<Vec2>> {
+        let room_grid = self.room
+            .and_then(|room| self.grids.get(&room))
+            .filter(|grid| grid.contains(start) && grid.contains(goal));
+        if let Some(grid) = room_grid {
+            return grid.search(start, goal);
+        }
+        let origin = start.min(goal) - Vec2::splat(NAV_SEARCH_MARGIN);
+        let extent = start.max(goal) + Vec2::splat(NAV_SEARCH_MARGIN) - origin;
+        NavGrid::new(origin, extent, &self.boxes).search(start, goal)
+    }
+}
+
+// Grade do A*: quais células têm o centro dentro de um obstáculo
+struct NavGrid {
+    origin: Vec2,
+    cell: f32,
+    width: usize,
+    height: usize,
+    blocked: Vec<bool>,
+}
+
+impl NavGrid {
+    fn new(origin: Vec2, extent: Vec2, boxes: &[NavBox]) -> Self {
+        // Distâncias longas: células maiores em vez de uma grade gigante
+        let cell = NAV_CELL.max(extent.max_element() / NAV_MAX_CELLS as f32);
+        let width = ((extent.x / cell).ceil() as usize).max(1);
+        let height = ((extent.y / cell).ceil() as usize).max(1);
+        let mut grid = NavGrid { origin, cell, width, height, blocked: Vec::new() };
+        grid.blocked = (0..width * height)
+            .map(|i| {
+                let center = grid.center((i % width, i / width));
+                boxes.iter().any(|nav_box| nav_box.contains(center))
+            })
+            .collect();
+        grid
+    }
+
+    // Grade que cobre todas as paredes da sala
+    fn covering(boxes: &[NavBox]) -> Option<Self> {
+        let min = boxes.iter().map(|nav_box| nav_box.min).reduce(Vec2::min)?;
+        let max = boxes.iter().map(|nav_box| nav_box.max).reduce(Vec2::max)?;
+        Some(NavGrid::new(min, max - min, boxes))
+    }
+
+    fn contains(&self, point: Vec2) -> bool {
+        let size = Vec2::new(self.width as f32, self.height as f32) * self.cell;
+        point.cmpge(self.origin).all() && point.cmplt(self.origin + size).all()
+    }
+
+    fn to_cell(&self, point: Vec2) -> (usize, usize) {
+        let local = (point - self.origin) / self.cell;
+        ((local.x as usize).min(self.width - 1), (local.y as usize).min(self.height - 1))
+    }
+
+    fn center(&self, (x, y): (usize, usize)) -> Vec2 {
+        self.origin + (Vec2::new(x as f32, y as f32) + 0.5) * self.cell
+    }
+
+    // A* de 8 vizinhos; devolve os centros das células. As células da origem e do alvo
+    // nunca contam como bloqueadas
+    fn search(&self, start: Vec2, goal: Vec2) -> Option<Vec<Vec2>> {
+        let width = self.width;
+        let index = |(x, y): (usize, usize)| y * width + x;
+        let start_cell = self.to_cell(start);
+        let goal_cell = self.to_cell(goal);
+        let (start_index, goal_index) = (index(start_cell), index(goal_cell));
+        let blocked = |i: usize| self.blocked[i] && i != start_index && i != goal_index;
+
+        let heuristic = |(x, y): (usize, usize)| {
+            let dx = x.abs_diff(goal_cell.0) as u32;
+            let dy = y.abs_diff(goal_cell.1) as u32;
+            STEP_COST * dx.max(dy) + (DIAGONAL_COST - STEP_COST) * dx.min(dy)
+        };
+        let mut cost = vec![u32::MAX; width * self.height];
+        let mut came_from: Vec<Option<usize>> = vec![None; width * self.height];
+        let mut open = BinaryHeap::new();
+        cost[start_index] = 0;
+        open.push(Reverse((heuristic(start_cell), start_index)));
+
+        while let Some(Reverse((_, current))) = open.pop() {
+            if current == goal_index {
+                let mut path = vec![goal];
+                let mut node = came_from[current];
+                while let Some(previous) = node.filter(|previous| *previous != start_index) {
+                    path.push(self.center((previous % width, previous / width)));
+                    node = came_from[previous];
+                }
+                path.reverse();
+                return Some(path);
+            }
+            let (x, y) = (current % width, current / width);
+            for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1), (-1, -1), (-1, 1), (1, -1), (1, 1)] {
+                let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+                if nx < 0 || ny < 0 || nx >= width as i32 || ny >= self.height as i32 {
+                    continue;
+                }
+                let neighbour = index((nx as usize, ny as usize));
+                if blocked(neighbour) {
+                    continue;
+                }
+                // Diagonal só com os dois lados livres (não atravessa quina de parede)
+                let diagonal = dx != 0 && dy != 0;
+                if diagonal && (blocked(index((nx as usize, y))) || blocked(index((x, ny as usize)))) {
+                    continue;
+                }
+                let step = if diagonal { DIAGONAL_COST } else { STEP_COST };
+                let candidate = cost[current] + step;
+                if candidate < cost[neighbour] {
+                    cost[neighbour] = candidate;
+                    came_from[neighbour] = Some(current);
+                    open.push(Reverse((candidate + heuristic((nx as usize, ny as usize)), neighbour)));
+                }
+            }
+        }
+        None
+    }
+}
+
+pub struct EnemyPathfindingPlugin;
+
+impl Plugin for EnemyPathfindingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NavObstacles>()
+            .add_systems(FixedUpdate, update_nav_obstacles.in_set(SimulationSet).before(crate::ai_system));
+    }
+}
+
+fn update_nav_obstacles(
+    mut obstacles: ResMut<NavObstacles>,
+    game_state: Res<GameState>,
+    walls: Query<(&Transform, &WallObstacle)>,
+    new_walls: Query<(), Added<WallObstacle>>,
+) {
+    // Caminhos guardados vencem sozinhos (inclusive os de inimigos que já morreram)
+    obstacles.paths.retain(|_, cached| {
+        cached.ticks_left = cached.ticks_left.saturating_sub(1);
+        cached.ticks_left > 0
+    });
+    // Paredes novas (run nova): as grades guardadas eram das salas antigas
+    if !new_walls.is_empty() {
+        obstacles.grids.clear();
+        obstacles.room = None;
+    }
+    let room = game_state.current_room;
+    if obstacles.room == Some(room) {
+        return;
+    }
+
+    let NavObstacles { room: current, walls: wall_boxes, boxes, grids, paths } = &mut *obstacles;
+    *current = Some(room);
+    wall_boxes.clear();
+    boxes.clear();
+    paths.clear();
+    for (transform, wall) in walls.iter().filter(|(_, wall)| wall.room == room) {
+        let center = transform.translation.xz();
+        wall_boxes.push(NavBox { min: center - wall.half_extents, max: center + wall.half_extents });
+        let half = wall.half_extents + Vec2::splat(NAV_CLEARANCE);
+        boxes.push(NavBox { min: center - half, max: center + half });
+    }
+    if let Entry::Vacant(entry) = grids.entry(room) {
+        if let Some(grid) = NavGrid::covering(boxes) {
+            entry.insert(grid);
+        }
+    }
+}
@@ -0,0 +1,203 @@
+/*!
+🗺️ FAST TRAVEL
+Painel de viagem (M) sobre o minimapa: teleporta para salas já limpas e conectadas
+*/
+
+use bevy::prelude::*;
+use crate::ui::AppState;
+use crate::{FixedInput, GameState, Player, PlayerId, Room, RoomEnteredEvent, RoomTransition, RoomType, SimulationSet, COOP_SPAWN_SPACING};
+
+const TRAVEL_ENABLED_COLOR: Color = Color::rgb(0.2, 0.15, 0.1);
+const TRAVEL_HOVER_COLOR: Color = Color::rgb(0.35, 0.25, 0.15);
+const TRAVEL_DISABLED_COLOR: Color = Color::rgba(0.1, 0.08, 0.05, 0.6);
+
+#[derive(Component)]
+struct FastTravelPanel;
+
+#[derive(Component)]
+struct TravelButton {
+    room_id: usize,
+    enabled: bool,
+}
+
+pub struct FastTravelPlugin;
+
+impl Plugin for FastTravelPlugin {
+    fn build(&self, app: &mut App) {
+        // M lê a entrada da simulação (replays); os botões do painel seguem no quadro
+        app.add_systems(FixedUpdate, toggle_travel_panel.in_set(SimulationSet).run_if(in_state(AppState::InGame)))
+            .add_systems(Update, travel_button_system.run_if(in_state(AppState::InGame)))
+            .add_systems(OnExit(AppState::InGame), cleanup_travel_panel);
+    }
+}
+
+// Salas alcançáveis a partir da atual: conexões desbloqueadas (nos dois sentidos) passando só por salas limpas
+fn reachable_rooms(current: usize, rooms: &[&Room], transitions: &[&RoomTransition]) -> Vec<usize> {
+    let is_cleared = |id: usize| rooms.iter().any(|room| room.id == id && room.cleared);
+    if !is_cleared(current) {
+        return Vec::new();
+    }
+
+    let mut visited = vec![current];
+    let mut frontier = vec![current];
+    while let Some(room_id) = frontier.pop() {
+        for transition in transitions.iter().filter(|transition| transition.active) {
+            let neighbor = if transition.from_room == room_id {
+                transition.to_room
+            } else if transition.to_room == room_id {
+                transition.from_room
+            } else {
+                continue;
+            };
+            if !visited.contains(&neighbor) && is_cleared(neighbor) {
+                visited.push(neighbor);
+                frontier.push(neighbor);
+            }
+        }
+    }
+    visited.retain(|&id| id != current);
+    visited
+}
+
+fn room_type_name(room_type: RoomType) -> &'static str {
+    match room_type {
+        RoomType::Start => "Início",
+        RoomType::Combat => "Combate",
+        RoomType::Boss => "Chefe",
+        RoomType::Treasure => "Tesouro",
+        RoomType::Shop => "Loja",
+    }
+}
+
+fn toggle_travel_panel(
+    mut commands: Commands,
+    fixed_input: Res<FixedInput>,
+    game_state: Res<GameState>,
+    rooms: Query<&Room>,
+    transitions: Query<&RoomTransition>,
+    panels: Query<Entity, With<FastTravelPanel>>,
+) {
+    if !fixed_input.get(PlayerId::P1).toggle_travel {
+        return;
+    }
+    if let Ok(panel) = panels.get_single() {
+        commands.entity(panel).despawn_recursive();
+        return;
+    }
+
+    let mut room_list: Vec<&Room> = rooms.iter().collect();
+    room_list.sort_by_key(|room| room.id);
+    let transition_list: Vec<&RoomTransition> = transitions.iter().collect();
+    let reachable = reachable_rooms(game_state.current_room, &room_list, &transition_list);
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                right: Val::Px(50.0),
+                bottom: Val::Px(240.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            border_color: Color::rgb(0.8, 0.6, 0.2).into(),
+            background_color: Color::rgba(0.1, 0.08, 0.05, 0.9).into(),
+            ..default()
+        },
+        FastTravelPanel,
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            "Viagem (M)",
+            TextStyle {
+                font_size: 20.0,
+                color: Color::rgb(0.9, 0.8, 0.4),
+                ..default()
+            },
+        ));
+
+        for room in room_list {
+            let is_current = room.id == game_state.current_room;
+            let enabled = reachable.contains(&room.id);
+            let status = if is_current {
+                "você está aqui"
+            } else if enabled {
+                "viajar"
+            } else if room.cleared {
+                "sem conexão"
+            } else {
+                "bloqueada"
+            };
+
+            parent.spawn((
+                ButtonBundle {
+                    style: Style {
+                        padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    border_color: Color::rgb(0.8, 0.6, 0.2).into(),
+                    background_color: if enabled { TRAVEL_ENABLED_COLOR } else { TRAVEL_DISABLED_COLOR }.into(),
+                    ..default()
+                },
+                TravelButton { room_id: room.id, enabled },
+            )).with_children(|parent| {
+                parent.spawn(TextBundle::from_section(
+                    format!("Sala {} - {} ({})", room.id + 1, room_type_name(room.room_type), status),
+                    TextStyle {
+                        font_size: 16.0,
+                        color: if enabled { Color::rgb(0.9, 0.85, 0.7) } else { Color::rgb(0.5, 0.45, 0.4) },
+                        ..default()
+                    },
+                ));
+            });
+        }
+    });
+}
+
+fn travel_button_system(
+    mut commands: Commands,
+    mut game_state: ResMut<GameState>,
+    mut buttons: Query<(&Interaction, &TravelButton, &mut BackgroundColor), Changed<Interaction>>,
+    rooms: Query<&Room>,
+    mut player_query: Query<(&PlayerId, &mut Transform), With<Player>>,
+    panels: Query<Entity, With<FastTravelPanel>>,
+    mut entered_events: EventWriter<RoomEnteredEvent>,
+) {
+    for (interaction, button, mut color) in &mut buttons {
+        if !button.enabled {
+            continue;
+        }
+        match *interaction {
+            Interaction::Pressed => {
+                let Some(room) = rooms.iter().find(|room| room.id == button.room_id) else {
+                    continue;
+                };
+                // Mesma entrada usada pelas transições normais; o grupo viaja junto
+                for (id, mut player_transform) in &mut player_query {
+                    let offset = id.index() as f32 * COOP_SPAWN_SPACING;
+                    player_transform.translation = Vec3::new(room.center.x - 5.0, 0.5, room.center.y + offset);
+                }
+                game_state.previous_room = game_state.current_room;
+                game_state.current_room = room.id;
+                // Chegar viajando conta como entrar pela porta (banner, obeliscos, fila de spawns)
+                entered_events.send(RoomEnteredEvent { room: room.id });
+                info!("🗺️ Fast travel to room {}", room.id);
+
+                for panel in &panels {
+                    commands.entity(panel).despawn_recursive();
+                }
+                return;
+            }
+            Interaction::Hovered => *color = TRAVEL_HOVER_COLOR.into(),
+            Interaction::None => *color = TRAVEL_ENABLED_COLOR.into(),
+        }
+    }
+}
+
+fn cleanup_travel_panel(mut commands: Commands, panels: Query<Entity, With<FastTravelPanel>>) {
+    for panel in &panels {
+        commands.entity(panel).despawn_recursive();
+    }
+}
@@ -46,6 +46,7 @@ impl Plugin for Hades3DPlugin {
 fn load_3d_assets(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    mut required: ResMut<crate::ui::RequiredAssets>,
 ) {
     info!("Loading Hades-style 3D assets...");
     
@@ -56,6 +57,9 @@ fn load_3d_assets(
         isis_npc: asset_server.load("3d/hades_quality/isis_npc_hades.glb#Scene0"),
     };
     
+    for scene in [&assets.pharaoh_hero, &assets.anubis_boss, &assets.mummy_enemy, &assets.isis_npc] {
+        required.track(scene);
+    }
     commands.insert_resource(assets);
     info!("3D assets loading initiated");
 }
@@ -143,6 +147,7 @@ fn spawn_pharaoh_hero(
     mut commands: Commands,
     assets: Res<Hades3DAssets>,
     asset_server: Res<AssetServer>,
+    tuning: Res<crate::combat_tuning::CombatTuning>,
     mut spawned: Local<bool>,
 ) {
     if *spawned {
@@ -189,16 +194,11 @@ fn spawn_pharaoh_hero(
         },
         MainCharacter,
         crate::Player, // Make compatible with existing movement system
-        crate::Stats {
-            max_health: 100.0,
-            current_health: 100.0,
-            max_stamina: 100.0,
-            current_stamina: 100.0,
-            speed: 8.0,
-            stamina_regen_rate: 50.0,
-        },
-        crate::Dash::default(),
-        crate::Combat::default(), // Add combat component for combat system
+        crate::PlayerId::P1,
+        tuning.player_stats(),
+        tuning.dash(),
+        crate::Velocity::default(),
+        tuning.combat_for(crate::WeaponKind::default()), // Add combat component for combat system
         Name::new("Pharaoh Hero"),
     ));
     
@@ -208,18 +208,19 @@ fn spawn_pharaoh_hero(
 
 fn animate_characters(
     time: Res<Time>,
-    mut query: Query<&mut Transform, With<HadesCharacter>>,
+    mut query: Query<(&mut Transform, Option<&crate::Facing>), With<HadesCharacter>>,
 ) {
     // Simple breathing/idle animation
     let breathing = (time.elapsed_seconds() * 2.0).sin() * 0.02;
     
-    for mut transform in query.iter_mut() {
+    for (mut transform, facing) in query.iter_mut() {
         // Subtle breathing animation
         let original_scale = 1.0;
         transform.scale.y = original_scale + breathing * 0.1;
         
-        // Very slight rotation for life
-        transform.rotation = Quat::from_rotation_y((time.elapsed_seconds() * 0.5).sin() * 0.05);
+        // Very slight rotation for life, on top of where the hero is facing (player_facing_system)
+        let yaw = facing.map_or(0.0, |facing| facing.yaw);
+        transform.rotation = Quat::from_rotation_y(yaw + (time.elapsed_seconds() * 0.5).sin() * 0.05);
     }
 }
 
@@ -231,15 +232,17 @@ pub fn spawn_hades_enemy(
     position: Vec3,
     ai: crate::AI,
     stats: crate::Stats,
-) {
+) -> Entity {
     let (scene_handle, character_name) = match enemy_type {
         crate::EnemyType::Chaser => (assets.mummy_enemy.clone(), "mummy_enemy"),
         crate::EnemyType::Shooter => (assets.anubis_boss.clone(), "anubis_boss"),
         crate::EnemyType::Tank => (assets.anubis_boss.clone(), "anubis_boss"),
+        crate::EnemyType::LobberMummy => (assets.mummy_enemy.clone(), "mummy_enemy"),
+        crate::EnemyType::SandJackal => (assets.mummy_enemy.clone(), "mummy_enemy"),
     };
     
     info!("🔥 Spawning Hades enemy with scene: {:?}", scene_handle);
-    commands.spawn((
+    let entity = commands.spawn((
         SceneBundle {
             scene: scene_handle,
             transform: Transform::from_translation(position)
@@ -252,12 +255,15 @@ pub fn spawn_hades_enemy(
             max_health: stats.max_health,
         },
         crate::Enemy,
+        // O ai_system só conduz inimigos com EnemyType: sem ele os modelos glTF ficavam parados
+        enemy_type,
         ai,
         stats,
         Name::new(format!("Hades Enemy: {}", character_name)),
-    ));
+    )).id();
     
     info!("Spawned Hades-style enemy: {} at position {:?}", character_name, position);
+    entity
 }
 
 fn update_camera_follow(
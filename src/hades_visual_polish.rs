@@ -20,7 +20,7 @@ impl Plugin for HadesVisualPolishPlugin {
             ))
             .add_systems(Update, (
                 update_dynamic_lighting,
-                update_camera_effects,
+                update_camera_effects.after(crate::coop_system::CoopCameraSet),
                 performance_monitor,
             ))
             .insert_resource(Msaa::Sample4) // Anti-aliasing for quality
@@ -197,8 +197,8 @@ fn update_camera_effects(
         // Subtle camera breathing (very slight movement for immersion)
         let breathe = (time.elapsed_seconds() * 0.8).sin() * 0.02;
         
-        // Apply very subtle position variation
-        transform.translation.y = 12.0 + breathe;
+        // Apply very subtle position variation (on top of the follow camera's framing)
+        transform.translation.y += breathe;
     }
 }
 
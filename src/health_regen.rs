@@ -0,0 +1,198 @@
+/*!
+💚 HEALTH REGEN
+Cura contínua dos boons HealthRegen (Aura Curativa de Ísis, Abraço Amplo, sinergia do
+panteão). A cura fracionária de cada passo fixo se acumula e só entra na vida em pontos
+inteiros; de tempos em tempos um "+N" verde sobe da cabeça do jogador mostrando quanto curou
+*/
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::boons::{health_regen_per_second, ActiveBoons};
+use crate::coop_system::Downed;
+use crate::meta_progression::HeatModifiers;
+use crate::ui::AppState;
+use crate::{Player, SimulationSet, Stats};
+
+// A cada intervalo o total curado vira um número na tela
+const REGEN_POPUP_INTERVAL: f32 = 2.0;
+const REGEN_POPUP_LIFETIME: f32 = 0.9;
+const REGEN_POPUP_RISE: f32 = 40.0; // pixels subidos até sumir
+const REGEN_POPUP_HEIGHT: f32 = 2.2; // acima do jogador, em unidades do mundo
+
+#[derive(Default)]
+struct RegenTally {
+    pending: f32, // fração de vida ainda não aplicada
+    healed: f32,  // curado desde o último número
+    popup_timer: f32,
+}
+
+#[derive(Component)]
+struct HealPopup {
+    anchor: Vec3,
+    timer: f32,
+}
+
+pub struct HealthRegenPlugin;
+
+impl Plugin for HealthRegenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(FixedUpdate, health_regen_system.in_set(SimulationSet).run_if(in_state(AppState::InGame)))
+            .add_systems(Update, update_heal_popups.run_if(in_state(AppState::InGame)))
+            .add_systems(OnExit(AppState::InGame), cleanup_heal_popups);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn health_regen_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    active_boons: Res<ActiveBoons>,
+    heat: Res<HeatModifiers>,
+    mut players: Query<(Entity, &Transform, &mut Stats), (With<Player>, Without<Downed>)>,
+    mut tallies: Local<HashMap<Entity, RegenTally>>,
+) {
+    let regen = health_regen_per_second(&active_boons) * heat.healing_multiplier();
+    if regen <= 0.0 {
+        tallies.clear();
+        return;
+    }
+    let dt = time.delta_seconds();
+
+    for (entity, transform, mut stats) in &mut players {
+        let tally = tallies.entry(entity).or_default();
+        if stats.current_health <= 0.0 {
+            continue;
+        }
+
+        // Vida cheia não guarda cura para depois
+        if stats.current_health < stats.max_health {
+            tally.pending += regen * dt;
+        } else {
+            tally.pending = 0.0;
+        }
+        let whole = tally.pending.floor();
+        if whole >= 1.0 {
+            tally.pending -= whole;
+            let healed = whole.min(stats.max_health - stats.current_health);
+            stats.current_health += healed;
+            tally.healed += healed;
+        }
+
+        tally.popup_timer += dt;
+        if tally.popup_timer >= REGEN_POPUP_INTERVAL {
+            tally.popup_timer = 0.0;
+            if tally.healed >= 1.0 {
+                spawn_heal_popup(&mut commands, transform.translation, tally.healed);
+                tally.healed = 0.0;
+            }
+        }
+    }
+}
+
+fn spawn_heal_popup(commands: &mut Commands, position: Vec3, amount: f32) {
+    let mut text = TextBundle::from_section(
+        format!("+{}", amount.round()),
+        TextStyle {
+            font_size: 20.0,
+            color: Color::rgb(0.4, 1.0, 0.5),
+            ..default()
+        },
+    ).with_style(Style {
+        position_type: PositionType::Absolute,
+        ..default()
+    });
+    // Só aparece depois de posicionado na tela
+    text.visibility = Visibility::Hidden;
+    commands.spawn((
+        text,
+        HealPopup {
+            anchor: position + Vec3::Y * REGEN_POPUP_HEIGHT,
+            timer: REGEN_POPUP_LIFETIME,
+        },
+    ));
+}
+
+// Projeta o ponto acima do jogador na tela, sobe e esmaece
+fn update_heal_popups(
+    mut commands: Commands,
+    time: Res<Time>,
+    ui_scale: Res<UiScale>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    mut popups: Query<(Entity, &mut HealPopup, &mut Style, &mut Text, &mut Visibility)>,
+) {
+    let camera = cameras.iter()
+        .filter(|(camera, _)| camera.is_active)
+        .max_by_key(|(camera, _)| camera.order);
+
+    for (entity, mut popup, mut style, mut text, mut visibility) in &mut popups {
+        popup.timer -= time.delta_seconds();
+        if popup.timer <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        let Some(screen) = camera.and_then(|(camera, transform)| camera.world_to_viewport(transform, popup.anchor)) else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        let progress = 1.0 - popup.timer / REGEN_POPUP_LIFETIME;
+        style.left = Val::Px(screen.x / ui_scale.0);
+        style.top = Val::Px(screen.y / ui_scale.0 - REGEN_POPUP_RISE * progress);
+        for section in &mut text.sections {
+            section.style.color.set_a(1.0 - progress);
+        }
+        *visibility = Visibility::Visible;
+    }
+}
+
+fn cleanup_heal_popups(mut commands: Commands, popups: Query<Entity, With<HealPopup>>) {
+    for entity in &popups {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::boons::create_isis_boons;
+    use std::time::Duration;
+
+    // Jogador com a Aura Curativa de Ísis (2 de vida por segundo)
+    fn regen_world(current_health: f32) -> (World, Entity) {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(HeatModifiers::default());
+        let aura = create_isis_boons().into_iter().find(|boon| boon.id == "isis_healing_aura").unwrap();
+        world.insert_resource(ActiveBoons { player_boons: vec![aura], synergy_bonuses: Vec::new() });
+        let player = world.spawn((
+            Player,
+            Transform::default(),
+            Stats { max_health: 100.0, current_health, ..default() },
+        )).id();
+        (world, player)
+    }
+
+    // A fração acumulada fica num Local: o mesmo sistema roda em todos os passos
+    fn run_seconds(world: &mut World, seconds: u32) {
+        let regen = world.register_system(health_regen_system);
+        // Um passo a mais para a soma em ponto flutuante passar do inteiro
+        for _ in 0..seconds * 60 + 1 {
+            world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(1.0 / 60.0));
+            world.run_system(regen).unwrap();
+        }
+    }
+
+    #[test]
+    fn regen_heals_per_second_in_whole_points() {
+        let (mut world, player) = regen_world(50.0);
+        run_seconds(&mut world, 5);
+        assert_eq!(world.get::<Stats>(player).unwrap().current_health, 60.0);
+    }
+
+    #[test]
+    fn regen_stops_at_max_health() {
+        let (mut world, player) = regen_world(97.0);
+        run_seconds(&mut world, 5);
+        assert_eq!(world.get::<Stats>(player).unwrap().current_health, 100.0);
+    }
+}
@@ -0,0 +1,108 @@
+/*!
+💠 IFRAME FLASH
+Enquanto o dash (ou o avanço do especial, o blink) deixa o jogador invulnerável, o modelo
+pisca num brilho ciano. As cores originais dos materiais (do jogador e dos filhos: modelo
+do GLTF, arma) são guardadas uma vez no começo e devolvidas quando a invulnerabilidade acaba,
+então dashes seguidos não acumulam tinta. Cair ou morrer no meio do dash também restaura
+*/
+
+use bevy::prelude::*;
+use crate::coop_system::Downed;
+use crate::ui::AppState;
+use crate::{Dash, Player, Stats};
+
+const FLASH_COLOR: Color = Color::rgb(0.3, 0.9, 1.0);
+const FLASH_PULSE_SPEED: f32 = 30.0;
+const FLASH_MAX_GLOW: f32 = 1.5;
+const FLASH_TINT: f32 = 0.35; // quanto a cor base puxa para o ciano
+
+// Materiais do jogador com as cores de antes do flash
+#[derive(Component)]
+struct IFrameFlash {
+    originals: Vec<(Handle<StandardMaterial>, Color, Color)>, // (material, base_color, emissive)
+}
+
+pub struct IFrameFlashPlugin;
+
+impl Plugin for IFrameFlashPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, iframe_flash_system.run_if(in_state(AppState::InGame)))
+            // Tela de morte/menu no meio do dash: ninguém fica preso no brilho
+            .add_systems(OnExit(AppState::InGame), clear_iframe_flashes);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn iframe_flash_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    players: Query<(Entity, &Dash, &Stats, Has<Downed>, Option<&IFrameFlash>), With<Player>>,
+    children: Query<&Children>,
+    handles: Query<&Handle<StandardMaterial>>,
+) {
+    let pulse = (time.elapsed_seconds() * FLASH_PULSE_SPEED).sin() * 0.5 + 0.5;
+    for (entity, dash, stats, downed, flash) in &players {
+        let invulnerable = dash.i_timer > 0.0 && stats.current_health > 0.0 && !downed;
+        match (invulnerable, flash) {
+            (true, Some(flash)) => {
+                for (handle, base_color, emissive) in &flash.originals {
+                    if let Some(material) = materials.get_mut(handle) {
+                        material.base_color = tint_toward_flash(*base_color);
+                        material.emissive = *emissive + FLASH_COLOR * (pulse * FLASH_MAX_GLOW);
+                    }
+                }
+            }
+            (true, None) => {
+                // Guarda as cores uma vez só (o pulso começa no próximo quadro)
+                let mut originals: Vec<(Handle<StandardMaterial>, Color, Color)> = Vec::new();
+                for member in std::iter::once(entity).chain(children.iter_descendants(entity)) {
+                    let Ok(handle) = handles.get(member) else {
+                        continue;
+                    };
+                    if originals.iter().any(|(known, ..)| known == handle) {
+                        continue;
+                    }
+                    if let Some(material) = materials.get(handle) {
+                        originals.push((handle.clone(), material.base_color, material.emissive));
+                    }
+                }
+                commands.entity(entity).insert(IFrameFlash { originals });
+            }
+            (false, Some(flash)) => {
+                restore_materials(&mut materials, flash);
+                commands.entity(entity).remove::<IFrameFlash>();
+            }
+            (false, None) => {}
+        }
+    }
+}
+
+fn tint_toward_flash(base: Color) -> Color {
+    Color::rgba(
+        base.r() + (FLASH_COLOR.r() - base.r()) * FLASH_TINT,
+        base.g() + (FLASH_COLOR.g() - base.g()) * FLASH_TINT,
+        base.b() + (FLASH_COLOR.b() - base.b()) * FLASH_TINT,
+        base.a(),
+    )
+}
+
+fn restore_materials(materials: &mut Assets<StandardMaterial>, flash: &IFrameFlash) {
+    for (handle, base_color, emissive) in &flash.originals {
+        if let Some(material) = materials.get_mut(handle) {
+            material.base_color = *base_color;
+            material.emissive = *emissive;
+        }
+    }
+}
+
+fn clear_iframe_flashes(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    flashing: Query<(Entity, &IFrameFlash)>,
+) {
+    for (entity, flash) in &flashing {
+        restore_materials(&mut materials, flash);
+        commands.entity(entity).remove::<IFrameFlash>();
+    }
+}
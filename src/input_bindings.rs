@@ -0,0 +1,223 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+
+const BINDINGS_SAVE_PATH: &str = "saves/bindings.json";
+
+/// Ações do teclado/mouse do P1 que podem ser remapeadas (o gamepad do P2 tem o próprio mapa)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InputAction {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Dash,
+    Interact,
+    UseItem,
+    FastTravel,
+    PlaceTotem,
+    Blink,
+    Primary,
+    Secondary,
+    AbilityQ,
+    AbilityR,
+}
+
+impl InputAction {
+    pub fn get_all() -> Vec<InputAction> {
+        vec![
+            InputAction::MoveUp,
+            InputAction::MoveDown,
+            InputAction::MoveLeft,
+            InputAction::MoveRight,
+            InputAction::Dash,
+            InputAction::Blink,
+            InputAction::Primary,
+            InputAction::Secondary,
+            InputAction::AbilityQ,
+            InputAction::AbilityR,
+            InputAction::Interact,
+            InputAction::UseItem,
+            InputAction::PlaceTotem,
+            InputAction::FastTravel,
+        ]
+    }
+
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            InputAction::MoveUp => "Mover para Cima",
+            InputAction::MoveDown => "Mover para Baixo",
+            InputAction::MoveLeft => "Mover para a Esquerda",
+            InputAction::MoveRight => "Mover para a Direita",
+            InputAction::Dash => "Dash",
+            InputAction::Interact => "Interagir",
+            InputAction::UseItem => "Usar Item",
+            InputAction::FastTravel => "Viagem Rápida",
+            InputAction::PlaceTotem => "Obelisco",
+            InputAction::Blink => "Blink",
+            InputAction::Primary => "Ataque",
+            InputAction::Secondary => "Ataque Especial",
+            InputAction::AbilityQ => "Habilidade Q",
+            InputAction::AbilityR => "Habilidade R",
+        }
+    }
+
+    fn default_binding(&self) -> InputBinding {
+        match self {
+            InputAction::MoveUp => InputBinding::Key(KeyCode::KeyW),
+            InputAction::MoveDown => InputBinding::Key(KeyCode::KeyS),
+            InputAction::MoveLeft => InputBinding::Key(KeyCode::KeyA),
+            InputAction::MoveRight => InputBinding::Key(KeyCode::KeyD),
+            InputAction::Dash => InputBinding::Key(KeyCode::Space),
+            InputAction::Interact => InputBinding::Key(KeyCode::KeyE),
+            InputAction::UseItem => InputBinding::Key(KeyCode::KeyF),
+            InputAction::FastTravel => InputBinding::Key(KeyCode::KeyM),
+            InputAction::PlaceTotem => InputBinding::Key(KeyCode::KeyT),
+            InputAction::Blink => InputBinding::Key(KeyCode::ShiftLeft),
+            InputAction::Primary => InputBinding::Mouse(MouseButton::Left),
+            InputAction::Secondary => InputBinding::Mouse(MouseButton::Right),
+            InputAction::AbilityQ => InputBinding::Key(KeyCode::KeyQ),
+            InputAction::AbilityR => InputBinding::Key(KeyCode::KeyR),
+        }
+    }
+}
+
+/// Tecla ou botão do mouse ligado a uma ação
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InputBinding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+}
+
+impl InputBinding {
+    // Nome curto para menus e avisos ("W", "Espaço", "Mouse Esq.")
+    pub fn label(&self) -> String {
+        match self {
+            InputBinding::Key(KeyCode::Space) => "Espaço".to_string(),
+            InputBinding::Key(KeyCode::ShiftLeft) => "Shift Esq.".to_string(),
+            InputBinding::Key(KeyCode::ShiftRight) => "Shift Dir.".to_string(),
+            InputBinding::Key(KeyCode::ControlLeft) => "Ctrl Esq.".to_string(),
+            InputBinding::Key(KeyCode::ControlRight) => "Ctrl Dir.".to_string(),
+            InputBinding::Key(KeyCode::AltLeft) => "Alt".to_string(),
+            InputBinding::Key(KeyCode::ArrowUp) => "↑".to_string(),
+            InputBinding::Key(KeyCode::ArrowDown) => "↓".to_string(),
+            InputBinding::Key(KeyCode::ArrowLeft) => "←".to_string(),
+            InputBinding::Key(KeyCode::ArrowRight) => "→".to_string(),
+            InputBinding::Key(key) => {
+                let name = format!("{:?}", key);
+                name.strip_prefix("Key")
+                    .or_else(|| name.strip_prefix("Digit"))
+                    .unwrap_or(&name)
+                    .to_string()
+            }
+            InputBinding::Mouse(MouseButton::Left) => "Mouse Esq.".to_string(),
+            InputBinding::Mouse(MouseButton::Right) => "Mouse Dir.".to_string(),
+            InputBinding::Mouse(MouseButton::Middle) => "Mouse Meio".to_string(),
+            InputBinding::Mouse(button) => format!("Mouse {:?}", button),
+        }
+    }
+}
+
+/// Mapa ação -> tecla/botão do P1, salvo entre sessões. O read_input consulta este mapa
+/// em vez de teclas fixas (um teclado AZERTY troca WASD por ZQSD no menu de controles)
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct InputBindings {
+    bindings: HashMap<InputAction, InputBinding>,
+}
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        Self {
+            bindings: InputAction::get_all()
+                .into_iter()
+                .map(|action| (action, action.default_binding()))
+                .collect(),
+        }
+    }
+}
+
+impl InputBindings {
+    pub fn load() -> Self {
+        let mut bindings = match fs::read_to_string(BINDINGS_SAVE_PATH) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|err| {
+                warn!("⚠️ Corrupted bindings file, using defaults: {}", err);
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        };
+        // Ações novas (arquivo de uma versão anterior) ficam com a tecla padrão
+        for action in InputAction::get_all() {
+            bindings.bindings.entry(action).or_insert_with(|| action.default_binding());
+        }
+        bindings
+    }
+
+    pub fn save(&self) {
+        if let Some(parent) = std::path::Path::new(BINDINGS_SAVE_PATH).parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+
+        match serde_json::to_string_pretty(self) {
+            Ok(contents) => {
+                if let Err(err) = fs::write(BINDINGS_SAVE_PATH, contents) {
+                    warn!("⚠️ Failed to save bindings: {}", err);
+                }
+            }
+            Err(err) => warn!("⚠️ Failed to serialize bindings: {}", err),
+        }
+    }
+
+    pub fn binding(&self, action: InputAction) -> InputBinding {
+        self.bindings.get(&action).copied().unwrap_or_else(|| action.default_binding())
+    }
+
+    pub fn pressed(&self, action: InputAction, keys: &ButtonInput<KeyCode>, mouse: &ButtonInput<MouseButton>) -> bool {
+        match self.binding(action) {
+            InputBinding::Key(key) => keys.pressed(key),
+            InputBinding::Mouse(button) => mouse.pressed(button),
+        }
+    }
+
+    pub fn just_pressed(&self, action: InputAction, keys: &ButtonInput<KeyCode>, mouse: &ButtonInput<MouseButton>) -> bool {
+        match self.binding(action) {
+            InputBinding::Key(key) => keys.just_pressed(key),
+            InputBinding::Mouse(button) => mouse.just_pressed(button),
+        }
+    }
+
+    pub fn just_released(&self, action: InputAction, keys: &ButtonInput<KeyCode>, mouse: &ButtonInput<MouseButton>) -> bool {
+        match self.binding(action) {
+            InputBinding::Key(key) => keys.just_released(key),
+            InputBinding::Mouse(button) => mouse.just_released(button),
+        }
+    }
+
+    /// Liga a ação à nova tecla e salva. Se outra ação já usava essa tecla, as duas trocam
+    /// (ninguém fica sem tecla nem com tecla repetida); devolve a ação que foi trocada
+    pub fn rebind(&mut self, action: InputAction, binding: InputBinding) -> Option<InputAction> {
+        let previous = self.binding(action);
+        let conflict = InputAction::get_all()
+            .into_iter()
+            .find(|other| *other != action && self.binding(*other) == binding);
+        if let Some(other) = conflict {
+            self.bindings.insert(other, previous);
+        }
+        self.bindings.insert(action, binding);
+        self.save();
+        conflict
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+        self.save();
+    }
+}
+
+pub struct InputBindingsPlugin;
+
+impl Plugin for InputBindingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(InputBindings::load());
+    }
+}
@@ -0,0 +1,197 @@
+/*!
+✨ INTERACT HIGHLIGHT
+Retorno único para tudo que se usa com interagir (E / botão do controle): baús, portas e o que
+vier depois só precisam do componente Interactable. O mais próximo ao alcance de um jogador
+pulsa com brilho próprio e ganha o aviso "[E] ..." (tecla de interagir atual) flutuando acima dele
+*/
+
+use bevy::prelude::*;
+use crate::coop_system::Downed;
+use crate::input_bindings::{InputAction, InputBindings};
+use crate::ui::AppState;
+use crate::Player;
+
+const HIGHLIGHT_PULSE_SPEED: f32 = 5.0;
+const HIGHLIGHT_COLOR: Color = Color::rgb(1.0, 0.85, 0.4);
+const HIGHLIGHT_MAX_GLOW: f32 = 0.8;
+const PROMPT_HEIGHT: f32 = 1.6; // acima do objeto, em unidades do mundo
+const PROMPT_FONT_SIZE: f32 = 20.0;
+
+#[derive(Component)]
+pub struct Interactable {
+    pub prompt: &'static str,
+    pub radius: f32,
+    pub enabled: bool, // porta trancada, baú já aberto...
+}
+
+impl Interactable {
+    pub fn new(prompt: &'static str, radius: f32) -> Self {
+        Self { prompt, radius, enabled: true }
+    }
+}
+
+/// Objeto em foco (o mais próximo ao alcance de algum jogador)
+#[derive(Resource, Default)]
+pub struct InteractFocus {
+    pub entity: Option<Entity>,
+}
+
+// Brilho original guardado enquanto o objeto está destacado
+#[derive(Component)]
+struct InteractHighlight {
+    base_emissive: Color,
+}
+
+#[derive(Component)]
+struct InteractPrompt;
+
+pub struct InteractHighlightPlugin;
+
+impl Plugin for InteractHighlightPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InteractFocus>()
+            .add_systems(OnEnter(AppState::InGame), setup_interact_prompt)
+            .add_systems(OnExit(AppState::InGame), (cleanup_interact_prompt, clear_interact_highlights))
+            .add_systems(Update, (
+                update_interact_focus,
+                pulse_interact_highlight,
+                position_interact_prompt,
+            ).chain().run_if(in_state(AppState::InGame)));
+    }
+}
+
+fn update_interact_focus(
+    mut focus: ResMut<InteractFocus>,
+    players: Query<&Transform, (With<Player>, Without<Downed>)>,
+    interactables: Query<(Entity, &GlobalTransform, &Interactable)>,
+) {
+    focus.entity = interactables.iter()
+        .filter(|(_, _, interactable)| interactable.enabled)
+        .filter_map(|(entity, transform, interactable)| {
+            let position = transform.translation();
+            players.iter()
+                .map(|player| {
+                    let offset = player.translation - position;
+                    Vec3::new(offset.x, 0.0, offset.z).length()
+                })
+                .filter(|distance| *distance <= interactable.radius)
+                .min_by(f32::total_cmp)
+                .map(|distance| (entity, distance))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _)| entity);
+}
+
+// Pulso no emissivo do material do próprio objeto; quem perde o foco volta ao brilho original
+fn pulse_interact_highlight(
+    mut commands: Commands,
+    time: Res<Time>,
+    focus: Res<InteractFocus>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    focused: Query<(&Handle<StandardMaterial>, Option<&InteractHighlight>), With<Interactable>>,
+    highlighted: Query<(Entity, &Handle<StandardMaterial>, &InteractHighlight)>,
+) {
+    for (entity, handle, highlight) in &highlighted {
+        if focus.entity == Some(entity) {
+            continue;
+        }
+        if let Some(material) = materials.get_mut(handle) {
+            material.emissive = highlight.base_emissive;
+        }
+        commands.entity(entity).remove::<InteractHighlight>();
+    }
+
+    let Some(entity) = focus.entity else {
+        return;
+    };
+    let Ok((handle, highlight)) = focused.get(entity) else {
+        return;
+    };
+    let Some(material) = materials.get_mut(handle) else {
+        return;
+    };
+    let base_emissive = match highlight {
+        Some(highlight) => highlight.base_emissive,
+        None => {
+            commands.entity(entity).insert(InteractHighlight { base_emissive: material.emissive });
+            material.emissive
+        }
+    };
+    let pulse = (time.elapsed_seconds() * HIGHLIGHT_PULSE_SPEED).sin() * 0.5 + 0.5;
+    material.emissive = base_emissive + HIGHLIGHT_COLOR * (pulse * HIGHLIGHT_MAX_GLOW);
+}
+
+fn setup_interact_prompt(mut commands: Commands) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: PROMPT_FONT_SIZE,
+                color: HIGHLIGHT_COLOR,
+                ..default()
+            },
+        ).with_style(Style {
+            position_type: PositionType::Absolute,
+            ..default()
+        }),
+        Visibility::Hidden,
+        InteractPrompt,
+    ));
+}
+
+fn position_interact_prompt(
+    focus: Res<InteractFocus>,
+    interactables: Query<(&GlobalTransform, &Interactable)>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    ui_scale: Res<UiScale>,
+    bindings: Res<InputBindings>,
+    mut prompts: Query<(&mut Text, &mut Style, &mut Visibility, &Node), With<InteractPrompt>>,
+) {
+    let Ok((mut text, mut style, mut visibility, node)) = prompts.get_single_mut() else {
+        return;
+    };
+    let camera = cameras.iter()
+        .filter(|(camera, _)| camera.is_active)
+        .max_by_key(|(camera, _)| camera.order);
+    let target = focus.entity.and_then(|entity| interactables.get(entity).ok());
+
+    let screen_pos = target.zip(camera).and_then(|((transform, _), (camera, camera_transform))| {
+        camera.world_to_viewport(camera_transform, transform.translation() + Vec3::Y * PROMPT_HEIGHT)
+    });
+    let (Some((_, interactable)), Some(screen_pos)) = (target, screen_pos) else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+
+    let label = format!("[{}] {}", bindings.binding(InputAction::Interact).label(), interactable.prompt);
+    if text.sections[0].value != label {
+        text.sections[0].value = label;
+    }
+    // Centralizado sobre o objeto (pixels da viewport -> unidades da UI)
+    let anchor = (screen_pos - node.size() / 2.0) / ui_scale.0;
+    style.left = Val::Px(anchor.x);
+    style.top = Val::Px(anchor.y);
+    *visibility = Visibility::Visible;
+}
+
+fn cleanup_interact_prompt(mut commands: Commands, prompts: Query<Entity, With<InteractPrompt>>) {
+    for entity in &prompts {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// Saindo do jogo (menu, recompensa) nenhum objeto fica preso no brilho do destaque
+fn clear_interact_highlights(
+    mut commands: Commands,
+    mut focus: ResMut<InteractFocus>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    highlighted: Query<(Entity, &Handle<StandardMaterial>, &InteractHighlight)>,
+) {
+    focus.entity = None;
+    for (entity, handle, highlight) in &highlighted {
+        if let Some(material) = materials.get_mut(handle) {
+            material.emissive = highlight.base_emissive;
+        }
+        commands.entity(entity).remove::<InteractHighlight>();
+    }
+}
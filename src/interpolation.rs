@@ -0,0 +1,103 @@
+/*!
+🎞️ INTERPOLATION
+A simulação anda a 60 Hz em FixedUpdate; num monitor de 165 Hz jogadores, inimigos e
+projéteis ficariam parados por dois ou três quadros e depois pulariam. Aqui a posição
+desenhada é interpolada entre os dois últimos passos fixos pela fração de overstep.
+
+Ciclo de cada quadro:
+- PreUpdate: devolve ao Transform a posição real da simulação (o que a IA, colisões e
+  sistemas de Update enxergam é sempre a posição simulada)
+- FixedLast: ao fim de cada passo fixo guarda a posição anterior e a nova
+- PostUpdate: antes da propagação, troca a posição pela interpolada só para o desenho
+
+Só a translação é interpolada: escala e rotação continuam livres para os efeitos visuais
+em Update (pulso de dano, morte). Teletransportes feitos fora do passo fixo (troca de
+sala, viagem rápida) são detectados e entram sem interpolar
+*/
+
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+use crate::arc_projectile::ArcProjectile;
+use crate::{Dying, Enemy, Player, Projectile};
+
+#[derive(Component)]
+pub struct PreviousTransform {
+    pub translation: Vec3, // Posição no passo fixo anterior
+    simulated: Vec3,       // Posição no último passo fixo
+    rendered: Vec3,        // Posição desenhada neste quadro
+}
+
+impl PreviousTransform {
+    fn new(translation: Vec3) -> Self {
+        Self { translation, simulated: translation, rendered: translation }
+    }
+
+    // Sem interpolação: anterior, simulada e desenhada na mesma posição
+    fn snap(&mut self, translation: Vec3) {
+        *self = Self::new(translation);
+    }
+}
+
+pub struct InterpolationPlugin;
+
+impl Plugin for InterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreUpdate, restore_simulated_translation)
+            .add_systems(FixedLast, record_simulated_translation)
+            .add_systems(PostUpdate, (
+                attach_previous_transform,
+                interpolate_rendered_translation,
+            ).chain().before(TransformSystem::TransformPropagate));
+    }
+}
+
+// Jogadores, inimigos e projéteis (retos e em arco) entram na interpolação ao surgir
+#[allow(clippy::type_complexity)]
+fn attach_previous_transform(
+    mut commands: Commands,
+    new_entities: Query<
+        (Entity, &Transform),
+        (Without<PreviousTransform>, Or<(With<Player>, With<Enemy>, With<Projectile>, With<ArcProjectile>)>),
+    >,
+) {
+    for (entity, transform) in &new_entities {
+        commands.entity(entity).insert(PreviousTransform::new(transform.translation));
+    }
+}
+
+fn restore_simulated_translation(mut query: Query<(&mut Transform, &mut PreviousTransform), Without<Dying>>) {
+    for (mut transform, mut previous) in &mut query {
+        if transform.translation == previous.rendered {
+            transform.translation = previous.simulated;
+        } else {
+            // Movido depois da interpolação do quadro anterior: vale a posição nova
+            let translation = transform.translation;
+            previous.snap(translation);
+        }
+    }
+}
+
+fn record_simulated_translation(mut query: Query<(&Transform, &mut PreviousTransform), Without<Dying>>) {
+    for (transform, mut previous) in &mut query {
+        previous.translation = previous.simulated;
+        previous.simulated = transform.translation;
+    }
+}
+
+fn interpolate_rendered_translation(
+    fixed_time: Res<Time<Fixed>>,
+    mut query: Query<(&mut Transform, &mut PreviousTransform), Without<Dying>>,
+) {
+    let alpha = fixed_time.overstep_fraction();
+    for (mut transform, mut previous) in &mut query {
+        // Teletransportado em Update (troca de sala, viagem rápida): entra direto
+        if transform.translation != previous.simulated {
+            let translation = transform.translation;
+            previous.snap(translation);
+            continue;
+        }
+        let rendered = previous.translation.lerp(previous.simulated, alpha);
+        transform.translation = rendered;
+        previous.rendered = rendered;
+    }
+}
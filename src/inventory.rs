@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+// Itens consumíveis de uso único
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConsumableKind {
+    HealthPotion,  // Poção de cura
+    StaminaElixir, // Elixir de vigor
+    Bomb,          // Bomba de nafta
+}
+
+impl ConsumableKind {
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            ConsumableKind::HealthPotion => "Poção de Cura",
+            ConsumableKind::StaminaElixir => "Elixir de Vigor",
+            ConsumableKind::Bomb => "Bomba",
+        }
+    }
+
+    pub fn get_color(&self) -> Color {
+        match self {
+            ConsumableKind::HealthPotion => Color::rgb(0.9, 0.2, 0.3),
+            ConsumableKind::StaminaElixir => Color::rgb(0.2, 0.7, 0.9),
+            ConsumableKind::Bomb => Color::rgb(1.0, 0.5, 0.1),
+        }
+    }
+}
+
+/// Consumíveis carregados pelo jogador - o primeiro da fila é usado com F
+#[derive(Resource)]
+pub struct Inventory {
+    pub items: Vec<ConsumableKind>,
+    pub capacity: usize,
+}
+
+impl Default for Inventory {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            capacity: 3,
+        }
+    }
+}
+
+impl Inventory {
+    /// Adiciona um item; retorna false se o inventário estiver cheio
+    pub fn add(&mut self, item: ConsumableKind) -> bool {
+        if self.items.len() >= self.capacity {
+            return false;
+        }
+        self.items.push(item);
+        true
+    }
+
+    pub fn take_next(&mut self) -> Option<ConsumableKind> {
+        if self.items.is_empty() {
+            None
+        } else {
+            Some(self.items.remove(0))
+        }
+    }
+}
@@ -4,5 +4,9 @@ pub mod components;
 pub mod boons;
 pub mod placeholder_assets;
 pub mod hades_assets;
+pub mod meta_progression;
+pub mod inventory;
+pub mod settings;
+pub mod input_bindings;
 
 pub use components::*;
\ No newline at end of file
@@ -0,0 +1,212 @@
+/*!
+💰 LOOT TABLE
+Saque de cada inimigo ao morrer: uma tabela com pesos por tipo (moedas, orbe de vida,
+consumível, romã) sorteada no enemy_death_system. Elites e o chefe têm tabelas mais ricas
+e drops garantidos. O reward_multiplier do modelo da sala aumenta as moedas e deixa o
+"nada" menos provável. Orbes de vida ficam no chão até um jogador passar por cima
+*/
+
+use bevy::prelude::*;
+use rand::Rng;
+use crate::coop_system::Downed;
+use crate::meta_progression::HeatModifiers;
+use crate::ui::{AppState, DamageEvent, DamageType};
+use crate::{EnemyType, Player, Stats};
+
+const ORB_PICKUP_RADIUS: f32 = 1.1;
+const ORB_BOB_SPEED: f32 = 3.0;
+const ORB_BOB_HEIGHT: f32 = 0.15;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LootDrop {
+    Nothing,
+    Coins { min: u32, max: u32 },
+    HealthOrb { heal: f32 },
+    Consumable,
+    Pom, // Romã do Poder: moeda rara das bênçãos
+}
+
+#[derive(Component, Clone)]
+pub struct LootTable {
+    pub rolls: u32,
+    pub entries: Vec<(LootDrop, f32)>, // (drop, peso)
+    pub guaranteed: Vec<LootDrop>,
+}
+
+impl LootTable {
+    pub fn for_enemy(enemy_type: EnemyType) -> Self {
+        let (rolls, entries) = match enemy_type {
+            EnemyType::Chaser => (1, vec![
+                (LootDrop::Nothing, 50.0),
+                (LootDrop::Coins { min: 2, max: 4 }, 35.0),
+                (LootDrop::HealthOrb { heal: 10.0 }, 10.0),
+                (LootDrop::Consumable, 5.0),
+            ]),
+            EnemyType::Shooter => (1, vec![
+                (LootDrop::Nothing, 45.0),
+                (LootDrop::Coins { min: 3, max: 5 }, 35.0),
+                (LootDrop::HealthOrb { heal: 10.0 }, 10.0),
+                (LootDrop::Consumable, 10.0),
+            ]),
+            EnemyType::Tank => (2, vec![
+                (LootDrop::Nothing, 40.0),
+                (LootDrop::Coins { min: 4, max: 8 }, 35.0),
+                (LootDrop::HealthOrb { heal: 15.0 }, 15.0),
+                (LootDrop::Consumable, 10.0),
+            ]),
+            EnemyType::LobberMummy => (1, vec![
+                (LootDrop::Nothing, 45.0),
+                (LootDrop::Coins { min: 3, max: 6 }, 30.0),
+                (LootDrop::HealthOrb { heal: 10.0 }, 10.0),
+                (LootDrop::Consumable, 15.0),
+            ]),
+            EnemyType::SandJackal => (1, vec![
+                (LootDrop::Nothing, 55.0),
+                (LootDrop::Coins { min: 2, max: 4 }, 35.0),
+                (LootDrop::HealthOrb { heal: 8.0 }, 8.0),
+                (LootDrop::Consumable, 2.0),
+            ]),
+        };
+        Self { rolls, entries, guaranteed: Vec::new() }
+    }
+
+    // Elite: dois sorteios a mais, nunca vem vazio, chance de romã e um consumível garantido
+    pub fn elite(mut self) -> Self {
+        self.rolls += 2;
+        self.entries.retain(|(drop, _)| *drop != LootDrop::Nothing);
+        self.entries.push((LootDrop::Pom, 5.0));
+        self.guaranteed.push(LootDrop::Consumable);
+        self
+    }
+
+    pub fn boss() -> Self {
+        Self {
+            rolls: 5,
+            entries: vec![
+                (LootDrop::Coins { min: 15, max: 30 }, 50.0),
+                (LootDrop::HealthOrb { heal: 25.0 }, 25.0),
+                (LootDrop::Consumable, 15.0),
+                (LootDrop::Pom, 10.0),
+            ],
+            guaranteed: vec![LootDrop::Pom, LootDrop::HealthOrb { heal: 40.0 }],
+        }
+    }
+}
+
+/// Único sorteio de saque: vale para qualquer morte (golpe, projétil, explosão, status)
+pub fn roll_loot(table: &LootTable, reward_multiplier: f32, rng: &mut impl Rng) -> Vec<LootDrop> {
+    let multiplier = reward_multiplier.max(0.1);
+    // Salas mais generosas deixam o "nada" menos provável
+    let weight = |(drop, weight): &(LootDrop, f32)| if *drop == LootDrop::Nothing { weight / multiplier } else { *weight };
+    let total: f32 = table.entries.iter().map(weight).sum();
+
+    let mut drops = table.guaranteed.clone();
+    for _ in 0..table.rolls {
+        if total <= 0.0 {
+            break;
+        }
+        let mut pick = rng.gen::<f32>() * total;
+        let rolled = table.entries.iter()
+            .find(|entry| {
+                pick -= weight(entry);
+                pick <= 0.0
+            })
+            .or(table.entries.last())
+            .map(|(drop, _)| *drop);
+        if let Some(drop) = rolled.filter(|drop| *drop != LootDrop::Nothing) {
+            drops.push(drop);
+        }
+    }
+
+    // Moedas já saem com a quantidade final
+    drops.into_iter()
+        .map(|drop| match drop {
+            LootDrop::Coins { min, max } => {
+                let coins = (rng.gen_range(min..=max) as f32 * multiplier).round() as u32;
+                LootDrop::Coins { min: coins, max: coins }
+            }
+            other => other,
+        })
+        .collect()
+}
+
+#[derive(Component)]
+pub struct HealthOrb {
+    heal: f32,
+    age: f32,
+}
+
+pub struct LootTablePlugin;
+
+impl Plugin for LootTablePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (animate_health_orbs, collect_health_orbs).run_if(in_state(AppState::InGame)))
+            .add_systems(OnEnter(AppState::Loading), cleanup_health_orbs);
+    }
+}
+
+pub fn spawn_health_orb(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    position: Vec3,
+    heal: f32,
+) {
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Sphere::new(0.25)),
+            material: materials.add(StandardMaterial {
+                base_color: Color::rgb(0.9, 0.2, 0.25),
+                emissive: Color::rgb(1.5, 0.2, 0.3),
+                ..default()
+            }),
+            transform: Transform::from_translation(Vec3::new(position.x, 0.4, position.z)),
+            ..default()
+        },
+        HealthOrb { heal, age: 0.0 },
+        Name::new("Health Orb"),
+    ));
+}
+
+fn animate_health_orbs(time: Res<Time>, mut orbs: Query<(&mut Transform, &mut HealthOrb)>) {
+    for (mut transform, mut orb) in &mut orbs {
+        orb.age += time.delta_seconds();
+        transform.translation.y = 0.4 + (orb.age * ORB_BOB_SPEED).sin() * ORB_BOB_HEIGHT;
+    }
+}
+
+// Só é recolhido por quem está ferido: com vida cheia o orbe espera no chão
+#[allow(clippy::type_complexity)]
+fn collect_health_orbs(
+    mut commands: Commands,
+    heat: Res<HeatModifiers>,
+    orbs: Query<(Entity, &Transform, &HealthOrb)>,
+    mut players: Query<(&Transform, &mut Stats), (With<Player>, Without<Downed>)>,
+    mut damage_events: EventWriter<DamageEvent>,
+) {
+    for (entity, orb_transform, orb) in &orbs {
+        let collector = players.iter_mut().find(|(transform, stats)| {
+            let offset = orb_transform.translation - transform.translation;
+            Vec3::new(offset.x, 0.0, offset.z).length() <= ORB_PICKUP_RADIUS && stats.current_health < stats.max_health
+        });
+        if let Some((transform, mut stats)) = collector {
+            let heal = orb.heal * heat.healing_multiplier();
+            stats.current_health = (stats.current_health + heal).min(stats.max_health);
+            // "+N" verde sobre quem pegou
+            damage_events.send(DamageEvent {
+                position: transform.translation,
+                damage: heal.round() as i32,
+                damage_type: DamageType::Heal,
+                is_critical: false,
+            });
+            commands.entity(entity).despawn_recursive();
+            info!("❤️ Health orb +{:.0}", heal);
+        }
+    }
+}
+
+fn cleanup_health_orbs(mut commands: Commands, orbs: Query<Entity, With<HealthOrb>>) {
+    for entity in &orbs {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
+use bevy::audio::Volume;
 use bevy::window::{WindowResolution, PresentMode};
+use std::collections::HashMap;
 
 mod asset_loader;
 mod sprite_animation;
@@ -13,6 +15,51 @@ mod ui;
 mod procedural;
 mod components;
 mod boons;
+mod meta_progression;
+mod summon_system;
+mod inventory;
+mod settings;
+mod input_bindings;
+mod consumable_system;
+mod arc_projectile;
+mod boss_system;
+mod status_effects;
+mod combo_system;
+mod fast_travel;
+mod compass_system;
+mod damage_indicator;
+mod replay_system;
+mod coop_system;
+mod debug_tools;
+mod combat_tuning;
+mod aim_assist;
+mod dungeon_map;
+mod elite_affixes;
+mod room_banner;
+mod trap_system;
+mod chest_system;
+mod shop_system;
+mod decoy_system;
+mod totem_system;
+mod corpse_system;
+mod spawn_governor;
+mod interpolation;
+mod health_regen;
+mod momentum_system;
+mod clear_timer;
+mod loot_table;
+mod door_preview;
+mod interact_highlight;
+mod bestiary;
+mod slow_motion;
+mod billboard_sort;
+mod projectile_pool;
+mod enemy_health_bar;
+mod iframe_flash;
+mod practice_room;
+mod enemy_pathfinding;
+mod adaptive_difficulty;
+mod enemy_formation;
 
 use asset_loader::{AssetLoaderPlugin, GameAssets};
 use sprite_animation::SpriteAnimationPlugin;
@@ -27,15 +74,64 @@ use ui::{
     HudSystemPlugin, 
     TransitionSystemPlugin,
     BoonSelectionPlugin,
+    RewardChoicePlugin,
+    BoonUpgradePlugin,
+    LevelUpPlugin,
     CombatFeedbackPlugin,
+    DamageEvent,
+    DamageType,
+    UiPalettePlugin,
     AppState,
 };
-use procedural::ProceduralPlugin;
+use procedural::{ProceduralPlugin, BiomeType, GeneratedDungeon, RoomId, RunRng, RunRngStream};
 use components::*;
-use boons::BoonSystemPlugin;
+use boons::{BoonSystemPlugin, BuildCodePlugin};
 use placeholder_assets::PlaceholderAssetsPlugin;
+use summon_system::SummonPlugin;
+use consumable_system::ConsumablePlugin;
+use boss_system::{Boss, BossEnrage, BossPlugin, spawn_reinforcement_portal};
+use status_effects::{ApplyStatusEvent, OnHitStatus, StatusEffects, StatusEffectsPlugin, attack_burn, attack_on_hit_statuses, heavy_hit_stuns, q_on_hit_status, r_on_hit_status};
+use combo_system::{ComboMeter, ComboPlugin};
+use fast_travel::FastTravelPlugin;
+use compass_system::CompassPlugin;
+use damage_indicator::{DamageIndicatorPlugin, PlayerDamagedEvent};
+use replay_system::ReplayPlugin;
+use debug_tools::DebugToolsPlugin;
+use combat_tuning::{CombatTuning, CombatTuningPlugin};
+use aim_assist::apply_aim_assist;
+use dungeon_map::DungeonMapPlugin;
+use elite_affixes::{EliteAffix, EliteAffixPlugin, VampiricShot, ELITE_POM_REWARD, VAMPIRIC_HEAL_FRACTION};
+use room_banner::RoomBannerPlugin;
+use trap_system::TrapPlugin;
+use chest_system::ChestPlugin;
+use shop_system::ShopPlugin;
+use decoy_system::{Decoy, DecoyPlugin, DECOY_AGGRO_RADIUS};
+use totem_system::TotemPlugin;
+use corpse_system::CorpsePlugin;
+use spawn_governor::{QueuedSpawn, SpawnGovernor, SpawnGovernorPlugin, SpawnSource};
+use interpolation::InterpolationPlugin;
+use health_regen::HealthRegenPlugin;
+use momentum_system::{Momentum, MomentumPlugin};
+use clear_timer::ClearTimerPlugin;
+use loot_table::{roll_loot, LootDrop, LootTable, LootTablePlugin};
+use door_preview::DoorPreviewPlugin;
+use interact_highlight::{Interactable, InteractHighlightPlugin};
+use slow_motion::{SlowMotion, SlowMotionMoment, SlowMotionPlugin};
+use billboard_sort::{billboard_material, BillboardSort, BillboardSortPlugin};
+use projectile_pool::{fire_projectile, reclaim_projectile, ProjectilePool, ProjectilePoolPlugin, ProjectileVisual};
+use enemy_health_bar::EnemyHealthBarPlugin;
+use iframe_flash::IFrameFlashPlugin;
+use enemy_pathfinding::{EnemyPathfindingPlugin, NavObstacles, WallObstacle};
+use practice_room::PracticeRoomPlugin;
+use adaptive_difficulty::{AdaptiveDifficulty, AdaptiveDifficultyPlugin};
+use enemy_formation::{formation_radius, formation_slot, step_toward_slot, CombatRole, EnemyFormation, FormationPlugin};
+use coop_system::{CoopPlugin, Downed, GamepadInputState, COOP_SPAWN_SPACING};
+use arc_projectile::{ArcProjectile, ArcProjectilePlugin, spawn_arc_projectile};
+use settings::SettingsPlugin;
+use input_bindings::{InputAction, InputBindings, InputBindingsPlugin};
+use meta_progression::{MetaProgressionPlugin, HeatModifiers, RunCompletedEvent, RunLoadout, RunStats};
 
-// 🔧 Controles estilo Hades (Mouse + R/Q)
+// 🔧 Controles estilo Hades (Mouse + R/Q) - padrão; remapeáveis em Configurações > Controles
 // * Mover: WASD
 // * Dash: Espaço (com i-frames)
 // * Ataque principal: Mouse Esquerdo
@@ -43,7 +139,10 @@ use placeholder_assets::PlaceholderAssetsPlugin;
 // * Habilidade principal: R (explosão/AoE curta)
 // * Habilidade extra: Q (cast/projétil)
 // * Interagir/Avançar: E
+// * Viagem rápida: M
 // * Menu: Esc
+// * P2 (gamepad): Start entra, analógicos movem/miram, A dash, X ataque, Y secundário,
+//   RB Q, RT R, B interagir, LB consumível
 
 #[derive(Resource, Default, Clone, Copy)]
 pub struct InputState {
@@ -53,12 +152,81 @@ pub struct InputState {
     pub right: bool,
     pub dash: bool,
     pub interact: bool,
+    pub use_item: bool, // F: usar consumível
+    pub toggle_travel: bool, // M: painel de viagem rápida
+    pub place_totem: bool, // T: ergue o obelisco (bênção de Rá)
+    pub blink: bool, // Shift: teleporte curto até a mira
     // Remapeamentos Hades-like:
     pub primary: bool,    // Mouse Esquerdo: ataque principal
+    pub primary_held: bool,     // Segurando o primário (carregando)
+    pub primary_released: bool, // Soltou o primário (libera ataque carregado)
     pub secondary: bool,  // Mouse Direito: ataque secundário (especial leve)
+    pub secondary_held: bool, // Segurando o secundário (bloqueio das armas com escudo)
     pub ability_q: bool,  // Q: habilidade extra (cast)
     pub ability_r: bool,  // R: habilidade principal (AoE)
-    pub mouse_world_pos: Vec3, // Posição do mouse no mundo 3D
+    pub ability_r_held: bool,     // Segurando R (mirando a AoE)
+    pub ability_r_released: bool, // Soltou R (detona a AoE)
+    pub join: bool, // Start no gamepad: P2 entra no jogo (co-op)
+    pub mouse_world_pos: Vec3, // Posição do mouse (ou da mira do analógico) no mundo 3D
+}
+
+impl InputState {
+    // Acumula os pulsos ainda não consumidos com a entrada nova
+    fn latched_with(&self, live: &InputState) -> InputState {
+        InputState {
+            dash: self.dash || live.dash,
+            interact: self.interact || live.interact,
+            use_item: self.use_item || live.use_item,
+            toggle_travel: self.toggle_travel || live.toggle_travel,
+            place_totem: self.place_totem || live.place_totem,
+            blink: self.blink || live.blink,
+            primary: self.primary || live.primary,
+            primary_released: self.primary_released || live.primary_released,
+            secondary: self.secondary || live.secondary,
+            ability_q: self.ability_q || live.ability_q,
+            ability_r: self.ability_r || live.ability_r,
+            ability_r_released: self.ability_r_released || live.ability_r_released,
+            join: self.join || live.join,
+            ..*live
+        }
+    }
+
+    // Só as teclas seguradas continuam valendo
+    fn without_pulses(&self) -> InputState {
+        InputState {
+            dash: false,
+            interact: false,
+            use_item: false,
+            toggle_travel: false,
+            place_totem: false,
+            blink: false,
+            primary: false,
+            primary_released: false,
+            secondary: false,
+            ability_q: false,
+            ability_r: false,
+            ability_r_released: false,
+            join: false,
+            ..*self
+        }
+    }
+}
+
+// Simulação (movimento, IA, combate, projéteis) roda em FixedUpdate com passo fixo
+const SIMULATION_HZ: f64 = 60.0;
+
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+struct SimulationSet;
+
+// Entrada vista pela simulação, uma por jogador: pulsos (just_pressed) ficam travados
+// até um tick de FixedUpdate consumi-los, para não serem perdidos nem repetidos entre quadros
+#[derive(Resource, Default)]
+struct FixedInput([InputState; MAX_PLAYERS]);
+
+impl FixedInput {
+    fn get(&self, id: PlayerId) -> &InputState {
+        &self.0[id.index()]
+    }
 }
 
 fn main() {
@@ -67,7 +235,7 @@ fn main() {
             primary_window: Some(Window {
                 title: "Sands of Duat - Hades-like Egyptian Roguelike".into(),
                 resolution: WindowResolution::new(3440.0, 1440.0),
-                present_mode: PresentMode::AutoNoVsync, // For 120fps benchmark
+                present_mode: PresentMode::Fifo, // Trocado pelas configurações (VSync / sem limite / limite de FPS)
                 resizable: false,
                 ..default()
             }),
@@ -79,7 +247,11 @@ fn main() {
         .add_plugins(HudSystemPlugin)
         .add_plugins(TransitionSystemPlugin)
         .add_plugins(BoonSelectionPlugin)
+        .add_plugins(RewardChoicePlugin) // Escolha bênção / moedas / cura ao limpar a sala
+        .add_plugins(BoonUpgradePlugin) // Altar das romãs: sobe o nível de bênçãos (U)
+        .add_plugins(LevelUpPlugin) // XP da run e bônus de atributo a cada nível
         .add_plugins(CombatFeedbackPlugin)
+        .add_plugins(UiPalettePlugin) // Paleta normal ou daltônica para raridades e dano
         .add_plugins(HadesUIPlugin) // NEW: Hades-quality Egyptian UI system
         // Game Systems
         .add_plugins(AssetLoaderPlugin)
@@ -87,6 +259,49 @@ fn main() {
         .add_plugins(HadesAssetsPlugin) // NEW: Hades-style Egyptian art system
         .add_plugins(ProceduralPlugin)
         .add_plugins(BoonSystemPlugin) // NEW: Egyptian god boon system with synergies
+        .add_plugins(BuildCodePlugin) // Códigos de build: F8 exporta bênçãos e semente
+        .add_plugins(CombatTuningPlugin) // Balanceamento do combate (assets/config/combat_tuning.ron)
+        .add_plugins(MetaProgressionPlugin) // Meta-progressão + modificadores de calor
+        .add_plugins(SettingsPlugin) // Configurações salvas (bússola, ...)
+        .add_plugins(InputBindingsPlugin) // Teclas remapeáveis (saves/bindings.json)
+        .add_plugins(SummonPlugin) // Aliados invocados por boons
+        .add_plugins(ConsumablePlugin) // Inventário de consumíveis (F)
+        .add_plugins(ArcProjectilePlugin) // Bombas e jarros lançados em arco
+        .add_plugins(BossPlugin) // Barra do chefe, timer de fúria e portais de reforço
+        .add_plugins(FormationPlugin) // Papéis dos inimigos na sala: poucos atacam, o resto cerca ou segura distância
+        .add_plugins(SpawnGovernorPlugin) // Teto de inimigos vivos (Qualidade Gráfica); o excesso espera na fila
+        .add_plugins(InterpolationPlugin) // Desenho suave entre os passos fixos da simulação
+        .add_plugins(EliteAffixPlugin) // Afixos dos elites (volátil, blindado, veloz, vampírico)
+        .add_plugins(RoomBannerPlugin) // Nome da sala ao entrar e introdução do chefe
+        .add_plugins(AdaptiveDifficultyPlugin) // Opcional: vida e dano dos inimigos acompanham (pouco) o desempenho
+        .add_plugins(ClearTimerPlugin) // Tempo par das salas de combate: limpeza veloz dá moedas e cura
+        .add_plugins(LootTablePlugin) // Saque com pesos por tipo de inimigo e orbes de vida
+        .add_plugins(DoorPreviewPlugin) // Símbolo da recompensa da próxima sala acima de cada porta
+        .add_plugins(InteractHighlightPlugin) // Destaque e aviso "[E]" no objeto interagível mais próximo
+        .add_plugins(SlowMotionPlugin) // Câmera lenta no golpe final do chefe, no parry e ao reviver
+        .add_plugins(BillboardSortPlugin) // Sprites recortados e viés de profundidade contra o chão
+        .add_plugins(ProjectilePoolPlugin) // Projéteis reaproveitados em vez de spawn/despawn a cada tiro
+        .add_plugins(EnemyHealthBarPlugin) // Barrinhas de vida acima dos inimigos feridos
+        .add_plugins(IFrameFlashPlugin) // Brilho ciano enquanto o dash deixa o jogador invulnerável
+        .add_plugins(EnemyPathfindingPlugin) // Inimigos contornam paredes em vez de se esfregar nelas
+        .add_plugins(TrapPlugin) // Armadilhas das salas do tesouro e secretas
+        .add_plugins(ChestPlugin) // Baús do tesouro: abrir, sortear o saque, guardiões nos armadilhados
+        .add_plugins(ShopPlugin) // Lojas: consumíveis comprados com moedas
+        .add_plugins(DecoyPlugin) // Miragem de Thoth: ilusões que atraem os inimigos
+        .add_plugins(TotemPlugin) // Obelisco Solar de Rá: torre parada que atira sozinha (T)
+        .add_plugins(CorpsePlugin) // Mortos Inquietos de Anúbis: cadáveres que explodem em cadeia
+        .add_plugins(HealthRegenPlugin) // Cura contínua dos boons de regeneração (Ísis)
+        .add_plugins(StatusEffectsPlugin) // Lentidão, veneno e sangramento em inimigos
+        .add_plugins(ComboPlugin) // Medidor de combo com buffs temporários
+        .add_plugins(MomentumPlugin) // Ímpeto: abates aceleram caminhada e recargas (OnKillBuff)
+        .add_plugins(FastTravelPlugin) // Viagem rápida entre salas limpas (M)
+        .add_plugins(CompassPlugin) // Marcador apontando para o chefe
+        .add_plugins(DungeonMapPlugin) // Mapa completo da masmorra (Tab)
+        .add_plugins(DamageIndicatorPlugin) // Setas na borda indicando a origem do dano
+        .add_plugins(ReplayPlugin) // Gravação e reprodução de runs (semente + entrada)
+        .add_plugins(PracticeRoomPlugin) // Sala de Treino: boneco com DPS, bênçãos à vontade e códigos de build
+        .add_plugins(CoopPlugin) // Segundo jogador no gamepad (Start para entrar)
+        .add_plugins(DebugToolsPlugin) // Só em builds de debug: hitboxes (F3)
         // .add_plugins(SpriteAnimationPlugin) // Disabled: Now using Hades-quality 3D models
         // .add_plugins(True3DPlugin) // Disabled: Replaced by Hades3DPlugin
         .add_plugins(Hades3DPlugin) // NEW: Hades-style 3D rendering with dramatic lighting
@@ -94,36 +309,69 @@ fn main() {
         // .add_plugins(Placeholder3DPlugin) // Disabled: Now using real 3D models
         .add_event::<SpawnParticlesEvent>()
         .add_event::<AudioEvent>()
+        .add_event::<EnemyKilledEvent>()
+        .add_event::<RoomEnteredEvent>()
+        .add_event::<RoomClearedEvent>()
+        .add_event::<AoeExplosionEvent>()
         .init_resource::<InputState>()
+        .init_resource::<FixedInput>()
+        .init_resource::<CombatRng>()
+        .insert_resource(Time::<Fixed>::from_hz(SIMULATION_HZ))
         .init_resource::<AudioHandles>()
         .insert_resource(GameState {
             current_room: 0,
             rooms_cleared: 0,
-            total_rooms: 3,
+            total_rooms: 0, // Preenchido ao montar a dungeon
             previous_room: 0,
-            enemies_spawned: vec![false, false, false], // No enemies spawned initially
         })
         .add_systems(Startup, setup)
+        .add_systems(OnEnter(AppState::Loading), (
+            clear_dungeon_rooms,
+            reset_run_state,
+            reseed_combat_rng.after(procedural::generate_run_dungeon),
+        ))
+        .add_systems(OnEnter(AppState::InGame), spawn_dungeon_rooms)
         .add_systems(Update, (
-            read_input,
+            (read_input, latch_fixed_input, apply_aim_assist).chain(),
             fps_counter_system,
         ))
+        // Simulação determinística em passo fixo
+        .add_systems(FixedUpdate, (
+            player_movement_system,
+            player_blink_system.after(player_movement_system),
+            player_facing_system.after(player_blink_system),
+            player_block_system.before(ai_system),
+            stamina_regen_system,
+            enemy_spawn_in_system.before(ai_system),
+            ai_system,
+            sync_boon_combat_modifiers.before(hades_combat_system),
+            hades_combat_system,
+            room_transition_system,
+            (projectile_movement_system, projectile_collision_system, aoe_explosion_system, knockback_system, enemy_stagger_system, shield_break_system, enemy_death_system)
+                .chain()
+                .after(hades_combat_system),
+        ).in_set(SimulationSet).run_if(in_state(AppState::InGame)))
+        .add_systems(FixedUpdate, clear_fixed_input_pulses.after(SimulationSet))
+        // Fim da run: sair do InGame para a simulação (dano, entrada) de vez
+        .add_systems(FixedUpdate, player_death_system.after(SimulationSet).run_if(in_state(AppState::InGame)))
         // Game systems only run during gameplay
         .add_systems(Update, (
             dash_ui_system,
             combat_ui_system,
             health_stamina_ui_system,
-            player_movement_system,
-            stamina_regen_system,
-            ai_system,
-            hades_combat_system,
-            (projectile_movement_system, projectile_collision_system).chain(),
+            equip_selected_weapon,
+            charge_ring_system,
+            aoe_reticle_system,
             hit_effect_system,
+            enemy_dying_system,
             particle_spawn_system,
             particle_system,
-            room_transition_system,
-            room_clear_system,
+            door_interactable_system,
+            sync_dungeon_current_room,
+            // Depois do spawn (com os comandos aplicados) para a sala não contar como limpa antes da hora
+            room_clear_system.after(room_enemy_spawn_system),
             room_enemy_spawn_system,
+            count_run_kills,
             audio_system,
         ).run_if(in_state(AppState::InGame)))
         .run();
@@ -132,21 +380,211 @@ fn main() {
 #[derive(Component)]
 struct Enemy;
 
-#[derive(Component)]
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
 enum EnemyType {
     Chaser,    // Basic enemy that chases player
     Shooter,   // Ranged enemy that shoots projectiles
     Tank,      // Heavy enemy with lots of health, moves slowly
+    LobberMummy, // Lança jarros em arco por cima de cobertura
+    SandJackal,  // Se aproxima e dispara numa investida telegrafada (deserto e submundo)
+}
+
+// XP de chefes (não têm EnemyType); elites com afixo valem o dobro
+const BOSS_XP: u32 = 60;
+const ELITE_XP_MULT: u32 = 2;
+
+impl EnemyType {
+    // Experiência por abate, pela ameaça do tipo
+    fn xp_reward(&self) -> u32 {
+        match self {
+            EnemyType::Chaser => 10,
+            EnemyType::Shooter => 12,
+            EnemyType::LobberMummy => 14,
+            EnemyType::SandJackal => 16,
+            EnemyType::Tank => 20,
+        }
+    }
+
+    // Cor da explosão de partículas na morte (mesma paleta dos materiais de fallback)
+    fn death_color(&self) -> Color {
+        match self {
+            EnemyType::Chaser => Color::rgb(0.8, 0.2, 0.2),
+            EnemyType::Shooter => Color::rgb(0.2, 0.8, 0.2),
+            EnemyType::Tank => Color::rgb(0.6, 0.6, 0.2),
+            EnemyType::LobberMummy => Color::rgb(0.5, 0.8, 0.3),
+            EnemyType::SandJackal => Color::rgb(0.85, 0.65, 0.3),
+        }
+    }
+
+    // Peso na separação entre inimigos: o mais pesado empurra, o mais leve sai da frente
+    fn mass(&self) -> f32 {
+        match self {
+            EnemyType::Tank => 4.0,
+            EnemyType::Chaser | EnemyType::SandJackal => 1.0,
+            EnemyType::Shooter | EnemyType::LobberMummy => 0.8,
+        }
+    }
+}
+
+// Inimigo morto: já não é `Enemy` (sem colisão, alvo ou dano); afunda e se dissolve até sumir
+#[derive(Component)]
+struct Dying {
+    timer: f32,
+    start_scale: Vec3,
+    start_height: f32,
+}
+
+const DYING_DURATION: f32 = 0.6;
+
+// Inimigo chegando na sala: intangível (sem IA, dano ou alvo) durante o telegraph e o fade-in
+#[derive(Component)]
+struct Spawning {
+    timer: f32,
+    telegraph: Entity,
+    color: Color,
+    full_scale: Option<Vec3>, // Lida no primeiro passo: modelos glTF e elites têm escalas próprias
 }
 
+#[derive(Component)]
+struct SpawnTelegraph;
+
+const SPAWN_TELEGRAPH_TIME: f32 = 0.5;
+const SPAWN_FADE_IN_TIME: f32 = 0.3;
+
 #[derive(Component)]
 struct AI {
     target_range: f32,
     chase_speed: f32,
     attack_cooldown: f32,
     attack_timer: f32,
+    windup: Option<MeleeWindup>,
+    lunge: Option<LungePhase>, // Só o SandJackal
+    queued_shot: Option<f32>, // Shooter mirando: tempo até o disparo
+}
+
+impl AI {
+    // Cancela o ataque em preparação (atordoado, morto); retorna o telegrafo para despawn
+    fn cancel_windup(&mut self) -> Option<Entity> {
+        self.queued_shot = None;
+        if let Some(LungePhase::Windup { telegraph, .. }) = self.lunge {
+            self.lunge = None;
+            return Some(telegraph);
+        }
+        self.windup.take().map(|windup| windup.telegraph)
+    }
+
+    // Preparando golpe, investida ou disparo: janela em que um golpe forte interrompe
+    fn is_mid_attack(&self) -> bool {
+        self.windup.is_some()
+            || self.queued_shot.is_some()
+            || matches!(self.lunge, Some(LungePhase::Windup { .. }))
+    }
+}
+
+// Investida do SandJackal: preparação parada (faixa no chão) → disparada em linha reta → recuperação vulnerável
+enum LungePhase {
+    Windup { timer: f32, direction: Vec3, telegraph: Entity },
+    Lunge { timer: f32, direction: Vec3, hit: bool },
+    Recovery { timer: f32 },
+}
+
+const JACKAL_LUNGE_RANGE: f32 = 6.0;
+const JACKAL_WINDUP_TIME: f32 = 0.55;
+const JACKAL_LUNGE_SPEED: f32 = 18.0;
+const JACKAL_LUNGE_TIME: f32 = 0.35;
+const JACKAL_RECOVERY_TIME: f32 = 0.8;
+const JACKAL_LUNGE_DAMAGE: f32 = 18.0;
+const JACKAL_HIT_RADIUS: f32 = 1.0;
+
+// Empurrão dos golpes: desliza e desacelera em knockback_system, sem atravessar as paredes da sala
+#[derive(Component)]
+struct Knockback {
+    velocity: Vec3,
+    decay: f32, // Desaceleração exponencial (1/s)
+}
+
+impl Knockback {
+    // `distance` é o quanto o alvo desliza até parar, reduzido pela resistência do Stats
+    fn new(direction: Vec3, distance: f32, decay: f32, stats: &Stats) -> Self {
+        let direction = Vec3::new(direction.x, 0.0, direction.z).normalize_or_zero();
+        Self {
+            velocity: direction * distance * stats.knockback_multiplier * decay,
+            decay,
+        }
+    }
+}
+
+// Folga entre o inimigo empurrado e a parede
+const KNOCKBACK_WALL_MARGIN: f32 = 0.6;
+// Abaixo desta velocidade o empurrão acaba
+const KNOCKBACK_STOP_SPEED: f32 = 0.1;
+
+// Fúria do último de pé: sozinho na sala, o inimigo acelera por alguns segundos (uma vez)
+#[derive(Component)]
+struct Berserk {
+    remaining: f32,
+    aura: Option<Entity>, // Anel vermelho enquanto dura
+}
+
+impl Berserk {
+    fn is_active(&self) -> bool {
+        self.remaining > 0.0
+    }
+
+    fn speed_multiplier(&self) -> f32 {
+        if self.is_active() { BERSERK_SPEED_MULT } else { 1.0 }
+    }
+
+    fn attack_rate_multiplier(&self) -> f32 {
+        if self.is_active() { BERSERK_ATTACK_RATE_MULT } else { 1.0 }
+    }
+}
+
+const BERSERK_DURATION: f32 = 6.0;
+const BERSERK_SPEED_MULT: f32 = 1.4;
+const BERSERK_ATTACK_RATE_MULT: f32 = 1.6;
+
+// Shooter: tempo mirando antes do disparo
+const SHOOTER_AIM_TIME: f32 = 0.4;
+// Stagger: golpe acima do poise durante a preparação cancela o ataque e atordoa brevemente
+const STAGGER_DURATION: f32 = 0.35;
+
+// Preparação do golpe corpo a corpo: parado com o telegrafo no chão, dano só no fim
+struct MeleeWindup {
+    timer: f32,
+    anchor: Vec3, // Empurrado para longe daqui = golpe cancelado
+    telegraph: Entity,
+}
+
+// Círculo no chão que cresce durante a preparação (filho do inimigo)
+#[derive(Component)]
+struct WindupTelegraph;
+
+// Deslocamento durante a preparação que conta como interrupção (knockback)
+const WINDUP_INTERRUPT_DISTANCE: f32 = 0.5;
+
+// Golpe dos inimigos corpo a corpo: (alcance, tempo de preparação, dano)
+fn melee_strike(enemy_type: EnemyType) -> (f32, f32, f32) {
+    match enemy_type {
+        EnemyType::Tank => (1.7, 0.7, 30.0),
+        _ => (1.2, 0.4, 15.0),
+    }
+}
+
+// Inimigo de elite (mais vida, maior) - gerado pelo modificador de calor EliteLegion
+#[derive(Component)]
+struct Elite;
+
+// Escudo de guardiões/elites: absorve todo o dano até quebrar, depois a vida sofre normalmente
+#[derive(Component)]
+struct ShieldedEnemy {
+    shield: f32,
 }
 
+// Esfera translúcida que indica o escudo ativo (filha do inimigo)
+#[derive(Component)]
+struct ShieldOverlay;
+
 #[derive(Component)]
 struct FpsText;
 
@@ -162,6 +600,14 @@ struct HealthBar;
 #[derive(Component)]
 struct StaminaBar;
 
+// Anel de carga do ataque pesado (segue o jogador)
+#[derive(Component)]
+struct ChargeRing;
+
+// Retícula no chão da AoE (R) enquanto o jogador mira
+#[derive(Component)]
+struct AoeReticle;
+
 #[derive(Component)]
 struct HitEffect {
     timer: f32,
@@ -221,7 +667,7 @@ struct SpawnParticlesEvent {
 }
 
 // Audio events for combat feedback
-#[derive(Event, Debug)]
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum AudioEvent {
     AttackPrimary,
     AttackSecondary,
@@ -231,6 +677,39 @@ enum AudioEvent {
     EnemyHit,
     Dash,
     EnemyDeath,
+    ShieldBreak,
+    Parry,
+    ChestOpen,
+}
+
+// Explosão em área do jogador (R, bombas) - resolvida em aoe_explosion_system
+#[derive(Event)]
+struct AoeExplosionEvent {
+    center: Vec3,
+    radius: f32,
+    damage: f32,
+    on_hit: Option<OnHitStatus>, // Status de boon aplicado em quem for atingido (ex.: knockup do R)
+    falloff: AoeFalloff, // Quem está na borda leva menos que quem está no centro
+}
+
+// Grupo atravessou uma porta - dispara o banner da sala (e a introdução do chefe)
+#[derive(Event)]
+struct RoomEnteredEvent {
+    room: usize,
+}
+
+// Sala acabou de ser limpa (último inimigo morto) - gancho para recompensas da sala
+#[derive(Event)]
+struct RoomClearedEvent {
+    room: usize,
+    room_type: RoomType,
+}
+
+// Inimigo derrotado - gancho para drops e efeitos on-kill
+#[derive(Event)]
+struct EnemyKilledEvent {
+    position: Vec3,
+    consumable_drops: u32, // Consumíveis sorteados na tabela de saque do inimigo
 }
 
 // Room system components
@@ -241,16 +720,38 @@ struct Room {
     center: Vec2,
     cleared: bool,
     room_type: RoomType,
+    template: procedural::RoomTemplate, // Nome e descrição mostrados no banner de entrada
 }
 
 #[derive(Clone, Copy, Debug)]
 enum RoomType {
     Combat,
     Treasure,
+    Shop,
     Boss,
     Start,
 }
 
+impl RoomType {
+    // Tipo jogável de uma sala do gerador: elites lutam como combate; evento, descanso
+    // e segredo ainda não têm mecânica própria e ficam como salas de tesouro (sem inimigos)
+    fn from_template(room_type: procedural::RoomType) -> Self {
+        match room_type {
+            procedural::RoomType::Combat | procedural::RoomType::Elite => RoomType::Combat,
+            procedural::RoomType::Boss => RoomType::Boss,
+            procedural::RoomType::Shop => RoomType::Shop,
+            procedural::RoomType::Treasure
+            | procedural::RoomType::Event
+            | procedural::RoomType::Rest
+            | procedural::RoomType::Secret => RoomType::Treasure,
+        }
+    }
+}
+
+// Tudo o que a montagem das salas cria (chão, paredes, decoração, portas): some na run seguinte
+#[derive(Component)]
+struct RoomGeometry;
+
 #[derive(Component)]
 struct RoomTransition {
     from_room: usize,
@@ -266,7 +767,6 @@ struct GameState {
     rooms_cleared: usize,
     total_rooms: usize,
     previous_room: usize,
-    enemies_spawned: Vec<bool>, // Track which rooms have spawned enemies
 }
 
 #[derive(Resource, Default)]
@@ -279,6 +779,45 @@ struct AudioHandles {
     enemy_hit: Handle<AudioSource>,
     dash: Handle<AudioSource>,
     enemy_death: Handle<AudioSource>,
+    shield_break: Handle<AudioSource>,
+    parry: Handle<AudioSource>,
+    chest_open: Handle<AudioSource>,
+}
+
+impl AudioHandles {
+    fn load(asset_server: &AssetServer) -> Self {
+        Self {
+            attack_primary: asset_server.load("audio/attack_primary.ogg"),
+            attack_secondary: asset_server.load("audio/attack_secondary.ogg"),
+            ability_q: asset_server.load("audio/ability_q.ogg"),
+            ability_r: asset_server.load("audio/ability_r.ogg"),
+            projectile_hit: asset_server.load("audio/projectile_hit.ogg"),
+            enemy_hit: asset_server.load("audio/enemy_hit.ogg"),
+            dash: asset_server.load("audio/dash.ogg"),
+            enemy_death: asset_server.load("audio/enemy_death.ogg"),
+            shield_break: asset_server.load("audio/shield_break.ogg"),
+            parry: asset_server.load("audio/parry.ogg"),
+            chest_open: asset_server.load("audio/chest_open.ogg"),
+        }
+    }
+
+    // (som, volume, intervalo mínimo em segundos reais entre duas execuções)
+    fn cue(&self, event: AudioEvent) -> (&Handle<AudioSource>, f32, f32) {
+        match event {
+            AudioEvent::AttackPrimary => (&self.attack_primary, 0.5, 0.05),
+            AudioEvent::AttackSecondary => (&self.attack_secondary, 0.6, 0.1),
+            AudioEvent::AbilityQ => (&self.ability_q, 0.7, 0.1),
+            AudioEvent::AbilityR => (&self.ability_r, 0.8, 0.15),
+            AudioEvent::ProjectileHit => (&self.projectile_hit, 0.4, 0.06),
+            // Golpes em área acertam vários inimigos no mesmo quadro: um som basta
+            AudioEvent::EnemyHit => (&self.enemy_hit, 0.45, 0.08),
+            AudioEvent::Dash => (&self.dash, 0.5, 0.1),
+            AudioEvent::EnemyDeath => (&self.enemy_death, 0.6, 0.08),
+            AudioEvent::ShieldBreak => (&self.shield_break, 0.8, 0.15),
+            AudioEvent::Parry => (&self.parry, 0.8, 0.1),
+            AudioEvent::ChestOpen => (&self.chest_open, 0.7, 0.3),
+        }
+    }
 }
 
 fn setup(
@@ -286,13 +825,11 @@ fn setup(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     asset_server: Res<AssetServer>,
-    game_assets: Option<Res<GameAssets>>,
-    _audio_handles: ResMut<AudioHandles>,
+    mut audio_handles: ResMut<AudioHandles>,
 ) {
-    // Load audio assets (using procedural sound generation since we don't have audio files)
-    // These will be placeholder handles since we don't have actual audio files
-    // In a real game, you would load actual audio files here
-    
+    // Sons em assets/audio; arquivo ausente só deixa o evento mudo (ver audio_system)
+    *audio_handles = AudioHandles::load(&asset_server);
+
     // Light
     commands.insert_resource(AmbientLight {
         color: Color::rgb(1.0, 0.9, 0.7),
@@ -327,6 +864,38 @@ fn setup(
         ..default()
     });
 
+    // Charge ring for the heavy primary (hidden until charging)
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Torus::new(0.9, 1.0)),
+            material: materials.add(StandardMaterial {
+                base_color: Color::rgb(1.0, 0.8, 0.2),
+                emissive: Color::rgb(3.0, 2.2, 0.5),
+                unlit: true,
+                ..default()
+            }),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        ChargeRing,
+    ));
+
+    // Ground reticle for the aimed R ability (unit ring, scaled to the tuned AoE radius)
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Torus::new(0.96, 1.0)),
+            material: materials.add(StandardMaterial {
+                base_color: Color::rgb(1.0, 0.3, 0.2),
+                emissive: Color::rgb(3.0, 0.8, 0.5),
+                unlit: true,
+                ..default()
+            }),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        AoeReticle,
+    ));
+
     // NOTE: Player spawning now handled by Placeholder3DPlugin
     // Old 2D sprite player disabled in favor of 3D placeholder hero
 
@@ -473,7 +1042,7 @@ fn setup(
     // Controls help - Hades style
     commands.spawn(
         TextBundle::from_section(
-            "WASD: Move | SPACE: Dash | LMB: Attack | RMB: Special | Q: Cast | R: AoE | E: Interact",
+            "WASD: Move | SPACE: Dash | SHIFT: Blink | LMB: Attack | RMB: Special | Q: Cast | R: AoE | E: Interact | F: Item | T: Totem | M: Travel",
             TextStyle {
                 font_size: 22.0,
                 color: Color::rgb(0.7, 0.7, 0.7),
@@ -488,9 +1057,7 @@ fn setup(
         }),
     );
 
-    // Create rooms layout with RTX-generated environments
-    let game_assets_ref = game_assets.as_ref().map(|a| a.as_ref());
-    setup_rooms(&mut commands, &mut meshes, &mut materials, game_assets_ref);
+    // Rooms come from the procedural dungeon (spawn_dungeon_rooms, on entering the game)
 
     // NOTE: Enemy spawning now handled by room_enemy_spawn_system
     // Initial enemies will spawn when entering Combat rooms
@@ -529,8 +1096,9 @@ fn setup(
                     AI {
                         target_range: 12.0,
                         chase_speed: 4.0,
-                        attack_cooldown: 0.0,
+                        attack_cooldown: 0.8,
                         attack_timer: 0.0,
+                        windup: None,
                     },
                     Stats {
                         max_health: 30.0,
@@ -566,6 +1134,7 @@ fn setup(
                         chase_speed: 1.5,
                         attack_cooldown: 2.0,
                         attack_timer: 0.0,
+                        windup: None,
                     },
                     Stats {
                         max_health: 40.0,
@@ -599,8 +1168,9 @@ fn setup(
                     AI {
                         target_range: 8.0,
                         chase_speed: 1.0,
-                        attack_cooldown: 0.0,
+                        attack_cooldown: 1.2,
                         attack_timer: 0.0,
+                        windup: None,
                     },
                     Stats {
                         max_health: 120.0,
@@ -634,25 +1204,38 @@ fn setup(
 fn read_input(
     kb: Res<ButtonInput<KeyCode>>,
     mouse: Res<ButtonInput<MouseButton>>,
+    bindings: Res<InputBindings>,
     mut input_state: ResMut<InputState>,
     windows: Query<&Window>,
     cameras: Query<(&Camera, &GlobalTransform)>,
 ) {
+    let pressed = |action| bindings.pressed(action, &kb, &mouse);
+    let just_pressed = |action| bindings.just_pressed(action, &kb, &mouse);
+
     // Continuous inputs
-    input_state.up = kb.pressed(KeyCode::KeyW);
-    input_state.down = kb.pressed(KeyCode::KeyS);
-    input_state.left = kb.pressed(KeyCode::KeyA);
-    input_state.right = kb.pressed(KeyCode::KeyD);
+    input_state.up = pressed(InputAction::MoveUp);
+    input_state.down = pressed(InputAction::MoveDown);
+    input_state.left = pressed(InputAction::MoveLeft);
+    input_state.right = pressed(InputAction::MoveRight);
 
     // Pulse inputs (just_pressed)
-    input_state.dash = kb.just_pressed(KeyCode::Space);
-    input_state.interact = kb.just_pressed(KeyCode::KeyE);
+    input_state.dash = just_pressed(InputAction::Dash);
+    input_state.interact = just_pressed(InputAction::Interact);
+    input_state.use_item = just_pressed(InputAction::UseItem);
+    input_state.toggle_travel = just_pressed(InputAction::FastTravel);
+    input_state.place_totem = just_pressed(InputAction::PlaceTotem);
+    input_state.blink = just_pressed(InputAction::Blink);
 
     // Hades-style remapped controls
-    input_state.primary = mouse.just_pressed(MouseButton::Left);
-    input_state.secondary = mouse.just_pressed(MouseButton::Right);
-    input_state.ability_q = kb.just_pressed(KeyCode::KeyQ);
-    input_state.ability_r = kb.just_pressed(KeyCode::KeyR);
+    input_state.primary = just_pressed(InputAction::Primary);
+    input_state.primary_held = pressed(InputAction::Primary);
+    input_state.primary_released = bindings.just_released(InputAction::Primary, &kb, &mouse);
+    input_state.secondary = just_pressed(InputAction::Secondary);
+    input_state.secondary_held = pressed(InputAction::Secondary);
+    input_state.ability_q = just_pressed(InputAction::AbilityQ);
+    input_state.ability_r = just_pressed(InputAction::AbilityR);
+    input_state.ability_r_held = pressed(InputAction::AbilityR);
+    input_state.ability_r_released = bindings.just_released(InputAction::AbilityR, &kb, &mouse);
     
     // Mouse world position calculation
     if let Ok(window) = windows.get_single() {
@@ -669,6 +1252,25 @@ fn read_input(
     }
 }
 
+// Copia a entrada do quadro (teclado/mouse do P1, gamepad do P2) para a simulação
+fn latch_fixed_input(
+    input: Res<InputState>,
+    gamepad_input: Res<GamepadInputState>,
+    mut fixed_input: ResMut<FixedInput>,
+) {
+    let live = [*input, gamepad_input.input];
+    for (pending, live) in fixed_input.0.iter_mut().zip(live.iter()) {
+        *pending = pending.latched_with(live);
+    }
+}
+
+// Fim do tick fixo: pulsos consumidos
+fn clear_fixed_input_pulses(mut fixed_input: ResMut<FixedInput>) {
+    for pending in &mut fixed_input.0 {
+        *pending = pending.without_pulses();
+    }
+}
+
 fn fps_counter_system(
     diagnostics: Res<bevy::diagnostic::DiagnosticsStore>,
     mut query: Query<&mut Text, With<FpsText>>,
@@ -683,10 +1285,10 @@ fn fps_counter_system(
 }
 
 fn dash_ui_system(
-    player_query: Query<&Dash, With<Player>>,
+    player_query: Query<(&PlayerId, &Dash), With<Player>>,
     mut dash_text_query: Query<&mut Text, With<DashText>>,
 ) {
-    if let Ok(dash) = player_query.get_single() {
+    if let Some((_, dash)) = player_query.iter().find(|(id, _)| id.is_primary()) {
         for mut text in &mut dash_text_query {
             if dash.is_dashing {
                 text.sections[1].value = "DASHING!".to_string();
@@ -702,16 +1304,50 @@ fn dash_ui_system(
     }
 }
 
+// Quanto tempo o aviso de sequência perdida fica na tela
+const CHAIN_RESET_NOTICE: f32 = 0.6;
+
 fn combat_ui_system(
-    player_query: Query<&Combat, With<Player>>,
+    time: Res<Time>,
+    player_query: Query<(&PlayerId, &Combat), With<Player>>,
     mut combat_text_query: Query<&mut Text, With<CombatText>>,
+    // (passo da sequência no quadro anterior, tempo restante do aviso de reset)
+    mut chain_notice: Local<(u8, f32)>,
 ) {
-    if let Ok(combat) = player_query.get_single() {
+    if let Some((_, combat)) = player_query.iter().find(|(id, _)| id.is_primary()) {
+        // A janela fechou no meio da sequência. Finalizador e golpe carregado também zeram o passo,
+        // mas acabaram de atacar (recarga correndo), então não contam como sequência perdida
+        let (previous_step, notice) = &mut *chain_notice;
+        *notice = (*notice - time.delta_seconds()).max(0.0);
+        if *previous_step > 0 && combat.chain_step == 0 && combat.atk_timer <= 0.0 {
+            *notice = CHAIN_RESET_NOTICE;
+        }
+        *previous_step = combat.chain_step;
+        let chain_reset = *notice > 0.0;
+
         for mut text in &mut combat_text_query {
             let mut status = String::new();
             
-            // Show chain step for primary attack
-            if combat.atk_timer > 0.0 {
+            // Show block, charge progress, then chain step for primary attack
+            if combat.blocking {
+                status = if combat.perfect_block_timer > 0.0 { "PERFECT BLOCK".to_string() } else { "Block".to_string() };
+                text.sections[1].style.color = Color::SILVER;
+            } else if combat.charge_time > 0.0 {
+                let charge = (combat.charge_time / combat.charge_threshold).min(1.0);
+                status = format!("Charge {:.0}%", charge * 100.0);
+                text.sections[1].style.color = Color::GOLD;
+            } else if combat.chain_step > 0 {
+                // Próximo golpe da sequência e quanto falta para a janela fechar
+                status = if combat.chain_step == 2 {
+                    format!("FINISHER ({:.1}s)", combat.chain_timer)
+                } else {
+                    format!("Chain {}/3 ({:.1}s)", combat.chain_step + 1, combat.chain_timer)
+                };
+                text.sections[1].style.color = if combat.chain_step == 2 { Color::ORANGE } else { Color::YELLOW };
+            } else if chain_reset {
+                status = "Chain reset".to_string();
+                text.sections[1].style.color = Color::GRAY;
+            } else if combat.atk_timer > 0.0 {
                 status = format!("Chain {}", combat.chain_step + 1);
                 text.sections[1].style.color = Color::YELLOW;
             } else if combat.special_timer > 0.0 {
@@ -723,6 +1359,9 @@ fn combat_ui_system(
             } else if combat.q_timer > 0.0 {
                 status = format!("Cast: {:.1}s", combat.q_timer);
                 text.sections[1].style.color = Color::CYAN;
+            } else if combat.blink_timer > 0.0 {
+                status = format!("Blink: {:.1}s", combat.blink_timer);
+                text.sections[1].style.color = Color::TEAL;
             } else {
                 status = "READY".to_string();
                 text.sections[1].style.color = Color::GREEN;
@@ -733,84 +1372,302 @@ fn combat_ui_system(
     }
 }
 
+#[allow(clippy::type_complexity)]
 fn player_movement_system(
     time: Res<Time>,
-    input: Res<InputState>,
-    mut player_query: Query<(&mut Transform, &mut Stats, &mut Dash), With<Player>>,
+    fixed_input: Res<FixedInput>,
+    mut player_query: Query<(&PlayerId, &mut Transform, &mut Stats, &mut Dash, &mut Velocity), (With<Player>, Without<Downed>)>,
     mut audio_events: EventWriter<AudioEvent>,
+    active_boons: Res<boons::ActiveBoons>,
+    momentum: Res<Momentum>,
+    debug: Option<Res<debug_tools::DebugSettings>>,
 ) {
-    let (mut transform, mut stats, mut dash) = player_query.single_mut();
     let dt = time.delta_seconds();
+    let speed_mult = debug_tools::player_speed_multiplier(debug.as_deref());
+    // Bênçãos de velocidade e o ímpeto dos abates só afetam a caminhada, não o dash
+    let walk_mult = boons::movement_speed_multiplier(&active_boons) * momentum.speed_multiplier();
+    let noclip = debug_tools::noclip_enabled(debug.as_deref());
 
-    // Continuous movement
-    let mut dir = Vec3::ZERO;
-    if input.up { dir.z -= 1.0; }
-    if input.down { dir.z += 1.0; }
-    if input.left { dir.x -= 1.0; }
-    if input.right { dir.x += 1.0; }
-    dir = dir.normalize_or_zero();
-
-    // Update cooldowns
-    dash.cooldown_timer = (dash.cooldown_timer - dt).max(0.0);
-    dash.i_timer = (dash.i_timer - dt).max(0.0);
-
-    // Handle dash input (requires stamina)
-    if input.dash && dash.cooldown_timer <= 0.0 && !dash.is_dashing && stats.current_stamina >= dash.stamina_cost {
-        let dash_dir = if dir.length_squared() > 0.0 { dir } else { Vec3::new(0.0, 0.0, -1.0) };
-        dash.is_dashing = true;
-        dash.dash_timer = 0.2; // dash duration
-        dash.dash_direction = dash_dir;
-        dash.cooldown_timer = dash.cooldown;
-        dash.i_timer = dash.i_frames;
+    for (id, mut transform, mut stats, mut dash, mut velocity) in &mut player_query {
+        let input = fixed_input.get(*id);
+
+        // Continuous movement
+        let mut dir = Vec3::ZERO;
+        if input.up { dir.z -= 1.0; }
+        if input.down { dir.z += 1.0; }
+        if input.left { dir.x -= 1.0; }
+        if input.right { dir.x += 1.0; }
+        dir = dir.normalize_or_zero();
+
+        // Update cooldowns
+        dash.cooldown_timer = (dash.cooldown_timer - dt).max(0.0);
+        dash.i_timer = (dash.i_timer - dt).max(0.0);
+
+        // Handle dash input (requires stamina)
+        if input.dash && dash.cooldown_timer <= 0.0 && !dash.is_dashing && stats.current_stamina >= dash.stamina_cost {
+            let dash_dir = if dir.length_squared() > 0.0 { dir } else { Vec3::new(0.0, 0.0, -1.0) };
+            dash.is_dashing = true;
+            dash.dash_timer = 0.2; // dash duration
+            dash.dash_direction = dash_dir;
+            dash.cooldown_timer = dash.cooldown;
+            dash.i_timer = dash.i_frames;
         
-        // Consume stamina
-        stats.current_stamina -= dash.stamina_cost;
-        stats.current_stamina = stats.current_stamina.max(0.0);
+            // Consume stamina
+            stats.current_stamina -= dash.stamina_cost;
+            stats.current_stamina = stats.current_stamina.max(0.0);
         
-        // Play dash audio
-        audio_events.send(AudioEvent::Dash);
+            // Play dash audio
+            audio_events.send(AudioEvent::Dash);
+        }
+
+        // Handle dash movement
+        velocity.0 = Vec3::ZERO;
+        if dash.is_dashing {
+            dash.dash_timer -= dt;
+            if dash.dash_timer > 0.0 {
+                // Dash movement (instant distance)
+                let dash_speed = dash.distance / 0.2;
+                velocity.0 = dash.dash_direction * dash_speed * speed_mult;
+            } else {
+                dash.is_dashing = false;
+                dash.dash_direction = Vec3::ZERO;
+            }
+        } else if dir != Vec3::ZERO {
+            // Normal movement
+            velocity.0 = dir * stats.speed * walk_mult * speed_mult;
+        }
+
+        transform.translation += velocity.0 * dt;
+        // Keep player above ground (no noclip a altura é controlada pelo debug_tools)
+        if !noclip {
+            transform.translation.y = 0.5;
+        }
+    }
+}
+
+// Bloqueio das armas com escudo: frontal = até este ângulo (rad) entre a mira e a origem do golpe
+const BLOCK_HALF_ANGLE: f32 = 1.05;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BlockOutcome {
+    Unblocked,
+    Blocked,
+    Perfect,
+}
+
+impl BlockOutcome {
+    // Fração do dano que ainda chega ao jogador
+    fn damage_taken(self, attacks: &combat_tuning::AttackTuning) -> f32 {
+        match self {
+            BlockOutcome::Unblocked => 1.0,
+            BlockOutcome::Blocked => attacks.block_damage_taken,
+            BlockOutcome::Perfect => 0.0,
+        }
+    }
+}
+
+// Golpe vindo de `source` contra um jogador possivelmente bloqueando (ângulo no plano do chão)
+fn block_outcome(combat: &Combat, player_position: Vec3, source: Vec3) -> BlockOutcome {
+    if !combat.blocking {
+        return BlockOutcome::Unblocked;
+    }
+    let to_source = Vec3::new(source.x - player_position.x, 0.0, source.z - player_position.z).normalize_or_zero();
+    if to_source.dot(combat.block_direction) < BLOCK_HALF_ANGLE.cos() {
+        return BlockOutcome::Unblocked;
+    }
+    if combat.perfect_block_timer > 0.0 {
+        BlockOutcome::Perfect
+    } else {
+        BlockOutcome::Blocked
+    }
+}
+
+fn perfect_block_stagger(attacks: &combat_tuning::AttackTuning) -> OnHitStatus {
+    OnHitStatus {
+        kind: status_effects::StatusEffectKind::Stun,
+        duration: attacks.perfect_block_stagger,
+        magnitude: 0.0,
+    }
+}
+
+// Segurar o secundário com arma de escudo ergue o escudo na direção da mira. Drena stamina
+// enquanto erguido (e pausa a regeneração); sem stamina o escudo cai
+#[allow(clippy::type_complexity)]
+fn player_block_system(
+    time: Res<Time>,
+    fixed_input: Res<FixedInput>,
+    tuning: Res<CombatTuning>,
+    mut player_query: Query<(&PlayerId, &Transform, &mut Stats, &mut Combat), (With<Player>, Without<Downed>)>,
+) {
+    let dt = time.delta_seconds();
+    let attacks = &tuning.attacks;
+
+    for (id, transform, mut stats, mut combat) in &mut player_query {
+        let input = fixed_input.get(*id);
+        combat.perfect_block_timer = (combat.perfect_block_timer - dt).max(0.0);
+
+        if !combat.weapon.has_shield() || !input.secondary_held || stats.current_stamina <= 0.0 {
+            combat.blocking = false;
+            combat.perfect_block_timer = 0.0;
+            continue;
+        }
+        // Bloqueio perfeito só nos primeiros instantes depois de erguer
+        if !combat.blocking {
+            combat.blocking = true;
+            combat.perfect_block_timer = attacks.perfect_block_window;
+        }
+        let offset = input.mouse_world_pos - transform.translation;
+        let aim = Vec3::new(offset.x, 0.0, offset.z).normalize_or_zero();
+        if aim != Vec3::ZERO {
+            combat.block_direction = aim;
+        }
+        stats.current_stamina = (stats.current_stamina - attacks.block_stamina_drain * dt).max(0.0);
+    }
+}
+
+// Giro do modelo do jogador (rad/s): vira suave em vez de estalar
+const PLAYER_TURN_RATE: f32 = 14.0;
+// Depois de atacar, continua olhando para a mira por um instante antes de voltar ao movimento
+const AIM_FACING_LINGER: f32 = 0.4;
+
+// Para onde o modelo do jogador olha (rotação em Y); animate_characters soma a respiração por cima
+#[derive(Component)]
+struct Facing {
+    yaw: f32,
+    turn_rate: f32, // Ajustável por jogador (padrão PLAYER_TURN_RATE)
+    aim_timer: f32,
+}
+
+impl Default for Facing {
+    fn default() -> Self {
+        Self { yaw: 0.0, turn_rate: PLAYER_TURN_RATE, aim_timer: 0.0 }
+    }
+}
+
+// Ângulo em Y que leva o +Z do modelo até a direção no plano
+fn yaw_toward(direction: Vec3) -> Option<f32> {
+    let flat = Vec2::new(direction.x, direction.z);
+    (flat.length_squared() > 0.0001).then(|| flat.x.atan2(flat.y))
+}
+
+// Atacando (ou mirando/bloqueando) vira para o mouse no chão; fora disso, para onde anda
+#[allow(clippy::type_complexity)]
+fn player_facing_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    fixed_input: Res<FixedInput>,
+    new_players: Query<Entity, (With<Player>, Without<Facing>)>,
+    mut players: Query<(&PlayerId, &mut Transform, &Velocity, &mut Facing), (With<Player>, Without<Downed>)>,
+) {
+    for entity in &new_players {
+        commands.entity(entity).insert(Facing::default());
     }
 
-    // Handle dash movement
-    if dash.is_dashing {
-        dash.dash_timer -= dt;
-        if dash.dash_timer > 0.0 {
-            // Dash movement (instant distance)
-            let dash_speed = dash.distance / 0.2;
-            transform.translation += dash.dash_direction * dash_speed * dt;
+    let dt = time.delta_seconds();
+    for (id, mut transform, velocity, mut facing) in &mut players {
+        let input = fixed_input.get(*id);
+        let attacking = input.primary || input.primary_held || input.secondary || input.secondary_held
+            || input.ability_q || input.ability_r_held;
+        if attacking {
+            facing.aim_timer = AIM_FACING_LINGER;
+        } else {
+            facing.aim_timer = (facing.aim_timer - dt).max(0.0);
+        }
+
+        let target = if facing.aim_timer > 0.0 {
+            yaw_toward(input.mouse_world_pos - transform.translation)
         } else {
-            dash.is_dashing = false;
-            dash.dash_direction = Vec3::ZERO;
+            yaw_toward(velocity.0)
+        };
+        if let Some(target) = target {
+            // Menor caminho até o alvo, limitado pela velocidade de giro
+            let delta = (target - facing.yaw + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU) - std::f32::consts::PI;
+            let step = facing.turn_rate * dt;
+            facing.yaw += delta.clamp(-step, step);
         }
-    } else if dir != Vec3::ZERO {
-        // Normal movement
-        transform.translation += dir * stats.speed * dt;
+        transform.rotation = Quat::from_rotation_y(facing.yaw);
     }
+}
 
-    // Keep player above ground
-    transform.translation.y = 0.5;
+// Distância mínima das bordas da sala ao reaparecer do blink
+const BLINK_ROOM_MARGIN: f32 = 1.0;
+
+// Blink: teleporte instantâneo na direção da mira. Atravessa paredes e inimigos,
+// mas o destino fica preso dentro da sala atual
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn player_blink_system(
+    time: Res<Time>,
+    fixed_input: Res<FixedInput>,
+    mut player_query: Query<(&PlayerId, &mut Transform, &mut Stats, &mut Combat, &mut Dash), (With<Player>, Without<Downed>)>,
+    rooms: Query<&Room>,
+    game_state: Res<GameState>,
+    tuning: Res<CombatTuning>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+    mut audio_events: EventWriter<AudioEvent>,
+) {
+    let dt = time.delta_seconds();
+    let attacks = &tuning.attacks;
+    let current_room = rooms.iter().find(|room| room.id == game_state.current_room);
+
+    for (id, mut transform, mut stats, mut combat, mut dash) in &mut player_query {
+        let input = fixed_input.get(*id);
+        combat.blink_timer = (combat.blink_timer - dt).max(0.0);
+
+        if !input.blink || combat.blink_timer > 0.0 || dash.is_dashing || stats.current_stamina < attacks.blink_stamina_cost {
+            continue;
+        }
+
+        // Direção no plano até a mira (sem mira válida, para frente)
+        let offset = input.mouse_world_pos - transform.translation;
+        let direction = Vec3::new(offset.x, 0.0, offset.z).normalize_or_zero();
+        let direction = if direction != Vec3::ZERO { direction } else { Vec3::new(0.0, 0.0, -1.0) };
+
+        let departure = transform.translation;
+        let mut arrival = departure + direction * attacks.blink_distance;
+        if let Some(room) = current_room {
+            let half = room.size / 2.0 - Vec2::splat(BLINK_ROOM_MARGIN);
+            arrival.x = arrival.x.clamp(room.center.x - half.x, room.center.x + half.x);
+            arrival.z = arrival.z.clamp(room.center.y - half.y, room.center.y + half.y);
+        }
+        transform.translation = arrival;
+
+        combat.blink_timer = combat.blink_cd;
+        stats.current_stamina = (stats.current_stamina - attacks.blink_stamina_cost).max(0.0);
+        dash.i_timer = dash.i_timer.max(attacks.blink_i_frames);
+
+        // Lampejo na saída e na chegada
+        for position in [departure, arrival] {
+            particle_events.send(SpawnParticlesEvent {
+                position,
+                color: Color::rgb(0.5, 0.8, 1.0),
+                count: 12,
+            });
+        }
+        audio_events.send(AudioEvent::Dash);
+    }
 }
 
 fn stamina_regen_system(
     time: Res<Time>,
-    mut player_query: Query<&mut Stats, With<Player>>,
+    mut player_query: Query<(&mut Stats, &Combat), With<Player>>,
 ) {
-    let mut stats = player_query.single_mut();
     let dt = time.delta_seconds();
     
-    // Regenerate stamina over time
-    if stats.current_stamina < stats.max_stamina {
-        stats.current_stamina += stats.stamina_regen_rate * dt;
-        stats.current_stamina = stats.current_stamina.min(stats.max_stamina);
+    // Regenerate stamina over time (não com o escudo erguido)
+    for (mut stats, combat) in &mut player_query {
+        if stats.current_stamina < stats.max_stamina && !combat.blocking {
+            stats.current_stamina += stats.stamina_regen_rate * dt;
+            stats.current_stamina = stats.current_stamina.min(stats.max_stamina);
+        }
     }
 }
 
 fn health_stamina_ui_system(
-    player_query: Query<&Stats, With<Player>>,
+    player_query: Query<(&PlayerId, &Stats), With<Player>>,
     mut health_bar_query: Query<&mut Style, (With<HealthBar>, Without<StaminaBar>)>,
     mut stamina_bar_query: Query<&mut Style, (With<StaminaBar>, Without<HealthBar>)>,
 ) {
-    if let Ok(stats) = player_query.get_single() {
+    if let Some((_, stats)) = player_query.iter().find(|(id, _)| id.is_primary()) {
         // Update health bar
         if let Ok(mut style) = health_bar_query.get_single_mut() {
             let health_percent = (stats.current_health / stats.max_health) * 100.0;
@@ -825,280 +1682,925 @@ fn health_stamina_ui_system(
     }
 }
 
-fn ai_system(
-    time: Res<Time>,
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-    mut player_query: Query<(&Transform, &mut Stats, &Dash), With<Player>>,
-    mut enemy_query: Query<(&mut Transform, &mut AI, &Stats, &EnemyType), (With<Enemy>, Without<Player>)>,
-) {
-    let (player_transform, mut player_stats, dash) = player_query.single_mut();
-    let dt = time.delta_seconds();
+// Jogador visto pela IA neste tick
+struct AiTarget {
+    entity: Entity,
+    position: Vec3,
+    velocity: Vec3, // Para antecipar os jarros lançados
+    vulnerable: bool,
+}
 
-    for (mut enemy_transform, mut ai, enemy_stats, enemy_type) in &mut enemy_query {
-        let distance = player_transform.translation.distance(enemy_transform.translation);
-        
-        // Update attack timer
-        ai.attack_timer = (ai.attack_timer - dt).max(0.0);
+fn nearest_ai_target(candidates: &[AiTarget], from: Vec3) -> Option<&AiTarget> {
+    candidates.iter().min_by(|a, b| {
+        a.position.distance_squared(from).total_cmp(&b.position.distance_squared(from))
+    })
+}
+
+// Separação (boids): inimigos mais perto que isso se empurram, mais forte quanto mais colados
+const SEPARATION_RADIUS: f32 = 1.2;
+const SEPARATION_STRENGTH: f32 = 1.5;
+
+// Repulsão somada dos vizinhos no plano do chão, em fração da velocidade do inimigo. Cada
+// vizinho pesa pela massa dele contra a própria: o tanque atravessa os fracos sem ser desviado
+fn separation_from(neighbours: &[(Entity, Vec3, f32)], entity: Entity, position: Vec3, mass: f32) -> Vec3 {
+    let mut push = Vec3::ZERO;
+    for &(other, other_position, other_mass) in neighbours {
+        let offset = Vec3::new(position.x - other_position.x, 0.0, position.z - other_position.z);
+        let distance = offset.length();
+        if other == entity || distance >= SEPARATION_RADIUS {
+            continue;
+        }
+        // Exatamente em cima do outro: desempata pelo índice da entidade
+        let away = if distance > 0.001 {
+            offset / distance
+        } else {
+            let angle = entity.index() as f32;
+            Vec3::new(angle.cos(), 0.0, angle.sin())
+        };
+        let weight = other_mass / (mass + other_mass);
+        push += away * (1.0 - distance / SEPARATION_RADIUS) * weight;
+    }
+    push * SEPARATION_STRENGTH
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn ai_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    (mut meshes, mut materials, mut projectile_pool): (ResMut<Assets<Mesh>>, ResMut<Assets<StandardMaterial>>, ResMut<ProjectilePool>),
+    mut player_query: Query<(Entity, &Transform, &mut Stats, &Dash, &Velocity, &Combat), (With<Player>, Without<Downed>)>,
+    mut enemy_query: Query<(Entity, &mut Transform, &mut AI, &mut Stats, &EnemyType, Option<&BossEnrage>, Option<&StatusEffects>, Option<&EliteAffix>, Option<&mut Berserk>), (With<Enemy>, Without<Player>, Without<Spawning>)>,
+    mut telegraph_query: Query<&mut Transform, (With<WindupTelegraph>, Without<Enemy>, Without<Player>)>,
+    mut decoy_query: Query<(Entity, &Transform, &mut Decoy), (Without<Enemy>, Without<WindupTelegraph>)>,
+    heat: Res<HeatModifiers>,
+    tuning: Res<CombatTuning>,
+    mut damaged_events: EventWriter<PlayerDamagedEvent>,
+    mut status_events: EventWriter<ApplyStatusEvent>,
+    debug: Option<Res<debug_tools::DebugSettings>>,
+    formation: Res<EnemyFormation>,
+    difficulty: Res<AdaptiveDifficulty>,
+    // Contagem da sala para a fúria do último (inimigos surgindo e na fila do governor contam)
+    // e paredes a contornar no caminho até o alvo
+    (living, governor, mut nav): (Query<(), With<Enemy>>, Res<SpawnGovernor>, ResMut<NavObstacles>),
+) {
+    let godmode = debug_tools::godmode_enabled(debug.as_deref());
+    let last_standing = living.iter().count() == 1 && governor.queued() == 0;
+    let attacks = &tuning.attacks;
+    // Alvos possíveis: jogadores de pé (caídos no co-op são ignorados)
+    let targets: Vec<AiTarget> = player_query.iter()
+        .map(|(entity, transform, _, dash, velocity, _)| AiTarget {
+            entity,
+            position: transform.translation,
+            velocity: velocity.0,
+            vulnerable: dash.i_timer <= 0.0 && !godmode,
+        })
+        .collect();
+    // Ilusões da Miragem de Thoth: paradas e sempre atingíveis
+    let decoys: Vec<AiTarget> = decoy_query.iter()
+        .map(|(entity, transform, _)| AiTarget {
+            entity,
+            position: transform.translation,
+            velocity: Vec3::ZERO,
+            vulnerable: true,
+        })
+        .collect();
+    let dt = time.delta_seconds();
+    let heat_damage_mult = heat.enemy_damage_multiplier() * difficulty.enemy_damage_multiplier();
+    // Posições do início do tick para a separação (ninguém empurra com a posição já empurrada)
+    let neighbours: Vec<(Entity, Vec3, f32)> = enemy_query.iter()
+        .map(|(entity, transform, _, _, enemy_type, ..)| (entity, transform.translation, enemy_type.mass()))
+        .collect();
+
+    for (enemy_entity, mut enemy_transform, mut ai, mut enemy_stats, enemy_type, enrage, status, affix, mut berserk) in &mut enemy_query {
+        // Cada inimigo persegue o jogador mais próximo, a não ser que uma ilusão esteja por perto
+        let decoy = nearest_ai_target(&decoys, enemy_transform.translation)
+            .filter(|decoy| decoy.position.distance(enemy_transform.translation) <= DECOY_AGGRO_RADIUS);
+        let Some(target) = decoy.or_else(|| nearest_ai_target(&targets, enemy_transform.translation)) else {
+            break;
+        };
+        // Atordoado ou arremessado (knockup): não age nem é preso ao chão e perde o ataque preparado;
+        // a altura no ar vem do knockup_motion_system
+        if status.is_some_and(StatusEffects::is_incapacitated) {
+            if let Some(telegraph) = ai.cancel_windup() {
+                commands.entity(telegraph).despawn_recursive();
+            }
+            continue;
+        }
+        let damage_mult = heat_damage_mult * enrage.map_or(1.0, BossEnrage::damage_multiplier);
+        let distance = target.position.distance(enemy_transform.translation);
+
+        // Último de pé (o chefe já tem a fúria própria): entra em fúria; a aura some quando acaba
+        if last_standing && berserk.is_none() && enrage.is_none() {
+            let aura = spawn_berserk_aura(&mut commands, &mut meshes, &mut materials, enemy_entity, &enemy_transform);
+            commands.entity(enemy_entity).insert(Berserk { remaining: BERSERK_DURATION, aura: Some(aura) });
+            info!("😤 Last enemy standing goes berserk!");
+        }
+        if let Some(berserk) = berserk.as_mut().filter(|berserk| berserk.is_active()) {
+            berserk.remaining -= dt;
+            if let Some(aura) = berserk.aura.filter(|_| !berserk.is_active()) {
+                commands.entity(aura).despawn_recursive();
+                berserk.aura = None;
+            }
+        }
+        let berserk = berserk.as_deref();
+        
+        // Lentidão/congelamento reduz movimento e cadência de ataque; elites velozes e a fúria compensam
+        let speed = enemy_stats.speed * status.map_or(1.0, StatusEffects::speed_multiplier)
+            * affix.map_or(1.0, EliteAffix::speed_multiplier)
+            * berserk.map_or(1.0, Berserk::speed_multiplier);
+        let attack_rate = status.map_or(1.0, StatusEffects::attack_rate_multiplier)
+            * affix.map_or(1.0, EliteAffix::attack_rate_multiplier)
+            * berserk.map_or(1.0, Berserk::attack_rate_multiplier);
+        
+        // Update attack timer
+        ai.attack_timer = (ai.attack_timer - dt * attack_rate).max(0.0);
+        
+        // Afasta dos vizinhos antes de andar; parado preparando golpe ou investindo, não sai do lugar
+        if ai.windup.is_none() && ai.lunge.is_none() {
+            let separation = separation_from(&neighbours, enemy_entity, enemy_transform.translation, enemy_type.mass());
+            enemy_transform.translation += separation * speed * dt;
+        }
         
         match enemy_type {
-            EnemyType::Chaser => {
-                // Move towards player aggressively
-                if distance < ai.target_range && distance > 1.0 {
-                    let direction = (player_transform.translation - enemy_transform.translation).normalize();
-                    enemy_transform.translation += direction * enemy_stats.speed * dt;
+            EnemyType::Chaser | EnemyType::Tank => {
+                let (reach, windup_time, strike_damage) = melee_strike(*enemy_type);
+                
+                // Winding up: stand still, grow the telegraph, strike at the end
+                if let Some(mut windup) = ai.windup.take() {
+                    windup.timer -= dt * attack_rate;
+                    let interrupted = enemy_stats.current_health <= 0.0
+                        || speed <= 0.0
+                        || enemy_transform.translation.distance(windup.anchor) > WINDUP_INTERRUPT_DISTANCE;
+                    
+                    if !interrupted && windup.timer > 0.0 {
+                        if let Ok(mut telegraph_transform) = telegraph_query.get_mut(windup.telegraph) {
+                            let progress = 1.0 - windup.timer / windup_time;
+                            telegraph_transform.scale = Vec3::splat(0.2 + 0.8 * progress);
+                        }
+                        ai.windup = Some(windup);
+                        continue;
+                    }
+                    
+                    commands.entity(windup.telegraph).despawn_recursive();
+                    ai.attack_timer = ai.attack_cooldown;
+                    // Dashing through or stepping out of reach dodges the strike
+                    if !interrupted && distance <= reach && target.vulnerable {
+                        let mut dealt = strike_damage * damage_mult;
+                        if let Ok((_, player_transform, mut player_stats, _, _, player_combat)) = player_query.get_mut(target.entity) {
+                            // Escudo erguido de frente: reduz ou, no bloqueio perfeito, anula e atordoa quem bateu
+                            let block = block_outcome(player_combat, player_transform.translation, enemy_transform.translation);
+                            if block == BlockOutcome::Perfect {
+                                status_events.send(perfect_block_stagger(attacks).event_for(enemy_entity));
+                            }
+                            dealt *= block.damage_taken(attacks);
+                            if dealt > 0.0 {
+                                player_stats.current_health = (player_stats.current_health - dealt).max(0.0);
+                                damaged_events.send(PlayerDamagedEvent { target: target.entity, source: enemy_transform.translation });
+                            }
+                        } else if let Ok((_, _, mut decoy)) = decoy_query.get_mut(target.entity) {
+                            decoy.health -= dealt;
+                        }
+                        if affix == Some(&EliteAffix::Vampiric) {
+                            let healed = enemy_stats.current_health + dealt * VAMPIRIC_HEAL_FRACTION;
+                            enemy_stats.current_health = healed.min(enemy_stats.max_health);
+                        }
+                    }
+                    continue;
+                }
+                
+                // Cercando: espera a vez no anel em volta do alvo
+                if let CombatRole::Surround { angle } = formation.role(enemy_entity) {
+                    if distance < ai.target_range {
+                        let slot = formation_slot(target.position, angle, formation_radius(*enemy_type));
+                        let waypoint = nav.waypoint(enemy_entity, enemy_transform.translation, slot);
+                        enemy_transform.translation = step_toward_slot(enemy_transform.translation, waypoint, speed * dt);
+                    }
+                } else if distance < ai.target_range && distance > reach * 0.8 {
+                    // Close in until just inside striking reach (contornando paredes no caminho)
+                    let waypoint = nav.waypoint(enemy_entity, enemy_transform.translation, target.position);
+                    let direction = (waypoint - enemy_transform.translation).normalize();
+                    enemy_transform.translation += direction * speed * dt;
                     enemy_transform.translation.y = 0.5;
                 }
                 
-                // Damage player if touching
-                if distance <= 1.0 && dash.i_timer <= 0.0 {
-                    player_stats.current_health -= 25.0 * dt;
-                    player_stats.current_health = player_stats.current_health.max(0.0);
+                // In reach: start the telegraphed windup
+                if distance <= reach && ai.attack_timer <= 0.0 && speed > 0.0 {
+                    let telegraph = commands.spawn((
+                        PbrBundle {
+                            mesh: meshes.add(Circle::new(reach)),
+                            material: materials.add(StandardMaterial {
+                                base_color: Color::rgba(1.0, 0.2, 0.1, 0.45),
+                                emissive: Color::rgb(1.5, 0.2, 0.1),
+                                alpha_mode: AlphaMode::Blend,
+                                unlit: true,
+                                ..default()
+                            }),
+                            transform: Transform::from_xyz(0.0, -0.45, 0.0)
+                                .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+                                .with_scale(Vec3::splat(0.2)),
+                            ..default()
+                        },
+                        WindupTelegraph,
+                    )).id();
+                    commands.entity(enemy_entity).add_child(telegraph);
+                    ai.windup = Some(MeleeWindup {
+                        timer: windup_time,
+                        anchor: enemy_transform.translation,
+                        telegraph,
+                    });
                 }
             },
             
             EnemyType::Shooter => {
                 // Keep distance and shoot projectiles
                 if distance < ai.target_range {
-                    if distance > 6.0 {
+                    if let CombatRole::HoldRange { angle } = formation.role(enemy_entity) {
+                        // Cada atirador no seu ângulo, sem empilhar na mesma linha de tiro
+                        let slot = formation_slot(target.position, angle, formation_radius(*enemy_type));
+                        let waypoint = nav.waypoint(enemy_entity, enemy_transform.translation, slot);
+                        enemy_transform.translation = step_toward_slot(enemy_transform.translation, waypoint, speed * dt);
+                    } else if distance > 6.0 {
                         // Too far - move closer
-                        let direction = (player_transform.translation - enemy_transform.translation).normalize();
-                        enemy_transform.translation += direction * enemy_stats.speed * dt;
+                        let waypoint = nav.waypoint(enemy_entity, enemy_transform.translation, target.position);
+                        let direction = (waypoint - enemy_transform.translation).normalize();
+                        enemy_transform.translation += direction * speed * dt;
                         enemy_transform.translation.y = 0.5;
                     } else if distance < 4.0 {
                         // Too close - back away
-                        let direction = (enemy_transform.translation - player_transform.translation).normalize();
-                        enemy_transform.translation += direction * enemy_stats.speed * dt;
+                        let direction = (enemy_transform.translation - target.position).normalize();
+                        enemy_transform.translation += direction * speed * dt;
                         enemy_transform.translation.y = 0.5;
                     }
                     
+                    // Mira por um instante antes de atirar (um golpe forte aqui cancela o tiro)
+                    if ai.attack_timer <= 0.0 && ai.queued_shot.is_none() {
+                        ai.queued_shot = Some(SHOOTER_AIM_TIME);
+                    }
+                    let aim = ai.queued_shot.map(|remaining| remaining - dt * attack_rate);
+                    ai.queued_shot = aim.filter(|remaining| *remaining > 0.0);
+                    
                     // Shoot at player
-                    if ai.attack_timer <= 0.0 {
-                        let direction = (player_transform.translation - enemy_transform.translation).normalize();
-                        commands.spawn((
-                            PbrBundle {
-                                mesh: meshes.add(Sphere::new(0.1)),
-                                material: materials.add(StandardMaterial {
-                                    base_color: Color::rgb(0.8, 0.1, 0.1),
-                                    emissive: Color::rgb(2.0, 0.5, 0.5).into(),
-                                    ..default()
-                                }),
-                                transform: Transform::from_translation(enemy_transform.translation + direction * 0.5),
-                                ..default()
-                            },
+                    if aim.is_some() && ai.queued_shot.is_none() {
+                        let direction = (target.position - enemy_transform.translation).normalize();
+                        let shot = fire_projectile(
+                            &mut commands,
+                            &mut projectile_pool,
+                            &mut meshes,
+                            &mut materials,
+                            ProjectileVisual::EnemyShot,
+                            Transform::from_translation(enemy_transform.translation + direction * 0.5),
                             Projectile {
-                                damage: 15,
+                                damage: (15.0 * damage_mult).round() as i32,
                                 velocity: direction * 8.0,
                                 ttl: 3.0,
                                 from_enemy: true,
                             },
-                            EnemyProjectile,
-                        ));
+                        );
+                        commands.entity(shot).insert(EnemyProjectile);
+                        if affix == Some(&EliteAffix::Vampiric) {
+                            commands.entity(shot).insert(VampiricShot { owner: enemy_entity });
+                        }
                         ai.attack_timer = ai.attack_cooldown;
                     }
                 }
             },
             
-            EnemyType::Tank => {
-                // Slow but heavy damage
-                if distance < ai.target_range && distance > 1.5 {
-                    let direction = (player_transform.translation - enemy_transform.translation).normalize();
-                    enemy_transform.translation += direction * enemy_stats.speed * dt;
-                    enemy_transform.translation.y = 0.5;
+            EnemyType::LobberMummy => {
+                // Stay at long range and lob jars where the player is heading
+                if distance < ai.target_range {
+                    if let CombatRole::HoldRange { angle } = formation.role(enemy_entity) {
+                        let slot = formation_slot(target.position, angle, formation_radius(*enemy_type));
+                        let waypoint = nav.waypoint(enemy_entity, enemy_transform.translation, slot);
+                        enemy_transform.translation = step_toward_slot(enemy_transform.translation, waypoint, speed * dt);
+                    } else if distance > 9.0 {
+                        let waypoint = nav.waypoint(enemy_entity, enemy_transform.translation, target.position);
+                        let direction = (waypoint - enemy_transform.translation).normalize();
+                        enemy_transform.translation += direction * speed * dt;
+                        enemy_transform.translation.y = 0.5;
+                    } else if distance < 6.0 {
+                        let direction = (enemy_transform.translation - target.position).normalize();
+                        enemy_transform.translation += direction * speed * dt;
+                        enemy_transform.translation.y = 0.5;
+                    }
+                    
+                    if ai.attack_timer <= 0.0 {
+                        const LOB_FLIGHT_TIME: f32 = 1.1;
+                        let predicted = target.position + target.velocity * LOB_FLIGHT_TIME;
+                        spawn_arc_projectile(
+                            &mut commands,
+                            &mut meshes,
+                            &mut materials,
+                            ArcProjectile {
+                                start: enemy_transform.translation,
+                                target: Vec3::new(predicted.x, 0.5, predicted.z),
+                                elapsed: 0.0,
+                                flight_time: LOB_FLIGHT_TIME,
+                                arc_height: 4.0,
+                                fuse: 0.0,
+                                radius: 1.5,
+                                damage: 20.0 * damage_mult,
+                                from_enemy: true,
+                                shadow: None,
+                            },
+                            0.2,
+                            Color::rgb(0.5, 0.8, 0.3),
+                        );
+                        ai.attack_timer = ai.attack_cooldown;
+                    }
                 }
-                
-                // Heavy damage if touching
-                if distance <= 1.5 && dash.i_timer <= 0.0 {
-                    player_stats.current_health -= 40.0 * dt;
-                    player_stats.current_health = player_stats.current_health.max(0.0);
+            },
+            
+            EnemyType::SandJackal => {
+                match ai.lunge.take() {
+                    Some(LungePhase::Windup { timer, direction, telegraph }) => {
+                        // Congelado perde a investida
+                        if speed <= 0.0 {
+                            commands.entity(telegraph).despawn_recursive();
+                            ai.attack_timer = ai.attack_cooldown;
+                            continue;
+                        }
+                        let timer = timer - dt * attack_rate;
+                        ai.lunge = if timer > 0.0 {
+                            Some(LungePhase::Windup { timer, direction, telegraph })
+                        } else {
+                            commands.entity(telegraph).despawn_recursive();
+                            Some(LungePhase::Lunge { timer: JACKAL_LUNGE_TIME, direction, hit: false })
+                        };
+                    }
+                    Some(LungePhase::Lunge { mut timer, direction, mut hit }) => {
+                        enemy_transform.translation += direction * JACKAL_LUNGE_SPEED * dt;
+                        enemy_transform.translation.y = 0.5;
+                        
+                        // Fere (uma vez por investida) tudo o que estiver no caminho
+                        if !hit {
+                            let position = enemy_transform.translation;
+                            let strike_damage = JACKAL_LUNGE_DAMAGE * damage_mult;
+                            for (player_entity, player_transform, mut player_stats, player_dash, _, player_combat) in &mut player_query {
+                                if player_transform.translation.distance(position) > JACKAL_HIT_RADIUS || player_dash.i_timer > 0.0 || godmode {
+                                    continue;
+                                }
+                                hit = true;
+                                // Vem de onde a investida começou
+                                let block = block_outcome(player_combat, player_transform.translation, position - direction * JACKAL_HIT_RADIUS);
+                                if block == BlockOutcome::Perfect {
+                                    // Bate no escudo e para na hora, atordoado
+                                    status_events.send(perfect_block_stagger(attacks).event_for(enemy_entity));
+                                    timer = 0.0;
+                                    continue;
+                                }
+                                let dealt = strike_damage * block.damage_taken(attacks);
+                                player_stats.current_health = (player_stats.current_health - dealt).max(0.0);
+                                damaged_events.send(PlayerDamagedEvent { target: player_entity, source: position });
+                            }
+                            for (_, decoy_transform, mut decoy) in &mut decoy_query {
+                                if decoy_transform.translation.distance(position) <= JACKAL_HIT_RADIUS {
+                                    decoy.health -= strike_damage;
+                                    hit = true;
+                                }
+                            }
+                            if hit && affix == Some(&EliteAffix::Vampiric) {
+                                let healed = enemy_stats.current_health + strike_damage * VAMPIRIC_HEAL_FRACTION;
+                                enemy_stats.current_health = healed.min(enemy_stats.max_health);
+                            }
+                        }
+                        
+                        let timer = timer - dt;
+                        ai.lunge = Some(if timer > 0.0 {
+                            LungePhase::Lunge { timer, direction, hit }
+                        } else {
+                            LungePhase::Recovery { timer: JACKAL_RECOVERY_TIME }
+                        });
+                    }
+                    Some(LungePhase::Recovery { timer }) => {
+                        // Parado e ofegante: janela para punir
+                        let timer = timer - dt * attack_rate;
+                        if timer > 0.0 {
+                            ai.lunge = Some(LungePhase::Recovery { timer });
+                        } else {
+                            ai.attack_timer = ai.attack_cooldown;
+                        }
+                    }
+                    None => {
+                        if let CombatRole::Surround { angle } = formation.role(enemy_entity) {
+                            // Rodeia fora do alcance da investida até abrir vaga
+                            if distance < ai.target_range {
+                                let slot = formation_slot(target.position, angle, formation_radius(*enemy_type));
+                                let waypoint = nav.waypoint(enemy_entity, enemy_transform.translation, slot);
+                                enemy_transform.translation = step_toward_slot(enemy_transform.translation, waypoint, speed * dt);
+                            }
+                        } else if distance < ai.target_range && distance > JACKAL_LUNGE_RANGE * 0.8 {
+                            // Aproxima até a distância da investida
+                            let waypoint = nav.waypoint(enemy_entity, enemy_transform.translation, target.position);
+                            let direction = (waypoint - enemy_transform.translation).normalize();
+                            enemy_transform.translation += direction * speed * dt;
+                            enemy_transform.translation.y = 0.5;
+                        }
+                        
+                        if distance <= JACKAL_LUNGE_RANGE && ai.attack_timer <= 0.0 && speed > 0.0 {
+                            let offset = target.position - enemy_transform.translation;
+                            let direction = Vec3::new(offset.x, 0.0, offset.z).normalize_or_zero();
+                            if direction != Vec3::ZERO {
+                                let telegraph = spawn_lunge_telegraph(&mut commands, &mut meshes, &mut materials, enemy_entity, &enemy_transform, direction);
+                                ai.lunge = Some(LungePhase::Windup { timer: JACKAL_WINDUP_TIME, direction, telegraph });
+                            }
+                        }
+                    }
                 }
             },
         }
     }
 }
 
+// Anel vermelho no chão em volta do inimigo em fúria (filho dele; desfaz a escala do pai)
+fn spawn_berserk_aura(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    owner: Entity,
+    owner_transform: &Transform,
+) -> Entity {
+    let inverse_scale = 1.0 / owner_transform.scale.x.max(0.01);
+    let aura = commands.spawn(PbrBundle {
+        mesh: meshes.add(Torus::new(0.7, 0.85)),
+        material: materials.add(StandardMaterial {
+            base_color: Color::rgba(1.0, 0.1, 0.05, 0.6),
+            emissive: Color::rgb(2.0, 0.2, 0.1),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        }),
+        transform: Transform::from_translation(Vec3::Y * (0.05 - owner_transform.translation.y) * inverse_scale)
+            .with_scale(Vec3::splat(inverse_scale)),
+        ..default()
+    }).id();
+    commands.entity(owner).add_child(aura);
+    aura
+}
+
+// Faixa no chão mostrando o caminho da investida. É filha do chacal (some com ele), então
+// desfaz a rotação/escala do pai para ficar alinhada ao mundo
+fn spawn_lunge_telegraph(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    owner: Entity,
+    owner_transform: &Transform,
+    direction: Vec3,
+) -> Entity {
+    let length = JACKAL_LUNGE_SPEED * JACKAL_LUNGE_TIME;
+    let world_offset = direction * length / 2.0 + Vec3::Y * (0.05 - owner_transform.translation.y);
+    let world_rotation = Quat::from_rotation_y(direction.x.atan2(direction.z));
+    let inverse_rotation = owner_transform.rotation.inverse();
+    let inverse_scale = 1.0 / owner_transform.scale.x.max(0.01);
+
+    let telegraph = commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Plane3d::default().mesh().size(JACKAL_HIT_RADIUS * 1.5, length)),
+            material: materials.add(StandardMaterial {
+                base_color: Color::rgba(1.0, 0.45, 0.1, 0.4),
+                emissive: Color::rgb(1.5, 0.5, 0.1),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            }),
+            transform: Transform {
+                translation: inverse_rotation * world_offset * inverse_scale,
+                rotation: inverse_rotation * world_rotation,
+                scale: Vec3::splat(inverse_scale),
+            },
+            ..default()
+        },
+        WindupTelegraph,
+    )).id();
+    commands.entity(owner).add_child(telegraph);
+    telegraph
+}
+
+// Raios de colisão dos projéteis: projétil inimigo x jogador, projétil do jogador x inimigo
+const PLAYER_HURT_RADIUS: f32 = 0.8;
+const ENEMY_HURT_RADIUS: f32 = 0.7;
+
+// Parry do secundário: velocidade do projétil rebatido (janela no CombatTuning)
+const PARRY_SPEED_MULT: f32 = 1.5;
+
+// R (AoE): tempo segurando para entrar no modo mira (raio e alcance no CombatTuning)
+const AOE_AIM_THRESHOLD: f32 = 0.15;
+// Faíscas por unidade de raio da explosão (raio base 2.6 ≈ 20 partículas)
+const AOE_PARTICLES_PER_UNIT: f32 = 8.0;
+// Abertura entre projéteis do Q em leque (rad)
+const Q_FAN_SPREAD: f32 = 0.2;
+
+// Direções de um disparo em leque, centrado na mira (`count` tiros separados por `spread` rad)
+fn fan_directions(aim: Vec3, count: u32, spread: f32) -> impl Iterator<Item = Vec3> {
+    let count = count.max(1);
+    (0..count).map(move |index| {
+        let angle = (index as f32 - (count - 1) as f32 / 2.0) * spread;
+        Quat::from_rotation_y(angle) * aim
+    })
+}
+
+// Bênçãos que mexem nos números do combate são reaplicadas a cada passo (novas bênçãos,
+// troca de arma e P2 entrando ficam em dia sem ganchos extras)
+fn sync_boon_combat_modifiers(
+    active_boons: Res<boons::ActiveBoons>,
+    boon_modifiers: Res<boons::CombatModifiers>,
+    mut player_query: Query<&mut Combat, With<Player>>,
+) {
+    let projectile_count = 1 + boons::extra_projectiles(&active_boons);
+    let area_multiplier = boons::area_multiplier(&active_boons);
+    for mut combat in &mut player_query {
+        combat.projectile_count = projectile_count;
+        combat.area_multiplier = area_multiplier;
+        combat.crit_chance = boon_modifiers.crit_chance;
+    }
+}
+
+// Ground target for R, clamped to the max cast range around the player
+fn aoe_target_position(player_pos: Vec3, mouse_pos: Vec3, max_range: f32) -> Vec3 {
+    let offset = Vec3::new(mouse_pos.x - player_pos.x, 0.0, mouse_pos.z - player_pos.z);
+    let target = player_pos + offset.clamp_length_max(max_range);
+    Vec3::new(target.x, player_pos.y, target.z)
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 fn hades_combat_system(
     time: Res<Time>,
-    input: Res<InputState>,
+    fixed_input: Res<FixedInput>,
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
-    mut player_query: Query<(&Transform, &mut Combat), With<Player>>,
-    mut enemy_query: Query<(Entity, &Transform, &mut Stats), (With<Enemy>, Without<Player>)>,
-    mut particle_events: EventWriter<SpawnParticlesEvent>,
+    mut player_query: Query<(&PlayerId, &Transform, &mut Combat, &mut Dash), (With<Player>, Without<Downed>)>,
+    mut enemy_query: Query<(Entity, &mut Transform, &mut Stats, Option<&mut ShieldedEnemy>), (With<Enemy>, Without<Player>, Without<Spawning>)>,
+    // Retorno visual dos golpes: faíscas e números de dano
+    (mut particle_events, mut damage_events): (EventWriter<SpawnParticlesEvent>, EventWriter<DamageEvent>),
     mut audio_events: EventWriter<AudioEvent>,
+    mut aoe_events: EventWriter<AoeExplosionEvent>,
+    active_boons: Res<boons::ActiveBoons>,
+    mut status_events: EventWriter<ApplyStatusEvent>,
+    // Buffs do combo, do ímpeto dos abates e das bênçãos (dano e recargas)
+    (combo, momentum, boon_modifiers): (Res<ComboMeter>, Res<Momentum>, Res<boons::CombatModifiers>),
+    tuning: Res<CombatTuning>,
+    mut projectile_pool: ResMut<ProjectilePool>,
+    mut combat_rng: ResMut<CombatRng>,
 ) {
-    let (player_transform, mut combat) = player_query.single_mut();
     let dt = time.delta_seconds();
+    let attacks = &tuning.attacks;
+    // Buffs do combo (mais dano e recargas mais rápidas), bênçãos de dano e ímpeto dos abates (recargas mais rápidas)
+    let damage_mult = combo.damage_multiplier() * boon_modifiers.damage_multiplier;
+    let cooldown_dt = dt * combo.cooldown_rate() * momentum.attack_speed_multiplier();
+    // Veneno/sangramento de boons aplicados por golpes corpo a corpo
+    let melee_statuses = attack_on_hit_statuses(&active_boons);
+    // Fogo de Rá: cada golpe que acerta pode incendiar (ou renovar a queimadura)
+    let melee_burn = attack_burn(&active_boons);
+    let (heavy_stun, dash_strike_stun) = heavy_hit_stuns(&active_boons);
+
+    for (id, player_transform, mut combat, mut dash) in &mut player_query {
+        let input = fixed_input.get(*id);
+
+        // Update cooldowns
+        combat.atk_timer = (combat.atk_timer - cooldown_dt).max(0.0);
+        combat.special_timer = (combat.special_timer - cooldown_dt).max(0.0);
+        combat.q_timer = (combat.q_timer - cooldown_dt).max(0.0);
+        combat.r_timer = (combat.r_timer - cooldown_dt).max(0.0);
+        // A janela de parry não acelera com o combo
+        combat.parry_timer = (combat.parry_timer - dt).max(0.0);
+        // Parou de acertar: a sequência recomeça do primeiro golpe
+        if combat.chain_timer > 0.0 {
+            combat.chain_timer -= dt;
+            if combat.chain_timer <= 0.0 {
+                combat.chain_step = 0;
+            }
+        }
 
-    // Update cooldowns
-    combat.atk_timer = (combat.atk_timer - dt).max(0.0);
-    combat.special_timer = (combat.special_timer - dt).max(0.0);
-    combat.q_timer = (combat.q_timer - dt).max(0.0);
-    combat.r_timer = (combat.r_timer - dt).max(0.0);
+        // Alcance depende da arma equipada
+        let weapon = tuning.weapon(combat.weapon);
+        let hit_range = weapon.hit_range;
 
-    const HIT_RANGE: f32 = 1.6;
+        // Holding Mouse Left builds the charge; the release decides tap or heavy,
+        // so a heavy never lands right after a free tap from the same press
+        if input.primary_held {
+            combat.charge_time += dt;
+        }
+        let heavy_ready = combat.charge_time >= combat.charge_threshold;
 
-    // PRIMARY ATTACK (Mouse Left) - Chain 3 hits
-    if input.primary && combat.atk_timer <= 0.0 {
-        let mut hits = 0;
-        for (entity, enemy_transform, mut enemy_stats) in &mut enemy_query {
-            if player_transform.translation.distance(enemy_transform.translation) <= HIT_RANGE {
-                let damage = combat.base_damage + (combat.chain_step as i32 * 2);
-                enemy_stats.current_health -= damage as f32;
+        // PRIMARY ATTACK (Mouse Left, released before the charge fills) - Chain 3 hits, the third is a finisher
+        if input.primary_released && !heavy_ready && combat.atk_timer <= 0.0 {
+            let mut hits = 0;
+            let finisher = combat.chain_step == 2;
+            let (finisher_mult, knockback) = if finisher {
+                (attacks.finisher_damage_mult, attacks.finisher_knockback)
+            } else {
+                (1.0, attacks.primary_knockback)
+            };
+            for (entity, enemy_transform, mut enemy_stats, shield) in &mut enemy_query {
+                if player_transform.translation.distance(enemy_transform.translation) <= hit_range {
+                    let critical = combat_rng.chance(combat.crit_chance);
+                    let damage = ((combat.base_damage + combat.chain_step as i32 * attacks.chain_bonus_damage) as f32
+                        * damage_mult * finisher_mult * combat.critical_multiplier(critical)) as i32;
+                    apply_enemy_damage(&mut enemy_stats, shield, damage as f32);
+                    damage_events.send(enemy_hit_feedback(enemy_transform.translation, damage as f32, critical));
+                    status_events.send_batch(melee_statuses.iter().map(|status| status.event_for(entity)));
+                    if let Some(burn) = melee_burn.filter(|burn| combat_rng.chance(burn.chance)) {
+                        commands.entity(entity).insert(burn.burning());
+                    }
                 
-                // Add hit effect
-                commands.entity(entity).insert(HitEffect {
-                    timer: 0.0,
-                    duration: 0.3,
-                    original_scale: enemy_transform.scale,
-                });
+                    // Add hit effect
+                    commands.entity(entity).insert((
+                        HitEffect {
+                            timer: 0.0,
+                            duration: 0.3,
+                            original_scale: enemy_transform.scale,
+                        },
+                        Knockback::new(enemy_transform.translation - player_transform.translation, knockback, attacks.knockback_decay, &enemy_stats),
+                    ));
                 
-                // Spawn impact particles
-                particle_events.send(SpawnParticlesEvent {
-                    position: enemy_transform.translation,
-                    color: Color::rgb(1.0, 0.8, 0.2),
-                    count: 8,
-                });
+                    // Spawn impact particles (more for the finisher)
+                    particle_events.send(SpawnParticlesEvent {
+                        position: enemy_transform.translation,
+                        color: Color::rgb(1.0, 0.8, 0.2),
+                        count: if finisher { 14 } else { 8 },
+                    });
+                    if critical {
+                        particle_events.send(critical_hit_burst(enemy_transform.translation));
+                    }
                 
-                hits += 1;
-                if enemy_stats.current_health <= 0.0 {
-                    commands.entity(entity).despawn();
-                    // Play enemy death audio
-                    audio_events.send(AudioEvent::EnemyDeath);
-                } else {
-                    // Play enemy hit audio
-                    audio_events.send(AudioEvent::EnemyHit);
+                    hits += 1;
+                    // Deaths are resolved in enemy_death_system
+                    if enemy_stats.current_health > 0.0 {
+                        // Play enemy hit audio
+                        audio_events.send(AudioEvent::EnemyHit);
+                    }
                 }
             }
-        }
         
-        if hits > 0 {
-            combat.chain_step = (combat.chain_step + 1) % 3;
-            combat.atk_timer = combat.atk_cd;
-            // Play primary attack audio
-            audio_events.send(AudioEvent::AttackPrimary);
+            if hits > 0 {
+                combat.chain_step = (combat.chain_step + 1) % 3;
+                combat.chain_timer = if combat.chain_step == 0 { 0.0 } else { attacks.chain_window };
+                combat.atk_timer = combat.atk_cd * boon_modifiers.attack_cooldown;
+                // Play primary attack audio
+                audio_events.send(AudioEvent::AttackPrimary);
+            }
         }
-    }
 
-    // SECONDARY ATTACK (Mouse Right) - Special attack
-    if input.secondary && combat.special_timer <= 0.0 {
-        let range = HIT_RANGE * 1.35;
-        let mut hits = 0;
-        for (entity, enemy_transform, mut enemy_stats) in &mut enemy_query {
-            if player_transform.translation.distance(enemy_transform.translation) <= range {
-                let damage = (combat.base_damage as f32 * 1.8) as i32;
-                enemy_stats.current_health -= damage as f32;
+        // CHARGED HEAVY (hold Mouse Left) - wider hit with knockback, consumes the chain
+        if input.primary_released {
+            if heavy_ready && combat.atk_timer <= 0.0 {
+                let range = hit_range * attacks.heavy_range_mult;
+                let heavy_damage = (combat.base_damage + combat.chain_step as i32 * attacks.chain_bonus_damage) as f32
+                    * attacks.heavy_damage_mult * damage_mult;
+                for (entity, enemy_transform, mut enemy_stats, shield) in &mut enemy_query {
+                    let offset = enemy_transform.translation - player_transform.translation;
+                    if offset.length() <= range {
+                        let critical = combat_rng.chance(combat.crit_chance);
+                        let damage = (heavy_damage * combat.critical_multiplier(critical)) as i32;
+                        apply_enemy_damage(&mut enemy_stats, shield, damage as f32);
+                        damage_events.send(enemy_hit_feedback(enemy_transform.translation, damage as f32, critical));
+                        status_events.send_batch(melee_statuses.iter().map(|status| status.event_for(entity)));
+                        if let Some(burn) = melee_burn.filter(|burn| combat_rng.chance(burn.chance)) {
+                            commands.entity(entity).insert(burn.burning());
+                        }
+                        status_events.send(heavy_stun.event_for(entity));
+                    
+                        // Knockback away from the player
+                        commands.entity(entity).insert((
+                            HitEffect {
+                                timer: 0.0,
+                                duration: 0.5,
+                                original_scale: enemy_transform.scale,
+                            },
+                            Knockback::new(offset, attacks.heavy_knockback, attacks.knockback_decay, &enemy_stats),
+                        ));
+                    
+                        particle_events.send(SpawnParticlesEvent {
+                            position: enemy_transform.translation,
+                            color: Color::rgb(1.0, 0.9, 0.3),
+                            count: 14,
+                        });
+                        if critical {
+                            particle_events.send(critical_hit_burst(enemy_transform.translation));
+                        }
+                    
+                        // Deaths are resolved in enemy_death_system
+                        if enemy_stats.current_health > 0.0 {
+                            audio_events.send(AudioEvent::EnemyHit);
+                        }
+                    }
+                }
+            
+                combat.chain_step = 0;
+                combat.chain_timer = 0.0;
+                combat.atk_timer = combat.atk_cd * boon_modifiers.attack_cooldown;
+                audio_events.send(AudioEvent::AttackPrimary);
+            }
+            combat.charge_time = 0.0;
+        }
+
+        // SECONDARY ATTACK (Mouse Right) - Dash-strike: lunge toward the mouse, then hit on arrival
+        if input.secondary && combat.special_timer <= 0.0 && !dash.is_dashing && !combat.weapon.has_shield() {
+            let offset = input.mouse_world_pos - player_transform.translation;
+            let flat = Vec3::new(offset.x, 0.0, offset.z);
+            let direction = if flat.length_squared() > 0.01 {
+                flat.normalize()
+            } else {
+                Vec3::new(0.0, 0.0, -1.0)
+            };
+            let lunge_distance = flat.length().clamp(1.0, attacks.dash_strike_max_distance);
+        
+            // Reuse the dash movement: shorten the dash window to cover only the lunge
+            let lunge_duration = 0.2 * (lunge_distance / dash.distance);
+            dash.is_dashing = true;
+            dash.dash_timer = lunge_duration;
+            dash.dash_direction = direction;
+            dash.i_timer = dash.i_timer.max(lunge_duration + 0.1); // Invulnerável durante o avanço
+        
+            combat.dash_strike_pending = true;
+            combat.special_timer = combat.special_cd * boon_modifiers.special_cooldown;
+            combat.parry_timer = attacks.parry_window;
+            audio_events.send(AudioEvent::Dash);
+        }
+    
+        // Special hit lands once the lunge finishes
+        if combat.dash_strike_pending && !dash.is_dashing {
+            combat.dash_strike_pending = false;
+            let range = hit_range * attacks.dash_strike_range_mult * combat.area_multiplier;
+            let mut hits = 0;
+            for (entity, enemy_transform, mut enemy_stats, shield) in &mut enemy_query {
+                let distance = player_transform.translation.distance(enemy_transform.translation);
+                if distance <= range {
+                    let falloff = combat.aoe_falloff.damage_multiplier(distance, range);
+                    let critical = combat_rng.chance(combat.crit_chance);
+                    let damage = (combat.base_damage as f32 * attacks.dash_strike_damage_mult * damage_mult * falloff
+                        * combat.critical_multiplier(critical)) as i32;
+                    apply_enemy_damage(&mut enemy_stats, shield, damage as f32);
+                    damage_events.send(enemy_hit_feedback(enemy_transform.translation, damage as f32, critical));
+                    status_events.send_batch(melee_statuses.iter().map(|status| status.event_for(entity)));
+                    if let Some(burn) = melee_burn.filter(|burn| combat_rng.chance(burn.chance)) {
+                        commands.entity(entity).insert(burn.burning());
+                    }
+                    if let Some(stun) = dash_strike_stun {
+                        status_events.send(stun.event_for(entity));
+                    }
                 
-                // Add stronger hit effect
-                commands.entity(entity).insert(HitEffect {
-                    timer: 0.0,
-                    duration: 0.5,
-                    original_scale: enemy_transform.scale,
-                });
+                    // Add stronger hit effect
+                    commands.entity(entity).insert((
+                        HitEffect {
+                            timer: 0.0,
+                            duration: 0.5,
+                            original_scale: enemy_transform.scale,
+                        },
+                        Knockback::new(enemy_transform.translation - player_transform.translation, attacks.dash_strike_knockback, attacks.knockback_decay, &enemy_stats),
+                    ));
                 
-                // Spawn special attack particles (purple)
-                particle_events.send(SpawnParticlesEvent {
-                    position: enemy_transform.translation,
-                    color: Color::rgb(0.8, 0.3, 1.0),
-                    count: 12,
-                });
+                    // Spawn special attack particles (purple)
+                    particle_events.send(SpawnParticlesEvent {
+                        position: enemy_transform.translation,
+                        color: Color::rgb(0.8, 0.3, 1.0),
+                        count: 12,
+                    });
+                    if critical {
+                        particle_events.send(critical_hit_burst(enemy_transform.translation));
+                    }
                 
-                hits += 1;
-                if enemy_stats.current_health <= 0.0 {
-                    commands.entity(entity).despawn();
-                    // Play enemy death audio
-                    audio_events.send(AudioEvent::EnemyDeath);
-                } else {
-                    // Play enemy hit audio
-                    audio_events.send(AudioEvent::EnemyHit);
+                    hits += 1;
+                    // Deaths are resolved in enemy_death_system
+                    if enemy_stats.current_health > 0.0 {
+                        // Play enemy hit audio
+                        audio_events.send(AudioEvent::EnemyHit);
+                    }
                 }
             }
-        }
         
-        if hits > 0 {
-            combat.special_timer = combat.special_cd;
-            // Play secondary attack audio
-            audio_events.send(AudioEvent::AttackSecondary);
+            if hits > 0 {
+                // Play secondary attack audio
+                audio_events.send(AudioEvent::AttackSecondary);
+            }
         }
-    }
 
-    // Q ABILITY - Cast projectile (follows mouse direction)
-    if input.ability_q && combat.q_timer <= 0.0 {
-        // Calculate direction from player to mouse position
-        let direction = (input.mouse_world_pos - player_transform.translation).normalize_or_zero();
+        // Q ABILITY - Cast projectile (follows mouse direction)
+        if input.ability_q && combat.q_timer <= 0.0 {
+            // Calculate direction from player to mouse position
+            let direction = (input.mouse_world_pos - player_transform.translation).normalize_or_zero();
         
-        // Fallback to forward direction if mouse position is invalid
-        let direction = if direction.length_squared() > 0.01 {
-            direction
-        } else {
-            Vec3::new(0.0, 0.0, -1.0)
-        };
+            // Fallback to forward direction if mouse position is invalid
+            let direction = if direction.length_squared() > 0.01 {
+                direction
+            } else {
+                Vec3::new(0.0, 0.0, -1.0)
+            };
         
-        commands.spawn((
-            PbrBundle {
-                mesh: meshes.add(Sphere::new(0.15)),
-                material: materials.add(StandardMaterial {
-                    base_color: Color::rgb(0.3, 0.8, 1.0),
-                    emissive: Color::rgb(2.0, 4.0, 6.0).into(),
-                    ..default()
-                }),
-                transform: Transform::from_translation(player_transform.translation + direction * 0.8),
-                ..default()
-            },
-            Projectile {
-                damage: 12,
-                velocity: direction * 20.0,
-                ttl: 2.5,
-                from_enemy: false,
-            },
-        ));
-        combat.q_timer = combat.q_cd;
-        // Play ability Q audio
-        audio_events.send(AudioEvent::AbilityQ);
-    }
-
-    // R ABILITY - AoE attack
-    if input.ability_r && combat.r_timer <= 0.0 {
-        let radius = 2.6;
-        let mut hits = 0;
-        for (entity, enemy_transform, mut enemy_stats) in &mut enemy_query {
-            if player_transform.translation.distance(enemy_transform.translation) <= radius {
-                let damage = (combat.base_damage as f32 * 2.4) as i32;
-                enemy_stats.current_health -= damage as f32;
-                
-                // Add AoE hit effect
-                commands.entity(entity).insert(HitEffect {
-                    timer: 0.0,
-                    duration: 0.6,
-                    original_scale: enemy_transform.scale,
-                });
-                
-                // Spawn AoE particles (red explosion)
-                particle_events.send(SpawnParticlesEvent {
-                    position: enemy_transform.translation,
-                    color: Color::rgb(1.0, 0.3, 0.2),
-                    count: 16,
-                });
-                
-                hits += 1;
-                if enemy_stats.current_health <= 0.0 {
-                    commands.entity(entity).despawn();
-                    // Play enemy death audio
-                    audio_events.send(AudioEvent::EnemyDeath);
-                } else {
-                    // Play enemy hit audio
-                    audio_events.send(AudioEvent::EnemyHit);
+            // Each weapon casts its own projectile (damage/speed/lifetime from the tuning file)
+            let (damage, speed, ttl) = (weapon.q_damage, weapon.q_speed, weapon.q_ttl);
+        
+            // Bênçãos de múltiplos projéteis abrem o Q em leque, centrado na mira
+            let q_on_hit = q_on_hit_status(&active_boons);
+            for direction in fan_directions(direction, combat.projectile_count, Q_FAN_SPREAD) {
+                let projectile = fire_projectile(
+                    &mut commands,
+                    &mut projectile_pool,
+                    &mut meshes,
+                    &mut materials,
+                    ProjectileVisual::PlayerQ(combat.weapon),
+                    Transform::from_translation(player_transform.translation + direction * 0.8),
+                    Projectile {
+                        damage: (damage as f32 * damage_mult).round() as i32,
+                        velocity: direction * speed,
+                        ttl,
+                        from_enemy: false,
+                    },
+                );
+                // Boons de Thoth: o Q carrega lentidão para o alvo
+                if let Some(on_hit) = q_on_hit {
+                    commands.entity(projectile).insert(on_hit);
                 }
             }
+            combat.q_timer = combat.q_cd * boon_modifiers.q_cooldown;
+            // Play ability Q audio
+            audio_events.send(AudioEvent::AbilityQ);
+        }
+
+        // R ABILITY - AoE attack (hold to aim at the ground, tap to detonate on self)
+        if input.ability_r_held && combat.r_timer <= 0.0 {
+            combat.r_aim_time += dt;
         }
+        if input.ability_r_released && combat.r_timer <= 0.0 {
+            let center = if combat.r_aim_time >= AOE_AIM_THRESHOLD {
+                aoe_target_position(player_transform.translation, input.mouse_world_pos, attacks.aoe_max_cast_range)
+            } else {
+                player_transform.translation
+            };
+            combat.r_aim_time = 0.0;
         
-        if hits > 0 {
-            combat.r_timer = combat.r_cd;
+            aoe_events.send(AoeExplosionEvent {
+                center,
+                radius: attacks.aoe_radius * combat.area_multiplier,
+                damage: combat.base_damage as f32 * attacks.aoe_damage_mult * damage_mult,
+                on_hit: r_on_hit_status(&active_boons),
+                falloff: combat.aoe_falloff,
+            });
+        
+            combat.r_timer = combat.r_cd * boon_modifiers.r_cooldown;
             // Play ability R audio
             audio_events.send(AudioEvent::AbilityR);
         }
+        if !input.ability_r_held {
+            combat.r_aim_time = 0.0;
+        }
+    }
+}
+
+// Aplica a arma escolhida no menu ao jogador (o herói pode nascer antes da escolha)
+fn equip_selected_weapon(
+    loadout: Res<RunLoadout>,
+    tuning: Res<CombatTuning>,
+    mut player_query: Query<&mut Combat, With<Player>>,
+) {
+    for mut combat in &mut player_query {
+        if combat.weapon != loadout.weapon {
+            *combat = tuning.combat_for(loadout.weapon);
+            info!("⚔️ Equipped {}", loadout.weapon.get_display_name());
+        }
+    }
+}
+
+fn charge_ring_system(
+    player_query: Query<(&PlayerId, &GlobalTransform, &Combat), With<Player>>,
+    mut ring_query: Query<(&mut Transform, &mut Visibility), With<ChargeRing>>,
+) {
+    let Ok((mut ring_transform, mut visibility)) = ring_query.get_single_mut() else {
+        return;
+    };
+    
+    match player_query.iter().find(|(id, ..)| id.is_primary()) {
+        Some((_, player_transform, combat)) if combat.charge_time > 0.0 => {
+            // Ring grows until the heavy is ready, then stays full size
+            let charge = (combat.charge_time / combat.charge_threshold).min(1.0);
+            let player_pos = player_transform.translation();
+            ring_transform.translation = Vec3::new(player_pos.x, 0.05, player_pos.z);
+            ring_transform.scale = Vec3::splat(0.4 + charge * 0.8);
+            *visibility = Visibility::Visible;
+        }
+        _ => *visibility = Visibility::Hidden,
+    }
+}
+
+fn aoe_reticle_system(
+    input: Res<InputState>,
+    tuning: Res<CombatTuning>,
+    player_query: Query<(&PlayerId, &GlobalTransform, &Combat), With<Player>>,
+    mut reticle_query: Query<(&mut Transform, &mut Visibility), With<AoeReticle>>,
+) {
+    let Ok((mut reticle_transform, mut visibility)) = reticle_query.get_single_mut() else {
+        return;
+    };
+    
+    match player_query.iter().find(|(id, ..)| id.is_primary()) {
+        Some((_, player_transform, combat)) if combat.r_aim_time >= AOE_AIM_THRESHOLD => {
+            let target = aoe_target_position(player_transform.translation(), input.mouse_world_pos, tuning.attacks.aoe_max_cast_range);
+            reticle_transform.translation = Vec3::new(target.x, 0.05, target.z);
+            reticle_transform.scale = Vec3::splat(tuning.attacks.aoe_radius * combat.area_multiplier);
+            *visibility = Visibility::Visible;
+        }
+        _ => *visibility = Visibility::Hidden,
     }
 }
 
@@ -1106,6 +2608,7 @@ fn projectile_movement_system(
     time: Res<Time>,
     mut commands: Commands,
     mut projectiles: Query<(Entity, &mut Transform, &mut Projectile)>,
+    mut projectile_pool: ResMut<ProjectilePool>,
 ) {
     let dt = time.delta_seconds();
     
@@ -1113,7 +2616,7 @@ fn projectile_movement_system(
         // Update TTL
         projectile.ttl -= dt;
         if projectile.ttl <= 0.0 {
-            commands.entity(proj_entity).despawn();
+            reclaim_projectile(&mut commands, &mut projectile_pool, proj_entity);
             continue;
         }
         
@@ -1122,52 +2625,116 @@ fn projectile_movement_system(
     }
 }
 
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 fn projectile_collision_system(
     mut commands: Commands,
-    projectiles: Query<(Entity, &Transform, &Projectile)>,
-    mut enemies: Query<(Entity, &Transform, &mut Stats), (With<Enemy>, Without<Player>)>,
-    mut player_query: Query<(Entity, &Transform, &mut Stats, &Dash), With<Player>>,
+    mut projectiles: Query<(Entity, &Transform, &mut Projectile, Option<&OnHitStatus>, Option<&VampiricShot>)>,
+    mut enemies: Query<(Entity, &Transform, &mut Stats, Option<&mut ShieldedEnemy>), (With<Enemy>, Without<Player>, Without<Spawning>)>,
+    mut player_query: Query<(Entity, &Transform, &mut Stats, &Dash, &Combat), (With<Player>, Without<Downed>)>,
     mut particle_events: EventWriter<SpawnParticlesEvent>,
     mut audio_events: EventWriter<AudioEvent>,
+    mut status_events: EventWriter<ApplyStatusEvent>,
+    mut damaged_events: EventWriter<PlayerDamagedEvent>,
+    mut slow_motion: ResMut<SlowMotion>,
+    tuning: Res<CombatTuning>,
+    debug: Option<Res<debug_tools::DebugSettings>>,
+    mut projectile_pool: ResMut<ProjectilePool>,
+    mut damage_events: EventWriter<DamageEvent>,
 ) {
-    for (proj_entity, proj_transform, projectile) in &projectiles {
+    let godmode = debug_tools::godmode_enabled(debug.as_deref());
+    for (proj_entity, proj_transform, mut projectile, on_hit, vampiric) in &mut projectiles {
         if projectile.from_enemy {
-            // Enemy projectile - check collision with player
-            if let Ok((player_entity, player_transform, mut player_stats, player_dash)) = player_query.get_single_mut() {
-                if proj_transform.translation.distance(player_transform.translation) <= 0.8 {
-                    // Only damage player if not in i-frames
-                    if player_dash.i_timer <= 0.0 {
-                        player_stats.current_health -= projectile.damage as f32;
-                        player_stats.current_health = player_stats.current_health.max(0.0);
-                        
-                        // Add hit effect to player
-                        commands.entity(player_entity).insert(HitEffect {
-                            timer: 0.0,
-                            duration: 0.2,
-                            original_scale: player_transform.scale,
-                        });
-                    }
+            // Enemy projectile - check collision with players (caídos não são alvo)
+            let hit_player = player_query.iter_mut()
+                .find(|(_, player_transform, ..)| proj_transform.translation.distance(player_transform.translation) <= PLAYER_HURT_RADIUS);
+            if let Some((player_entity, player_transform, mut player_stats, player_dash, combat)) = hit_player {
+                // Parry: durante o secundário o projétil volta contra os inimigos
+                if combat.parry_timer > 0.0 {
+                    projectile.velocity = -projectile.velocity * PARRY_SPEED_MULT;
+                    projectile.from_enemy = false;
+                    projectile.ttl = 3.0;
+                    commands.entity(proj_entity).remove::<(EnemyProjectile, VampiricShot)>();
                     
-                    // Always destroy projectile on hit
-                    commands.entity(proj_entity).despawn();
-                    // Play projectile hit audio
-                    audio_events.send(AudioEvent::ProjectileHit);
-                    break;
-                }
-            }
+                    particle_events.send(SpawnParticlesEvent {
+                        position: proj_transform.translation,
+                        color: Color::rgb(1.0, 0.9, 0.4),
+                        count: 10,
+                    });
+                    audio_events.send(AudioEvent::Parry);
+                    slow_motion.trigger(SlowMotionMoment::Parry);
+                    info!("🛡️ Parry! Projectile deflected");
+                    continue;
+                }
+                
+                // Escudo frontal: reduz o dano; o bloqueio perfeito absorve o tiro inteiro
+                let source = proj_transform.translation - projectile.velocity;
+                let block = block_outcome(combat, player_transform.translation, source);
+                if block == BlockOutcome::Perfect {
+                    reclaim_projectile(&mut commands, &mut projectile_pool, proj_entity);
+                    particle_events.send(SpawnParticlesEvent {
+                        position: proj_transform.translation,
+                        color: Color::rgb(0.9, 0.9, 1.0),
+                        count: 8,
+                    });
+                    audio_events.send(AudioEvent::Parry);
+                    continue;
+                }
+                let damage = projectile.damage as f32 * block.damage_taken(&tuning.attacks);
+                
+                // Only damage player if not in i-frames
+                if player_dash.i_timer <= 0.0 && !godmode {
+                    player_stats.current_health -= damage;
+                    player_stats.current_health = player_stats.current_health.max(0.0);
+                    damage_events.send(DamageEvent {
+                        position: player_transform.translation,
+                        damage: damage.round() as i32,
+                        damage_type: DamageType::Player,
+                        is_critical: false,
+                    });
+                    // Elite vampírico: o dono se cura com parte do dano
+                    if let Some((_, _, mut owner_stats, _)) = vampiric.and_then(|shot| enemies.get_mut(shot.owner).ok()) {
+                        let healed = owner_stats.current_health + damage * VAMPIRIC_HEAL_FRACTION;
+                        owner_stats.current_health = healed.min(owner_stats.max_health);
+                    }
+                    // Origem aproximada: de onde o projétil vinha
+                    damaged_events.send(PlayerDamagedEvent {
+                        target: player_entity,
+                        source: proj_transform.translation - projectile.velocity,
+                    });
+                    
+                    // Add hit effect to player
+                    commands.entity(player_entity).insert(HitEffect {
+                        timer: 0.0,
+                        duration: 0.2,
+                        original_scale: player_transform.scale,
+                    });
+                }
+                
+                // Always destroy projectile on hit
+                reclaim_projectile(&mut commands, &mut projectile_pool, proj_entity);
+                // Play projectile hit audio
+                audio_events.send(AudioEvent::ProjectileHit);
+            }
         } else {
             // Player projectile - check collision with enemies
-            for (enemy_entity, enemy_transform, mut enemy_stats) in &mut enemies {
-                if proj_transform.translation.distance(enemy_transform.translation) <= 0.7 {
+            for (enemy_entity, enemy_transform, mut enemy_stats, shield) in &mut enemies {
+                if proj_transform.translation.distance(enemy_transform.translation) <= ENEMY_HURT_RADIUS {
                     // Hit enemy
-                    enemy_stats.current_health -= projectile.damage as f32;
+                    apply_enemy_damage(&mut enemy_stats, shield, projectile.damage as f32);
+                    damage_events.send(enemy_hit_feedback(enemy_transform.translation, projectile.damage as f32, false));
+                    if let Some(on_hit) = on_hit {
+                        status_events.send(on_hit.event_for(enemy_entity));
+                    }
                     
-                    // Add hit effect
-                    commands.entity(enemy_entity).insert(HitEffect {
-                        timer: 0.0,
-                        duration: 0.4,
-                        original_scale: enemy_transform.scale,
-                    });
+                    // Add hit effect (empurra na direção do voo)
+                    commands.entity(enemy_entity).insert((
+                        HitEffect {
+                            timer: 0.0,
+                            duration: 0.4,
+                            original_scale: enemy_transform.scale,
+                        },
+                        Knockback::new(projectile.velocity, tuning.attacks.projectile_knockback, tuning.attacks.knockback_decay, &enemy_stats),
+                    ));
                     
                     // Spawn projectile impact particles (cyan)
                     particle_events.send(SpawnParticlesEvent {
@@ -1176,18 +2743,14 @@ fn projectile_collision_system(
                         count: 6,
                     });
                     
-                    // Destroy enemy if dead
-                    if enemy_stats.current_health <= 0.0 {
-                        commands.entity(enemy_entity).despawn();
-                        // Play enemy death audio
-                        audio_events.send(AudioEvent::EnemyDeath);
-                    } else {
+                    // Deaths are resolved in enemy_death_system
+                    if enemy_stats.current_health > 0.0 {
                         // Play enemy hit audio
                         audio_events.send(AudioEvent::EnemyHit);
                     }
                     
                     // Destroy projectile
-                    commands.entity(proj_entity).despawn();
+                    reclaim_projectile(&mut commands, &mut projectile_pool, proj_entity);
                     // Play projectile hit audio
                     audio_events.send(AudioEvent::ProjectileHit);
                     break;
@@ -1197,6 +2760,368 @@ fn projectile_collision_system(
     }
 }
 
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn aoe_explosion_system(
+    mut commands: Commands,
+    mut aoe_events: EventReader<AoeExplosionEvent>,
+    mut enemies: Query<(Entity, &Transform, &mut Stats, Option<&mut ShieldedEnemy>), (With<Enemy>, Without<Spawning>)>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+    mut audio_events: EventWriter<AudioEvent>,
+    mut status_events: EventWriter<ApplyStatusEvent>,
+    mut damage_events: EventWriter<DamageEvent>,
+    tuning: Res<CombatTuning>,
+) {
+    let attacks = &tuning.attacks;
+    for explosion in aoe_events.read() {
+        // Explosion at the detonation point; bigger blasts (area boons) throw more sparks
+        particle_events.send(SpawnParticlesEvent {
+            position: explosion.center,
+            color: Color::rgb(1.0, 0.5, 0.2),
+            count: (explosion.radius * AOE_PARTICLES_PER_UNIT).round().max(8.0) as usize,
+        });
+        
+        let mut hits = 0;
+        for (entity, enemy_transform, mut enemy_stats, shield) in &mut enemies {
+            let distance = explosion.center.distance(enemy_transform.translation);
+            if distance <= explosion.radius {
+                let damage = explosion.damage * explosion.falloff.damage_multiplier(distance, explosion.radius);
+                apply_enemy_damage(&mut enemy_stats, shield, damage.floor());
+                damage_events.send(enemy_hit_feedback(enemy_transform.translation, damage.floor(), false));
+                if let Some(on_hit) = explosion.on_hit {
+                    status_events.send(on_hit.event_for(entity));
+                }
+                
+                // Add AoE hit effect (a explosão arremessa para longe do centro, mais forte perto dele)
+                let falloff = explosion.falloff.damage_multiplier(distance, explosion.radius);
+                commands.entity(entity).insert((
+                    HitEffect {
+                        timer: 0.0,
+                        duration: 0.6,
+                        original_scale: enemy_transform.scale,
+                    },
+                    Knockback::new(enemy_transform.translation - explosion.center, attacks.aoe_knockback * falloff, attacks.knockback_decay, &enemy_stats),
+                ));
+                
+                // Spawn AoE particles (red explosion)
+                particle_events.send(SpawnParticlesEvent {
+                    position: enemy_transform.translation,
+                    color: Color::rgb(1.0, 0.3, 0.2),
+                    count: 16,
+                });
+                
+                hits += 1;
+                // Deaths are resolved in enemy_death_system
+                if enemy_stats.current_health > 0.0 {
+                    // Play enemy hit audio
+                    audio_events.send(AudioEvent::EnemyHit);
+                }
+            }
+        }
+        
+        if hits > 0 {
+            info!("💥 AoE hit {} enemies", hits);
+        }
+    }
+}
+
+// Empurrões deslizam e desaceleram; presos à sala onde o inimigo está (paredes de create_room_walls)
+fn knockback_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    rooms: Query<&Room>,
+    mut knocked: Query<(Entity, &mut Transform, &mut Knockback), Without<Dying>>,
+) {
+    let dt = time.delta_seconds();
+    for (entity, mut transform, mut knockback) in &mut knocked {
+        let position = transform.translation;
+        let room = rooms.iter().find(|room| {
+            let half = room.size / 2.0;
+            (position.x - room.center.x).abs() <= half.x && (position.z - room.center.y).abs() <= half.y
+        });
+        let mut next = position + knockback.velocity * dt;
+        if let Some(room) = room {
+            let half = room.size / 2.0 - Vec2::splat(KNOCKBACK_WALL_MARGIN);
+            next.x = next.x.clamp(room.center.x - half.x, room.center.x + half.x);
+            next.z = next.z.clamp(room.center.y - half.y, room.center.y + half.y);
+        }
+        transform.translation = next;
+
+        let decay = (-knockback.decay * dt).exp();
+        knockback.velocity *= decay;
+        if knockback.velocity.length() < KNOCKBACK_STOP_SPEED {
+            commands.entity(entity).remove::<Knockback>();
+        }
+    }
+}
+
+// Número de dano dos nossos golpes (combat_feedback; também alimenta o combo)
+fn enemy_hit_feedback(position: Vec3, damage: f32, critical: bool) -> DamageEvent {
+    DamageEvent {
+        position,
+        damage: damage.round() as i32,
+        damage_type: DamageType::Enemy,
+        is_critical: critical,
+    }
+}
+
+// Faíscas douradas a mais por cima do impacto normal de um crítico
+const CRIT_PARTICLE_COUNT: usize = 20;
+
+fn critical_hit_burst(position: Vec3) -> SpawnParticlesEvent {
+    SpawnParticlesEvent {
+        position: position + Vec3::Y * 0.3,
+        color: Color::rgb(1.0, 0.95, 0.55),
+        count: CRIT_PARTICLE_COUNT,
+    }
+}
+
+// Sorteios do combate (críticos, chance de queimar). Ressemeado com a semente de cada run:
+// a mesma semente repete os mesmos acertos
+#[derive(Resource)]
+struct CombatRng(rand_chacha::ChaCha8Rng);
+
+impl Default for CombatRng {
+    // A semente de verdade chega com a dungeon da run (reseed_combat_rng)
+    fn default() -> Self {
+        Self::seeded(0)
+    }
+}
+
+impl CombatRng {
+    fn seeded(seed: u64) -> Self {
+        Self(procedural::run_stream_rng(seed, RunRngStream::Combat))
+    }
+
+    fn chance(&mut self, probability: f32) -> bool {
+        use rand::Rng;
+        probability > 0.0 && self.0.gen::<f32>() < probability
+    }
+}
+
+// Run nova: os sorteios do combate recomeçam do fluxo da semente da dungeon
+fn reseed_combat_rng(dungeon: Res<GeneratedDungeon>, mut combat_rng: ResMut<CombatRng>) {
+    *combat_rng = CombatRng::seeded(dungeon.generation_seed);
+}
+
+// Escudo absorve o golpe inteiro (inclusive o que o quebra) antes de chegar na vida
+fn apply_enemy_damage(stats: &mut Stats, shield: Option<Mut<ShieldedEnemy>>, damage: f32) {
+    match shield {
+        Some(mut shield) if shield.shield > 0.0 => shield.shield -= damage,
+        _ => stats.current_health -= damage,
+    }
+}
+
+// Stagger: compara a vida com o passo anterior para pegar golpes de qualquer fonte
+// (corpo a corpo, projéteis, explosões, armadilhas). Um golpe >= poise durante a
+// preparação cancela o ataque e atordoa brevemente - o atordoamento suspende a IA
+#[allow(clippy::type_complexity)]
+fn enemy_stagger_system(
+    mut commands: Commands,
+    mut enemies: Query<(Entity, &Transform, &Stats, &mut AI), (With<Enemy>, Without<Spawning>)>,
+    mut last_health: Local<HashMap<Entity, f32>>,
+    mut status_events: EventWriter<ApplyStatusEvent>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+) {
+    let mut current = HashMap::with_capacity(enemies.iter().len());
+    for (entity, transform, stats, mut ai) in &mut enemies {
+        current.insert(entity, stats.current_health);
+        let Some(previous) = last_health.get(&entity) else {
+            continue;
+        };
+        let hit = previous - stats.current_health;
+        if hit <= 0.0 || hit < stats.poise || stats.current_health <= 0.0 || !ai.is_mid_attack() {
+            continue;
+        }
+        
+        if let Some(telegraph) = ai.cancel_windup() {
+            commands.entity(telegraph).despawn_recursive();
+        }
+        ai.attack_timer = ai.attack_cooldown;
+        status_events.send(ApplyStatusEvent {
+            target: entity,
+            kind: status_effects::StatusEffectKind::Stun,
+            duration: STAGGER_DURATION,
+            magnitude: 0.0,
+        });
+        particle_events.send(SpawnParticlesEvent {
+            position: transform.translation,
+            color: Color::rgb(1.0, 0.95, 0.6),
+            count: 8,
+        });
+    }
+    *last_health = current;
+}
+
+// Quebra de escudo: efeito + som, remove o componente e a esfera visual
+fn shield_break_system(
+    mut commands: Commands,
+    enemies: Query<(Entity, &Transform, &ShieldedEnemy, Option<&Children>), With<Enemy>>,
+    overlays: Query<(), With<ShieldOverlay>>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+    mut audio_events: EventWriter<AudioEvent>,
+) {
+    for (entity, transform, shield, children) in &enemies {
+        if shield.shield > 0.0 {
+            continue;
+        }
+        
+        commands.entity(entity).remove::<ShieldedEnemy>();
+        for &child in children.into_iter().flatten() {
+            if overlays.contains(child) {
+                commands.entity(child).despawn_recursive();
+            }
+        }
+        
+        particle_events.send(SpawnParticlesEvent {
+            position: transform.translation,
+            color: Color::rgb(0.4, 0.9, 1.0),
+            count: 18,
+        });
+        audio_events.send(AudioEvent::ShieldBreak);
+        info!("🛡️ Shield broken!");
+    }
+}
+
+// Central kill resolution: every player-side damage source (golpes, projéteis, invocações) ends here
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn enemy_death_system(
+    mut commands: Commands,
+    mut enemies: Query<(
+        Entity,
+        &mut Transform,
+        &Stats,
+        Option<&EnemyType>,
+        Option<&mut AI>,
+        Option<&HitEffect>,
+        Option<&Handle<StandardMaterial>>,
+        Option<&EliteAffix>,
+        Option<&LootTable>,
+        Has<Boss>,
+    ), With<Enemy>>,
+    game_state: Res<GameState>,
+    rooms: Query<&Room>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut boon_data: ResMut<ui::BoonData>,
+    mut experience: ResMut<ui::RunExperience>,
+    mut audio_events: EventWriter<AudioEvent>,
+    mut kill_events: EventWriter<EnemyKilledEvent>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+    mut slow_motion: ResMut<SlowMotion>,
+    mut run_rng: ResMut<RunRng>,
+) {
+    let reward_multiplier = rooms.iter()
+        .find(|room| room.id == game_state.current_room)
+        .map_or(1.0, |room| room.template.reward_multiplier);
+    let rng = run_rng.stream(RunRngStream::Loot);
+
+    for (entity, mut transform, stats, enemy_type, ai, hit_effect, material, affix, loot, is_boss) in &mut enemies {
+        if stats.current_health > 0.0 {
+            continue;
+        }
+        // Golpe em preparação morre junto
+        if let Some(telegraph) = ai.and_then(|mut ai| ai.cancel_windup()) {
+            commands.entity(telegraph).despawn_recursive();
+        }
+        // O pulso de dano não pode ficar congelado no tamanho aumentado
+        if let Some(hit_effect) = hit_effect {
+            transform.scale = hit_effect.original_scale;
+        }
+        // Material próprio para o fade não apagar outros inimigos que compartilham o mesmo
+        if let Some(faded) = material.and_then(|handle| materials.get(handle)).cloned() {
+            commands.entity(entity).insert(materials.add(faded));
+        }
+        // Golpe final no chefe: câmera lenta cinematográfica
+        if is_boss {
+            slow_motion.trigger(SlowMotionMoment::BossKill);
+        }
+        commands.entity(entity)
+            .remove::<(Enemy, HitEffect)>()
+            .insert(Dying {
+                timer: DYING_DURATION,
+                start_scale: transform.scale,
+                start_height: transform.translation.y,
+            });
+
+        // Elites com afixo: moedas e uma romã além do saque; o volátil ainda deixa uma carga
+        if let Some(affix) = affix {
+            boon_data.coins += affix.bonus_coins();
+            boon_data.pom += ELITE_POM_REWARD;
+            if *affix == EliteAffix::Volatile {
+                elite_affixes::spawn_volatile_blast(&mut commands, &mut meshes, &mut materials, transform.translation);
+            }
+            info!("👑 {} elite defeated (+{} coins, +{} pom)", affix.name(), affix.bonus_coins(), ELITE_POM_REWARD);
+        }
+
+        // Saque da tabela do inimigo (o boneco de treino não tem tabela)
+        let mut consumable_drops = 0;
+        let drops = loot.map(|table| roll_loot(table, reward_multiplier, rng)).unwrap_or_default();
+        for (index, drop) in drops.into_iter().enumerate() {
+            // Orbes se espalham em volta do ponto da morte
+            let angle = index as f32 * 2.4;
+            let scatter = Vec3::new(angle.cos(), 0.0, angle.sin()) * 0.9;
+            match drop {
+                LootDrop::Nothing => {}
+                LootDrop::Coins { min: coins, .. } => {
+                    boon_data.coins += coins;
+                    particle_events.send(SpawnParticlesEvent {
+                        position: transform.translation,
+                        color: Color::rgb(1.0, 0.84, 0.0),
+                        count: coins.min(12) as usize,
+                    });
+                }
+                LootDrop::HealthOrb { heal } => {
+                    loot_table::spawn_health_orb(&mut commands, &mut meshes, &mut materials, transform.translation + scatter, heal);
+                }
+                LootDrop::Consumable => consumable_drops += 1,
+                LootDrop::Pom => {
+                    boon_data.pom += 1;
+                    info!("💰 Enemy dropped a Pom of Power");
+                }
+            }
+        }
+
+        let xp = enemy_type.map_or(BOSS_XP, EnemyType::xp_reward) * if affix.is_some() { ELITE_XP_MULT } else { 1 };
+        if experience.add_xp(xp) > 0 {
+            info!("⭐ Reached level {}", experience.level);
+        }
+
+        // Moedas/orbes caem no início da morte
+        kill_events.send(EnemyKilledEvent { position: transform.translation, consumable_drops });
+        particle_events.send(SpawnParticlesEvent {
+            position: transform.translation,
+            color: enemy_type.map_or(Color::rgb(0.8, 0.2, 0.2), EnemyType::death_color),
+            count: 20,
+        });
+        // Play enemy death audio
+        audio_events.send(AudioEvent::EnemyDeath);
+    }
+}
+
+fn enemy_dying_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut dying: Query<(Entity, &mut Transform, &mut Dying, Option<&Handle<StandardMaterial>>)>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (entity, mut transform, mut dying, material) in &mut dying {
+        dying.timer -= time.delta_seconds();
+        if dying.timer <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        // Modelos 3D afundam e encolhem; sprites/malhas também se dissolvem no próprio material
+        let progress = 1.0 - dying.timer / DYING_DURATION;
+        transform.scale = dying.start_scale * (1.0 - 0.6 * progress);
+        transform.translation.y = dying.start_height - 0.4 * progress;
+        if let Some(material) = material.and_then(|handle| materials.get_mut(handle)) {
+            material.alpha_mode = AlphaMode::Blend;
+            material.base_color.set_a(1.0 - progress);
+        }
+    }
+}
+
 fn particle_spawn_system(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
@@ -1282,34 +3207,67 @@ fn particle_system(
     }
 }
 
-fn setup_rooms(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
-    game_assets: Option<&GameAssets>,
+// Salas do jogo montadas a partir do DungeonGenerator: posição de cada sala vem do layout
+// gerado (1 unidade do gerador = 1 unidade do mundo; salas distantes, as portas teleportam)
+const ROOM_SIZE: Vec2 = Vec2::new(20.0, 20.0);
+// Porta recuada da parede para dentro da sala; portas na mesma parede ficam lado a lado
+const DOOR_WALL_INSET: f32 = 2.5;
+const DOOR_SPACING: f32 = 4.0;
+// Chegando por uma porta: para dentro da sala, a partir da porta de volta
+const DOOR_ARRIVAL_OFFSET: f32 = 2.5;
+
+fn connection_direction(direction: procedural::ConnectionDirection) -> Vec2 {
+    match direction {
+        procedural::ConnectionDirection::North => Vec2::Y,
+        procedural::ConnectionDirection::South => Vec2::NEG_Y,
+        procedural::ConnectionDirection::East => Vec2::X,
+        procedural::ConnectionDirection::West => Vec2::NEG_X,
+    }
+}
+
+// Roda a cada entrada no jogo, mas só monta uma vez (voltar da pausa/recompensa não duplica)
+#[allow(clippy::too_many_arguments)]
+fn spawn_dungeon_rooms(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    game_assets: Option<Res<GameAssets>>,
+    dungeon: Res<GeneratedDungeon>,
+    mut game_state: ResMut<GameState>,
+    existing_rooms: Query<(), With<Room>>,
+    mut players: Query<(&PlayerId, &mut Transform), With<Player>>,
 ) {
-    let rooms = [
-        (0, Vec2::new(0.0, 0.0), RoomType::Combat),     // Starting combat room
-        (1, Vec2::new(25.0, 0.0), RoomType::Combat),    // Combat room 1
-        (2, Vec2::new(50.0, 0.0), RoomType::Boss),      // Boss room
-    ];
+    if !existing_rooms.is_empty() {
+        return;
+    }
+    let layout = &dungeon.layout;
+    // Salas secretas dependem de um interruptor que ainda não existe: ficam de fora
+    let mut rooms: Vec<&procedural::DungeonRoom> = layout.rooms.values()
+        .filter(|room| room.template.room_type != procedural::RoomType::Secret)
+        .collect();
+    rooms.sort_by_key(|room| room.id.0);
 
     // Create room boundaries
-    for (id, center, room_type) in rooms {
-        let room_size = Vec2::new(20.0, 20.0);
+    for dungeon_room in &rooms {
+        let id = dungeon_room.id.0 as usize;
+        let center = dungeon_room.position;
+        let room_type = if dungeon_room.id == layout.start_room {
+            RoomType::Start
+        } else {
+            RoomType::from_template(dungeon_room.template.room_type)
+        };
         
         // Room floor with RTX-generated 3D environment
         commands.spawn((
             PbrBundle {
-                mesh: meshes.add(Plane3d::default().mesh().size(room_size.x, room_size.y)),
-                material: if let Some(assets) = game_assets {
-                    info!("✅ Applying RTX-generated environment texture for {:?} room", room_type);
+                mesh: meshes.add(Plane3d::default().mesh().size(ROOM_SIZE.x, ROOM_SIZE.y)),
+                material: if let Some(assets) = game_assets.as_deref() {
                     materials.add(StandardMaterial {
                         base_color_texture: Some(match room_type {
                             RoomType::Start => assets.desert_oasis.clone(),
                             RoomType::Combat => assets.tomb_chamber.clone(),
                             RoomType::Boss => assets.pyramid_interior.clone(),
-                            RoomType::Treasure => assets.temple_halls.clone(),
+                            RoomType::Treasure | RoomType::Shop => assets.temple_halls.clone(),
                         }),
                         alpha_mode: AlphaMode::Opaque, // No transparency for floors
                         unlit: false, // Use lighting for atmosphere
@@ -1323,6 +3281,7 @@ fn setup_rooms(
                             RoomType::Combat => Color::rgb(0.8, 0.6, 0.6),
                             RoomType::Boss => Color::rgb(0.8, 0.6, 0.8),
                             RoomType::Treasure => Color::rgb(0.8, 0.8, 0.6),
+                            RoomType::Shop => Color::rgb(0.6, 0.7, 0.8),
                         },
                         ..default()
                     })
@@ -1332,47 +3291,137 @@ fn setup_rooms(
             },
             Room {
                 id,
-                size: room_size,
+                size: ROOM_SIZE,
                 center,
-                cleared: id == 0, // Start room is already "cleared"
+                cleared: dungeon_room.id == layout.start_room, // Start room is already "cleared"
                 room_type,
+                template: dungeon_room.template.clone(),
             },
+            RoomGeometry,
         ));
 
         // Add atmospheric 3D environment elements
-        add_room_decorations(commands, meshes, materials, game_assets, center, room_type);
+        add_room_decorations(&mut commands, &mut meshes, &mut materials, game_assets.as_deref(), center, room_type);
         
         // Create room walls (visual boundaries)
-        create_room_walls(commands, meshes, materials, center, room_size);
+        create_room_walls(&mut commands, &mut meshes, &mut materials, id, center, ROOM_SIZE);
+
+        // Uma porta por conexão, na parede da direção dela. Conexões trancadas até limpar a sala
+        // funcionam como qualquer porta: abrem quando a sala é limpa
+        let connections: Vec<&procedural::RoomConnection> = layout.connections.get(&dungeon_room.id)
+            .into_iter()
+            .flatten()
+            .filter(|connection| rooms.iter().any(|room| room.id == connection.to_room))
+            .collect();
+        for connection in &connections {
+            let side = connection_direction(connection.direction);
+            let same_wall: Vec<_> = connections.iter()
+                .filter(|other| other.direction == connection.direction)
+                .map(|other| other.to_room)
+                .collect();
+            let slot = same_wall.iter().position(|room| *room == connection.to_room).unwrap_or(0);
+            let along = (slot as f32 - (same_wall.len() - 1) as f32 / 2.0) * DOOR_SPACING;
+            let door = center + side * (ROOM_SIZE.x / 2.0 - DOOR_WALL_INSET) + side.perp() * along;
+            let position = Vec3::new(door.x, 0.5, door.y);
+            
+            commands.spawn((
+                PbrBundle {
+                    mesh: meshes.add(Cuboid::new(2.0, 2.0, 1.0)),
+                    material: materials.add(StandardMaterial {
+                        base_color: Color::rgb(0.2, 0.8, 1.0),
+                        emissive: Color::rgb(0.1, 0.4, 0.5),
+                        ..default()
+                    }),
+                    // Face larga virada para o centro da sala
+                    transform: Transform::from_translation(position)
+                        .with_rotation(Quat::from_rotation_y(side.x.atan2(side.y))),
+                    ..default()
+                },
+                RoomTransition {
+                    from_room: id,
+                    to_room: connection.to_room.0 as usize,
+                    position,
+                    size: Vec3::new(2.0, 2.0, 1.0),
+                    active: dungeon_room.id == layout.start_room, // First room's doors start active
+                },
+                Interactable::new("Atravessar", DOOR_INTERACT_RADIUS),
+                RoomGeometry,
+            ));
+        }
     }
 
-    // Create transitions between rooms
-    let transitions = [
-        (0, 1, Vec3::new(12.5, 0.5, 0.0)),  // Room 0 -> Room 1
-        (1, 2, Vec3::new(37.5, 0.5, 0.0)),  // Room 1 -> Room 2
-    ];
+    game_state.current_room = layout.start_room.0 as usize;
+    game_state.previous_room = game_state.current_room;
+    game_state.total_rooms = rooms.len();
+    // Run nova: o grupo começa no centro da sala inicial
+    if let Some(start) = layout.rooms.get(&layout.start_room) {
+        for (id, mut transform) in &mut players {
+            let offset = id.index() as f32 * COOP_SPAWN_SPACING;
+            transform.translation = Vec3::new(start.position.x, transform.translation.y, start.position.y + offset);
+        }
+    }
+    info!("🏛️ Spawned {} dungeon rooms from seed {}", rooms.len(), dungeon.generation_seed);
+}
 
-    for (from_room, to_room, position) in transitions {
-        commands.spawn((
-            PbrBundle {
-                mesh: meshes.add(Cuboid::new(2.0, 2.0, 1.0)),
-                material: materials.add(StandardMaterial {
-                    base_color: Color::rgb(0.2, 0.8, 1.0),
-                    emissive: Color::rgb(0.1, 0.4, 0.5).into(),
-                    ..default()
-                }),
-                transform: Transform::from_translation(position),
-                ..default()
-            },
-            RoomTransition {
-                from_room,
-                to_room,
-                position,
-                size: Vec3::new(2.0, 2.0, 1.0),
-                active: from_room == 0, // First transition starts active
-            },
-        ));
+// Todos os jogadores sem vida (no co-op, o último de pé também caiu): fim da run
+fn player_death_system(
+    players: Query<&Stats, With<Player>>,
+    game_state: Res<GameState>,
+    active_boons: Res<boons::ActiveBoons>,
+    mut run_stats: ResMut<RunStats>,
+    mut app_state: ResMut<NextState<AppState>>,
+) {
+    if players.is_empty() || players.iter().any(|stats| stats.current_health > 0.0) {
+        return;
     }
+    run_stats.rooms_cleared = game_state.rooms_cleared as u32;
+    run_stats.boons_collected = active_boons.player_boons.len() as u32;
+    info!("💀 Run over: {} rooms, {} kills", run_stats.rooms_cleared, run_stats.enemies_killed);
+    app_state.set(AppState::Death);
+}
+
+fn count_run_kills(mut kill_events: EventReader<EnemyKilledEvent>, mut run_stats: ResMut<RunStats>) {
+    let kills = kill_events.read().count() as u32;
+    if kills > 0 {
+        run_stats.enemies_killed += kills;
+    }
+}
+
+// Run nova ("Tentar Novamente" ou pelo menu): jogadores inteiros, sem bênçãos, inimigos
+// nem números da run anterior. Roda depois da saída do treino, que devolve as bênçãos de antes dele
+fn reset_run_state(
+    mut commands: Commands,
+    tuning: Res<CombatTuning>,
+    mut run_stats: ResMut<RunStats>,
+    mut active_boons: ResMut<boons::ActiveBoons>,
+    mut players: Query<(Entity, &mut Stats, &mut Transform), With<Player>>,
+    enemies: Query<Entity, With<Enemy>>,
+) {
+    *run_stats = RunStats::default();
+    *active_boons = boons::ActiveBoons::default();
+    for (entity, mut stats, mut transform) in &mut players {
+        *stats = tuning.player_stats();
+        // Caído no co-op volta de pé
+        commands.entity(entity).remove::<Downed>();
+        transform.rotation = Quat::IDENTITY;
+    }
+    for enemy in &enemies {
+        commands.entity(enemy).despawn_recursive();
+    }
+}
+
+// Run nova: a dungeon foi gerada de novo (outra semente), as salas da anterior saem
+fn clear_dungeon_rooms(
+    mut commands: Commands,
+    mut game_state: ResMut<GameState>,
+    geometry: Query<Entity, With<RoomGeometry>>,
+) {
+    for entity in &geometry {
+        commands.entity(entity).despawn_recursive();
+    }
+    game_state.current_room = 0;
+    game_state.previous_room = 0;
+    game_state.rooms_cleared = 0;
 }
 
 fn add_room_decorations(
@@ -1398,18 +3447,13 @@ fn add_room_decorations(
             commands.spawn((
                 PbrBundle {
                     mesh: meshes.add(Plane3d::default().mesh().size(2.0, 2.0)),
-                    material: materials.add(StandardMaterial {
-                        base_color_texture: Some(assets.torch_brazier.clone()),
-                        alpha_mode: AlphaMode::Blend,
-                        unlit: true, // Self-illuminated for atmospheric effect
-                        double_sided: true,
-                        base_color: Color::WHITE,
-                        ..default()
-                    }),
+                    material: materials.add(billboard_material(assets.torch_brazier.clone(), Color::WHITE, true)),
                     transform: Transform::from_translation(torch_pos)
                         .with_rotation(Quat::from_rotation_y(std::f32::consts::FRAC_PI_4)),
                     ..default()
                 },
+                BillboardSort,
+                RoomGeometry,
             ));
         }
         
@@ -1420,18 +3464,13 @@ fn add_room_decorations(
                 commands.spawn((
                     PbrBundle {
                         mesh: meshes.add(Plane3d::default().mesh().size(3.0, 3.0)),
-                        material: materials.add(StandardMaterial {
-                            base_color_texture: Some(assets.anubis_guardian_statue.clone()),
-                            alpha_mode: AlphaMode::Blend,
-                            unlit: false,
-                            double_sided: true,
-                            base_color: Color::WHITE,
-                            ..default()
-                        }),
+                        material: materials.add(billboard_material(assets.anubis_guardian_statue.clone(), Color::WHITE, false)),
                         transform: Transform::from_xyz(center.x, 1.5, center.y - 5.0)
                             .with_rotation(Quat::from_rotation_y(std::f32::consts::FRAC_PI_4)),
                         ..default()
                     },
+                    BillboardSort,
+                    RoomGeometry,
                 ));
             },
             RoomType::Combat => {
@@ -1445,18 +3484,13 @@ fn add_room_decorations(
                     commands.spawn((
                         PbrBundle {
                             mesh: meshes.add(Plane3d::default().mesh().size(2.5, 2.5)),
-                            material: materials.add(StandardMaterial {
-                                base_color_texture: Some(assets.stone_pillar_ornate.clone()),
-                                alpha_mode: AlphaMode::Blend,
-                                unlit: false,
-                                double_sided: true,
-                                base_color: Color::WHITE,
-                                ..default()
-                            }),
+                            material: materials.add(billboard_material(assets.stone_pillar_ornate.clone(), Color::WHITE, false)),
                             transform: Transform::from_translation(pillar_pos)
                                 .with_rotation(Quat::from_rotation_y(std::f32::consts::FRAC_PI_4)),
                             ..default()
                         },
+                        BillboardSort,
+                        RoomGeometry,
                     ));
                 }
             },
@@ -1465,21 +3499,16 @@ fn add_room_decorations(
                 commands.spawn((
                     PbrBundle {
                         mesh: meshes.add(Plane3d::default().mesh().size(4.0, 4.0)),
-                        material: materials.add(StandardMaterial {
-                            base_color_texture: Some(assets.egyptian_wall_section.clone()),
-                            alpha_mode: AlphaMode::Blend,
-                            unlit: false,
-                            double_sided: true,
-                            base_color: Color::WHITE,
-                            ..default()
-                        }),
+                        material: materials.add(billboard_material(assets.egyptian_wall_section.clone(), Color::WHITE, false)),
                         transform: Transform::from_xyz(center.x, 2.0, center.y + 8.0)
                             .with_rotation(Quat::from_rotation_y(std::f32::consts::FRAC_PI_4)),
                         ..default()
                     },
+                    BillboardSort,
+                    RoomGeometry,
                 ));
             },
-            RoomType::Treasure => {
+            RoomType::Treasure | RoomType::Shop => {
                 // Future treasure room decorations
             },
         }
@@ -1490,6 +3519,7 @@ fn create_room_walls(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
+    room: usize,
     center: Vec2,
     size: Vec2,
 ) {
@@ -1502,96 +3532,149 @@ fn create_room_walls(
         ..default()
     });
 
-    // North wall
-    commands.spawn(PbrBundle {
-        mesh: meshes.add(Cuboid::new(size.x + wall_thickness, wall_height, wall_thickness)),
-        material: wall_material.clone(),
-        transform: Transform::from_translation(Vec3::new(center.x, wall_height / 2.0, center.y + half_size.y)),
-        ..default()
-    });
+    // (tamanho, posição) de cada trecho; também viram obstáculos para o caminho dos inimigos
+    let walls = [
+        // North wall
+        (Vec3::new(size.x + wall_thickness, wall_height, wall_thickness), Vec3::new(center.x, wall_height / 2.0, center.y + half_size.y)),
+        // South wall
+        (Vec3::new(size.x + wall_thickness, wall_height, wall_thickness), Vec3::new(center.x, wall_height / 2.0, center.y - half_size.y)),
+        // East wall (with gaps for transitions)
+        (Vec3::new(wall_thickness, wall_height, size.y - 4.0), Vec3::new(center.x + half_size.x, wall_height / 2.0, center.y + 2.0)),
+        (Vec3::new(wall_thickness, wall_height, size.y - 4.0), Vec3::new(center.x + half_size.x, wall_height / 2.0, center.y - 2.0)),
+        // West wall
+        (Vec3::new(wall_thickness, wall_height, size.y + wall_thickness), Vec3::new(center.x - half_size.x, wall_height / 2.0, center.y)),
+    ];
 
-    // South wall
-    commands.spawn(PbrBundle {
-        mesh: meshes.add(Cuboid::new(size.x + wall_thickness, wall_height, wall_thickness)),
-        material: wall_material.clone(),
-        transform: Transform::from_translation(Vec3::new(center.x, wall_height / 2.0, center.y - half_size.y)),
-        ..default()
-    });
+    for (wall_size, position) in walls {
+        commands.spawn((
+            PbrBundle {
+                mesh: meshes.add(Cuboid::from_size(wall_size)),
+                material: wall_material.clone(),
+                transform: Transform::from_translation(position),
+                ..default()
+            },
+            WallObstacle { half_extents: wall_size.xz() / 2.0, room },
+            RoomGeometry,
+        ));
+    }
+}
 
-    // East wall (with gaps for transitions)
-    commands.spawn(PbrBundle {
-        mesh: meshes.add(Cuboid::new(wall_thickness, wall_height, size.y - 4.0)), // Gap for transition
-        material: wall_material.clone(),
-        transform: Transform::from_translation(Vec3::new(center.x + half_size.x, wall_height / 2.0, center.y + 2.0)),
-        ..default()
-    });
-    
-    commands.spawn(PbrBundle {
-        mesh: meshes.add(Cuboid::new(wall_thickness, wall_height, size.y - 4.0)),
-        material: wall_material.clone(),
-        transform: Transform::from_translation(Vec3::new(center.x + half_size.x, wall_height / 2.0, center.y - 2.0)),
-        ..default()
-    });
-
-    // West wall
-    commands.spawn(PbrBundle {
-        mesh: meshes.add(Cuboid::new(wall_thickness, wall_height, size.y + wall_thickness)),
-        material: wall_material,
-        transform: Transform::from_translation(Vec3::new(center.x - half_size.x, wall_height / 2.0, center.y)),
-        ..default()
-    });
-}
+const DOOR_INTERACT_RADIUS: f32 = 2.0;
 
 fn room_transition_system(
     mut game_state: ResMut<GameState>,
-    mut player_query: Query<&mut Transform, With<Player>>,
+    mut player_query: Query<(&PlayerId, &mut Transform), With<Player>>,
     transitions: Query<&RoomTransition>,
-    input: Res<InputState>,
+    rooms: Query<&Room>,
+    fixed_input: Res<FixedInput>,
+    mut entered_events: EventWriter<RoomEnteredEvent>,
 ) {
-    if let Ok(mut player_transform) = player_query.get_single_mut() {
-        for transition in &transitions {
-            if !transition.active || transition.from_room != game_state.current_room {
-                continue;
-            }
+    // Qualquer jogador pode abrir a porta; o grupo inteiro é levado junto
+    let interacting: Vec<Vec3> = player_query.iter()
+        .filter(|(id, _)| fixed_input.get(**id).interact)
+        .map(|(_, transform)| transform.translation)
+        .collect();
 
-            let distance = player_transform.translation.distance(transition.position);
-            if distance <= 2.0 && input.interact {
-                // Trigger room transition
-                game_state.previous_room = game_state.current_room;
-                game_state.current_room = transition.to_room;
-                
-                // Move player to new room center
-                let room_centers = [
-                    Vec2::new(0.0, 0.0),   // Room 0
-                    Vec2::new(25.0, 0.0),  // Room 1
-                    Vec2::new(50.0, 0.0),  // Room 2
-                ];
-                
-                if let Some(new_center) = room_centers.get(transition.to_room) {
-                    player_transform.translation = Vec3::new(new_center.x - 5.0, 0.5, new_center.y);
-                }
-                
+    for transition in &transitions {
+        if !transition.active || transition.from_room != game_state.current_room {
+            continue;
+        }
+
+        if interacting.iter().any(|position| position.distance(transition.position) <= DOOR_INTERACT_RADIUS) {
+            // Trigger room transition
+            game_state.previous_room = game_state.current_room;
+            game_state.current_room = transition.to_room;
+            entered_events.send(RoomEnteredEvent { room: transition.to_room });
+            
+            // Chega pela porta de volta (ou no centro, se a sala não tem porta de volta)
+            let Some(room) = rooms.iter().find(|room| room.id == transition.to_room) else {
                 break;
+            };
+            let arrival = transitions.iter()
+                .find(|back| back.from_room == transition.to_room && back.to_room == transition.from_room)
+                .map_or(room.center, |back| {
+                    let door = back.position.xz();
+                    door + (room.center - door).normalize_or_zero() * DOOR_ARRIVAL_OFFSET
+                });
+            for (id, mut player_transform) in &mut player_query {
+                let offset = id.index() as f32 * COOP_SPAWN_SPACING;
+                player_transform.translation = Vec3::new(arrival.x, 0.5, arrival.y + offset);
             }
+            
+            break;
         }
     }
 }
 
+// O gerador acompanha a sala atual (portas e viagem rápida mudam só o GameState)
+fn sync_dungeon_current_room(game_state: Res<GameState>, mut dungeon: ResMut<GeneratedDungeon>) {
+    let current = RoomId(game_state.current_room as u32);
+    if dungeon.current_room != current {
+        dungeon.current_room = current;
+        dungeon.unlocked_rooms.insert(current);
+    }
+}
+
+// Só as portas abertas da sala atual aparecem como interagíveis
+fn door_interactable_system(
+    game_state: Res<GameState>,
+    mut doors: Query<(&RoomTransition, &mut Interactable)>,
+) {
+    for (transition, mut interactable) in &mut doors {
+        let enabled = transition.active && transition.from_room == game_state.current_room;
+        if interactable.enabled != enabled {
+            interactable.enabled = enabled;
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn room_clear_system(
     mut game_state: ResMut<GameState>,
     mut rooms: Query<&mut Room>,
     mut transitions: Query<&mut RoomTransition>,
     enemies: Query<&Enemy>,
+    heat: Res<HeatModifiers>,
+    mut run_events: EventWriter<RunCompletedEvent>,
+    mut dungeon: ResMut<GeneratedDungeon>,
+    downed_players: Query<(), With<Downed>>,
+    mut app_state: ResMut<NextState<AppState>>,
+    governor: Res<SpawnGovernor>,
+    mut cleared_events: EventWriter<RoomClearedEvent>,
 ) {
-    // Check if current room should be cleared
-    let enemy_count = enemies.iter().count();
+    // Check if current room should be cleared (inimigos ainda na fila do governor contam)
+    let enemy_count = enemies.iter().count() + governor.queued();
     
-    if enemy_count == 0 {
+    // Co-op: a sala só conta como limpa com todos os jogadores de pé
+    if enemy_count == 0 && downed_players.is_empty() {
         // Clear current room
         for mut room in &mut rooms {
             if room.id == game_state.current_room && !room.cleared {
                 room.cleared = true;
                 game_state.rooms_cleared += 1;
+                cleared_events.send(RoomClearedEvent { room: room.id, room_type: room.room_type });
+                
+                let room_state = dungeon.room_state_mut(RoomId(room.id as u32));
+                room_state.cleared = true;
+                
+                // Derrotar o chefe completa a run - recompensa de meta-progressão (uma vez por sala)
+                if matches!(room.room_type, RoomType::Boss) && !room_state.events_resolved {
+                    room_state.events_resolved = true;
+                    run_events.send(RunCompletedEvent { heat: heat.total_heat() });
+                }
+                
+                // Portas da sala limpa levam às vizinhas: elas aparecem abertas no minimapa
+                let neighbours: Vec<RoomId> = dungeon.layout.connections.get(&RoomId(room.id as u32))
+                    .into_iter()
+                    .flatten()
+                    .map(|connection| connection.to_room)
+                    .collect();
+                dungeon.unlocked_rooms.extend(neighbours);
+                
+                // Salas de combate deixam o jogador escolher a recompensa antes de seguir
+                if matches!(room.room_type, RoomType::Combat) {
+                    app_state.set(AppState::RewardChoice);
+                }
                 
                 // Activate next transition
                 for mut transition in &mut transitions {
@@ -1604,18 +3687,27 @@ fn room_clear_system(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn room_enemy_spawn_system(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
-    mut game_state: ResMut<GameState>,
+    game_state: Res<GameState>,
     game_assets: Option<Res<GameAssets>>,
     hades_3d_assets: Option<Res<hades_3d_system::Hades3DAssets>>,
     rooms: Query<&Room>,
+    heat: Res<HeatModifiers>,
+    difficulty: Res<AdaptiveDifficulty>,
+    mut dungeon: ResMut<GeneratedDungeon>,
+    mut governor: ResMut<SpawnGovernor>,
+    mut run_rng: ResMut<RunRng>,
 ) {
-    // Check if we need to spawn enemies in the current room
-    if game_state.current_room < game_state.enemies_spawned.len() && 
-       !game_state.enemies_spawned[game_state.current_room] {
+    // As salas do jogo usam o próprio RoomId do gerador como índice
+    let room_id = RoomId(game_state.current_room as u32);
+    
+    // Check if we need to spawn enemies in the current room (never again on revisits)
+    let room_state = dungeon.room_state(room_id);
+    if !room_state.enemies_spawned && !room_state.cleared {
         
         // Find current room info
         if let Some(current_room) = rooms.iter().find(|r| r.id == game_state.current_room) {
@@ -1623,11 +3715,8 @@ fn room_enemy_spawn_system(
             
             let enemy_spawns = match current_room.room_type {
                 RoomType::Start => vec![], // No new enemies in start room
-                RoomType::Combat => vec![
-                    (Vec3::new(room_center.x + 3.0, 0.5, room_center.y + 2.0), EnemyType::Chaser),
-                    (Vec3::new(room_center.x - 2.0, 0.5, room_center.y - 3.0), EnemyType::Shooter),
-                    (Vec3::new(room_center.x + 1.0, 0.5, room_center.y - 1.0), EnemyType::Tank),
-                ],
+                // Tabela do bioma: os inimigos listados no template da sala
+                RoomType::Combat => bestiary::roll_room_spawns(&current_room.template, current_room.template.biome, room_center, run_rng.stream(RunRngStream::RoomSpawns)),
                 RoomType::Boss => vec![
                     (Vec3::new(room_center.x, 0.5, room_center.y + 3.0), EnemyType::Tank),
                     (Vec3::new(room_center.x + 4.0, 0.5, room_center.y), EnemyType::Shooter),
@@ -1635,196 +3724,462 @@ fn room_enemy_spawn_system(
                     (Vec3::new(room_center.x + 2.0, 0.5, room_center.y - 2.0), EnemyType::Chaser),
                     (Vec3::new(room_center.x - 2.0, 0.5, room_center.y - 2.0), EnemyType::Chaser),
                 ],
-                RoomType::Treasure => vec![], // No enemies in treasure rooms
+                RoomType::Treasure | RoomType::Shop => vec![], // No enemies in treasure rooms or shops
             };
             
-            for (pos, enemy_type) in enemy_spawns {
-                // Use TRUE 3D models from assets/models/
-                let enemy_3d_type = match enemy_type {
-                    EnemyType::Chaser => true_3d_system::EnemyType::MummyGuardian,
-                    EnemyType::Shooter => true_3d_system::EnemyType::EgyptianWarrior, 
-                    EnemyType::Tank => true_3d_system::EnemyType::AnubisBoss,
-                };
-                
-                let ai = match enemy_type {
-                    EnemyType::Chaser => AI {
-                        target_range: 12.0,
-                        chase_speed: 4.0,
-                        attack_cooldown: 0.0,
-                        attack_timer: 0.0,
-                    },
-                    EnemyType::Shooter => AI {
-                        target_range: 15.0,
-                        chase_speed: 1.5,
-                        attack_cooldown: 2.0,
-                        attack_timer: 0.0,
-                    },
-                    EnemyType::Tank => AI {
-                        target_range: 8.0,
-                        chase_speed: 1.0,
-                        attack_cooldown: 0.0,
-                        attack_timer: 0.0,
-                    },
-                };
-                
-                let stats = match enemy_type {
-                    EnemyType::Chaser => Stats {
-                        max_health: 30.0,
-                        current_health: 30.0,
-                        max_stamina: 0.0,
-                        current_stamina: 0.0,
-                        speed: 4.0,
-                        stamina_regen_rate: 0.0,
-                    },
-                    EnemyType::Shooter => Stats {
-                        max_health: 40.0,
-                        current_health: 40.0,
-                        max_stamina: 0.0,
-                        current_stamina: 0.0,
-                        speed: 1.5,
-                        stamina_regen_rate: 0.0,
-                    },
-                    EnemyType::Tank => Stats {
-                        max_health: 120.0,
-                        current_health: 120.0,
-                        max_stamina: 0.0,
-                        current_stamina: 0.0,
-                        speed: 1.0,
-                        stamina_regen_rate: 0.0,
-                    },
-                };
-                
-                // Spawn Hades-quality 3D enemy using glTF models
-                if let Some(hades_assets) = hades_3d_assets.as_ref() {
-                    hades_3d_system::spawn_hades_enemy(
-                        &mut commands,
-                        hades_assets,
-                        enemy_type,
-                        pos,
-                        ai,
-                        stats,
-                    );
+            // Sala Elite do gerador: o primeiro inimigo vira elite; EliteLegion soma mais um
+            let elite_room = current_room.template.room_type == procedural::RoomType::Elite;
+            let elites = usize::from(elite_room) + usize::from(heat.extra_elites());
+            for (index, (pos, enemy_type)) in enemy_spawns.into_iter().enumerate() {
+                let is_elite = index < elites;
+                let affix = is_elite.then(|| EliteAffix::roll(enemy_type, run_rng.stream(RunRngStream::EliteAffixes)));
+                let is_boss = matches!(current_room.room_type, RoomType::Boss) && index == 0;
+                // Sem vaga no governor: espera na fila (o chefe sempre surge na hora)
+                if !is_boss && !governor.try_reserve() {
+                    governor.enqueue(QueuedSpawn { position: pos, enemy_type, affix, source: SpawnSource::RoomWave });
                     continue;
                 }
-                
-                // Fallback to 2D sprite system
-                let (mesh, material, ai, stats) = match enemy_type {
-                    EnemyType::Chaser => (
-                        meshes.add(Plane3d::default().mesh().size(2.5, 2.5)),
-                        if let Some(assets) = &game_assets {
-                            materials.add(StandardMaterial {
-                                base_color_texture: Some(assets.anubis_judge.clone()),
-                                alpha_mode: AlphaMode::Blend,
-                                unlit: true,
-                                double_sided: true,
-                                ..default()
-                            })
-                        } else {
-                            materials.add(StandardMaterial {
-                                base_color: Color::rgb(0.8, 0.2, 0.2),
-                                emissive: Color::rgb(0.4, 0.1, 0.1).into(),
-                                ..default()
-                            })
-                        },
-                        AI {
-                            target_range: 12.0,
-                            chase_speed: 4.0,
-                            attack_cooldown: 0.0,
-                            attack_timer: 0.0,
-                        },
-                        Stats {
-                            max_health: 30.0,
-                            current_health: 30.0,
-                            max_stamina: 0.0,
-                            current_stamina: 0.0,
-                            speed: 4.0,
-                            stamina_regen_rate: 0.0,
-                        },
-                    ),
-                    EnemyType::Shooter => (
-                        meshes.add(Plane3d::default().mesh().size(2.0, 2.0)), // Billboard for 3D sprite
-                        if let Some(assets) = &game_assets {
-                            materials.add(StandardMaterial {
-                                base_color_texture: Some(assets.mummy_guardian.clone()),
-                                alpha_mode: AlphaMode::Blend,
-                                unlit: true,
-                                double_sided: true,
-                                ..default()
-                            })
-                        } else {
-                            materials.add(StandardMaterial {
-                                base_color: Color::rgb(0.2, 0.8, 0.2),
-                                emissive: Color::rgb(0.1, 0.4, 0.1).into(),
-                                ..default()
-                            })
-                        },
-                        AI {
-                            target_range: 15.0,
-                            chase_speed: 1.5,
-                            attack_cooldown: 2.0,
-                            attack_timer: 0.0,
-                        },
-                        Stats {
-                            max_health: 40.0,
-                            current_health: 40.0,
-                            max_stamina: 0.0,
-                            current_stamina: 0.0,
-                            speed: 1.5,
-                            stamina_regen_rate: 0.0,
-                        },
-                    ),
-                    EnemyType::Tank => (
-                        meshes.add(Plane3d::default().mesh().size(2.5, 2.5)), // Larger billboard for tank
-                        if let Some(assets) = &game_assets {
-                            materials.add(StandardMaterial {
-                                base_color_texture: Some(assets.set_chaos.clone()),
-                                alpha_mode: AlphaMode::Blend,
-                                unlit: true,
-                                double_sided: true,
-                                ..default()
-                            })
-                        } else {
-                            materials.add(StandardMaterial {
-                                base_color: Color::rgb(0.6, 0.6, 0.2),
-                                emissive: Color::rgb(0.3, 0.3, 0.1).into(),
-                                ..default()
-                            })
-                        },
-                        AI {
-                            target_range: 8.0,
-                            chase_speed: 1.0,
-                            attack_cooldown: 0.0,
-                            attack_timer: 0.0,
-                        },
-                        Stats {
-                            max_health: 120.0,
-                            current_health: 120.0,
-                            max_stamina: 0.0,
-                            current_stamina: 0.0,
-                            speed: 1.0,
-                            stamina_regen_rate: 0.0,
-                        },
-                    ),
-                };
-
-                commands.spawn((
-                    PbrBundle {
-                        mesh,
-                        material,
-                        transform: Transform::from_translation(pos)
-                            .with_rotation(Quat::from_rotation_y(std::f32::consts::FRAC_PI_4)), // Face camera for 3D effect
-                        ..default()
-                    },
-                    Enemy,
+                let enemy_entity = spawn_room_enemy(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    game_assets.as_deref(),
+                    hades_3d_assets.as_deref(),
+                    &heat,
+                    &difficulty,
+                    current_room.template.biome,
+                    pos,
                     enemy_type,
-                    ai,
-                    stats,
-                ));
+                    affix,
+                );
+                
+                // O primeiro inimigo da sala do chefe é o chefe - com a Ampulheta, o timer de fúria começa agora
+                if is_boss {
+                    commands.entity(enemy_entity).insert((Boss, LootTable::boss()));
+                    if heat.boss_enrage() {
+                        commands.entity(enemy_entity).insert(BossEnrage::default());
+                    }
+                }
+            }
+            
+            // Portais de reforço: dois na sala do chefe, um nas salas com elite
+            let portal_spawns = match current_room.room_type {
+                RoomType::Boss => vec![
+                    (Vec3::new(room_center.x + 7.0, 0.0, room_center.y - 5.0), true),
+                    (Vec3::new(room_center.x - 7.0, 0.0, room_center.y - 5.0), true),
+                ],
+                RoomType::Combat if heat.extra_elites() => vec![
+                    (Vec3::new(room_center.x, 0.0, room_center.y - 6.0), false),
+                ],
+                _ => vec![],
+            };
+            for (pos, tied_to_boss) in portal_spawns {
+                spawn_reinforcement_portal(&mut commands, &mut meshes, &mut materials, pos, tied_to_boss);
+            }
+            
+            // Templates com baú armadilhado/saque lendário ganham armadilhas no chão
+            if trap_system::room_has_traps(&current_room.template.special_mechanics) {
+                trap_system::spawn_room_traps(&mut commands, &mut meshes, &mut materials, room_center, run_rng.stream(RunRngStream::RoomSpawns));
+            }
+            
+            // Salas do tesouro: baú fechado até alguém abrir (saqueado fica marcado no RoomState)
+            if matches!(current_room.room_type, RoomType::Treasure) && !room_state.loot_taken {
+                let trapped = current_room.template.special_mechanics.iter().any(|mechanic| mechanic == "Trapped_Chest");
+                chest_system::spawn_chest(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    room_id,
+                    Vec3::new(room_center.x, 0.0, room_center.y),
+                    current_room.template.reward_multiplier,
+                    trapped,
+                );
+            }
+            
+            // Lojas: estoque nos pedestais (sai uma vez só; o que foi comprado não volta)
+            if matches!(current_room.room_type, RoomType::Shop) {
+                shop_system::spawn_shop_stock(&mut commands, &mut meshes, &mut materials, Vec3::new(room_center.x, 0.0, room_center.y));
             }
             
             // Mark this room as having spawned enemies
-            let current_room_id = game_state.current_room;
-            game_state.enemies_spawned[current_room_id] = true;
+            dungeon.room_state_mut(room_id).enemies_spawned = true;
+        }
+    }
+}
+
+// Spawn de um inimigo de sala: stats base + calor, modelo 3D (ou fallback), elite, escudo e entrada
+#[allow(clippy::too_many_arguments)]
+fn spawn_room_enemy(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    game_assets: Option<&GameAssets>,
+    hades_3d_assets: Option<&hades_3d_system::Hades3DAssets>,
+    heat: &HeatModifiers,
+    difficulty: &AdaptiveDifficulty,
+    biome: BiomeType,
+    pos: Vec3,
+    enemy_type: EnemyType,
+    affix: Option<EliteAffix>,
+) -> Entity {
+    let is_elite = affix.is_some();
+    // Use TRUE 3D models from assets/models/
+    let enemy_3d_type = match enemy_type {
+        EnemyType::Chaser => true_3d_system::EnemyType::MummyGuardian,
+        EnemyType::Shooter => true_3d_system::EnemyType::EgyptianWarrior, 
+        EnemyType::Tank => true_3d_system::EnemyType::AnubisBoss,
+        EnemyType::LobberMummy => true_3d_system::EnemyType::MummyGuardian,
+        EnemyType::SandJackal => true_3d_system::EnemyType::EgyptianWarrior,
+    };
+    
+    let mut ai = match enemy_type {
+        EnemyType::Chaser => AI {
+            target_range: 12.0,
+            chase_speed: 4.0,
+            attack_cooldown: 0.8,
+            attack_timer: 0.0,
+            windup: None,
+            lunge: None,
+            queued_shot: None,
+        },
+        EnemyType::Shooter => AI {
+            target_range: 15.0,
+            chase_speed: 1.5,
+            attack_cooldown: 2.0,
+            attack_timer: 0.0,
+            windup: None,
+            lunge: None,
+            queued_shot: None,
+        },
+        EnemyType::Tank => AI {
+            target_range: 8.0,
+            chase_speed: 1.0,
+            attack_cooldown: 1.2,
+            attack_timer: 0.0,
+            windup: None,
+            lunge: None,
+            queued_shot: None,
+        },
+        EnemyType::LobberMummy => AI {
+            target_range: 18.0,
+            chase_speed: 1.2,
+            attack_cooldown: 3.0,
+            attack_timer: 1.5,
+            windup: None,
+            lunge: None,
+            queued_shot: None,
+        },
+        EnemyType::SandJackal => AI {
+            target_range: 14.0,
+            chase_speed: 5.0,
+            attack_cooldown: 2.2,
+            attack_timer: 1.0,
+            windup: None,
+            lunge: None,
+            queued_shot: None,
+        },
+    };
+    
+    let mut stats = match enemy_type {
+        EnemyType::Chaser => Stats {
+            max_health: 30.0,
+            current_health: 30.0,
+            max_stamina: 0.0,
+            current_stamina: 0.0,
+            speed: 4.0,
+            stamina_regen_rate: 0.0,
+            poise: 8.0,
+            knockback_multiplier: 1.0,
+        },
+        EnemyType::Shooter => Stats {
+            max_health: 40.0,
+            current_health: 40.0,
+            max_stamina: 0.0,
+            current_stamina: 0.0,
+            speed: 1.5,
+            stamina_regen_rate: 0.0,
+            poise: 10.0,
+            knockback_multiplier: 1.0,
+        },
+        EnemyType::Tank => Stats {
+            max_health: 120.0,
+            current_health: 120.0,
+            max_stamina: 0.0,
+            current_stamina: 0.0,
+            speed: 1.0,
+            stamina_regen_rate: 0.0,
+            poise: 30.0,
+            knockback_multiplier: 0.35,
+        },
+        EnemyType::LobberMummy => Stats {
+            max_health: 35.0,
+            current_health: 35.0,
+            max_stamina: 0.0,
+            current_stamina: 0.0,
+            speed: 1.2,
+            stamina_regen_rate: 0.0,
+            poise: 10.0,
+            knockback_multiplier: 1.0,
+        },
+        EnemyType::SandJackal => Stats {
+            max_health: 28.0,
+            current_health: 28.0,
+            max_stamina: 0.0,
+            current_stamina: 0.0,
+            speed: 5.0,
+            stamina_regen_rate: 0.0,
+            poise: 8.0,
+            knockback_multiplier: 1.0,
+        },
+    };
+    
+    // Criatura do bioma (bestiário): vida, velocidade e cor próprias sobre o tipo base
+    let archetype = bestiary::biome_archetype(biome, enemy_type);
+    let elite_variant = is_elite.then(|| bestiary::elite_variant(biome));
+    let speed = archetype.speed * elite_variant.as_ref().map_or(1.0, |variant| variant.speed);
+    stats.max_health *= archetype.health;
+    stats.speed *= speed;
+    ai.chase_speed *= speed;
+
+    // Heat modifiers (Pacto) e dificuldade adaptativa - aplicados antes do spawn
+    stats.max_health *= heat.enemy_health_multiplier() * difficulty.enemy_health_multiplier();
+    ai.attack_cooldown *= heat.enemy_attack_cooldown_multiplier();
+    
+    if let Some(variant) = &elite_variant {
+        stats.max_health *= 2.0 * variant.health;
+        stats.poise *= 2.0;
+        stats.knockback_multiplier *= 0.5;
+    }
+    stats.current_health = stats.max_health;
+    
+    // Spawn Hades-quality 3D enemy using glTF models
+    let enemy_entity = if let Some(hades_assets) = hades_3d_assets {
+        hades_3d_system::spawn_hades_enemy(
+            commands,
+            hades_assets,
+            enemy_type,
+            pos,
+            ai,
+            stats,
+        )
+    } else {
+        // Fallback to 2D sprite system
+        let (mesh, material) = match enemy_type {
+            EnemyType::Chaser => (
+                meshes.add(Plane3d::default().mesh().size(2.5, 2.5)),
+                if let Some(assets) = &game_assets {
+                    materials.add(billboard_material(assets.anubis_judge.clone(), Color::WHITE, true))
+                } else {
+                    materials.add(StandardMaterial {
+                        base_color: Color::rgb(0.8, 0.2, 0.2),
+                        emissive: Color::rgb(0.4, 0.1, 0.1).into(),
+                        ..default()
+                    })
+                },
+            ),
+            EnemyType::Shooter => (
+                meshes.add(Plane3d::default().mesh().size(2.0, 2.0)), // Billboard for 3D sprite
+                if let Some(assets) = &game_assets {
+                    materials.add(billboard_material(assets.mummy_guardian.clone(), Color::WHITE, true))
+                } else {
+                    materials.add(StandardMaterial {
+                        base_color: Color::rgb(0.2, 0.8, 0.2),
+                        emissive: Color::rgb(0.1, 0.4, 0.1).into(),
+                        ..default()
+                    })
+                },
+            ),
+            EnemyType::Tank => (
+                meshes.add(Plane3d::default().mesh().size(2.5, 2.5)), // Larger billboard for tank
+                if let Some(assets) = &game_assets {
+                    materials.add(billboard_material(assets.set_chaos.clone(), Color::WHITE, true))
+                } else {
+                    materials.add(StandardMaterial {
+                        base_color: Color::rgb(0.6, 0.6, 0.2),
+                        emissive: Color::rgb(0.3, 0.3, 0.1).into(),
+                        ..default()
+                    })
+                },
+            ),
+            EnemyType::LobberMummy => (
+                meshes.add(Plane3d::default().mesh().size(2.0, 2.0)),
+                if let Some(assets) = &game_assets {
+                    materials.add(billboard_material(assets.mummy_guardian.clone(), Color::rgb(0.7, 1.0, 0.6), true)) // Tom esverdeado para distinguir
+                } else {
+                    materials.add(StandardMaterial {
+                        base_color: Color::rgb(0.5, 0.7, 0.3),
+                        emissive: Color::rgb(0.2, 0.35, 0.1),
+                        ..default()
+                    })
+                },
+            ),
+            EnemyType::SandJackal => (
+                meshes.add(Plane3d::default().mesh().size(1.8, 1.8)),
+                if let Some(assets) = &game_assets {
+                    materials.add(billboard_material(assets.anubis_judge.clone(), Color::rgb(1.0, 0.85, 0.55), true)) // Tom de areia para distinguir
+                } else {
+                    materials.add(StandardMaterial {
+                        base_color: Color::rgb(0.85, 0.65, 0.3),
+                        emissive: Color::rgb(0.4, 0.3, 0.1),
+                        ..default()
+                    })
+                },
+            ),
+        };
+
+        // Tom da criatura do bioma (e da variante de elite) por cima da cor do tipo base
+        if let Some(base) = materials.get_mut(&material) {
+            base.base_color = bestiary::tint(base.base_color, archetype.tint);
+            if let Some(variant) = &elite_variant {
+                base.base_color = bestiary::tint(base.base_color, variant.tint);
+            }
+        }
+
+        let scale = if is_elite { Vec3::splat(1.3) } else { Vec3::ONE };
+        commands.spawn((
+            PbrBundle {
+                mesh,
+                material,
+                transform: Transform::from_translation(pos)
+                    .with_rotation(Quat::from_rotation_y(std::f32::consts::FRAC_PI_4)) // Face camera for 3D effect
+                    .with_scale(scale),
+                ..default()
+            },
+            Enemy,
+            enemy_type,
+            ai,
+            stats,
+            BillboardSort,
+        )).id()
+    };
+    commands.entity(enemy_entity).insert(Name::new(match &elite_variant {
+        Some(variant) => format!("{} {}", archetype.name, variant.title),
+        None => archetype.name.to_string(),
+    }));
+    
+    // Saque por tipo; elites sorteiam numa tabela mais rica
+    let loot = LootTable::for_enemy(enemy_type);
+    commands.entity(enemy_entity).insert(if is_elite { loot.elite() } else { loot });
+
+    // Elites chegam com o afixo já sorteado, marcado por uma joia na cor dele
+    if let Some(affix) = affix {
+        commands.entity(enemy_entity).insert((Elite, affix));
+        // Modelos glTF já vêm escalados 3x
+        let (icon_height, icon_size) = if hades_3d_assets.is_some() { (0.65, 0.1) } else { (1.6, 0.3) };
+        elite_affixes::spawn_affix_icon(commands, meshes, materials, enemy_entity, affix, icon_height, icon_size);
+    }
+    
+    // Guardiões (Tank) entram com escudo; elites blindados ganham um escudo extra
+    let mut shield = if enemy_type == EnemyType::Tank { 40.0 } else { 0.0 };
+    if affix == Some(EliteAffix::Shielded) {
+        shield += elite_affixes::SHIELDED_EXTRA_SHIELD;
+    }
+    if shield > 0.0 {
+        // Modelos glTF já vêm escalados 3x, a esfera herda a escala do pai
+        let overlay_radius = if hades_3d_assets.is_some() { 0.4 } else { 1.1 };
+        let overlay = commands.spawn((
+            PbrBundle {
+                mesh: meshes.add(Sphere::new(overlay_radius)),
+                material: materials.add(StandardMaterial {
+                    base_color: Color::rgba(0.3, 0.7, 1.0, 0.25),
+                    emissive: Color::rgb(0.2, 0.5, 1.0),
+                    alpha_mode: AlphaMode::Blend,
+                    unlit: true,
+                    ..default()
+                }),
+                ..default()
+            },
+            ShieldOverlay,
+        )).id();
+        commands.entity(enemy_entity)
+            .insert(ShieldedEnemy { shield })
+            .add_child(overlay);
+    }
+
+    // Entrada: escondido sobre o telegraph do bioma até o fade-in
+    let (telegraph, color) = spawn_enemy_telegraph(commands, meshes, materials, pos, biome);
+    commands.entity(enemy_entity).insert((
+        Spawning {
+            timer: 0.0,
+            telegraph,
+            color,
+            full_scale: None,
+        },
+        Visibility::Hidden,
+    ));
+
+    enemy_entity
+}
+
+// Redemoinho de areia no deserto, círculo dourado no templo, fenda de sombras no submundo
+fn spawn_enemy_telegraph(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    pos: Vec3,
+    biome: BiomeType,
+) -> (Entity, Color) {
+    let (color, emissive, radius) = match biome {
+        BiomeType::Desert => (Color::rgba(0.9, 0.75, 0.45, 0.6), Color::rgb(0.7, 0.5, 0.2), 1.2),
+        BiomeType::Temple => (Color::rgba(1.0, 0.85, 0.4, 0.55), Color::rgb(1.0, 0.75, 0.2), 1.0),
+        BiomeType::Underworld => (Color::rgba(0.25, 0.05, 0.35, 0.8), Color::rgb(0.5, 0.1, 0.9), 1.0),
+    };
+    let telegraph = commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Circle::new(radius)),
+            material: materials.add(StandardMaterial {
+                base_color: color,
+                emissive,
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            }),
+            transform: Transform::from_xyz(pos.x, 0.03, pos.z)
+                .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+                .with_scale(Vec3::splat(0.2)),
+            ..default()
+        },
+        SpawnTelegraph,
+    )).id();
+    (telegraph, color.with_a(1.0))
+}
+
+// Telegraph cresce; depois o inimigo surge, cresce até a escala cheia e só então fica ativo
+fn enemy_spawn_in_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut spawning: Query<(Entity, &mut Transform, &mut Visibility, &mut Spawning), Without<SpawnTelegraph>>,
+    mut telegraphs: Query<&mut Transform, With<SpawnTelegraph>>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+) {
+    let dt = time.delta_seconds();
+    for (entity, mut transform, mut visibility, mut spawning) in &mut spawning {
+        let full_scale = *spawning.full_scale.get_or_insert(transform.scale);
+        let previous = spawning.timer;
+        spawning.timer += dt;
+
+        if spawning.timer < SPAWN_TELEGRAPH_TIME {
+            if let Ok(mut telegraph) = telegraphs.get_mut(spawning.telegraph) {
+                let progress = spawning.timer / SPAWN_TELEGRAPH_TIME;
+                telegraph.scale = Vec3::splat(0.2 + 0.8 * progress);
+            }
+            continue;
+        }
+
+        if previous < SPAWN_TELEGRAPH_TIME {
+            commands.entity(spawning.telegraph).despawn_recursive();
+            *visibility = Visibility::Inherited;
+            particle_events.send(SpawnParticlesEvent {
+                position: transform.translation,
+                color: spawning.color,
+                count: 15,
+            });
+        }
+
+        let fade_in = ((spawning.timer - SPAWN_TELEGRAPH_TIME) / SPAWN_FADE_IN_TIME).min(1.0);
+        transform.scale = full_scale * fade_in.max(0.05);
+        if fade_in >= 1.0 {
+            commands.entity(entity).remove::<Spawning>();
         }
     }
 }
@@ -1854,51 +4209,412 @@ fn hit_effect_system(
 }
 
 fn audio_system(
-    _commands: Commands,
+    mut commands: Commands,
     mut audio_events: EventReader<AudioEvent>,
+    audio_handles: Res<AudioHandles>,
+    audio_sources: Res<Assets<AudioSource>>,
+    real_time: Res<Time<Real>>,
+    mut last_played: Local<HashMap<AudioEvent, f32>>,
 ) {
+    let now = real_time.elapsed_seconds();
     for event in audio_events.read() {
-        // Since we don't have audio files, we'll create simple procedural audio feedback
-        // In a real game, you would play actual audio files using commands.spawn(AudioBundle::from(...))
-        
-        // For now, we'll just spawn a simple audio bundle with default sounds
-        // This creates a brief audio pulse for each event type
-        match event {
-            AudioEvent::AttackPrimary => {
-                // Quick slash sound (high pitch, short)
-                // commands.spawn(AudioBundle { /* play slash sound */ });
-            },
-            AudioEvent::AttackSecondary => {
-                // Heavier attack sound (lower pitch, medium)
-                // commands.spawn(AudioBundle { /* play heavy attack sound */ });
-            },
-            AudioEvent::AbilityQ => {
-                // Magical cast sound (ethereal, rising pitch)
-                // commands.spawn(AudioBundle { /* play magic cast sound */ });
-            },
-            AudioEvent::AbilityR => {
-                // Explosive AoE sound (deep boom)
-                // commands.spawn(AudioBundle { /* play explosion sound */ });
-            },
-            AudioEvent::ProjectileHit => {
-                // Impact sound (sharp, brief)
-                // commands.spawn(AudioBundle { /* play impact sound */ });
-            },
-            AudioEvent::EnemyHit => {
-                // Enemy damage sound (grunt/hit)
-                // commands.spawn(AudioBundle { /* play enemy hit sound */ });
-            },
-            AudioEvent::Dash => {
-                // Whoosh sound (quick, windy)
-                // commands.spawn(AudioBundle { /* play dash sound */ });
+        let (source, volume, cooldown) = audio_handles.cue(*event);
+        // Handle padrão, arquivo ausente ou ainda carregando: sem som (e sem erro a cada evento)
+        if !audio_sources.contains(source) {
+            continue;
+        }
+        // Rajadas do mesmo evento (vários acertos no mesmo golpe) tocam uma vez só
+        if last_played.get(event).is_some_and(|last| now - last < cooldown) {
+            continue;
+        }
+        last_played.insert(*event, now);
+
+        commands.spawn(AudioBundle {
+            source: source.clone(),
+            settings: PlaybackSettings::DESPAWN.with_volume(Volume::new(volume)),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use status_effects::StatusEffectKind;
+    use std::time::Duration;
+
+    const TICK: f32 = 1.0 / SIMULATION_HZ as f32;
+
+    // Mundo mínimo com os recursos que os sistemas da simulação leem
+    fn simulation_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.init_resource::<ProjectilePool>();
+        world.init_resource::<HeatModifiers>();
+        world.init_resource::<CombatTuning>();
+        world.init_resource::<EnemyFormation>();
+        world.init_resource::<AdaptiveDifficulty>();
+        world.init_resource::<SpawnGovernor>();
+        world.init_resource::<NavObstacles>();
+        world.init_resource::<Events<PlayerDamagedEvent>>();
+        world.init_resource::<Events<ApplyStatusEvent>>();
+        world
+    }
+
+    // Avança um passo fixo e roda o sistema (comandos aplicados no fim)
+    fn run_ticks<M>(world: &mut World, ticks: usize, system: impl IntoSystem<(), (), M> + Copy) {
+        for _ in 0..ticks {
+            world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(TICK));
+            world.run_system_once(system);
+        }
+    }
+
+    fn spawn_player(world: &mut World, position: Vec3) -> Entity {
+        world.spawn((
+            Player,
+            Transform::from_translation(position),
+            Stats::default(),
+            Dash::default(),
+            Velocity::default(),
+            Combat::default(),
+        )).id()
+    }
+
+    fn spawn_chaser(world: &mut World, position: Vec3) -> Entity {
+        world.spawn((
+            Enemy,
+            Transform::from_translation(position),
+            AI {
+                target_range: 20.0,
+                chase_speed: 4.0,
+                attack_cooldown: 1.0,
+                attack_timer: 0.0,
+                windup: None,
+                lunge: None,
+                queued_shot: None,
             },
-            AudioEvent::EnemyDeath => {
-                // Death sound (fade out, defeat)
-                // commands.spawn(AudioBundle { /* play death sound */ });
+            Stats { speed: 4.0, ..default() },
+            EnemyType::Chaser,
+        )).id()
+    }
+
+    #[test]
+    fn slowed_chaser_covers_less_distance_per_second() {
+        let mut world = simulation_world();
+        spawn_player(&mut world, Vec3::new(0.0, 0.5, 0.0));
+        let start_free = Vec3::new(12.0, 0.5, 0.0);
+        let start_slowed = Vec3::new(-12.0, 0.5, 0.0);
+        let free = spawn_chaser(&mut world, start_free);
+        let slowed = spawn_chaser(&mut world, start_slowed);
+        let mut status = StatusEffects::default();
+        status.apply(StatusEffectKind::Slow, 5.0, 0.5);
+        world.entity_mut(slowed).insert(status);
+
+        run_ticks(&mut world, SIMULATION_HZ as usize, ai_system);
+
+        let free_distance = world.get::<Transform>(free).unwrap().translation.distance(start_free);
+        let slowed_distance = world.get::<Transform>(slowed).unwrap().translation.distance(start_slowed);
+        assert!((free_distance - 4.0).abs() < 0.1, "sem lentidão anda a velocidade cheia: {free_distance}");
+        assert!((slowed_distance - 2.0).abs() < 0.1, "50% de lentidão anda metade: {slowed_distance}");
+    }
+
+    // Sala do gerador com o GameState apontando para ela
+    fn spawn_room(world: &mut World, id: usize, room_type: procedural::RoomType) {
+        world.spawn(Room {
+            id,
+            size: Vec2::splat(20.0),
+            center: Vec2::new(id as f32 * 30.0, 0.0),
+            cleared: false,
+            room_type: RoomType::from_template(room_type),
+            template: procedural::RoomTemplateGenerator::generate_room_template(
+                room_type,
+                BiomeType::Desert,
+                1,
+                &mut procedural::run_stream_rng(id as u64, RunRngStream::RoomSpawns),
+            ),
+        });
+    }
+
+    fn dungeon_world() -> World {
+        let mut world = simulation_world();
+        world.insert_resource(GameState { current_room: 1, rooms_cleared: 0, total_rooms: 2, previous_room: 0 });
+        world.insert_resource(GeneratedDungeon {
+            layout: procedural::DungeonLayout {
+                rooms: HashMap::new(),
+                connections: HashMap::new(),
+                start_room: RoomId(0),
+                boss_rooms: Vec::new(),
+                total_rooms: 2,
             },
+            current_room: RoomId(1),
+            unlocked_rooms: Default::default(),
+            generation_seed: 7,
+            room_states: HashMap::new(),
+        });
+        world.insert_resource(RunRng::new(7));
+        spawn_room(&mut world, 0, procedural::RoomType::Combat);
+        spawn_room(&mut world, 1, procedural::RoomType::Combat);
+        world
+    }
+
+    fn room_population(world: &mut World) -> usize {
+        let living = world.query_filtered::<(), With<Enemy>>().iter(world).count();
+        living + world.resource::<SpawnGovernor>().queued()
+    }
+
+    #[test]
+    fn cleared_room_does_not_respawn_on_revisit() {
+        let mut world = dungeon_world();
+        world.run_system_once(room_enemy_spawn_system);
+        assert!(room_population(&mut world) > 0, "a primeira visita povoa a sala");
+
+        // Sala limpa: todos mortos e fila vazia
+        let enemies: Vec<Entity> = world.query_filtered::<Entity, With<Enemy>>().iter(&world).collect();
+        for enemy in enemies {
+            world.despawn(enemy);
         }
-        
-        // Audio events are processed above (currently placeholder comments)
-        // In production, actual audio files would be played here
+        *world.resource_mut::<SpawnGovernor>() = SpawnGovernor::default();
+        world.resource_mut::<GeneratedDungeon>().room_state_mut(RoomId(1)).cleared = true;
+
+        // Sai para a sala vizinha (que povoa normalmente) e volta
+        world.resource_mut::<GameState>().current_room = 0;
+        world.run_system_once(room_enemy_spawn_system);
+        let enemies: Vec<Entity> = world.query_filtered::<Entity, With<Enemy>>().iter(&world).collect();
+        for enemy in enemies {
+            world.despawn(enemy);
+        }
+        *world.resource_mut::<SpawnGovernor>() = SpawnGovernor::default();
+        world.resource_mut::<GameState>().current_room = 1;
+        world.run_system_once(room_enemy_spawn_system);
+
+        assert_eq!(room_population(&mut world), 0, "sala já limpa não volta a ter inimigos");
+        assert!(world.resource::<GeneratedDungeon>().room_state(RoomId(1)).enemies_spawned);
+    }
+
+    #[test]
+    fn same_run_seed_rolls_the_same_room() {
+        let spawned = || {
+            let mut world = dungeon_world();
+            world.run_system_once(room_enemy_spawn_system);
+            let mut positions: Vec<[f32; 3]> = world.query_filtered::<&Transform, With<Enemy>>()
+                .iter(&world)
+                .map(|transform| transform.translation.to_array())
+                .collect();
+            positions.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            (positions, world.resource::<SpawnGovernor>().queued())
+        };
+        let first = spawned();
+        assert!(!first.0.is_empty());
+        assert_eq!(first, spawned());
+    }
+
+    #[test]
+    fn chaser_follows_nearby_decoy_while_it_lives() {
+        let mut world = simulation_world();
+        let player = Vec3::new(0.0, 0.5, 0.0);
+        spawn_player(&mut world, player);
+        let chaser = spawn_chaser(&mut world, Vec3::new(10.0, 0.5, 0.0));
+        let decoy_position = Vec3::new(10.0, 0.5, DECOY_AGGRO_RADIUS - 2.0);
+        let decoy = world.spawn((Transform::from_translation(decoy_position), Decoy { health: 50.0, remaining: 5.0 })).id();
+
+        run_ticks(&mut world, 30, ai_system);
+        let position = world.get::<Transform>(chaser).unwrap().translation;
+        assert!(position.distance(decoy_position) < DECOY_AGGRO_RADIUS - 3.0, "vai na ilusão: {position}");
+        assert!(position.distance(player) > 10.0, "ignora o jogador: {position}");
+
+        // Ilusão desfeita: volta a perseguir o jogador
+        world.despawn(decoy);
+        let before = position.distance(player);
+        run_ticks(&mut world, 30, ai_system);
+        let after = world.get::<Transform>(chaser).unwrap().translation.distance(player);
+        assert!(after < before - 1.5, "volta ao jogador: {before} -> {after}");
+    }
+
+    // Mundo da simulação com o que o hades_combat_system lê (P1 na origem mirando em -Z)
+    fn combat_world(combat: Combat) -> (World, Entity) {
+        let mut world = simulation_world();
+        world.init_resource::<FixedInput>();
+        world.init_resource::<boons::ActiveBoons>();
+        world.init_resource::<boons::CombatModifiers>();
+        world.init_resource::<ComboMeter>();
+        world.init_resource::<Momentum>();
+        world.init_resource::<CombatRng>();
+        world.init_resource::<Events<SpawnParticlesEvent>>();
+        world.init_resource::<Events<DamageEvent>>();
+        world.init_resource::<Events<AudioEvent>>();
+        world.init_resource::<Events<AoeExplosionEvent>>();
+        world.resource_mut::<FixedInput>().0[PlayerId::P1.index()].mouse_world_pos = Vec3::new(0.0, 0.5, -10.0);
+        let player = world.spawn((
+            Player,
+            PlayerId::P1,
+            Transform::from_xyz(0.0, 0.5, 0.0),
+            combat,
+            Dash::default(),
+        )).id();
+        (world, player)
+    }
+
+    fn press(world: &mut World, press: impl FnOnce(&mut InputState)) {
+        press(&mut world.resource_mut::<FixedInput>().0[PlayerId::P1.index()]);
+    }
+
+    #[test]
+    fn fan_of_three_is_centred_on_the_aim() {
+        let aim = Vec3::NEG_Z;
+        let directions: Vec<Vec3> = fan_directions(aim, 3, Q_FAN_SPREAD).collect();
+        assert_eq!(directions.len(), 3);
+        assert!(directions[1].abs_diff_eq(aim, 1e-5));
+        for (direction, expected) in directions.iter().zip([-Q_FAN_SPREAD, 0.0, Q_FAN_SPREAD]) {
+            assert!((direction.angle_between(aim) - expected.abs()).abs() < 1e-4);
+            assert!((direction.length() - 1.0).abs() < 1e-5);
+        }
+        // Os dois lados do leque são espelhados
+        assert!((directions[0].x + directions[2].x).abs() < 1e-5);
+        assert!((directions[0].angle_between(directions[2]) - 2.0 * Q_FAN_SPREAD).abs() < 1e-4);
+        // Sem bênção (ou contagem zerada) sai um tiro só, na mira
+        assert_eq!(fan_directions(aim, 0, Q_FAN_SPREAD).count(), 1);
+    }
+
+    #[test]
+    fn q_with_three_projectiles_fires_a_fan() {
+        let (mut world, _) = combat_world(Combat { projectile_count: 3, ..default() });
+        press(&mut world, |input| input.ability_q = true);
+        run_ticks(&mut world, 1, hades_combat_system);
+
+        let mut angles: Vec<f32> = world.query::<&Projectile>()
+            .iter(&world)
+            .map(|projectile| {
+                let direction = projectile.velocity.normalize();
+                direction.angle_between(Vec3::NEG_Z) * direction.x.signum()
+            })
+            .collect();
+        angles.sort_by(f32::total_cmp);
+        assert_eq!(angles.len(), 3);
+        for (angle, expected) in angles.iter().zip([-Q_FAN_SPREAD, 0.0, Q_FAN_SPREAD]) {
+            assert!((angle - expected).abs() < 1e-4, "ângulos do leque: {angles:?}");
+        }
+    }
+
+    fn r_blast_radius(area_multiplier: f32) -> f32 {
+        let (mut world, _) = combat_world(Combat { area_multiplier, ..default() });
+        press(&mut world, |input| input.ability_r_released = true);
+        run_ticks(&mut world, 1, hades_combat_system);
+
+        let events = world.resource::<Events<AoeExplosionEvent>>();
+        let mut reader = events.get_reader();
+        let blasts: Vec<&AoeExplosionEvent> = reader.read(events).collect();
+        assert_eq!(blasts.len(), 1);
+        // R tocado sem mirar explode em cima do jogador
+        assert!(blasts[0].center.abs_diff_eq(Vec3::new(0.0, 0.5, 0.0), 1e-5));
+        blasts[0].radius
+    }
+
+    #[test]
+    fn double_area_multiplier_doubles_the_r_radius() {
+        let base = r_blast_radius(1.0);
+        assert!((base - CombatTuning::default().attacks.aoe_radius).abs() < 1e-5);
+        assert!((r_blast_radius(2.0) - 2.0 * base).abs() < 1e-5);
+    }
+
+    fn spawn_aiming_shooter(world: &mut World, position: Vec3) -> Entity {
+        world.spawn((
+            Enemy,
+            Transform::from_translation(position),
+            AI {
+                target_range: 15.0,
+                chase_speed: 1.5,
+                attack_cooldown: 2.0,
+                attack_timer: 0.0,
+                windup: None,
+                lunge: None,
+                queued_shot: Some(SHOOTER_AIM_TIME),
+            },
+            Stats { max_health: 100.0, current_health: 100.0, poise: 10.0, ..default() },
+            EnemyType::Shooter,
+        )).id()
+    }
+
+    #[test]
+    fn hit_at_poise_cancels_the_queued_shot() {
+        let mut world = simulation_world();
+        world.init_resource::<Events<SpawnParticlesEvent>>();
+        let staggered = spawn_aiming_shooter(&mut world, Vec3::new(-3.0, 0.5, 0.0));
+        let steady = spawn_aiming_shooter(&mut world, Vec3::new(3.0, 0.5, 0.0));
+        // O sistema guarda a vida do tick anterior num Local: precisa ser o mesmo sistema nos dois ticks
+        let stagger = world.register_system(enemy_stagger_system);
+        world.run_system(stagger).unwrap();
+
+        world.get_mut::<Stats>(staggered).unwrap().current_health -= 10.0;
+        world.get_mut::<Stats>(steady).unwrap().current_health -= 9.0;
+        world.run_system(stagger).unwrap();
+
+        let ai = world.get::<AI>(staggered).unwrap();
+        assert!(ai.queued_shot.is_none());
+        assert_eq!(ai.attack_timer, ai.attack_cooldown);
+        let ai = world.get::<AI>(steady).unwrap();
+        assert_eq!(ai.queued_shot, Some(SHOOTER_AIM_TIME));
+        assert_eq!(ai.attack_timer, 0.0);
+
+        let events = world.resource::<Events<ApplyStatusEvent>>();
+        let mut reader = events.get_reader();
+        let stuns: Vec<&ApplyStatusEvent> = reader.read(events).collect();
+        assert_eq!(stuns.len(), 1);
+        assert_eq!(stuns[0].target, staggered);
+        assert_eq!(stuns[0].kind, StatusEffectKind::Stun);
+        assert_eq!(stuns[0].duration, STAGGER_DURATION);
+    }
+
+    // Vida que um inimigo colado no jogador perde com o primeiro golpe da sequência
+    fn primary_hit_damage(combat: Combat, modifiers: boons::CombatModifiers) -> f32 {
+        let (mut world, _) = combat_world(combat);
+        world.insert_resource(modifiers);
+        let enemy = world.spawn((
+            Enemy,
+            Transform::from_xyz(0.0, 0.5, -1.0),
+            Stats { max_health: 1000.0, current_health: 1000.0, ..default() },
+        )).id();
+        press(&mut world, |input| input.primary_released = true);
+        run_ticks(&mut world, 1, hades_combat_system);
+        1000.0 - world.get::<Stats>(enemy).unwrap().current_health
+    }
+
+    #[test]
+    fn damage_bonus_boon_raises_primary_damage() {
+        let mut blazing_might = boons::create_ra_boons().into_iter()
+            .find(|boon| boon.id == "ra_blazing_might")
+            .unwrap();
+        blazing_might.level = 3;
+        let active = boons::ActiveBoons { player_boons: vec![blazing_might], synergy_bonuses: Vec::new() };
+        let modifiers = boons::CombatModifiers::from_boons(&active);
+        assert!(modifiers.damage_multiplier > 1.0);
+
+        let base = primary_hit_damage(Combat::default(), boons::CombatModifiers::default());
+        let boosted = primary_hit_damage(Combat::default(), modifiers);
+        assert_eq!(base, Combat::default().base_damage as f32);
+        assert_eq!(boosted, (base * modifiers.damage_multiplier).trunc());
+        assert!(boosted > base);
+    }
+
+    #[test]
+    fn certain_crit_multiplies_the_hit() {
+        let combat = Combat { crit_chance: 1.0, crit_multiplier: 2.5, ..default() };
+        let expected = (combat.base_damage as f32 * combat.crit_multiplier).trunc();
+        assert_eq!(primary_hit_damage(combat, boons::CombatModifiers::default()), expected);
+    }
+
+    fn elites_in_room(room_type: procedural::RoomType) -> usize {
+        let mut world = dungeon_world();
+        spawn_room(&mut world, 2, room_type);
+        world.resource_mut::<GameState>().current_room = 2;
+        world.run_system_once(room_enemy_spawn_system);
+        world.query_filtered::<(), (With<Enemy>, With<EliteAffix>)>().iter(&world).count()
+    }
+
+    #[test]
+    fn elite_room_spawns_an_elite() {
+        assert_eq!(elites_in_room(procedural::RoomType::Elite), 1);
+        assert_eq!(elites_in_room(procedural::RoomType::Combat), 0);
     }
 }
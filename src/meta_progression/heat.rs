@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Modificadores de calor (estilo Pacto da Punição) escolhidos antes da run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum HeatModifier {
+    HardenedFoes,   // Inimigos com mais vida
+    SharpenedClaws, // Inimigos causam mais dano
+    EliteLegion,    // Um inimigo de elite extra por sala
+    RelentlessFoes, // Inimigos atacam mais rápido
+    WitheredBlessings, // Cura reduzida
+    BossEnrage,     // Chefe entra em fúria se a luta demorar
+}
+
+impl HeatModifier {
+    pub fn get_all() -> Vec<Self> {
+        vec![
+            HeatModifier::HardenedFoes,
+            HeatModifier::SharpenedClaws,
+            HeatModifier::EliteLegion,
+            HeatModifier::RelentlessFoes,
+            HeatModifier::WitheredBlessings,
+            HeatModifier::BossEnrage,
+        ]
+    }
+
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            HeatModifier::HardenedFoes => "Inimigos Endurecidos (+50% Vida)",
+            HeatModifier::SharpenedClaws => "Garras Afiadas (+40% Dano)",
+            HeatModifier::EliteLegion => "Legião de Elite",
+            HeatModifier::RelentlessFoes => "Fúria Implacável (Ataques +30%)",
+            HeatModifier::WitheredBlessings => "Bênçãos Murchas (-50% Cura)",
+            HeatModifier::BossEnrage => "Ampulheta do Chefe (Fúria após 90s)",
+        }
+    }
+
+    pub fn get_heat(&self) -> u32 {
+        match self {
+            HeatModifier::HardenedFoes => 2,
+            HeatModifier::SharpenedClaws => 2,
+            HeatModifier::EliteLegion => 3,
+            HeatModifier::RelentlessFoes => 1,
+            HeatModifier::WitheredBlessings => 1,
+            HeatModifier::BossEnrage => 1,
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct HeatModifiers {
+    pub active: Vec<HeatModifier>,
+}
+
+impl HeatModifiers {
+    pub fn toggle(&mut self, modifier: HeatModifier) {
+        if let Some(index) = self.active.iter().position(|m| *m == modifier) {
+            self.active.remove(index);
+        } else {
+            self.active.push(modifier);
+        }
+    }
+
+    pub fn is_active(&self, modifier: HeatModifier) -> bool {
+        self.active.contains(&modifier)
+    }
+
+    pub fn total_heat(&self) -> u32 {
+        self.active.iter().map(|m| m.get_heat()).sum()
+    }
+
+    pub fn enemy_health_multiplier(&self) -> f32 {
+        if self.is_active(HeatModifier::HardenedFoes) { 1.5 } else { 1.0 }
+    }
+
+    pub fn enemy_damage_multiplier(&self) -> f32 {
+        if self.is_active(HeatModifier::SharpenedClaws) { 1.4 } else { 1.0 }
+    }
+
+    pub fn enemy_attack_cooldown_multiplier(&self) -> f32 {
+        if self.is_active(HeatModifier::RelentlessFoes) { 0.7 } else { 1.0 }
+    }
+
+    pub fn healing_multiplier(&self) -> f32 {
+        if self.is_active(HeatModifier::WitheredBlessings) { 0.5 } else { 1.0 }
+    }
+
+    pub fn extra_elites(&self) -> bool {
+        self.is_active(HeatModifier::EliteLegion)
+    }
+
+    pub fn boss_enrage(&self) -> bool {
+        self.is_active(HeatModifier::BossEnrage)
+    }
+}
@@ -0,0 +1,136 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use crate::components::WeaponKind;
+
+pub mod heat;
+
+pub use heat::*;
+
+const META_SAVE_PATH: &str = "saves/meta_progress.json";
+
+// Recompensa base por completar uma run + bônus por ponto de calor
+const RUN_COMPLETION_ESSENCE: u32 = 20;
+const ESSENCE_PER_HEAT: u32 = 10;
+
+/// Progresso persistente entre runs (moeda do Duat, recordes, armas)
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MetaProgress {
+    pub essence: u32,        // Essência do Duat - moeda de meta-progressão
+    pub runs_completed: u32,
+    pub highest_heat: u32,
+    pub unlocked_weapons: Vec<WeaponKind>,
+}
+
+impl Default for MetaProgress {
+    fn default() -> Self {
+        Self {
+            essence: 0,
+            runs_completed: 0,
+            highest_heat: 0,
+            unlocked_weapons: vec![WeaponKind::Khopesh],
+        }
+    }
+}
+
+impl MetaProgress {
+    pub fn load() -> Self {
+        match fs::read_to_string(META_SAVE_PATH) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|err| {
+                warn!("⚠️ Corrupted meta progress save, starting fresh: {}", err);
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn save(&self) {
+        if let Some(parent) = std::path::Path::new(META_SAVE_PATH).parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+
+        match serde_json::to_string_pretty(self) {
+            Ok(contents) => {
+                if let Err(err) = fs::write(META_SAVE_PATH, contents) {
+                    warn!("⚠️ Failed to save meta progress: {}", err);
+                }
+            }
+            Err(err) => warn!("⚠️ Failed to serialize meta progress: {}", err),
+        }
+    }
+
+    pub fn completion_reward(heat: u32) -> u32 {
+        RUN_COMPLETION_ESSENCE + heat * ESSENCE_PER_HEAT
+    }
+
+    pub fn is_weapon_unlocked(&self, weapon: WeaponKind) -> bool {
+        self.unlocked_weapons.contains(&weapon)
+    }
+
+    /// Gasta essência para desbloquear a arma; retorna false se não houver saldo
+    pub fn try_unlock_weapon(&mut self, weapon: WeaponKind) -> bool {
+        if self.is_weapon_unlocked(weapon) {
+            return true;
+        }
+
+        let cost = weapon.unlock_cost();
+        if self.essence < cost {
+            return false;
+        }
+
+        self.essence -= cost;
+        self.unlocked_weapons.push(weapon);
+        self.save();
+        true
+    }
+}
+
+/// Escolhas feitas antes da run (arma inicial)
+#[derive(Resource, Default)]
+pub struct RunLoadout {
+    pub weapon: WeaponKind,
+}
+
+/// Números da run atual mostrados na tela de morte (zerados a cada run nova)
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct RunStats {
+    pub rooms_cleared: u32,
+    pub enemies_killed: u32,
+    pub boons_collected: u32,
+}
+
+/// Enviado quando o jogador derrota a sala do chefe
+#[derive(Event)]
+pub struct RunCompletedEvent {
+    pub heat: u32,
+}
+
+pub struct MetaProgressionPlugin;
+
+impl Plugin for MetaProgressionPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(MetaProgress::load())
+            .init_resource::<HeatModifiers>()
+            .init_resource::<RunLoadout>()
+            .init_resource::<RunStats>()
+            .add_event::<RunCompletedEvent>()
+            .add_systems(Update, award_run_completion);
+    }
+}
+
+fn award_run_completion(
+    mut run_events: EventReader<RunCompletedEvent>,
+    mut meta_progress: ResMut<MetaProgress>,
+) {
+    for event in run_events.read() {
+        let reward = MetaProgress::completion_reward(event.heat);
+        meta_progress.essence += reward;
+        meta_progress.runs_completed += 1;
+        meta_progress.highest_heat = meta_progress.highest_heat.max(event.heat);
+        meta_progress.save();
+
+        info!("🏺 Run completed at heat {} - awarded {} essence (total: {})",
+              event.heat, reward, meta_progress.essence);
+    }
+}
@@ -0,0 +1,242 @@
+/*!
+⚡ MOMENTUM SYSTEM
+Ímpeto dos boons OnKillBuff (Velocidade da Tempestade de Set, sinergia Ciclo Eterno):
+cada abate soma um acúmulo com timer próprio que acelera a caminhada e as recargas.
+No limite de acúmulos o mais antigo é renovado; durações enormes valem a run inteira
+*/
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::boons::{on_kill_momentum, ActiveBoons, OnKillMomentum};
+use crate::ui::AppState;
+use crate::{EnemyKilledEvent, SimulationSet};
+
+// A partir desta duração o acúmulo não expira
+const MOMENTUM_PERMANENT_DURATION: f32 = 900.0;
+// Teto do bônus somado de todas as fontes (+100% = dobro)
+const MOMENTUM_MAX_BONUS: f32 = 1.0;
+
+struct MomentumStacks {
+    speed_bonus: f32,
+    attack_speed_bonus: f32,
+    timers: Vec<f32>, // tempo restante de cada acúmulo
+}
+
+#[derive(Resource, Default)]
+pub struct Momentum {
+    sources: HashMap<String, MomentumStacks>,
+}
+
+impl Momentum {
+    fn register_kill(&mut self, buff: &OnKillMomentum) {
+        let duration = if buff.duration >= MOMENTUM_PERMANENT_DURATION { f32::INFINITY } else { buff.duration };
+        let stacks = self.sources.entry(buff.source.clone()).or_insert_with(|| MomentumStacks {
+            speed_bonus: buff.speed_bonus,
+            attack_speed_bonus: buff.attack_speed_bonus,
+            timers: Vec::new(),
+        });
+        // Nível novo da bênção ou tuning recarregado no meio da sequência valem já no próximo abate
+        stacks.speed_bonus = buff.speed_bonus;
+        stacks.attack_speed_bonus = buff.attack_speed_bonus;
+        // Teto menor (bênção perdeu nível): sobram só os acúmulos mais recentes
+        if stacks.timers.len() > buff.max_stacks as usize {
+            stacks.timers.sort_by(|a, b| b.total_cmp(a));
+            stacks.timers.truncate(buff.max_stacks as usize);
+        }
+        if stacks.timers.len() < buff.max_stacks as usize {
+            stacks.timers.push(duration);
+        } else if let Some(oldest) = stacks.timers.iter_mut().min_by(|a, b| a.total_cmp(b)) {
+            *oldest = duration;
+        }
+    }
+
+    fn tick(&mut self, dt: f32) {
+        for stacks in self.sources.values_mut() {
+            for timer in &mut stacks.timers {
+                *timer -= dt;
+            }
+            stacks.timers.retain(|timer| *timer > 0.0);
+        }
+        self.sources.retain(|_, stacks| !stacks.timers.is_empty());
+    }
+
+    pub fn stacks(&self) -> usize {
+        self.sources.values().map(|stacks| stacks.timers.len()).sum()
+    }
+
+    fn summed_bonus(&self, bonus: impl Fn(&MomentumStacks) -> f32) -> f32 {
+        self.sources.values()
+            .map(|stacks| bonus(stacks) * stacks.timers.len() as f32)
+            .sum::<f32>()
+            .min(MOMENTUM_MAX_BONUS)
+    }
+
+    // Multiplica a velocidade de caminhada
+    pub fn speed_multiplier(&self) -> f32 {
+        1.0 + self.summed_bonus(|stacks| stacks.speed_bonus)
+    }
+
+    // Multiplica a velocidade com que as recargas de ataque descem
+    pub fn attack_speed_multiplier(&self) -> f32 {
+        1.0 + self.summed_bonus(|stacks| stacks.attack_speed_bonus)
+    }
+}
+
+#[derive(Component)]
+struct MomentumText;
+
+pub struct MomentumPlugin;
+
+impl Plugin for MomentumPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Momentum>()
+            .add_systems(FixedUpdate, momentum_tracking_system.in_set(SimulationSet).run_if(in_state(AppState::InGame)))
+            .add_systems(OnEnter(AppState::InGame), setup_momentum_display)
+            .add_systems(OnExit(AppState::InGame), cleanup_momentum_display)
+            .add_systems(Update, update_momentum_display.run_if(in_state(AppState::InGame)))
+            .add_systems(OnEnter(AppState::Loading), reset_momentum);
+    }
+}
+
+fn momentum_tracking_system(
+    time: Res<Time>,
+    active_boons: Res<ActiveBoons>,
+    mut momentum: ResMut<Momentum>,
+    mut kill_events: EventReader<EnemyKilledEvent>,
+) {
+    let buffs = on_kill_momentum(&active_boons);
+    // Bênção perdida (ou sinergia desfeita) leva os acúmulos junto
+    momentum.sources.retain(|source, _| buffs.iter().any(|buff| buff.source == *source));
+
+    let kills = kill_events.read().count();
+    for _ in 0..kills {
+        for buff in &buffs {
+            momentum.register_kill(buff);
+        }
+    }
+    momentum.tick(time.delta_seconds());
+}
+
+fn reset_momentum(mut momentum: ResMut<Momentum>) {
+    momentum.sources.clear();
+}
+
+fn setup_momentum_display(mut commands: Commands) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 22.0,
+                color: Color::rgb(0.5, 0.8, 1.0),
+                ..default()
+            },
+        ).with_style(Style {
+            position_type: PositionType::Absolute,
+            right: Val::Px(60.0),
+            top: Val::Percent(55.0),
+            ..default()
+        }),
+        MomentumText,
+    ));
+}
+
+fn cleanup_momentum_display(mut commands: Commands, texts: Query<Entity, With<MomentumText>>) {
+    for entity in &texts {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn update_momentum_display(momentum: Res<Momentum>, mut texts: Query<&mut Text, With<MomentumText>>) {
+    if !momentum.is_changed() {
+        return;
+    }
+    for mut text in &mut texts {
+        text.sections[0].value = match momentum.stacks() {
+            0 => String::new(),
+            stacks => format!(
+                "Ímpeto x{}\n+{:.0}% velocidade",
+                stacks,
+                (momentum.speed_multiplier() - 1.0) * 100.0
+            ),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn storm_speed(max_stacks: u32, duration: f32) -> OnKillMomentum {
+        OnKillMomentum {
+            source: "set_storm_speed".to_string(),
+            speed_bonus: 0.1,
+            attack_speed_bonus: 0.05,
+            duration,
+            max_stacks,
+        }
+    }
+
+    #[test]
+    fn kills_add_stacks_and_bonus() {
+        let mut momentum = Momentum::default();
+        let buff = storm_speed(5, 4.0);
+        for _ in 0..3 {
+            momentum.register_kill(&buff);
+        }
+        assert_eq!(momentum.stacks(), 3);
+        assert!((momentum.speed_multiplier() - 1.3).abs() < 1e-5);
+        assert!((momentum.attack_speed_multiplier() - 1.15).abs() < 1e-5);
+    }
+
+    #[test]
+    fn stacks_stop_at_max_and_refresh_the_oldest() {
+        let mut momentum = Momentum::default();
+        let buff = storm_speed(3, 4.0);
+        for _ in 0..3 {
+            momentum.register_kill(&buff);
+            momentum.tick(1.0);
+        }
+        // Acúmulos com 1s, 2s e 3s restantes; o quarto abate renova o de 1s
+        momentum.register_kill(&buff);
+        assert_eq!(momentum.stacks(), 3);
+        let mut timers = momentum.sources["set_storm_speed"].timers.clone();
+        timers.sort_by(f32::total_cmp);
+        assert_eq!(timers, vec![2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn stacks_expire_one_by_one() {
+        let mut momentum = Momentum::default();
+        let buff = storm_speed(5, 2.0);
+        momentum.register_kill(&buff);
+        momentum.tick(1.0);
+        momentum.register_kill(&buff);
+        momentum.tick(1.0);
+        assert_eq!(momentum.stacks(), 1);
+        momentum.tick(1.0);
+        assert_eq!(momentum.stacks(), 0);
+        assert!(momentum.sources.is_empty());
+        assert_eq!(momentum.speed_multiplier(), 1.0);
+    }
+
+    #[test]
+    fn huge_durations_never_expire() {
+        let mut momentum = Momentum::default();
+        momentum.register_kill(&storm_speed(1, MOMENTUM_PERMANENT_DURATION));
+        momentum.tick(10_000.0);
+        assert_eq!(momentum.stacks(), 1);
+    }
+
+    #[test]
+    fn each_kill_uses_the_current_bonus() {
+        let mut momentum = Momentum::default();
+        momentum.register_kill(&storm_speed(5, 4.0));
+        momentum.register_kill(&storm_speed(5, 4.0));
+        // Bênção subiu de nível no meio da sequência: todos os acúmulos passam a valer o bônus novo
+        let stronger = OnKillMomentum { speed_bonus: 0.2, attack_speed_bonus: 0.1, ..storm_speed(5, 4.0) };
+        momentum.register_kill(&stronger);
+        assert_eq!(momentum.stacks(), 3);
+        assert!((momentum.speed_multiplier() - 1.6).abs() < 1e-5);
+        assert!((momentum.attack_speed_multiplier() - 1.3).abs() < 1e-5);
+    }
+}
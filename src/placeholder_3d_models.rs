@@ -103,8 +103,10 @@ fn spawn_placeholder_hero(
         },
         crate::true_3d_system::Hero3D,
         crate::Player,
+        crate::PlayerId::P1,
         crate::Stats::default(),
         crate::Dash::default(),
+        crate::Velocity::default(),
         crate::Combat::default(),
         Name::new("Placeholder_Hero"),
     )).id();
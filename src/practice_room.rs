@@ -0,0 +1,625 @@
+/*!
+🎯 PRACTICE ROOM
+"Sala de Treino" no menu principal: a run começa normalmente, mas a sala inicial ganha um
+boneco de treino que nunca morre (volta à vida cheia depois de alguns segundos sem apanhar)
+e mostra cada golpe, o DPS e o total da sequência.
+
+Reaproveita o estado InGame em vez de um estado próprio: toda a simulação (combate, status,
+boons, feedback) já roda só em InGame. O boneco é um Enemy sem AI nem EnemyType, então apanha
+de tudo mas não anda, não ataca e segura as portas da sala fechadas.
+
+- K: recursos infinitos (vigor e recargas de dash, especial, Q, R e blink)
+- L: painel para conceder qualquer bênção do registro, limpar, exportar e importar códigos de build
+- Ao sair, as bênçãos de antes do treino voltam (nada do treino vaza para a run seguinte)
+*/
+
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use bevy::window::ReceivedCharacter;
+use std::collections::VecDeque;
+use crate::boons::{
+    export_build_code, import_build_code, load_saved_build_code, save_build_code, ActiveBoons, BoonRegistry,
+    BoonSelectedEvent, EgyptianGod,
+};
+use crate::procedural::GeneratedDungeon;
+use crate::ui::{AppState, PracticeMode};
+use crate::{Combat, Dash, Enemy, Player, PlayerId, SimulationSet, Stats};
+
+// Vida enorme: o boneco não cai nem com as maiores sequências
+const DUMMY_HEALTH: f32 = 100_000.0;
+// Sem apanhar por esse tempo: vida cheia e sequência zerada
+const DUMMY_RESET_DELAY: f32 = 3.0;
+// Janela do DPS
+const DPS_WINDOW: f32 = 5.0;
+const DAMAGE_POPUP_LIFETIME: f32 = 0.8;
+const DAMAGE_POPUP_RISE: f32 = 50.0; // pixels subidos até sumir
+const DAMAGE_POPUP_HEIGHT: f32 = 2.0; // acima do boneco, em unidades do mundo
+const CODE_MAX_LENGTH: usize = 512;
+
+#[derive(Resource, Default)]
+struct PracticeState {
+    infinite_resources: bool,
+    panel_open: bool,
+    code_focused: bool,
+    code: String,
+    status: String,
+    import_requested: bool,
+    saved_boons: Option<ActiveBoons>, // Bênçãos de antes do treino, devolvidas na saída
+    clock: f32,
+    hits: VecDeque<(f32, f32)>, // (instante, dano) dentro da janela do DPS
+    combo_total: f32,
+}
+
+impl PracticeState {
+    fn dps(&self) -> f32 {
+        let Some(&(first, _)) = self.hits.front() else {
+            return 0.0;
+        };
+        let span = (self.clock - first).clamp(1.0, DPS_WINDOW);
+        self.hits.iter().map(|(_, damage)| damage).sum::<f32>() / span
+    }
+}
+
+#[derive(Component)]
+struct PracticeDummy {
+    last_health: f32,
+    idle_timer: f32,
+}
+
+#[derive(Component)]
+struct PracticeUi;
+
+#[derive(Component)]
+struct PracticeHudText;
+
+#[derive(Component)]
+struct PracticePanel;
+
+#[derive(Component)]
+struct PracticeCodeText;
+
+#[derive(Component)]
+struct PracticeStatusText;
+
+#[derive(Component)]
+struct DummyDamagePopup {
+    anchor: Vec3,
+    timer: f32,
+}
+
+#[derive(Component, Clone)]
+enum PracticeButton {
+    Grant(String),
+    ClearBoons,
+    FocusCode,
+    ImportCode,
+    LoadSavedCode,
+    ExportCode,
+    ToggleInfinite,
+    Exit,
+}
+
+pub struct PracticeRoomPlugin;
+
+impl Plugin for PracticeRoomPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PracticeState>()
+            .add_systems(OnEnter(AppState::InGame), setup_practice_room.run_if(practice_active))
+            .add_systems(OnEnter(AppState::MainMenu), leave_practice_room)
+            // "Novo Jogo" direto da tela de morte também desmonta o treino
+            // Antes do reset da run nova, que limpa as bênçãos devolvidas aqui
+            .add_systems(OnEnter(AppState::Loading), leave_practice_room.run_if(not(practice_active)).before(crate::reset_run_state))
+            // Digitando um código ou clicando no painel: o jogo não vê as teclas nem o clique
+            .add_systems(PreUpdate, capture_practice_input
+                .after(InputSystem)
+                .after(UiSystem::Focus)
+                .run_if(practice_active)
+                .run_if(in_state(AppState::InGame)))
+            .add_systems(Update, (
+                practice_hotkeys,
+                practice_button_system,
+                import_practice_code,
+                track_dummy_damage,
+                update_dummy_popups,
+                update_practice_texts,
+            ).chain().run_if(practice_active).run_if(in_state(AppState::InGame)))
+            .add_systems(FixedUpdate, refill_practice_resources
+                .in_set(SimulationSet)
+                .after(crate::hades_combat_system)
+                .run_if(practice_active)
+                .run_if(in_state(AppState::InGame)));
+    }
+}
+
+fn practice_active(practice: Res<PracticeMode>) -> bool {
+    practice.active
+}
+
+// OnEnter(InGame) também roda ao voltar de pausa e menus: só monta a sala uma vez
+#[allow(clippy::too_many_arguments)]
+fn setup_practice_room(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut state: ResMut<PracticeState>,
+    active_boons: Res<ActiveBoons>,
+    registry: Res<BoonRegistry>,
+    players: Query<(&PlayerId, &Transform), With<Player>>,
+    dummies: Query<(), With<PracticeDummy>>,
+) {
+    if !dummies.is_empty() {
+        return;
+    }
+    if state.saved_boons.is_none() {
+        state.saved_boons = Some(ActiveBoons {
+            player_boons: active_boons.player_boons.clone(),
+            synergy_bonuses: active_boons.synergy_bonuses.clone(),
+        });
+    }
+
+    let origin = players.iter()
+        .find(|(id, _)| id.is_primary())
+        .map_or(Vec3::ZERO, |(_, transform)| transform.translation);
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Capsule3d::new(0.5, 1.0)),
+            material: materials.add(StandardMaterial {
+                base_color: Color::rgb(0.75, 0.6, 0.35),
+                perceptual_roughness: 0.9,
+                ..default()
+            }),
+            transform: Transform::from_translation(Vec3::new(origin.x, 0.5, origin.z - 6.0)),
+            ..default()
+        },
+        Enemy,
+        Stats {
+            max_health: DUMMY_HEALTH,
+            current_health: DUMMY_HEALTH,
+            speed: 0.0,
+            ..default()
+        },
+        PracticeDummy { last_health: DUMMY_HEALTH, idle_timer: 0.0 },
+        Name::new("Practice Dummy"),
+    ));
+
+    spawn_practice_hud(&mut commands);
+    spawn_practice_panel(&mut commands, &registry);
+    info!("🎯 Practice room ready");
+}
+
+fn spawn_practice_hud(commands: &mut Commands) {
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 20.0,
+                color: Color::rgb(0.95, 0.85, 0.6),
+                ..default()
+            },
+        ).with_style(Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(20.0),
+            top: Val::Px(140.0),
+            ..default()
+        }),
+        PracticeUi,
+        PracticeHudText,
+    ));
+}
+
+fn spawn_practice_button(parent: &mut ChildBuilder, label: &str, border: Color, action: PracticeButton) {
+    parent.spawn((
+        ButtonBundle {
+            style: Style {
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            border_color: border.into(),
+            background_color: Color::rgba(0.12, 0.09, 0.06, 0.95).into(),
+            ..default()
+        },
+        action,
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            label,
+            TextStyle {
+                font_size: 15.0,
+                color: Color::rgb(0.95, 0.9, 0.75),
+                ..default()
+            },
+        ));
+    });
+}
+
+fn spawn_practice_panel(commands: &mut Commands, registry: &BoonRegistry) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                right: Val::Px(20.0),
+                top: Val::Px(80.0),
+                width: Val::Px(760.0),
+                max_height: Val::Percent(85.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            border_color: Color::rgb(0.8, 0.6, 0.2).into(),
+            background_color: Color::rgba(0.05, 0.04, 0.03, 0.92).into(),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        PracticeUi,
+        PracticePanel,
+    )).with_children(|parent| {
+        // Uma linha de bênçãos por deus, na ordem do registro
+        for god in EgyptianGod::get_all() {
+            parent.spawn(TextBundle::from_section(
+                god.get_display_name(),
+                TextStyle {
+                    font_size: 17.0,
+                    color: god.get_theme_color(),
+                    ..default()
+                },
+            ));
+            parent.spawn(NodeBundle {
+                style: Style {
+                    flex_wrap: FlexWrap::Wrap,
+                    column_gap: Val::Px(6.0),
+                    row_gap: Val::Px(6.0),
+                    ..default()
+                },
+                ..default()
+            }).with_children(|parent| {
+                for boon in registry.available_boons.get(&god).into_iter().flatten() {
+                    spawn_practice_button(parent, &boon.name, boon.rarity.get_color(), PracticeButton::Grant(boon.id.clone()));
+                }
+            });
+        }
+
+        // Código de build: clicar no campo para digitar, Enter importa
+        parent.spawn(NodeBundle {
+            style: Style {
+                column_gap: Val::Px(6.0),
+                align_items: AlignItems::Center,
+                margin: UiRect::top(Val::Px(6.0)),
+                ..default()
+            },
+            ..default()
+        }).with_children(|parent| {
+            parent.spawn((
+                ButtonBundle {
+                    style: Style {
+                        width: Val::Px(380.0),
+                        padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        overflow: Overflow::clip(),
+                        ..default()
+                    },
+                    border_color: Color::rgb(0.5, 0.45, 0.35).into(),
+                    background_color: Color::rgba(0.0, 0.0, 0.0, 0.8).into(),
+                    ..default()
+                },
+                PracticeButton::FocusCode,
+            )).with_children(|parent| {
+                parent.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font_size: 15.0,
+                            color: Color::WHITE,
+                            ..default()
+                        },
+                    ),
+                    PracticeCodeText,
+                ));
+            });
+            spawn_practice_button(parent, "Importar", Color::rgb(0.8, 0.6, 0.2), PracticeButton::ImportCode);
+            spawn_practice_button(parent, "Do arquivo", Color::rgb(0.8, 0.6, 0.2), PracticeButton::LoadSavedCode);
+            spawn_practice_button(parent, "Exportar", Color::rgb(0.8, 0.6, 0.2), PracticeButton::ExportCode);
+        });
+
+        parent.spawn(NodeBundle {
+            style: Style {
+                column_gap: Val::Px(6.0),
+                ..default()
+            },
+            ..default()
+        }).with_children(|parent| {
+            spawn_practice_button(parent, "Limpar bênçãos", Color::rgb(0.7, 0.3, 0.2), PracticeButton::ClearBoons);
+            spawn_practice_button(parent, "Recursos infinitos (K)", Color::rgb(0.3, 0.6, 0.9), PracticeButton::ToggleInfinite);
+            spawn_practice_button(parent, "Sair do treino", Color::rgb(0.7, 0.3, 0.2), PracticeButton::Exit);
+        });
+
+        parent.spawn((
+            TextBundle::from_section(
+                "",
+                TextStyle {
+                    font_size: 15.0,
+                    color: Color::rgb(0.9, 0.8, 0.5),
+                    ..default()
+                },
+            ),
+            PracticeStatusText,
+        ));
+    });
+}
+
+// Bênçãos do treino não vão para a próxima run
+#[allow(clippy::type_complexity)]
+fn leave_practice_room(
+    mut commands: Commands,
+    mut practice: ResMut<PracticeMode>,
+    mut state: ResMut<PracticeState>,
+    mut active_boons: ResMut<ActiveBoons>,
+    entities: Query<Entity, Or<(With<PracticeDummy>, With<PracticeUi>, With<DummyDamagePopup>)>>,
+) {
+    for entity in &entities {
+        commands.entity(entity).despawn_recursive();
+    }
+    if let Some(saved) = state.saved_boons.take() {
+        *active_boons = saved;
+    }
+    *state = PracticeState::default();
+    practice.active = false;
+}
+
+fn capture_practice_input(
+    mut state: ResMut<PracticeState>,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut mouse: ResMut<ButtonInput<MouseButton>>,
+    mut characters: EventReader<ReceivedCharacter>,
+    buttons: Query<&Interaction, With<PracticeButton>>,
+) {
+    if buttons.iter().any(|interaction| *interaction != Interaction::None) {
+        mouse.reset_all();
+    } else if mouse.get_just_pressed().next().is_some() {
+        // Clique fora do campo devolve o teclado ao jogo
+        state.code_focused = false;
+    }
+
+    if !state.code_focused {
+        characters.clear();
+        return;
+    }
+    for event in characters.read() {
+        for character in event.char.chars().filter(|character| character.is_ascii_graphic()) {
+            if state.code.len() < CODE_MAX_LENGTH {
+                state.code.push(character);
+            }
+        }
+    }
+    if keys.just_pressed(KeyCode::Backspace) {
+        state.code.pop();
+    }
+    if keys.just_pressed(KeyCode::Enter) {
+        state.import_requested = true;
+        state.code_focused = false;
+    }
+    if keys.just_pressed(KeyCode::Escape) {
+        state.code_focused = false;
+    }
+    keys.reset_all();
+}
+
+fn practice_hotkeys(keys: Res<ButtonInput<KeyCode>>, mut state: ResMut<PracticeState>) {
+    if keys.just_pressed(KeyCode::KeyL) {
+        state.panel_open = !state.panel_open;
+    }
+    if keys.just_pressed(KeyCode::KeyK) {
+        state.infinite_resources = !state.infinite_resources;
+        info!("🎯 Infinite resources {}", if state.infinite_resources { "on" } else { "off" });
+    }
+}
+
+fn practice_button_system(
+    mut state: ResMut<PracticeState>,
+    buttons: Query<(&Interaction, &PracticeButton), Changed<Interaction>>,
+    registry: Res<BoonRegistry>,
+    mut active_boons: ResMut<ActiveBoons>,
+    dungeon: Option<Res<GeneratedDungeon>>,
+    mut selected_events: EventWriter<BoonSelectedEvent>,
+    mut app_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, button) in &buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            PracticeButton::Grant(id) => {
+                if let Some(boon) = registry.find_boon(id) {
+                    state.status = format!("Concedida: {}", boon.name);
+                    selected_events.send(BoonSelectedEvent { boon: boon.clone() });
+                }
+            }
+            PracticeButton::ClearBoons => {
+                active_boons.player_boons.clear();
+                state.status = "Bênçãos removidas".to_string();
+            }
+            PracticeButton::FocusCode => state.code_focused = true,
+            PracticeButton::ImportCode => state.import_requested = true,
+            PracticeButton::LoadSavedCode => match load_saved_build_code() {
+                Some(code) => {
+                    state.code = code;
+                    state.import_requested = true;
+                }
+                None => state.status = "Nenhum código em saves/build_code.txt".to_string(),
+            },
+            PracticeButton::ExportCode => {
+                let seed = dungeon.as_ref().map_or(0, |dungeon| dungeon.generation_seed);
+                state.code = export_build_code(&active_boons, seed);
+                save_build_code(&state.code);
+                state.status = "Código salvo em saves/build_code.txt".to_string();
+            }
+            PracticeButton::ToggleInfinite => state.infinite_resources = !state.infinite_resources,
+            PracticeButton::Exit => app_state.set(AppState::MainMenu),
+        }
+    }
+}
+
+fn import_practice_code(
+    mut state: ResMut<PracticeState>,
+    registry: Res<BoonRegistry>,
+    mut active_boons: ResMut<ActiveBoons>,
+) {
+    if !state.import_requested {
+        return;
+    }
+    state.import_requested = false;
+    state.status = match import_build_code(&state.code, &registry) {
+        Ok(build) => {
+            let message = format!("Build importada: {} bênçãos (semente {:x})", build.boons.len(), build.seed);
+            build.apply(&mut active_boons);
+            message
+        }
+        Err(err) => err.to_string(),
+    };
+}
+
+// Cada queda de vida do boneco vira um número, entra no DPS e soma na sequência
+fn track_dummy_damage(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut state: ResMut<PracticeState>,
+    mut dummies: Query<(&Transform, &mut Stats, &mut PracticeDummy)>,
+) {
+    let dt = time.delta_seconds();
+    state.clock += dt;
+    let clock = state.clock;
+    while state.hits.front().is_some_and(|(instant, _)| clock - instant > DPS_WINDOW) {
+        state.hits.pop_front();
+    }
+
+    for (transform, mut stats, mut dummy) in &mut dummies {
+        let damage = dummy.last_health - stats.current_health;
+        if damage > 0.0 {
+            state.hits.push_back((clock, damage));
+            state.combo_total += damage;
+            dummy.idle_timer = 0.0;
+            spawn_dummy_popup(&mut commands, transform.translation, damage);
+        } else {
+            dummy.idle_timer += dt;
+            if dummy.idle_timer >= DUMMY_RESET_DELAY && stats.current_health < stats.max_health {
+                stats.current_health = stats.max_health;
+                state.combo_total = 0.0;
+            }
+        }
+        dummy.last_health = stats.current_health;
+    }
+}
+
+fn spawn_dummy_popup(commands: &mut Commands, position: Vec3, damage: f32) {
+    let mut text = TextBundle::from_section(
+        format!("{}", damage.round()),
+        TextStyle {
+            font_size: 24.0,
+            color: Color::rgb(1.0, 0.85, 0.3),
+            ..default()
+        },
+    ).with_style(Style {
+        position_type: PositionType::Absolute,
+        ..default()
+    });
+    // Só aparece depois de posicionado na tela
+    text.visibility = Visibility::Hidden;
+    // Números seguidos não se sobrepõem por completo
+    let jitter = Vec3::new((position.x * 7.3 + damage).sin() * 0.4, 0.0, 0.0);
+    commands.spawn((
+        text,
+        DummyDamagePopup {
+            anchor: position + Vec3::Y * DAMAGE_POPUP_HEIGHT + jitter,
+            timer: DAMAGE_POPUP_LIFETIME,
+        },
+    ));
+}
+
+fn update_dummy_popups(
+    mut commands: Commands,
+    time: Res<Time>,
+    ui_scale: Res<UiScale>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    mut popups: Query<(Entity, &mut DummyDamagePopup, &mut Style, &mut Text, &mut Visibility)>,
+) {
+    let camera = cameras.iter()
+        .filter(|(camera, _)| camera.is_active)
+        .max_by_key(|(camera, _)| camera.order);
+
+    for (entity, mut popup, mut style, mut text, mut visibility) in &mut popups {
+        popup.timer -= time.delta_seconds();
+        if popup.timer <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        let Some(screen) = camera.and_then(|(camera, transform)| camera.world_to_viewport(transform, popup.anchor)) else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        let progress = 1.0 - popup.timer / DAMAGE_POPUP_LIFETIME;
+        style.left = Val::Px(screen.x / ui_scale.0);
+        style.top = Val::Px(screen.y / ui_scale.0 - DAMAGE_POPUP_RISE * progress);
+        for section in &mut text.sections {
+            section.style.color.set_a(1.0 - progress);
+        }
+        *visibility = Visibility::Visible;
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_practice_texts(
+    state: Res<PracticeState>,
+    active_boons: Res<ActiveBoons>,
+    mut hud_texts: Query<&mut Text, (With<PracticeHudText>, Without<PracticeCodeText>, Without<PracticeStatusText>)>,
+    mut code_texts: Query<&mut Text, (With<PracticeCodeText>, Without<PracticeStatusText>)>,
+    mut status_texts: Query<&mut Text, With<PracticeStatusText>>,
+    mut panels: Query<&mut Visibility, With<PracticePanel>>,
+) {
+    for mut text in &mut hud_texts {
+        text.sections[0].value = format!(
+            "SALA DE TREINO\nDPS: {:.0}\nSequência: {:.0}\nBênçãos: {} ({} sinergias)\n[K] Recursos infinitos: {}\n[L] Painel de bênçãos",
+            state.dps(),
+            state.combo_total,
+            active_boons.player_boons.len(),
+            active_boons.synergy_bonuses.len(),
+            if state.infinite_resources { "Ligados" } else { "Desligados" },
+        );
+    }
+    for mut text in &mut code_texts {
+        let cursor = if state.code_focused { "_" } else { "" };
+        text.sections[0].value = if state.code.is_empty() && !state.code_focused {
+            "Clique para digitar um código de build".to_string()
+        } else {
+            format!("{}{}", state.code, cursor)
+        };
+    }
+    for mut text in &mut status_texts {
+        if text.sections[0].value != state.status {
+            text.sections[0].value = state.status.clone();
+        }
+    }
+    for mut visibility in &mut panels {
+        *visibility = if state.panel_open { Visibility::Visible } else { Visibility::Hidden };
+    }
+}
+
+fn refill_practice_resources(
+    state: Res<PracticeState>,
+    mut players: Query<(&mut Stats, &mut Dash, &mut Combat), With<Player>>,
+) {
+    if !state.infinite_resources {
+        return;
+    }
+    for (mut stats, mut dash, mut combat) in &mut players {
+        stats.current_stamina = stats.max_stamina;
+        dash.cooldown_timer = 0.0;
+        combat.special_timer = 0.0;
+        combat.q_timer = 0.0;
+        combat.r_timer = 0.0;
+        combat.blink_timer = 0.0;
+    }
+}
@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 use bevy::audio::Volume;
 use super::room_types::BiomeType;
+use crate::settings::GameSettings;
+use crate::ui::RequiredAssets;
 
 #[derive(Resource)]
 pub struct BiomeAssets {
@@ -94,6 +96,7 @@ impl Plugin for BiomeSystemPlugin {
 fn load_biome_assets(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    mut required: ResMut<RequiredAssets>,
 ) {
     info!("🎨 Loading biome assets...");
     
@@ -123,6 +126,36 @@ fn load_biome_assets(
         shadow_portal_model: asset_server.load("models/environment/shadow_portal.glb#Scene0"),
     };
     
+    for texture in [
+        &biome_assets.desert_floor_texture,
+        &biome_assets.desert_wall_texture,
+        &biome_assets.temple_floor_texture,
+        &biome_assets.temple_wall_texture,
+        &biome_assets.underworld_floor_texture,
+        &biome_assets.underworld_wall_texture,
+    ] {
+        required.track(texture);
+    }
+    for music in [
+        &biome_assets.desert_ambient_music,
+        &biome_assets.temple_ambient_music,
+        &biome_assets.underworld_ambient_music,
+    ] {
+        required.track(music);
+    }
+    for model in [
+        &biome_assets.sand_dune_model,
+        &biome_assets.palm_tree_model,
+        &biome_assets.desert_ruins_model,
+        &biome_assets.pillar_model,
+        &biome_assets.hieroglyph_wall_model,
+        &biome_assets.altar_model,
+        &biome_assets.bone_pile_model,
+        &biome_assets.soul_crystal_model,
+        &biome_assets.shadow_portal_model,
+    ] {
+        required.track(model);
+    }
     commands.insert_resource(biome_assets);
     info!("✅ Biome assets loaded");
 }
@@ -405,14 +438,15 @@ fn spawn_underworld_decorations(parent: &mut ChildBuilder, biome_assets: &BiomeA
 
 fn update_biome_lighting(
     biome_settings: Res<CurrentBiomeSettings>,
+    game_settings: Res<GameSettings>,
     mut ambient_light: ResMut<AmbientLight>,
 ) {
-    if biome_settings.is_changed() || biome_settings.transition_progress < 1.0 {
+    if biome_settings.is_changed() || game_settings.is_changed() || biome_settings.transition_progress < 1.0 {
         let progress = biome_settings.transition_progress;
         
-        // Smooth ambient light transition
+        // Smooth ambient light transition, scaled by the player's brightness setting
         ambient_light.color = biome_settings.ambient_light;
-        ambient_light.brightness = 0.3 + (progress * 0.7);
+        ambient_light.brightness = (0.3 + (progress * 0.7)) * game_settings.brightness();
         
         // Note: Fog settings would be handled by a separate fog system
         // if available in this version of Bevy
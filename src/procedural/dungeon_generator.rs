@@ -1,8 +1,10 @@
 use bevy::prelude::*;
 use super::room_types::*;
+use crate::ui::AppState;
 // Removed unused import
 use std::collections::{HashMap, HashSet, VecDeque};
 use rand::{Rng, seq::SliceRandom, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 
 #[derive(Debug, Clone)]
 pub struct DungeonLayout {
@@ -49,6 +51,26 @@ pub struct GeneratedDungeon {
     pub current_room: RoomId,
     pub unlocked_rooms: HashSet<RoomId>,
     pub generation_seed: u64,
+    pub room_states: HashMap<RoomId, RoomState>,
+}
+
+// Persistent per-room flags so revisiting a room (backtracking) never respawns enemies or rewards
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RoomState {
+    pub cleared: bool,
+    pub enemies_spawned: bool,
+    pub loot_taken: bool,
+    pub events_resolved: bool,
+}
+
+impl GeneratedDungeon {
+    pub fn room_state(&self, room: RoomId) -> RoomState {
+        self.room_states.get(&room).copied().unwrap_or_default()
+    }
+
+    pub fn room_state_mut(&mut self, room: RoomId) -> &mut RoomState {
+        self.room_states.entry(room).or_default()
+    }
 }
 
 #[derive(Resource)]
@@ -74,12 +96,73 @@ impl Default for DungeonGenerationConfig {
     }
 }
 
+/// Semente da dungeon de cada run. Sem semente fixa, toda run sorteia uma nova (entropia do
+/// sistema); com uma, a run reproduz o mesmo layout (speedrun, relato de bug, replay)
+#[derive(Resource, Default)]
+pub struct RunConfig {
+    pub fixed_seed: Option<u64>,
+    // Mantém a semente fixa nas runs seguintes; desligado, ela vale só para a próxima run
+    pub keep_seed: bool,
+}
+
+impl RunConfig {
+    fn next_seed(&mut self) -> u64 {
+        let seed = self.fixed_seed.unwrap_or_else(rand::random);
+        if !self.keep_seed {
+            self.fixed_seed = None;
+        }
+        seed
+    }
+}
+
+/// Finalidades dos sorteios de jogo da run; cada uma tem o próprio fluxo do gerador
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RunRngStream {
+    RoomSpawns, // inimigos e armadilhas das salas
+    EliteAffixes,
+    Loot, // tabela de saque dos inimigos
+    ConsumableDrops, // tipo dos consumíveis que o saque solta
+    Chests,
+    Rewards, // recompensas das salas e ofertas de bênçãos
+    Combat, // críticos e chances dos golpes
+}
+
+/// Gerador de uma finalidade, derivado da semente da run
+pub fn run_stream_rng(seed: u64, stream: RunRngStream) -> ChaCha8Rng {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    rng.set_stream(stream as u64);
+    rng
+}
+
+/// Sorteios de jogo da run (spawns, afixos, saque, baús, ofertas), todos derivados da semente
+/// da dungeon: a mesma semente e a mesma entrada repetem a run inteira. Fluxos separados por
+/// finalidade, para um sorteio a mais num sistema não deslocar os dos outros
+#[derive(Resource)]
+pub struct RunRng {
+    seed: u64,
+    streams: HashMap<RunRngStream, ChaCha8Rng>,
+}
+
+impl RunRng {
+    pub fn new(seed: u64) -> Self {
+        Self { seed, streams: HashMap::new() }
+    }
+
+    pub fn stream(&mut self, stream: RunRngStream) -> &mut ChaCha8Rng {
+        let seed = self.seed;
+        self.streams.entry(stream).or_insert_with(|| run_stream_rng(seed, stream))
+    }
+}
+
 pub struct DungeonGeneratorPlugin;
 
 impl Plugin for DungeonGeneratorPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<DungeonGenerationConfig>()
-            .add_systems(Startup, generate_initial_dungeon)
+            .init_resource::<RunConfig>()
+            // Já existe desde o início (sistemas do gerador); cada run nova gera a própria
+            .add_systems(Startup, generate_run_dungeon)
+            .add_systems(OnEnter(AppState::Loading), generate_run_dungeon)
             .add_systems(Update, (
                 handle_room_unlock_events,
                 validate_dungeon_integrity,
@@ -88,13 +171,14 @@ impl Plugin for DungeonGeneratorPlugin {
     }
 }
 
-fn generate_initial_dungeon(
+pub fn generate_run_dungeon(
     mut commands: Commands,
     config: Res<DungeonGenerationConfig>,
+    mut run_config: ResMut<RunConfig>,
 ) {
-    info!("🎲 Generating procedural dungeon...");
+    let seed = run_config.next_seed();
+    info!("🎲 Generating procedural dungeon (seed {})...", seed);
     
-    let seed = 42u64; // In practice, this would be random or from settings
     let layout = DungeonGenerator::generate_dungeon(&config, seed);
     
     info!("✅ Generated dungeon with {} rooms", layout.rooms.len());
@@ -109,7 +193,9 @@ fn generate_initial_dungeon(
         unlocked_rooms,
         generation_seed: seed,
         layout,
+        room_states: HashMap::new(),
     });
+    commands.insert_resource(RunRng::new(seed));
 }
 
 fn handle_room_unlock_events(
@@ -148,7 +234,7 @@ pub struct DungeonGenerator;
 
 impl DungeonGenerator {
     pub fn generate_dungeon(config: &DungeonGenerationConfig, seed: u64) -> DungeonLayout {
-        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(seed);
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
         
         // Step 1: Generate critical path (start -> boss rooms -> end)
         let critical_path = Self::generate_critical_path(config, &mut rng);
@@ -190,7 +276,8 @@ impl DungeonGenerator {
             template: RoomTemplateGenerator::generate_room_template(
                 RoomType::Combat, 
                 BiomeType::Desert, 
-                1
+                1,
+                rng,
             ),
             position: Vec2::ZERO,
             depth: 0,
@@ -214,6 +301,7 @@ impl DungeonGenerator {
                     RoomType::Boss,
                     biome,
                     floor,
+                    rng,
                 ),
                 position: Vec2::new(0.0, floor as f32 * 100.0),
                 depth: floor,
@@ -239,7 +327,7 @@ impl DungeonGenerator {
                 
                 let room = DungeonRoom {
                     id: RoomId(room_id_counter),
-                    template: RoomTemplateGenerator::generate_room_template(room_type, biome, depth),
+                    template: RoomTemplateGenerator::generate_room_template(room_type, biome, depth, rng),
                     position: Vec2::new(0.0, depth as f32 * 100.0),
                     depth,
                     is_critical_path: true,
@@ -284,6 +372,7 @@ impl DungeonGenerator {
                         branch_room_type,
                         biome,
                         critical_room.depth,
+                        rng,
                     ),
                     position: branch_position,
                     depth: critical_room.depth,
@@ -459,6 +548,7 @@ impl DungeonGenerator {
                         RoomType::Secret,
                         biome,
                         parent_room.depth,
+                        rng,
                     ),
                     position: secret_position,
                     depth: parent_room.depth,
@@ -96,7 +96,8 @@ fn initialize_room_system(
     let initial_room = RoomTemplateGenerator::generate_room_template(
         RoomType::Combat,
         BiomeType::Desert,
-        1
+        1,
+        &mut rand::thread_rng(),
     );
     
     info!("🏜️ Starting room: {}", initial_room.name);
@@ -221,7 +222,7 @@ fn track_room_progress(
 }
 
 fn generate_next_room(dungeon_state: &DungeonState, preferred_type: Option<RoomType>) -> RoomTemplate {
-    use rand::{thread_rng, Rng};
+    use rand::thread_rng;
     let mut rng = thread_rng();
     
     let floor = dungeon_state.current_floor + 1;
@@ -229,14 +230,14 @@ fn generate_next_room(dungeon_state: &DungeonState, preferred_type: Option<RoomT
     
     // Handle preferred room type (from events, story, etc.)
     if let Some(room_type) = preferred_type {
-        return RoomTemplateGenerator::generate_room_template(room_type, biome, floor);
+        return RoomTemplateGenerator::generate_room_template(room_type, biome, floor, &mut rng);
     }
     
     // Handle special floor rules
     match floor {
         4 | 8 | 12 => {
             // Boss floors
-            return RoomTemplateGenerator::generate_room_template(RoomType::Boss, biome, floor);
+            return RoomTemplateGenerator::generate_room_template(RoomType::Boss, biome, floor, &mut rng);
         },
         _ => {}
     }
@@ -257,7 +258,7 @@ fn generate_next_room(dungeon_state: &DungeonState, preferred_type: Option<RoomT
     // Select room type based on weights
     let selected_room_type = select_weighted_room_type(&room_weights, &mut rng);
     
-    RoomTemplateGenerator::generate_room_template(selected_room_type, biome, floor)
+    RoomTemplateGenerator::generate_room_template(selected_room_type, biome, floor, &mut rng)
 }
 
 fn determine_biome_for_floor(floor: u32) -> BiomeType {
@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use rand::{Rng, thread_rng};
+use rand::Rng;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RoomType {
@@ -113,15 +113,13 @@ impl BiomeType {
 pub struct RoomTemplateGenerator;
 
 impl RoomTemplateGenerator {
-    pub fn generate_room_template(room_type: RoomType, biome: BiomeType, floor: u32) -> RoomTemplate {
-        let mut rng = thread_rng();
-        
+    pub fn generate_room_template(room_type: RoomType, biome: BiomeType, floor: u32, rng: &mut impl Rng) -> RoomTemplate {
         match (room_type, biome) {
             // Desert Combat Rooms
             (RoomType::Combat, BiomeType::Desert) => {
                 let templates = vec![
                     ("Dunas Mortais", "Escorpiões gigantes emergem das areias douradas", 
-                     vec!["Desert_Scorpion", "Sand_Mummy"], 2, 4),
+                     vec!["Desert_Scorpion", "Sand_Mummy", "Sand_Jackal"], 2, 4),
                     ("Oásis Envenenado", "Águas contaminadas atraem criaturas perigosas",
                      vec!["Poisonous_Snake", "Desert_Bandit"], 3, 5),
                     ("Tempestade de Areia", "Visibilidade limitada, inimigos aparecem das dunas",
@@ -177,7 +175,7 @@ impl RoomTemplateGenerator {
                     ("Salão do Julgamento", "Anúbis observa enquanto você luta pela vida",
                      vec!["Judgment_Wraith", "Underworld_Guardian"], 2, 4),
                     ("Cavernas do Esquecimento", "Ecos de vidas passadas assombram o ar",
-                     vec!["Memory_Phantom", "Bone_Stalker"], 4, 7),
+                     vec!["Memory_Phantom", "Bone_Stalker", "Sand_Jackal"], 4, 7),
                 ];
                 let (name, desc, enemies, min, max) = templates[rng.gen_range(0..templates.len())].clone();
                 
@@ -0,0 +1,211 @@
+/*!
+🔁 PROJECTILE POOL
+Projéteis retos (Q do jogador, atiradores, obeliscos, dardos das armadilhas) saem de um pool de
+entidades pré-alocadas em vez de spawn/despawn a cada tiro. Disparar reativa uma entidade
+escondida (Projectile, transform, visibilidade); acertar ou expirar devolve ela ao pool. Malhas
+e materiais de cada tipo de tiro são criados uma vez só e reaproveitados
+*/
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::components::WeaponKind;
+use crate::elite_affixes::VampiricShot;
+use crate::interpolation::PreviousTransform;
+use crate::status_effects::OnHitStatus;
+use crate::ui::AppState;
+use crate::{EnemyProjectile, Projectile};
+
+// Entidades criadas de início; com todas em voo o pool cresce sob demanda
+const PROJECTILE_POOL_SIZE: usize = 128;
+
+/// Aparência de cada tipo de tiro (uma malha e um material compartilhados por tipo)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProjectileVisual {
+    PlayerQ(WeaponKind),
+    EnemyShot,
+    TotemShot,
+    Dart,
+}
+
+impl ProjectileVisual {
+    fn build(&self) -> (Mesh, StandardMaterial) {
+        match self {
+            ProjectileVisual::PlayerQ(weapon) => {
+                let (size, color) = match weapon {
+                    WeaponKind::Khopesh => (0.15, Color::rgb(0.3, 0.8, 1.0)),
+                    WeaponKind::Spear => (0.12, Color::rgb(1.0, 0.85, 0.4)), // Lança arremessada
+                    WeaponKind::WasScepter => (0.25, Color::rgb(0.7, 0.3, 1.0)), // Orbe do cetro
+                    WeaponKind::ShieldKhopesh => (0.15, Color::rgb(0.9, 0.75, 0.4)),
+                };
+                (Sphere::new(size).into(), StandardMaterial {
+                    base_color: color,
+                    emissive: color * 5.0,
+                    ..default()
+                })
+            }
+            ProjectileVisual::EnemyShot => (Sphere::new(0.1).into(), StandardMaterial {
+                base_color: Color::rgb(0.8, 0.1, 0.1),
+                emissive: Color::rgb(2.0, 0.5, 0.5),
+                ..default()
+            }),
+            ProjectileVisual::TotemShot => (Sphere::new(0.12).into(), StandardMaterial {
+                base_color: Color::rgb(1.0, 0.8, 0.3),
+                emissive: Color::rgb(5.0, 3.5, 1.0),
+                ..default()
+            }),
+            ProjectileVisual::Dart => (Cuboid::new(0.06, 0.06, 0.4).into(), StandardMaterial {
+                base_color: Color::rgb(0.5, 0.45, 0.3),
+                ..default()
+            }),
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct ProjectilePool {
+    free: Vec<Entity>,
+    // Devolvidos neste passo: só voltam a ser usados depois que os comandos da devolução rodarem
+    released: Vec<Entity>,
+    visuals: HashMap<ProjectileVisual, (Handle<Mesh>, Handle<StandardMaterial>)>,
+}
+
+// Entidade que pertence ao pool (ativa ou escondida)
+#[derive(Component)]
+pub struct PooledProjectile;
+
+pub struct ProjectilePoolPlugin;
+
+impl Plugin for ProjectilePoolPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ProjectilePool>()
+            .add_systems(Startup, preallocate_projectiles)
+            .add_systems(FixedPostUpdate, recycle_released_projectiles)
+            .add_systems(Last, recycle_released_projectiles)
+            // Run nova: tiros ainda em voo da run anterior voltam ao pool
+            .add_systems(OnEnter(AppState::Loading), reclaim_all_projectiles);
+    }
+}
+
+/// Dispara um projétil reto: reativa uma entidade livre do pool (ou cria uma se todas estão em voo).
+/// Devolve a entidade para o chamador anexar extras (EnemyProjectile, OnHitStatus...)
+pub fn fire_projectile(
+    commands: &mut Commands,
+    pool: &mut ProjectilePool,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    visual: ProjectileVisual,
+    transform: Transform,
+    projectile: Projectile,
+) -> Entity {
+    let (mesh, material) = pool.visuals.entry(visual)
+        .or_insert_with(|| {
+            let (mesh, material) = visual.build();
+            (meshes.add(mesh), materials.add(material))
+        })
+        .clone();
+    match pool.free.pop() {
+        Some(entity) => {
+            commands.entity(entity).insert((mesh, material, transform, Visibility::Inherited, projectile));
+            entity
+        }
+        None => commands.spawn((
+            PbrBundle { mesh, material, transform, ..default() },
+            projectile,
+            PooledProjectile,
+            Name::new("Pooled Projectile"),
+        )).id(),
+    }
+}
+
+/// Devolve um projétil ao pool (acertou ou expirou) no lugar do despawn
+pub fn reclaim_projectile(commands: &mut Commands, pool: &mut ProjectilePool, entity: Entity) {
+    if pool.released.contains(&entity) || pool.free.contains(&entity) {
+        return;
+    }
+    // Sem Projectile a entidade some de todas as consultas; a interpolação recomeça no próximo tiro
+    commands.entity(entity)
+        .remove::<(Projectile, EnemyProjectile, VampiricShot, OnHitStatus, PreviousTransform)>()
+        .insert(Visibility::Hidden);
+    pool.released.push(entity);
+}
+
+fn preallocate_projectiles(mut commands: Commands, mut pool: ResMut<ProjectilePool>) {
+    for _ in 0..PROJECTILE_POOL_SIZE {
+        let entity = commands.spawn((
+            PbrBundle { visibility: Visibility::Hidden, ..default() },
+            PooledProjectile,
+            Name::new("Pooled Projectile"),
+        )).id();
+        pool.free.push(entity);
+    }
+    info!("🔁 Projectile pool: {} entidades pré-alocadas", PROJECTILE_POOL_SIZE);
+}
+
+fn recycle_released_projectiles(mut pool: ResMut<ProjectilePool>) {
+    if pool.released.is_empty() {
+        return;
+    }
+    let ProjectilePool { free, released, .. } = &mut *pool;
+    free.append(released);
+}
+
+fn reclaim_all_projectiles(
+    mut commands: Commands,
+    mut pool: ResMut<ProjectilePool>,
+    projectiles: Query<Entity, (With<Projectile>, With<PooledProjectile>)>,
+) {
+    for entity in &projectiles {
+        reclaim_projectile(&mut commands, &mut pool, entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHOTS_PER_FRAME: usize = 10;
+
+    // Cada frame os tiros em voo acertam (voltam ao pool) e saem mais dez
+    fn fire_volley(
+        mut commands: Commands,
+        mut pool: ResMut<ProjectilePool>,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<StandardMaterial>>,
+        in_flight: Query<Entity, With<Projectile>>,
+    ) {
+        for entity in &in_flight {
+            reclaim_projectile(&mut commands, &mut pool, entity);
+        }
+        for _ in 0..SHOTS_PER_FRAME {
+            fire_projectile(
+                &mut commands,
+                &mut pool,
+                &mut meshes,
+                &mut materials,
+                ProjectileVisual::EnemyShot,
+                Transform::default(),
+                Projectile { damage: 10, velocity: Vec3::X, ttl: 2.0, from_enemy: true },
+            );
+        }
+    }
+
+    #[test]
+    fn thousand_shots_reuse_the_pool() {
+        let mut app = App::new();
+        app.init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .add_plugins(ProjectilePoolPlugin)
+            .add_systems(Update, fire_volley);
+
+        for _ in 0..1000 / SHOTS_PER_FRAME {
+            app.update();
+        }
+
+        let world = &mut app.world;
+        assert_eq!(world.query::<&PooledProjectile>().iter(world).count(), PROJECTILE_POOL_SIZE);
+        assert_eq!(world.query::<&Projectile>().iter(world).count(), SHOTS_PER_FRAME);
+        assert!(world.entities().len() as usize <= PROJECTILE_POOL_SIZE);
+        // Uma malha e um material só para todos os tiros do mesmo tipo
+        assert_eq!(world.resource::<Assets<Mesh>>().len(), 1);
+    }
+}
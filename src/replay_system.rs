@@ -0,0 +1,324 @@
+/*!
+🎬 REPLAY SYSTEM
+Grava a semente + a entrada de cada tick fixo da run e reproduz alimentando a simulação
+com a entrada gravada no lugar da entrada ao vivo
+*/
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use crate::components::WeaponKind;
+use crate::meta_progression::{HeatModifier, HeatModifiers, RunCompletedEvent, RunLoadout};
+use crate::procedural::{generate_run_dungeon, GeneratedDungeon, RunConfig};
+use crate::ui::{AppState, ReplayRequest};
+use crate::{FixedInput, InputState, Player, PlayerId, SimulationSet, Stats};
+
+const REPLAY_SAVE_PATH: &str = "saves/replay.json";
+// Posição do jogador conferida a cada segundo de simulação
+const CHECKSUM_INTERVAL: usize = 60;
+const CHECKSUM_TOLERANCE: f32 = 0.01;
+
+// InputState compacto: botões num bitmask + posição do mouse no mundo
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct RecordedInput {
+    buttons: u32,
+    mouse: [f32; 3],
+}
+
+// Ordem fixa dos bits - não reordenar, quebra replays salvos
+const BUTTON_COUNT: usize = 20;
+
+fn buttons_of(input: &InputState) -> [bool; BUTTON_COUNT] {
+    [
+        input.up, input.down, input.left, input.right,
+        input.dash, input.interact, input.use_item, input.toggle_travel,
+        input.primary, input.primary_held, input.primary_released, input.secondary,
+        input.ability_q, input.ability_r, input.ability_r_held, input.ability_r_released,
+        input.join, input.place_totem, input.blink, input.secondary_held,
+    ]
+}
+
+impl RecordedInput {
+    fn pack(input: &InputState) -> Self {
+        let buttons = buttons_of(input).iter()
+            .enumerate()
+            .fold(0, |bits, (index, pressed)| bits | ((*pressed as u32) << index));
+        Self { buttons, mouse: input.mouse_world_pos.to_array() }
+    }
+
+    fn unpack(&self) -> InputState {
+        let bit = |index: usize| self.buttons & (1 << index) != 0;
+        InputState {
+            up: bit(0),
+            down: bit(1),
+            left: bit(2),
+            right: bit(3),
+            dash: bit(4),
+            interact: bit(5),
+            use_item: bit(6),
+            toggle_travel: bit(7),
+            primary: bit(8),
+            primary_held: bit(9),
+            primary_released: bit(10),
+            secondary: bit(11),
+            ability_q: bit(12),
+            ability_r: bit(13),
+            ability_r_held: bit(14),
+            ability_r_released: bit(15),
+            join: bit(16),
+            place_totem: bit(17),
+            blink: bit(18),
+            secondary_held: bit(19),
+            mouse_world_pos: Vec3::from_array(self.mouse),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PositionChecksum {
+    tick: usize,
+    position: [f32; 3],
+}
+
+// Tudo que é preciso para refazer a run: semente, escolhas pré-run e a entrada por tick
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct ReplayFile {
+    seed: u64,
+    weapon: WeaponKind,
+    heat: Vec<HeatModifier>,
+    inputs: Vec<RecordedInput>,
+    // Entrada do P2 por tick (vazio em runs solo e replays antigos)
+    #[serde(default)]
+    coop_inputs: Vec<RecordedInput>,
+    checksums: Vec<PositionChecksum>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum ReplayMode {
+    #[default]
+    Idle,
+    Recording,
+    // Run terminou (morte/vitória) - gravação pronta para salvar
+    Finished,
+    Playback,
+}
+
+#[derive(Resource, Default)]
+struct ReplayState {
+    mode: ReplayMode,
+    replay: ReplayFile,
+    tick: usize,
+    diverged: bool,
+}
+
+#[derive(Component)]
+struct ReplayIndicator;
+
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ReplayState>()
+            .add_systems(OnEnter(AppState::Loading), begin_run_replay.after(generate_run_dungeon))
+            .add_systems(OnEnter(AppState::MainMenu), stop_playback)
+            .add_systems(OnEnter(AppState::InGame), setup_replay_indicator)
+            .add_systems(OnExit(AppState::InGame), cleanup_replay_indicator)
+            .add_systems(Update, (
+                handle_replay_requests,
+                finish_recording.run_if(in_state(AppState::InGame)),
+            ))
+            .add_systems(FixedUpdate, (
+                replay_input_system.before(SimulationSet),
+                replay_checksum_system.after(SimulationSet),
+            ).run_if(in_state(AppState::InGame)));
+    }
+}
+
+// Nova run: começa a gravar, ou reinicia a reprodução do replay carregado
+fn begin_run_replay(mut replay: ResMut<ReplayState>, dungeon: Option<Res<GeneratedDungeon>>, loadout: Res<RunLoadout>, heat: Res<HeatModifiers>) {
+    let seed = dungeon.map_or(0, |dungeon| dungeon.generation_seed);
+    replay.tick = 0;
+    replay.diverged = false;
+
+    if replay.mode == ReplayMode::Playback {
+        if replay.replay.seed != seed {
+            warn!("⚠️ Replay recorded with seed {} but the dungeon uses seed {}", replay.replay.seed, seed);
+        }
+        info!("🎬 Playing back replay ({} ticks)", replay.replay.inputs.len());
+        return;
+    }
+
+    replay.mode = ReplayMode::Recording;
+    replay.replay = ReplayFile {
+        seed,
+        weapon: loadout.weapon,
+        heat: heat.active.clone(),
+        ..default()
+    };
+}
+
+fn stop_playback(mut replay: ResMut<ReplayState>) {
+    if replay.mode == ReplayMode::Playback {
+        replay.mode = ReplayMode::Idle;
+    }
+}
+
+// Morte ou vitória encerram a gravação (a run pode ser salva pelo menu)
+fn finish_recording(
+    mut replay: ResMut<ReplayState>,
+    mut run_events: EventReader<RunCompletedEvent>,
+    player_query: Query<&Stats, With<Player>>,
+) {
+    let won = run_events.read().count() > 0;
+    let died = !player_query.is_empty() && player_query.iter().all(|stats| stats.current_health <= 0.0);
+    if replay.mode == ReplayMode::Recording && (won || died) {
+        replay.mode = ReplayMode::Finished;
+        info!("🎬 Run replay ready to save ({} ticks)", replay.replay.inputs.len());
+    }
+}
+
+fn handle_replay_requests(
+    mut requests: EventReader<ReplayRequest>,
+    mut replay: ResMut<ReplayState>,
+    mut loadout: ResMut<RunLoadout>,
+    mut heat: ResMut<HeatModifiers>,
+    mut run_config: ResMut<RunConfig>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for request in requests.read() {
+        match request {
+            ReplayRequest::SaveLastRun => {
+                if replay.replay.inputs.is_empty() || replay.mode == ReplayMode::Playback {
+                    info!("🎬 No recorded run to save");
+                    continue;
+                }
+                save_replay(&replay.replay);
+            }
+            ReplayRequest::Watch => {
+                let Some(file) = load_replay() else {
+                    continue;
+                };
+                // Mesmas escolhas pré-run da gravação (e a mesma dungeon)
+                loadout.weapon = file.weapon;
+                heat.active = file.heat.clone();
+                run_config.fixed_seed = Some(file.seed);
+                replay.replay = file;
+                replay.mode = ReplayMode::Playback;
+                next_state.set(AppState::Loading);
+            }
+        }
+    }
+}
+
+fn save_replay(file: &ReplayFile) {
+    if let Some(parent) = std::path::Path::new(REPLAY_SAVE_PATH).parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    match serde_json::to_string(file) {
+        Ok(contents) => match fs::write(REPLAY_SAVE_PATH, contents) {
+            Ok(()) => info!("🎬 Replay saved to {} ({} ticks)", REPLAY_SAVE_PATH, file.inputs.len()),
+            Err(err) => warn!("⚠️ Failed to save replay: {}", err),
+        },
+        Err(err) => warn!("⚠️ Failed to serialize replay: {}", err),
+    }
+}
+
+fn load_replay() -> Option<ReplayFile> {
+    let contents = fs::read_to_string(REPLAY_SAVE_PATH)
+        .map_err(|err| warn!("⚠️ No replay to load from {}: {}", REPLAY_SAVE_PATH, err))
+        .ok()?;
+    serde_json::from_str(&contents)
+        .map_err(|err| warn!("⚠️ Corrupted replay file: {}", err))
+        .ok()
+}
+
+// Antes de cada tick: grava a entrada da simulação ou a substitui pela gravada
+fn replay_input_system(
+    mut replay: ResMut<ReplayState>,
+    mut fixed_input: ResMut<FixedInput>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    match replay.mode {
+        ReplayMode::Recording => {
+            replay.replay.inputs.push(RecordedInput::pack(fixed_input.get(PlayerId::P1)));
+            replay.replay.coop_inputs.push(RecordedInput::pack(fixed_input.get(PlayerId::P2)));
+        }
+        ReplayMode::Playback => match replay.replay.inputs.get(replay.tick) {
+            Some(recorded) => {
+                fixed_input.0[PlayerId::P1.index()] = recorded.unpack();
+                fixed_input.0[PlayerId::P2.index()] = replay.replay.coop_inputs.get(replay.tick)
+                    .map(RecordedInput::unpack)
+                    .unwrap_or_default();
+            }
+            None => {
+                info!("🎬 Replay finished{}", if replay.diverged { " (diverged)" } else { "" });
+                fixed_input.0 = Default::default();
+                replay.mode = ReplayMode::Idle;
+                next_state.set(AppState::MainMenu);
+            }
+        },
+        ReplayMode::Idle | ReplayMode::Finished => {}
+    }
+}
+
+// Depois de cada tick: grava/confere a posição do jogador em intervalos fixos
+fn replay_checksum_system(
+    mut replay: ResMut<ReplayState>,
+    player_query: Query<(&PlayerId, &Transform), With<Player>>,
+) {
+    if !matches!(replay.mode, ReplayMode::Recording | ReplayMode::Playback) {
+        return;
+    }
+    replay.tick += 1;
+    if replay.tick % CHECKSUM_INTERVAL != 0 {
+        return;
+    }
+    let Some((_, transform)) = player_query.iter().find(|(id, _)| id.is_primary()) else {
+        return;
+    };
+    let position = transform.translation;
+    let tick = replay.tick;
+
+    if replay.mode == ReplayMode::Recording {
+        replay.replay.checksums.push(PositionChecksum { tick, position: position.to_array() });
+        return;
+    }
+
+    let expected = replay.replay.checksums.iter().find(|checksum| checksum.tick == tick);
+    if let Some(expected) = expected {
+        let expected = Vec3::from_array(expected.position);
+        if expected.distance(position) > CHECKSUM_TOLERANCE && !replay.diverged {
+            error!("❌ Replay diverged at tick {}: expected player at {:?}, got {:?}", tick, expected, position);
+            replay.diverged = true;
+        }
+    }
+}
+
+fn setup_replay_indicator(mut commands: Commands, replay: Res<ReplayState>) {
+    if replay.mode != ReplayMode::Playback {
+        return;
+    }
+    commands.spawn((
+        TextBundle::from_section(
+            "▶ REPLAY",
+            TextStyle {
+                font_size: 26.0,
+                color: Color::rgb(1.0, 0.3, 0.3),
+                ..default()
+            },
+        ).with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(20.0),
+            left: Val::Percent(48.0),
+            ..default()
+        }),
+        ReplayIndicator,
+    ));
+}
+
+fn cleanup_replay_indicator(mut commands: Commands, indicators: Query<Entity, With<ReplayIndicator>>) {
+    for entity in &indicators {
+        commands.entity(entity).despawn_recursive();
+    }
+}
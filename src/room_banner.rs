@@ -0,0 +1,217 @@
+/*!
+🏛️ ROOM BANNER
+Ao atravessar uma porta, o nome da sala (do template procedural) e o bioma deslizam na tela.
+Na sala do chefe o banner é maior, traz o nome do chefe e a câmera faz um breve pan até ele
+*/
+
+use bevy::prelude::*;
+use crate::boss_system::Boss;
+use crate::coop_system::CoopCameraSet;
+use crate::procedural::BiomeType;
+use crate::ui::AppState;
+use crate::{Room, RoomEnteredEvent, RoomType};
+
+const BANNER_SLIDE_TIME: f32 = 0.4;
+const BANNER_FADE_TIME: f32 = 0.6;
+const BANNER_DURATION: f32 = 3.0;
+const BOSS_BANNER_DURATION: f32 = 4.0;
+// Distância do deslize de entrada (px, da esquerda)
+const BANNER_SLIDE_OFFSET: f32 = 400.0;
+// Pan da introdução: vai até o chefe e volta ao grupo
+const BOSS_PAN_DURATION: f32 = 2.2;
+
+#[derive(Component)]
+struct RoomBanner {
+    elapsed: f32,
+    duration: f32,
+}
+
+// Pan da câmera em andamento (alvo = sala do chefe até o chefe surgir)
+#[derive(Resource)]
+struct BossIntroPan {
+    elapsed: f32,
+    fallback_target: Vec3,
+}
+
+pub struct RoomBannerPlugin;
+
+impl Plugin for RoomBannerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (
+                show_room_banner,
+                animate_room_banner,
+                boss_intro_pan.after(CoopCameraSet),
+            ).run_if(in_state(AppState::InGame)))
+            .add_systems(OnExit(AppState::InGame), cleanup_room_banner);
+    }
+}
+
+fn boss_title(biome: BiomeType) -> &'static str {
+    match biome {
+        BiomeType::Desert => "O Faraó Supremo",
+        BiomeType::Temple => "Set, Deus do Caos",
+        BiomeType::Underworld => "Anúbis, o Juiz dos Mortos",
+    }
+}
+
+fn show_room_banner(
+    mut commands: Commands,
+    mut entered_events: EventReader<RoomEnteredEvent>,
+    rooms: Query<&Room>,
+    banners: Query<Entity, With<RoomBanner>>,
+) {
+    let Some(event) = entered_events.read().last() else {
+        return;
+    };
+    let Some(room) = rooms.iter().find(|room| room.id == event.room) else {
+        return;
+    };
+    for banner in &banners {
+        commands.entity(banner).despawn_recursive();
+    }
+
+    let is_boss = matches!(room.room_type, RoomType::Boss);
+    let biome = room.template.biome;
+    // Chefe: nome do chefe em destaque, sala e descrição embaixo
+    let (title, subtitle, detail, accent, duration) = if is_boss {
+        (
+            boss_title(biome).to_string(),
+            room.template.name.clone(),
+            room.template.description.clone(),
+            Color::rgb(0.95, 0.3, 0.15),
+            BOSS_BANNER_DURATION,
+        )
+    } else {
+        (
+            room.template.name.clone(),
+            biome.get_display_name().to_string(),
+            String::new(),
+            Color::rgb(0.9, 0.8, 0.4),
+            BANNER_DURATION,
+        )
+    };
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(if is_boss { 30.0 } else { 14.0 }),
+                left: Val::Px(-BANNER_SLIDE_OFFSET),
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::vertical(Val::Px(12.0)),
+                ..default()
+            },
+            background_color: Color::rgba(0.05, 0.03, 0.02, 0.0).into(),
+            ..default()
+        },
+        RoomBanner { elapsed: 0.0, duration },
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            title,
+            TextStyle {
+                font_size: if is_boss { 64.0 } else { 44.0 },
+                color: accent.with_a(0.0),
+                ..default()
+            },
+        ));
+        parent.spawn(TextBundle::from_section(
+            subtitle,
+            TextStyle {
+                font_size: if is_boss { 30.0 } else { 22.0 },
+                color: Color::rgba(0.85, 0.75, 0.55, 0.0),
+                ..default()
+            },
+        ));
+        if !detail.is_empty() {
+            parent.spawn(TextBundle::from_section(
+                detail,
+                TextStyle {
+                    font_size: 20.0,
+                    color: Color::rgba(0.7, 0.6, 0.45, 0.0),
+                    ..default()
+                },
+            ));
+        }
+    });
+
+    if is_boss {
+        commands.insert_resource(BossIntroPan {
+            elapsed: 0.0,
+            fallback_target: Vec3::new(room.center.x, 0.0, room.center.y),
+        });
+    }
+    info!("🏛️ Entered {} ({})", room.template.name, biome.get_display_name());
+}
+
+// Desliza da esquerda, segura e some; o texto acompanha a opacidade do fundo
+fn animate_room_banner(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut banners: Query<(Entity, &mut RoomBanner, &mut Style, &mut BackgroundColor, &Children)>,
+    mut texts: Query<&mut Text>,
+) {
+    for (entity, mut banner, mut style, mut background, children) in &mut banners {
+        banner.elapsed += time.delta_seconds();
+        if banner.elapsed >= banner.duration {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        let slide = (banner.elapsed / BANNER_SLIDE_TIME).min(1.0);
+        let eased = 1.0 - (1.0 - slide).powi(3);
+        style.left = Val::Px(-BANNER_SLIDE_OFFSET * (1.0 - eased));
+
+        let fade_out = ((banner.duration - banner.elapsed) / BANNER_FADE_TIME).min(1.0);
+        let alpha = slide.min(fade_out);
+        background.0.set_a(0.6 * alpha);
+        for &child in children {
+            if let Ok(mut text) = texts.get_mut(child) {
+                for section in &mut text.sections {
+                    section.style.color.set_a(alpha);
+                }
+            }
+        }
+    }
+}
+
+// Roda depois da câmera do co-op: desloca o enquadramento até o chefe e volta
+fn boss_intro_pan(
+    mut commands: Commands,
+    time: Res<Time>,
+    pan: Option<ResMut<BossIntroPan>>,
+    bosses: Query<&Transform, (With<Boss>, Without<Camera3d>)>,
+    mut cameras: Query<&mut Transform, With<Camera3d>>,
+) {
+    let Some(mut pan) = pan else {
+        return;
+    };
+    pan.elapsed += time.delta_seconds();
+    if pan.elapsed >= BOSS_PAN_DURATION {
+        commands.remove_resource::<BossIntroPan>();
+        return;
+    }
+
+    let target = bosses.iter().next().map_or(pan.fallback_target, |transform| transform.translation);
+    // Sobe e desce suave: 0 -> 1 -> 0
+    let weight = (std::f32::consts::PI * pan.elapsed / BOSS_PAN_DURATION).sin().powi(2);
+    for mut transform in &mut cameras {
+        // Ponto do chão que a câmera enquadra agora
+        let forward = transform.forward();
+        if forward.y.abs() < 0.01 {
+            continue;
+        }
+        let focus = transform.translation + *forward * (-transform.translation.y / forward.y);
+        let shift = Vec3::new(target.x - focus.x, 0.0, target.z - focus.z) * weight;
+        transform.translation += shift;
+        transform.look_at(focus + shift, Vec3::Y);
+    }
+}
+
+fn cleanup_room_banner(mut commands: Commands, banners: Query<Entity, With<RoomBanner>>) {
+    for banner in &banners {
+        commands.entity(banner).despawn_recursive();
+    }
+    commands.remove_resource::<BossIntroPan>();
+}
@@ -0,0 +1,343 @@
+use bevy::prelude::*;
+use bevy::render::view::ColorGrading;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::time::{Duration, Instant};
+use bevy::window::{PresentMode, PrimaryWindow};
+
+const SETTINGS_SAVE_PATH: &str = "saves/settings.json";
+
+// Níveis da assistência de mira: desligada, fraca, média, forte
+const AIM_ASSIST_LEVELS: [f32; 4] = [0.0, 0.35, 0.65, 1.0];
+
+// Escalas da interface (UiScale do Bevy): telas pequenas até alta densidade
+const UI_SCALE_LEVELS: [f32; 4] = [0.75, 1.0, 1.25, 1.5];
+
+/// Sincronização de quadros: VSync, sem limite (benchmark) ou limite próprio de FPS
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FrameRateMode {
+    VSync,
+    Uncapped,
+    Capped { fps: u32 }, // Qualquer valor no arquivo; o menu alterna entre os predefinidos
+}
+
+const FRAME_RATE_PRESETS: [FrameRateMode; 5] = [
+    FrameRateMode::VSync,
+    FrameRateMode::Capped { fps: 60 },
+    FrameRateMode::Capped { fps: 120 },
+    FrameRateMode::Capped { fps: 144 },
+    FrameRateMode::Uncapped,
+];
+
+/// Qualidade gráfica: hoje limita quantos inimigos podem estar vivos ao mesmo tempo (SpawnGovernor)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GraphicsQuality {
+    Low,
+    Medium,
+    High,
+}
+
+impl GraphicsQuality {
+    // Teto de inimigos simultâneos; o excesso de ondas e portais espera na fila
+    pub fn max_live_enemies(&self) -> usize {
+        match self {
+            GraphicsQuality::Low => 14,
+            GraphicsQuality::Medium => 22,
+            GraphicsQuality::High => 32,
+        }
+    }
+
+    fn next(&self) -> Self {
+        match self {
+            GraphicsQuality::Low => GraphicsQuality::Medium,
+            GraphicsQuality::Medium => GraphicsQuality::High,
+            GraphicsQuality::High => GraphicsQuality::Low,
+        }
+    }
+}
+
+// Faixa do brilho: nem tela estourada nem preta
+pub const MIN_BRIGHTNESS: f32 = 0.5;
+pub const MAX_BRIGHTNESS: f32 = 1.5;
+pub const BRIGHTNESS_STEP: f32 = 0.1;
+
+pub const VOLUME_STEP: f32 = 0.1;
+
+/// Preferências do jogador, salvas entre sessões
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameSettings {
+    pub show_compass: bool, // Marcador apontando para o próximo chefe
+    pub aim_assist_strength: f32, // 0 = desligada, 1 = mira presa ao inimigo no cone
+    pub aim_assist_mouse: bool, // Assistência também para o mouse (o gamepad sempre usa)
+    pub brightness: f32, // Multiplica a luz ambiente e clareia as sombras (gama)
+    pub colorblind_mode: bool, // Paleta distinguível + símbolos para raridade e tipo de dano
+    pub ui_scale: f32, // Multiplica todos os tamanhos em pixels da HUD e dos menus
+    pub frame_rate: FrameRateMode,
+    pub master_volume: f32, // 0 a 1, aplicado ao GlobalVolume
+    pub graphics_quality: GraphicsQuality,
+    pub adaptive_difficulty: bool, // Acessibilidade: inimigos se ajustam (pouco) ao desempenho recente
+    pub slow_motion: bool, // Câmera lenta em momentos marcantes (parry, golpe final no chefe, reviver)
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        Self {
+            show_compass: true,
+            aim_assist_strength: 0.65,
+            aim_assist_mouse: false,
+            brightness: 1.0,
+            colorblind_mode: false,
+            ui_scale: 1.0,
+            frame_rate: FrameRateMode::VSync,
+            master_volume: 0.7,
+            graphics_quality: GraphicsQuality::High,
+            adaptive_difficulty: false,
+            slow_motion: true,
+        }
+    }
+}
+
+impl GameSettings {
+    pub fn load() -> Self {
+        match fs::read_to_string(SETTINGS_SAVE_PATH) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|err| {
+                warn!("⚠️ Corrupted settings file, using defaults: {}", err);
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn save(&self) {
+        if let Some(parent) = std::path::Path::new(SETTINGS_SAVE_PATH).parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+
+        match serde_json::to_string_pretty(self) {
+            Ok(contents) => {
+                if let Err(err) = fs::write(SETTINGS_SAVE_PATH, contents) {
+                    warn!("⚠️ Failed to save settings: {}", err);
+                }
+            }
+            Err(err) => warn!("⚠️ Failed to serialize settings: {}", err),
+        }
+    }
+
+    // Arquivo editado à mão também respeita a faixa
+    pub fn brightness(&self) -> f32 {
+        self.brightness.clamp(MIN_BRIGHTNESS, MAX_BRIGHTNESS)
+    }
+
+    pub fn adjust_brightness(&mut self, delta: f32) {
+        let stepped = ((self.brightness() + delta) / BRIGHTNESS_STEP).round() * BRIGHTNESS_STEP;
+        self.brightness = stepped.clamp(MIN_BRIGHTNESS, MAX_BRIGHTNESS);
+        self.save();
+    }
+
+    pub fn master_volume(&self) -> f32 {
+        self.master_volume.clamp(0.0, 1.0)
+    }
+
+    pub fn adjust_master_volume(&mut self, delta: f32) {
+        let stepped = ((self.master_volume() + delta) / VOLUME_STEP).round() * VOLUME_STEP;
+        self.master_volume = stepped.clamp(0.0, 1.0);
+        self.save();
+    }
+
+    // Gama do color grading: < 1 levanta os tons escuros, > 1 escurece
+    pub fn brightness_gamma(&self) -> f32 {
+        1.0 / self.brightness()
+    }
+
+    pub fn ui_scale(&self) -> f32 {
+        self.ui_scale.clamp(UI_SCALE_LEVELS[0], UI_SCALE_LEVELS[UI_SCALE_LEVELS.len() - 1])
+    }
+
+    pub fn is_enabled(&self, toggle: SettingToggle) -> bool {
+        match toggle {
+            SettingToggle::Compass => self.show_compass,
+            SettingToggle::AimAssist => self.aim_assist_strength > 0.0,
+            SettingToggle::AimAssistMouse => self.aim_assist_mouse,
+            SettingToggle::ColorblindMode => self.colorblind_mode,
+            SettingToggle::AdaptiveDifficulty => self.adaptive_difficulty,
+            SettingToggle::SlowMotion => self.slow_motion,
+            SettingToggle::UiScale | SettingToggle::FrameRate | SettingToggle::GraphicsQuality => true,
+        }
+    }
+
+    // Liga/desliga, ou avança para o próximo nível nas opções com níveis
+    pub fn toggle(&mut self, toggle: SettingToggle) {
+        match toggle {
+            SettingToggle::Compass => self.show_compass = !self.show_compass,
+            SettingToggle::AimAssist => {
+                let next = AIM_ASSIST_LEVELS.iter()
+                    .position(|level| *level > self.aim_assist_strength + 0.01)
+                    .unwrap_or(0);
+                self.aim_assist_strength = AIM_ASSIST_LEVELS[next];
+            }
+            SettingToggle::AimAssistMouse => self.aim_assist_mouse = !self.aim_assist_mouse,
+            SettingToggle::ColorblindMode => self.colorblind_mode = !self.colorblind_mode,
+            SettingToggle::AdaptiveDifficulty => self.adaptive_difficulty = !self.adaptive_difficulty,
+            SettingToggle::SlowMotion => self.slow_motion = !self.slow_motion,
+            SettingToggle::UiScale => {
+                let next = UI_SCALE_LEVELS.iter()
+                    .position(|level| *level > self.ui_scale() + 0.01)
+                    .unwrap_or(0);
+                self.ui_scale = UI_SCALE_LEVELS[next];
+            }
+            SettingToggle::FrameRate => {
+                // Limite personalizado volta para o primeiro predefinido
+                let next = FRAME_RATE_PRESETS.iter()
+                    .position(|mode| *mode == self.frame_rate)
+                    .map_or(0, |index| (index + 1) % FRAME_RATE_PRESETS.len());
+                self.frame_rate = FRAME_RATE_PRESETS[next];
+            }
+            SettingToggle::GraphicsQuality => self.graphics_quality = self.graphics_quality.next(),
+        }
+        self.save();
+    }
+
+    // Texto do estado atual exibido no botão
+    pub fn value_label(&self, toggle: SettingToggle) -> &'static str {
+        match toggle {
+            SettingToggle::AimAssist => match self.aim_assist_strength {
+                strength if strength <= 0.0 => "Desligada",
+                strength if strength < 0.5 => "Fraca",
+                strength if strength < 0.9 => "Média",
+                _ => "Forte",
+            },
+            SettingToggle::UiScale => match self.ui_scale() {
+                scale if scale < 0.875 => "75%",
+                scale if scale < 1.125 => "100%",
+                scale if scale < 1.375 => "125%",
+                _ => "150%",
+            },
+            SettingToggle::FrameRate => match self.frame_rate {
+                FrameRateMode::VSync => "VSync",
+                FrameRateMode::Uncapped => "Sem limite",
+                FrameRateMode::Capped { fps: 60 } => "60 FPS",
+                FrameRateMode::Capped { fps: 120 } => "120 FPS",
+                FrameRateMode::Capped { fps: 144 } => "144 FPS",
+                FrameRateMode::Capped { .. } => "Limite personalizado",
+            },
+            SettingToggle::GraphicsQuality => match self.graphics_quality {
+                GraphicsQuality::Low => "Baixa",
+                GraphicsQuality::Medium => "Média",
+                GraphicsQuality::High => "Alta",
+            },
+            _ if self.is_enabled(toggle) => "Ligada",
+            _ => "Desligada",
+        }
+    }
+}
+
+/// Opções liga/desliga exibidas no menu de configurações
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingToggle {
+    Compass,
+    AimAssist,
+    AimAssistMouse,
+    ColorblindMode,
+    AdaptiveDifficulty,
+    SlowMotion,
+    UiScale,
+    FrameRate,
+    GraphicsQuality,
+}
+
+impl SettingToggle {
+    pub fn get_all() -> Vec<SettingToggle> {
+        vec![
+            SettingToggle::Compass,
+            SettingToggle::AimAssist,
+            SettingToggle::AimAssistMouse,
+            SettingToggle::ColorblindMode,
+            SettingToggle::AdaptiveDifficulty,
+            SettingToggle::SlowMotion,
+            SettingToggle::UiScale,
+            SettingToggle::FrameRate,
+            SettingToggle::GraphicsQuality,
+        ]
+    }
+
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            SettingToggle::Compass => "Bússola",
+            SettingToggle::AimAssist => "Assistência de Mira",
+            SettingToggle::AimAssistMouse => "Assistência no Mouse",
+            SettingToggle::ColorblindMode => "Modo Daltônico",
+            SettingToggle::AdaptiveDifficulty => "Dificuldade Adaptativa",
+            SettingToggle::SlowMotion => "Câmera Lenta",
+            SettingToggle::UiScale => "Escala da Interface",
+            SettingToggle::FrameRate => "Taxa de Quadros",
+            SettingToggle::GraphicsQuality => "Qualidade Gráfica",
+        }
+    }
+}
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GameSettings::load())
+            .add_systems(Update, (apply_brightness, apply_ui_scale, apply_present_mode, apply_master_volume))
+            .add_systems(Last, limit_frame_rate);
+    }
+}
+
+// Aplicada na inicialização (recurso recém-inserido conta como mudança) e a cada ajuste
+fn apply_ui_scale(settings: Res<GameSettings>, mut ui_scale: ResMut<UiScale>) {
+    if settings.is_changed() && (ui_scale.0 - settings.ui_scale()).abs() > f32::EPSILON {
+        ui_scale.0 = settings.ui_scale();
+    }
+}
+
+// O GlobalVolume multiplica o volume de cada som no momento em que ele começa a tocar
+fn apply_master_volume(settings: Res<GameSettings>, mut global_volume: ResMut<GlobalVolume>) {
+    if settings.is_changed() {
+        global_volume.volume = bevy::audio::Volume::new(settings.master_volume());
+    }
+}
+
+// Com limite próprio a janela fica sem VSync e o limitador cuida do ritmo
+fn apply_present_mode(settings: Res<GameSettings>, mut windows: Query<&mut Window, With<PrimaryWindow>>) {
+    if !settings.is_changed() {
+        return;
+    }
+    let present_mode = match settings.frame_rate {
+        FrameRateMode::VSync => PresentMode::Fifo,
+        FrameRateMode::Uncapped | FrameRateMode::Capped { .. } => PresentMode::AutoNoVsync,
+    };
+    for mut window in &mut windows {
+        if window.present_mode != present_mode {
+            window.present_mode = present_mode;
+            info!("🖥️ Present mode: {:?}", present_mode);
+        }
+    }
+}
+
+// Dorme o que falta do quadro para manter o FPS alvo
+fn limit_frame_rate(settings: Res<GameSettings>, mut frame_start: Local<Option<Instant>>) {
+    let FrameRateMode::Capped { fps } = settings.frame_rate else {
+        *frame_start = None;
+        return;
+    };
+    let target = Duration::from_secs_f64(1.0 / fps.max(1) as f64);
+    if let Some(start) = *frame_start {
+        if let Some(remaining) = target.checked_sub(start.elapsed()) {
+            std::thread::sleep(remaining);
+        }
+    }
+    *frame_start = Some(Instant::now());
+}
+
+// Câmeras 3D criadas depois (entrada na run) também recebem o gama atual
+fn apply_brightness(settings: Res<GameSettings>, mut cameras: Query<&mut ColorGrading, With<Camera3d>>) {
+    let gamma = settings.brightness_gamma();
+    for mut grading in &mut cameras {
+        if (grading.gamma - gamma).abs() > f32::EPSILON {
+            grading.gamma = gamma;
+        }
+    }
+}
@@ -0,0 +1,152 @@
+/*!
+🛒 SHOP SYSTEM
+Lojas do gerador: um consumível de cada tipo sobre pedestais, comprado com as moedas da run
+ao interagir (E / botão do controle). Sem moedas suficientes ou com o inventário cheio a
+compra não acontece e o item continua no pedestal. O estoque sai uma vez por run
+*/
+
+use bevy::prelude::*;
+use crate::interact_highlight::Interactable;
+use crate::inventory::{ConsumableKind, Inventory};
+use crate::ui::{AppState, BoonData};
+use crate::{AudioEvent, FixedInput, Player, PlayerId, SimulationSet, SpawnParticlesEvent};
+
+const SHOP_INTERACT_RADIUS: f32 = 1.3;
+const SHOP_PEDESTAL_SPACING: f32 = 2.5;
+const SHOP_STOCK: [ConsumableKind; 3] = [
+    ConsumableKind::HealthPotion,
+    ConsumableKind::StaminaElixir,
+    ConsumableKind::Bomb,
+];
+
+#[derive(Component)]
+pub struct ShopItem {
+    pub kind: ConsumableKind,
+    pub price: u32,
+}
+
+pub struct ShopPlugin;
+
+impl Plugin for ShopPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(FixedUpdate, buy_shop_items.in_set(SimulationSet).run_if(in_state(AppState::InGame)))
+            // Run nova: estoque da loja anterior some junto com as salas
+            .add_systems(OnEnter(AppState::Loading), cleanup_shop_items);
+    }
+}
+
+/// Preço em moedas de cada consumível
+fn shop_price(kind: ConsumableKind) -> u32 {
+    match kind {
+        ConsumableKind::HealthPotion => 40,
+        ConsumableKind::StaminaElixir => 25,
+        ConsumableKind::Bomb => 30,
+    }
+}
+
+// O aviso de interação é estático: um texto por item, já com o preço
+fn shop_prompt(kind: ConsumableKind) -> &'static str {
+    match kind {
+        ConsumableKind::HealthPotion => "Comprar Poção de Cura (40 moedas)",
+        ConsumableKind::StaminaElixir => "Comprar Elixir de Vigor (25 moedas)",
+        ConsumableKind::Bomb => "Comprar Bomba (30 moedas)",
+    }
+}
+
+/// Pedestais com o estoque da loja, em fila no centro da sala
+pub fn spawn_shop_stock(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    center: Vec3,
+) {
+    let stone = materials.add(StandardMaterial {
+        base_color: Color::rgb(0.55, 0.48, 0.38),
+        ..default()
+    });
+    let pedestal_mesh = meshes.add(Cuboid::new(0.8, 0.9, 0.8));
+    let orb_mesh = meshes.add(Sphere::new(0.25));
+
+    for (index, kind) in SHOP_STOCK.into_iter().enumerate() {
+        let x = (index as f32 - (SHOP_STOCK.len() - 1) as f32 / 2.0) * SHOP_PEDESTAL_SPACING;
+        let color = kind.get_color();
+        let orb = commands.spawn(PbrBundle {
+            mesh: orb_mesh.clone(),
+            material: materials.add(StandardMaterial {
+                base_color: color,
+                emissive: color * 2.0,
+                ..default()
+            }),
+            transform: Transform::from_xyz(0.0, 0.75, 0.0),
+            ..default()
+        }).id();
+        commands.spawn((
+            PbrBundle {
+                mesh: pedestal_mesh.clone(),
+                material: stone.clone(),
+                transform: Transform::from_xyz(center.x + x, 0.45, center.z),
+                ..default()
+            },
+            ShopItem { kind, price: shop_price(kind) },
+            Interactable::new(shop_prompt(kind), SHOP_INTERACT_RADIUS),
+            Name::new(format!("Shop: {}", kind.get_display_name())),
+        )).add_child(orb);
+    }
+}
+
+// Cada jogador que interage compra o item mais próximo ao alcance (moedas e inventário compartilhados)
+#[allow(clippy::too_many_arguments)]
+fn buy_shop_items(
+    mut commands: Commands,
+    fixed_input: Res<FixedInput>,
+    players: Query<(&PlayerId, &Transform), With<Player>>,
+    items: Query<(Entity, &Transform, &ShopItem)>,
+    mut boon_data: ResMut<BoonData>,
+    mut inventory: ResMut<Inventory>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+    mut audio_events: EventWriter<AudioEvent>,
+) {
+    let interacting = players.iter()
+        .filter(|(id, _)| fixed_input.get(**id).interact)
+        .map(|(_, transform)| transform.translation);
+
+    let mut sold: Vec<Entity> = Vec::new();
+    for position in interacting {
+        let nearest = items.iter()
+            .filter(|(entity, ..)| !sold.contains(entity))
+            .map(|(entity, transform, item)| {
+                let offset = transform.translation - position;
+                (entity, transform, item, Vec3::new(offset.x, 0.0, offset.z).length())
+            })
+            .filter(|(.., distance)| *distance <= SHOP_INTERACT_RADIUS)
+            .min_by(|a, b| a.3.total_cmp(&b.3));
+        let Some((entity, transform, item, _)) = nearest else {
+            continue;
+        };
+
+        if boon_data.coins < item.price {
+            info!("🛒 Not enough coins for {} ({}/{})", item.kind.get_display_name(), boon_data.coins, item.price);
+            continue;
+        }
+        if !inventory.add(item.kind) {
+            info!("🛒 Inventory full, can't buy {}", item.kind.get_display_name());
+            continue;
+        }
+        boon_data.coins -= item.price;
+        sold.push(entity);
+        commands.entity(entity).despawn_recursive();
+        particle_events.send(SpawnParticlesEvent {
+            position: transform.translation + Vec3::Y * 0.75,
+            color: item.kind.get_color(),
+            count: 12,
+        });
+        audio_events.send(AudioEvent::ChestOpen);
+        info!("🛒 Bought {} for {} coins", item.kind.get_display_name(), item.price);
+    }
+}
+
+fn cleanup_shop_items(mut commands: Commands, items: Query<Entity, With<ShopItem>>) {
+    for entity in &items {
+        commands.entity(entity).despawn_recursive();
+    }
+}
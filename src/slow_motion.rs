@@ -0,0 +1,105 @@
+/*!
+🐌 SLOW MOTION
+Câmera lenta cinematográfica nos momentos marcantes: golpe final no chefe, parry e reviver
+o parceiro. O tempo virtual desacelera, segura e volta ao normal (rampas suaves), contado em
+tempo real. Só mexe na velocidade relativa, nunca em pausar/despausar: o hit-stop (pausa total)
+continua valendo por cima. Fora do jogo (menus, recompensas) a velocidade volta a 1 na hora
+*/
+
+use bevy::prelude::*;
+use crate::settings::GameSettings;
+use crate::ui::AppState;
+
+// Fração da duração gasta desacelerando e acelerando de volta
+const RAMP_IN_FRACTION: f32 = 0.15;
+const RAMP_OUT_FRACTION: f32 = 0.35;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlowMotionMoment {
+    BossKill,
+    Parry,
+    Revive,
+}
+
+impl SlowMotionMoment {
+    // (escala do tempo no fundo da rampa, duração em tempo real)
+    fn profile(&self) -> (f32, f32) {
+        match self {
+            SlowMotionMoment::BossKill => (0.2, 1.4),
+            SlowMotionMoment::Parry => (0.3, 0.3),
+            SlowMotionMoment::Revive => (0.4, 0.6),
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct SlowMotion {
+    timer: f32,    // tempo real desde o início
+    duration: f32, // 0 = inativa
+    scale: f32,
+}
+
+impl SlowMotion {
+    // Um momento mais forte substitui o atual; um mais fraco não corta o que já está rolando
+    pub fn trigger(&mut self, moment: SlowMotionMoment) {
+        let (scale, duration) = moment.profile();
+        if self.is_active() && self.current_scale() <= scale {
+            return;
+        }
+        *self = SlowMotion { timer: 0.0, duration, scale };
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.timer < self.duration
+    }
+
+    // Desce até a escala, segura e sobe de volta a 1
+    fn current_scale(&self) -> f32 {
+        if !self.is_active() {
+            return 1.0;
+        }
+        let progress = self.timer / self.duration;
+        let depth = if progress < RAMP_IN_FRACTION {
+            progress / RAMP_IN_FRACTION
+        } else if progress > 1.0 - RAMP_OUT_FRACTION {
+            (1.0 - progress) / RAMP_OUT_FRACTION
+        } else {
+            1.0
+        };
+        1.0 + (self.scale - 1.0) * depth
+    }
+}
+
+pub struct SlowMotionPlugin;
+
+impl Plugin for SlowMotionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SlowMotion>()
+            .add_systems(Update, update_slow_motion)
+            .add_systems(OnExit(AppState::InGame), reset_slow_motion);
+    }
+}
+
+fn update_slow_motion(
+    real_time: Res<Time<Real>>,
+    settings: Res<GameSettings>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut slow_motion: ResMut<SlowMotion>,
+) {
+    if !slow_motion.is_active() {
+        return;
+    }
+    // Desligada nas configurações: os momentos ainda chegam, mas o tempo segue normal
+    if !settings.slow_motion {
+        *slow_motion = SlowMotion::default();
+        virtual_time.set_relative_speed(1.0);
+        return;
+    }
+    slow_motion.timer += real_time.delta_seconds();
+    virtual_time.set_relative_speed(slow_motion.current_scale());
+}
+
+fn reset_slow_motion(mut virtual_time: ResMut<Time<Virtual>>, mut slow_motion: ResMut<SlowMotion>) {
+    *slow_motion = SlowMotion::default();
+    virtual_time.set_relative_speed(1.0);
+}
@@ -0,0 +1,165 @@
+/*!
+🚦 SPAWN GOVERNOR
+Teto de inimigos vivos ao mesmo tempo, ligado à Qualidade Gráfica das configurações.
+Ondas da sala e portais de reforço pedem vaga antes de criar um inimigo; sem vaga o
+inimigo entra na fila e surge assim que outro morrer - andares fundos com portais e
+elites não derrubam o FPS com spawns descontrolados
+*/
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use crate::adaptive_difficulty::AdaptiveDifficulty;
+use crate::asset_loader::GameAssets;
+use crate::boss_system::{PortalAdd, ReinforcementPortal};
+use crate::elite_affixes::EliteAffix;
+use crate::hades_3d_system::Hades3DAssets;
+use crate::meta_progression::HeatModifiers;
+use crate::procedural::CurrentBiomeSettings;
+use crate::settings::GameSettings;
+use crate::ui::AppState;
+use crate::{spawn_room_enemy, Enemy, EnemyType, RoomEnteredEvent};
+
+// De onde veio o inimigo na fila (os reforços de portal ganham o marcador PortalAdd)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpawnSource {
+    RoomWave,
+    Portal,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct QueuedSpawn {
+    pub position: Vec3,
+    pub enemy_type: EnemyType,
+    pub affix: Option<EliteAffix>, // Some = elite (afixo sorteado ao pedir a vaga)
+    pub source: SpawnSource,
+}
+
+#[derive(Resource)]
+pub struct SpawnGovernor {
+    pub max_live_enemies: usize,
+    pub live_enemies: usize, // Recontado no início de cada quadro; os spawns do quadro somam aqui
+    queue: VecDeque<QueuedSpawn>,
+}
+
+impl Default for SpawnGovernor {
+    fn default() -> Self {
+        Self {
+            max_live_enemies: GameSettings::default().graphics_quality.max_live_enemies(),
+            live_enemies: 0,
+            queue: VecDeque::new(),
+        }
+    }
+}
+
+impl SpawnGovernor {
+    pub fn has_room(&self) -> bool {
+        self.live_enemies < self.max_live_enemies
+    }
+
+    // Ocupa uma vaga se houver; false = o chamador deve enfileirar
+    pub fn try_reserve(&mut self) -> bool {
+        if !self.has_room() {
+            return false;
+        }
+        self.live_enemies += 1;
+        true
+    }
+
+    pub fn enqueue(&mut self, spawn: QueuedSpawn) {
+        self.queue.push_back(spawn);
+    }
+
+    pub fn queued(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn queued_from(&self, source: SpawnSource) -> usize {
+        self.queue.iter().filter(|spawn| spawn.source == source).count()
+    }
+}
+
+pub struct SpawnGovernorPlugin;
+
+impl Plugin for SpawnGovernorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpawnGovernor>()
+            .add_systems(First, count_live_enemies.run_if(in_state(AppState::InGame)))
+            .add_systems(Update, (
+                apply_enemy_cap,
+                clear_queue_on_room_enter,
+                release_queued_spawns,
+            ).chain().run_if(in_state(AppState::InGame)))
+            .add_systems(OnEnter(AppState::Loading), clear_queue);
+    }
+}
+
+// Antes de qualquer spawn do quadro, com os comandos do quadro anterior já aplicados
+fn count_live_enemies(mut governor: ResMut<SpawnGovernor>, enemies: Query<(), With<Enemy>>) {
+    let live = enemies.iter().count();
+    if governor.live_enemies != live {
+        governor.live_enemies = live;
+    }
+}
+
+fn apply_enemy_cap(settings: Res<GameSettings>, mut governor: ResMut<SpawnGovernor>) {
+    let cap = settings.graphics_quality.max_live_enemies();
+    if governor.max_live_enemies != cap {
+        governor.max_live_enemies = cap;
+        info!("🚦 Enemy cap set to {}", cap);
+    }
+}
+
+// A fila pertence à sala atual: quem não chegou a surgir não segue o grupo
+fn clear_queue_on_room_enter(mut entered_events: EventReader<RoomEnteredEvent>, mut governor: ResMut<SpawnGovernor>) {
+    if entered_events.read().count() > 0 && governor.queued() > 0 {
+        governor.queue.clear();
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn release_queued_spawns(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    game_assets: Option<Res<GameAssets>>,
+    hades_3d_assets: Option<Res<Hades3DAssets>>,
+    heat: Res<HeatModifiers>,
+    difficulty: Res<AdaptiveDifficulty>,
+    biome: Res<CurrentBiomeSettings>,
+    mut governor: ResMut<SpawnGovernor>,
+    portals: Query<(), With<ReinforcementPortal>>,
+) {
+    if governor.queued() == 0 || !governor.has_room() {
+        return;
+    }
+    while governor.has_room() {
+        let Some(spawn) = governor.queue.pop_front() else {
+            break;
+        };
+        // Portal fechado: o reforço que esperava na fila não vem mais
+        if spawn.source == SpawnSource::Portal && portals.is_empty() {
+            continue;
+        }
+        let enemy_entity = spawn_room_enemy(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            game_assets.as_deref(),
+            hades_3d_assets.as_deref(),
+            &heat,
+            &difficulty,
+            biome.biome_type,
+            spawn.position,
+            spawn.enemy_type,
+            spawn.affix,
+        );
+        if spawn.source == SpawnSource::Portal {
+            commands.entity(enemy_entity).insert(PortalAdd);
+        }
+        governor.live_enemies += 1;
+    }
+}
+
+fn clear_queue(mut governor: ResMut<SpawnGovernor>) {
+    governor.queue.clear();
+}
@@ -95,6 +95,7 @@ fn billboard_system(
 }
 
 // Helper function to create animation from atlas metadata
+#[allow(clippy::type_complexity)]
 pub fn create_animation_from_atlas(
     atlas_meta_path: &str,
     asset_server: &AssetServer,
@@ -0,0 +1,767 @@
+/*!
+❄️ STATUS EFFECTS
+Efeitos de status em inimigos aplicados por boons: lentidão/congelamento (lido pelo ai_system),
+dano ao longo do tempo (veneno/sangramento), que passa pelo mesmo pipeline de dano dos golpes,
+knockup (o inimigo é arremessado para cima, não age e sofre dano extra até cair) e atordoamento
+(parado no lugar, sofre dano extra, com retornos decrescentes para não travar o mesmo alvo).
+Queimadura de Rá fica num componente próprio (Burning): dano contínuo a cada passo com brasas
+subindo; pegar fogo de novo só renova a duração
+*/
+
+use bevy::prelude::*;
+use crate::boons::{ActiveBoons, BoonEffect};
+use crate::ui::AppState;
+use crate::{Enemy, Stats, ShieldedEnemy, SimulationSet, SpawnParticlesEvent, Spawning, apply_enemy_damage};
+
+// Retornos decrescentes: cada lentidão aplicada reduz a duração da próxima
+const SLOW_RESISTANCE_PER_APPLY: f32 = 0.35;
+const SLOW_RESISTANCE_MAX: f32 = 0.85;
+const SLOW_RESISTANCE_DECAY: f32 = 0.15; // por segundo, enquanto não estiver lento
+// Abaixo deste multiplicador de velocidade o inimigo conta como congelado
+const FROZEN_THRESHOLD: f32 = 0.1;
+
+// Dano ao longo do tempo: intervalo entre ticks, limite de acúmulos do veneno
+// e bônus do sangramento por unidade percorrida entre ticks
+const DOT_TICK_INTERVAL: f32 = 0.5;
+pub const POISON_MAX_STACKS: u32 = 5;
+const BLEED_MOVEMENT_BONUS: f32 = 0.5;
+
+// Knockup: altura do chão dos inimigos e dano extra sofrido no ar (fração do golpe)
+const ENEMY_GROUND_Y: f32 = 0.5;
+const AIRBORNE_DAMAGE_BONUS: f32 = 0.5;
+
+// Atordoamento: dano extra, retornos decrescentes (como a lentidão) e duração do golpe carregado
+const STUNNED_DAMAGE_BONUS: f32 = 0.25;
+const STUN_RESISTANCE_PER_APPLY: f32 = 0.4;
+const STUN_RESISTANCE_MAX: f32 = 0.8;
+const STUN_RESISTANCE_DECAY: f32 = 0.2; // por segundo, enquanto não estiver atordoado
+const HEAVY_STUN_DURATION: f32 = 0.6;
+
+// Queimadura: intervalo das brasas e aumento do dano por nível da bênção
+const BURN_EMBER_INTERVAL: f32 = 0.25;
+const BURN_LEVEL_BONUS: f32 = 0.5;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StatusEffectKind {
+    Slow,
+    Poison,
+    Bleed,
+    Knockup,
+    Stun,
+}
+
+#[derive(Clone, Debug)]
+pub struct StatusEffect {
+    pub kind: StatusEffectKind,
+    pub remaining: f32,
+    pub duration: f32,
+    // Slow: fração de velocidade removida; Poison/Bleed: dano por segundo (total); Knockup: altura do arco
+    pub magnitude: f32,
+    pub stacks: u32,
+}
+
+#[derive(Component, Default)]
+pub struct StatusEffects {
+    pub effects: Vec<StatusEffect>,
+    pub slow_resistance: f32,
+    pub stun_resistance: f32,
+    dot_timer: f32,
+    // Distância percorrida desde o último tick (escala o sangramento)
+    moved_since_tick: f32,
+    last_position: Option<Vec3>,
+    // Ícones exibidos no momento: (acúmulos de veneno, sangrando)
+    shown_icons: (u32, bool),
+    // Vida no último passo enquanto vulnerável (no ar/atordoado); base do dano extra
+    vulnerable_health: Option<f32>,
+    airborne: bool,
+}
+
+impl StatusEffects {
+    pub fn apply(&mut self, kind: StatusEffectKind, duration: f32, magnitude: f32) {
+        match kind {
+            StatusEffectKind::Slow => {
+                let duration = duration * (1.0 - self.slow_resistance);
+                self.slow_resistance = (self.slow_resistance + SLOW_RESISTANCE_PER_APPLY).min(SLOW_RESISTANCE_MAX);
+                self.effects.push(StatusEffect { kind, remaining: duration, duration, magnitude, stacks: 1 });
+            }
+            // Veneno acumula intensidade (magnitude = dano por acúmulo) até o limite e renova a duração
+            StatusEffectKind::Poison => match self.find_mut(kind) {
+                Some(poison) => {
+                    poison.stacks = (poison.stacks + 1).min(POISON_MAX_STACKS);
+                    poison.magnitude = magnitude * poison.stacks as f32;
+                    poison.remaining = poison.remaining.max(duration);
+                }
+                None => self.effects.push(StatusEffect { kind, remaining: duration, duration, magnitude, stacks: 1 }),
+            },
+            // Sangramento não acumula: renova e mantém o mais forte
+            StatusEffectKind::Bleed => match self.find_mut(kind) {
+                Some(bleed) => {
+                    bleed.magnitude = bleed.magnitude.max(magnitude);
+                    bleed.remaining = bleed.remaining.max(duration);
+                }
+                None => self.effects.push(StatusEffect { kind, remaining: duration, duration, magnitude, stacks: 1 }),
+            },
+            // Não renova enquanto atordoado; cada aplicação encurta as próximas
+            StatusEffectKind::Stun => {
+                if !self.has(kind) {
+                    let duration = duration * (1.0 - self.stun_resistance);
+                    self.stun_resistance = (self.stun_resistance + STUN_RESISTANCE_PER_APPLY).min(STUN_RESISTANCE_MAX);
+                    self.effects.push(StatusEffect { kind, remaining: duration, duration, magnitude, stacks: 1 });
+                }
+            }
+            // Um arremesso por vez: quem já está no ar só cai
+            StatusEffectKind::Knockup => {
+                if !self.has(kind) {
+                    self.effects.push(StatusEffect { kind, remaining: duration, duration, magnitude, stacks: 1 });
+                }
+            }
+        }
+    }
+
+    fn find_mut(&mut self, kind: StatusEffectKind) -> Option<&mut StatusEffect> {
+        self.effects.iter_mut().find(|effect| effect.kind == kind)
+    }
+
+    fn has(&self, kind: StatusEffectKind) -> bool {
+        self.effects.iter().any(|effect| effect.kind == kind)
+    }
+
+    pub fn poison_stacks(&self) -> u32 {
+        self.effects.iter()
+            .find(|effect| effect.kind == StatusEffectKind::Poison)
+            .map_or(0, |effect| effect.stacks)
+    }
+
+    // Lentidões não somam: vale a mais forte ativa
+    pub fn speed_multiplier(&self) -> f32 {
+        self.effects.iter()
+            .filter(|effect| effect.kind == StatusEffectKind::Slow)
+            .map(|effect| 1.0 - effect.magnitude.clamp(0.0, 1.0))
+            .fold(1.0, f32::min)
+    }
+
+    // Ataques desaceleram junto com o movimento
+    pub fn attack_rate_multiplier(&self) -> f32 {
+        self.speed_multiplier()
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.speed_multiplier() <= FROZEN_THRESHOLD
+    }
+
+    pub fn is_airborne(&self) -> bool {
+        self.has(StatusEffectKind::Knockup)
+    }
+
+    pub fn is_stunned(&self) -> bool {
+        self.has(StatusEffectKind::Stun)
+    }
+
+    // Fora de ação: o ai_system pula o inimigo inteiro
+    pub fn is_incapacitated(&self) -> bool {
+        self.is_airborne() || self.is_stunned()
+    }
+
+    // Fração extra de dano sofrido enquanto indefeso (vale a maior)
+    fn vulnerable_bonus(&self) -> f32 {
+        if self.is_airborne() {
+            AIRBORNE_DAMAGE_BONUS
+        } else if self.is_stunned() {
+            STUNNED_DAMAGE_BONUS
+        } else {
+            0.0
+        }
+    }
+
+    // Altura acima do chão no arco do knockup (parábola: sobe e desce no mesmo tempo)
+    fn knockup_height(&self) -> Option<f32> {
+        self.effects.iter()
+            .find(|effect| effect.kind == StatusEffectKind::Knockup)
+            .map(|effect| {
+                let t = (1.0 - effect.remaining / effect.duration.max(0.01)).clamp(0.0, 1.0);
+                4.0 * effect.magnitude * t * (1.0 - t)
+            })
+    }
+}
+
+// Pedido de aplicação de status - resolvido em apply_status_events
+#[derive(Event)]
+pub struct ApplyStatusEvent {
+    pub target: Entity,
+    pub kind: StatusEffectKind,
+    pub duration: f32,
+    pub magnitude: f32,
+}
+
+// Status carregado por um projétil/golpe e aplicado no inimigo atingido
+#[derive(Component, Clone, Copy)]
+pub struct OnHitStatus {
+    pub kind: StatusEffectKind,
+    pub duration: f32,
+    pub magnitude: f32,
+}
+
+impl OnHitStatus {
+    pub fn event_for(&self, target: Entity) -> ApplyStatusEvent {
+        ApplyStatusEvent {
+            target,
+            kind: self.kind,
+            duration: self.duration,
+            magnitude: self.magnitude,
+        }
+    }
+}
+
+/// Inimigo em chamas: perde vida a cada passo até a duração acabar
+#[derive(Component, Clone, Copy)]
+pub struct Burning {
+    pub damage_per_second: f32,
+    pub remaining: f32,
+    ember_timer: f32,
+}
+
+/// Queimadura que os golpes corpo a corpo podem aplicar (chance por acerto)
+#[derive(Clone, Copy)]
+pub struct BurnOnHit {
+    pub chance: f32,
+    pub damage_per_second: f32,
+    pub duration: f32,
+}
+
+impl BurnOnHit {
+    // Inserir de novo substitui o componente: renova a duração em vez de empilhar
+    pub fn burning(&self) -> Burning {
+        Burning { damage_per_second: self.damage_per_second, remaining: self.duration, ember_timer: 0.0 }
+    }
+}
+
+// Esfera azul translúcida enquanto o inimigo está lento (filha do inimigo)
+#[derive(Component)]
+struct FrostOverlay;
+
+// Marcadores acima do inimigo: um por acúmulo de veneno, um para sangramento
+#[derive(Component)]
+struct StatusIcon;
+
+// Anel de estrelas girando acima do inimigo atordoado (filho do inimigo)
+#[derive(Component)]
+struct StunHalo;
+
+const STUN_STAR_COUNT: usize = 3;
+const STUN_STAR_SPIN: f32 = 6.0; // rad/s
+
+// Intervalo das partículas de gelo
+#[derive(Resource)]
+struct FrostParticleTimer(Timer);
+
+pub struct StatusEffectsPlugin;
+
+impl Plugin for StatusEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ApplyStatusEvent>()
+            .insert_resource(FrostParticleTimer(Timer::from_seconds(0.25, TimerMode::Repeating)))
+            // Duração e dano periódico fazem parte da simulação em passo fixo
+            .add_systems(FixedUpdate, (
+                apply_status_events,
+                tick_status_effects,
+                damage_over_time_system,
+                burn_tick_system,
+                vulnerable_damage_system,
+                knockup_motion_system,
+            ).chain()
+                // Depois dos golpes (e do stagger que aplica atordoamento), antes da checagem de mortes
+                .after(crate::enemy_stagger_system)
+                .before(crate::shield_break_system)
+                .in_set(SimulationSet)
+                .run_if(in_state(AppState::InGame)))
+            .add_systems(Update, (
+                frost_visual_system,
+                stun_visual_system,
+                status_icon_system,
+            ).chain().run_if(in_state(AppState::InGame)));
+    }
+}
+
+// Procura um boon que faça o Q aplicar status ao acertar
+pub fn q_on_hit_status(active_boons: &ActiveBoons) -> Option<OnHitStatus> {
+    active_boons.player_boons.iter()
+        .flat_map(|boon| boon.effects.iter().map(move |effect| (boon.level, effect)))
+        .find_map(|(level, effect)| match effect {
+            BoonEffect::AbilityEnhancement { ability, enhancement } if ability == "Q" => {
+                match enhancement.as_ref() {
+                    BoonEffect::ApplySlow { slow_percentage, duration } => Some(OnHitStatus {
+                        kind: StatusEffectKind::Slow,
+                        // Cada nível extra aumenta a duração em 0.5s
+                        duration: duration + 0.5 * (level - 1) as f32,
+                        magnitude: *slow_percentage,
+                    }),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+}
+
+// Boons que fazem o R arremessar os inimigos atingidos
+pub fn r_on_hit_status(active_boons: &ActiveBoons) -> Option<OnHitStatus> {
+    active_boons.player_boons.iter()
+        .flat_map(|boon| boon.effects.iter().map(move |effect| (boon.level, effect)))
+        .find_map(|(level, effect)| match effect {
+            BoonEffect::AbilityEnhancement { ability, enhancement } if ability == "R" => {
+                match enhancement.as_ref() {
+                    BoonEffect::Knockup { height, duration } => Some(OnHitStatus {
+                        kind: StatusEffectKind::Knockup,
+                        // Cada nível extra deixa o inimigo 0.15s a mais no ar
+                        duration: duration + 0.15 * (level - 1) as f32,
+                        magnitude: *height,
+                    }),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+}
+
+// Bênçãos de fogo de Rá nos golpes (direto ou com chance por acerto); vale a mais forte
+pub fn attack_burn(active_boons: &ActiveBoons) -> Option<BurnOnHit> {
+    active_boons.player_boons.iter()
+        .flat_map(|boon| boon.effects.iter().map(move |effect| (boon.level, effect)))
+        .filter_map(|(level, effect)| {
+            let (chance, burn) = match effect {
+                BoonEffect::OnHitChance { chance, effect } => (*chance, effect.as_ref()),
+                burn => (1.0, burn),
+            };
+            match burn {
+                BoonEffect::BurnDamage { damage_per_second, duration } => Some(BurnOnHit {
+                    chance,
+                    damage_per_second: damage_per_second * (1.0 + BURN_LEVEL_BONUS * (level - 1) as f32),
+                    duration: *duration,
+                }),
+                _ => None,
+            }
+        })
+        .max_by(|a, b| (a.chance * a.damage_per_second).total_cmp(&(b.chance * b.damage_per_second)))
+}
+
+// Golpe carregado sempre atordoa; boons de Set alongam e fazem o dash-strike atordoar também.
+// Retorna (atordoamento do golpe carregado, do dash-strike)
+pub fn heavy_hit_stuns(active_boons: &ActiveBoons) -> (OnHitStatus, Option<OnHitStatus>) {
+    let bonus = active_boons.player_boons.iter()
+        .flat_map(|boon| boon.effects.iter().map(move |effect| (boon.level, effect)))
+        .filter_map(|(level, effect)| match effect {
+            BoonEffect::StunOnHeavy { bonus_duration } => Some(bonus_duration * level as f32),
+            _ => None,
+        })
+        .reduce(|a, b| a + b);
+    let stun = |duration: f32| OnHitStatus { kind: StatusEffectKind::Stun, duration, magnitude: 0.0 };
+    (stun(HEAVY_STUN_DURATION + bonus.unwrap_or(0.0)), bonus.map(stun))
+}
+
+// Venenos/sangramentos aplicados pelos ataques básicos (boons de Anúbis e Set)
+pub fn attack_on_hit_statuses(active_boons: &ActiveBoons) -> Vec<OnHitStatus> {
+    active_boons.player_boons.iter()
+        .flat_map(|boon| boon.effects.iter().map(move |effect| (boon.level, effect)))
+        .filter_map(|(level, effect)| {
+            // Cada nível extra aumenta o dano por segundo em 50%
+            let level_mult = 1.0 + 0.5 * (level - 1) as f32;
+            match effect {
+                BoonEffect::ApplyPoison { damage_per_second, duration } => Some(OnHitStatus {
+                    kind: StatusEffectKind::Poison,
+                    duration: *duration,
+                    magnitude: damage_per_second * level_mult,
+                }),
+                BoonEffect::ApplyBleed { damage_per_second, duration } => Some(OnHitStatus {
+                    kind: StatusEffectKind::Bleed,
+                    duration: *duration,
+                    magnitude: damage_per_second * level_mult,
+                }),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+fn apply_status_events(
+    mut commands: Commands,
+    mut status_events: EventReader<ApplyStatusEvent>,
+    mut targets: Query<Option<&mut StatusEffects>, (With<Enemy>, Without<Spawning>)>,
+) {
+    for event in status_events.read() {
+        let Ok(status) = targets.get_mut(event.target) else {
+            continue;
+        };
+        match status {
+            Some(mut status) => status.apply(event.kind, event.duration, event.magnitude),
+            None => {
+                let mut status = StatusEffects::default();
+                status.apply(event.kind, event.duration, event.magnitude);
+                commands.entity(event.target).insert(status);
+            }
+        }
+    }
+}
+
+fn tick_status_effects(
+    time: Res<Time>,
+    mut targets: Query<&mut StatusEffects>,
+) {
+    let dt = time.delta_seconds();
+    for mut status in &mut targets {
+        for effect in &mut status.effects {
+            effect.remaining -= dt;
+        }
+        status.effects.retain(|effect| effect.remaining > 0.0);
+
+        if !status.has(StatusEffectKind::Slow) {
+            status.slow_resistance = (status.slow_resistance - SLOW_RESISTANCE_DECAY * dt).max(0.0);
+        }
+        if !status.is_stunned() {
+            status.stun_resistance = (status.stun_resistance - STUN_RESISTANCE_DECAY * dt).max(0.0);
+        }
+    }
+}
+
+// Ticks de veneno/sangramento - independem de golpes; mortes saem pelo enemy_death_system
+fn damage_over_time_system(
+    time: Res<Time>,
+    mut targets: Query<(&Transform, &mut StatusEffects, &mut Stats, Option<&mut ShieldedEnemy>), With<Enemy>>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+) {
+    let dt = time.delta_seconds();
+    for (transform, mut status, mut stats, mut shield) in &mut targets {
+        if let Some(last) = status.last_position {
+            status.moved_since_tick += last.distance(transform.translation);
+        }
+        status.last_position = Some(transform.translation);
+
+        status.dot_timer += dt;
+        if status.dot_timer < DOT_TICK_INTERVAL {
+            continue;
+        }
+        status.dot_timer -= DOT_TICK_INTERVAL;
+        let moved = std::mem::take(&mut status.moved_since_tick);
+
+        for effect in &status.effects {
+            let (damage, color) = match effect.kind {
+                StatusEffectKind::Poison => (effect.magnitude * DOT_TICK_INTERVAL, Color::rgb(0.4, 0.9, 0.2)),
+                // Fugir sangrando dói mais
+                StatusEffectKind::Bleed => (
+                    effect.magnitude * DOT_TICK_INTERVAL * (1.0 + moved * BLEED_MOVEMENT_BONUS),
+                    Color::rgb(0.8, 0.05, 0.05),
+                ),
+                StatusEffectKind::Slow | StatusEffectKind::Knockup | StatusEffectKind::Stun => continue,
+            };
+            apply_enemy_damage(&mut stats, shield.as_mut().map(|shield| shield.reborrow()), damage);
+            particle_events.send(SpawnParticlesEvent {
+                position: transform.translation,
+                color,
+                count: 3,
+            });
+        }
+    }
+}
+
+// Queimadura contínua (não espera o tick do veneno); mortes saem pelo enemy_death_system,
+// com som e abate contados como qualquer outro
+#[allow(clippy::type_complexity)]
+fn burn_tick_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut targets: Query<(Entity, &Transform, &mut Burning, &mut Stats, Option<&mut ShieldedEnemy>), With<Enemy>>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+) {
+    let dt = time.delta_seconds();
+    for (entity, transform, mut burning, mut stats, shield) in &mut targets {
+        apply_enemy_damage(&mut stats, shield, burning.damage_per_second * dt);
+        burning.remaining -= dt;
+        burning.ember_timer -= dt;
+        if burning.ember_timer <= 0.0 {
+            burning.ember_timer = BURN_EMBER_INTERVAL;
+            particle_events.send(SpawnParticlesEvent {
+                position: transform.translation + Vec3::Y * 0.4,
+                color: Color::rgb(1.0, 0.45, 0.05),
+                count: 2,
+            });
+        }
+        if burning.remaining <= 0.0 {
+            commands.entity(entity).remove::<Burning>();
+        }
+    }
+}
+
+// Dano que chega enquanto o inimigo está indefeso (no ar/atordoado) ganha um extra:
+// compara a vida com o passo anterior e aplica a fração de bônus sobre a diferença
+fn vulnerable_damage_system(
+    mut targets: Query<(&mut StatusEffects, &mut Stats), With<Enemy>>,
+) {
+    for (mut status, mut stats) in &mut targets {
+        let bonus = status.vulnerable_bonus();
+        if bonus <= 0.0 {
+            status.vulnerable_health = None;
+            continue;
+        }
+        if let Some(previous) = status.vulnerable_health {
+            let taken = previous - stats.current_health;
+            if taken > 0.0 {
+                stats.current_health -= taken * bonus;
+            }
+        }
+        status.vulnerable_health = Some(stats.current_health);
+    }
+}
+
+// Arco do knockup: o ai_system não mexe em quem está no ar, então a altura é toda daqui.
+// Ao cair, volta ao chão com poeira
+fn knockup_motion_system(
+    mut targets: Query<(&mut Transform, &mut StatusEffects), With<Enemy>>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+) {
+    for (mut transform, mut status) in &mut targets {
+        match status.knockup_height() {
+            Some(height) => {
+                status.airborne = true;
+                transform.translation.y = ENEMY_GROUND_Y + height;
+            }
+            None if status.airborne => {
+                status.airborne = false;
+                transform.translation.y = ENEMY_GROUND_Y;
+                particle_events.send(SpawnParticlesEvent {
+                    position: transform.translation,
+                    color: Color::rgb(0.7, 0.6, 0.4),
+                    count: 8,
+                });
+            }
+            None => {}
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn frost_visual_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    time: Res<Time>,
+    mut particle_timer: ResMut<FrostParticleTimer>,
+    targets: Query<(Entity, &Transform, &StatusEffects, Option<&Children>)>,
+    overlays: Query<(), With<FrostOverlay>>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+) {
+    let emit_particles = particle_timer.0.tick(time.delta()).just_finished();
+
+    for (entity, transform, status, children) in &targets {
+        let overlay = children.into_iter().flatten()
+            .copied()
+            .find(|child| overlays.contains(*child));
+        let slowed = status.has(StatusEffectKind::Slow);
+
+        match (slowed, overlay) {
+            (true, None) => {
+                // Compensa a escala do pai (modelos glTF vêm escalados)
+                let radius = 1.0 / transform.scale.x.max(0.1);
+                let alpha = if status.is_frozen() { 0.5 } else { 0.3 };
+                let frost = commands.spawn((
+                    PbrBundle {
+                        mesh: meshes.add(Sphere::new(radius)),
+                        material: materials.add(StandardMaterial {
+                            base_color: Color::rgba(0.5, 0.8, 1.0, alpha),
+                            emissive: Color::rgb(0.3, 0.6, 1.0),
+                            alpha_mode: AlphaMode::Blend,
+                            unlit: true,
+                            ..default()
+                        }),
+                        ..default()
+                    },
+                    FrostOverlay,
+                )).id();
+                commands.entity(entity).add_child(frost);
+            }
+            (false, Some(frost)) => {
+                commands.entity(frost).despawn_recursive();
+            }
+            _ => {}
+        }
+
+        if slowed && emit_particles {
+            particle_events.send(SpawnParticlesEvent {
+                position: transform.translation,
+                color: Color::rgb(0.7, 0.9, 1.0),
+                count: 2,
+            });
+        }
+    }
+}
+
+// Estrelinhas girando acima da cabeça enquanto atordoado
+fn stun_visual_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    time: Res<Time>,
+    targets: Query<(Entity, &Transform, &StatusEffects, Option<&Children>)>,
+    mut halos: Query<&mut Transform, (With<StunHalo>, Without<StatusEffects>)>,
+) {
+    for (entity, transform, status, children) in &targets {
+        let halo = children.into_iter().flatten()
+            .copied()
+            .find(|child| halos.contains(*child));
+
+        match (status.is_stunned(), halo) {
+            (true, None) => {
+                let inv_scale = 1.0 / transform.scale.x.max(0.1);
+                let star_mesh = meshes.add(Sphere::new(0.07 * inv_scale));
+                let star_material = materials.add(StandardMaterial {
+                    base_color: Color::rgb(1.0, 0.9, 0.3),
+                    emissive: Color::rgb(2.0, 1.8, 0.6),
+                    unlit: true,
+                    ..default()
+                });
+                let halo = commands.spawn((
+                    SpatialBundle::from_transform(Transform::from_xyz(0.0, 1.9 * inv_scale, 0.0)),
+                    StunHalo,
+                )).with_children(|halo| {
+                    for i in 0..STUN_STAR_COUNT {
+                        let angle = i as f32 / STUN_STAR_COUNT as f32 * std::f32::consts::TAU;
+                        halo.spawn(PbrBundle {
+                            mesh: star_mesh.clone(),
+                            material: star_material.clone(),
+                            transform: Transform::from_xyz(angle.cos() * 0.35 * inv_scale, 0.0, angle.sin() * 0.35 * inv_scale),
+                            ..default()
+                        });
+                    }
+                }).id();
+                commands.entity(entity).add_child(halo);
+            }
+            (true, Some(halo)) => {
+                if let Ok(mut halo_transform) = halos.get_mut(halo) {
+                    halo_transform.rotate_y(STUN_STAR_SPIN * time.delta_seconds());
+                }
+            }
+            (false, Some(halo)) => {
+                commands.entity(halo).despawn_recursive();
+            }
+            (false, None) => {}
+        }
+    }
+}
+
+fn status_icon_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut targets: Query<(Entity, &Transform, &mut StatusEffects, Option<&Children>)>,
+    icons: Query<(), With<StatusIcon>>,
+) {
+    for (entity, transform, mut status, children) in &mut targets {
+        let wanted = (status.poison_stacks(), status.has(StatusEffectKind::Bleed));
+        if wanted == status.shown_icons {
+            continue;
+        }
+        status.shown_icons = wanted;
+
+        for &child in children.into_iter().flatten() {
+            if icons.contains(child) {
+                commands.entity(child).despawn_recursive();
+            }
+        }
+
+        // Ícones em linha acima da cabeça, compensando a escala do pai
+        let inv_scale = 1.0 / transform.scale.x.max(0.1);
+        let (poison_stacks, bleeding) = wanted;
+        let mut colors = vec![Color::rgb(0.4, 0.9, 0.2); poison_stacks as usize];
+        if bleeding {
+            colors.push(Color::rgb(0.8, 0.05, 0.05));
+        }
+        let count = colors.len() as f32;
+        for (i, color) in colors.into_iter().enumerate() {
+            let x = (i as f32 - (count - 1.0) / 2.0) * 0.25;
+            let icon = commands.spawn((
+                PbrBundle {
+                    mesh: meshes.add(Sphere::new(0.08 * inv_scale)),
+                    material: materials.add(StandardMaterial {
+                        base_color: color,
+                        emissive: color * 2.0,
+                        unlit: true,
+                        ..default()
+                    }),
+                    transform: Transform::from_translation(Vec3::new(x, 1.6, 0.0) * inv_scale),
+                    ..default()
+                },
+                StatusIcon,
+            )).id();
+            commands.entity(entity).add_child(icon);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn slow_durations(status: &StatusEffects) -> Vec<f32> {
+        status.effects.iter()
+            .filter(|effect| effect.kind == StatusEffectKind::Slow)
+            .map(|effect| effect.duration)
+            .collect()
+    }
+
+    #[test]
+    fn strongest_slow_sets_the_speed() {
+        let mut status = StatusEffects::default();
+        assert_eq!(status.speed_multiplier(), 1.0);
+        status.apply(StatusEffectKind::Slow, 2.0, 0.3);
+        status.apply(StatusEffectKind::Slow, 2.0, 0.6);
+        assert!((status.speed_multiplier() - 0.4).abs() < 1e-5);
+        assert!(!status.is_frozen());
+    }
+
+    #[test]
+    fn repeated_slows_have_diminishing_duration() {
+        let mut status = StatusEffects::default();
+        for _ in 0..4 {
+            status.apply(StatusEffectKind::Slow, 2.0, 0.5);
+        }
+        let durations = slow_durations(&status);
+        assert!((durations[0] - 2.0).abs() < 1e-5);
+        assert!((durations[1] - 2.0 * (1.0 - SLOW_RESISTANCE_PER_APPLY)).abs() < 1e-5);
+        assert!(durations.windows(2).all(|pair| pair[1] < pair[0]));
+        // A resistência para no teto: a lentidão nunca some por completo
+        assert!((durations[3] - 2.0 * (1.0 - SLOW_RESISTANCE_MAX)).abs() < 1e-5);
+        assert_eq!(status.slow_resistance, SLOW_RESISTANCE_MAX);
+    }
+
+    #[test]
+    fn poison_stacks_up_to_the_cap() {
+        let mut status = StatusEffects::default();
+        for _ in 0..POISON_MAX_STACKS + 3 {
+            status.apply(StatusEffectKind::Poison, 3.0, 4.0);
+        }
+        assert_eq!(status.poison_stacks(), POISON_MAX_STACKS);
+        let poison = status.effects.iter().find(|effect| effect.kind == StatusEffectKind::Poison).unwrap();
+        assert_eq!(poison.magnitude, 4.0 * POISON_MAX_STACKS as f32);
+        // Um único efeito de veneno, não um por aplicação
+        assert_eq!(status.effects.len(), 1);
+    }
+
+    #[test]
+    fn poison_ticks_deal_damage_per_second_times_stacks() {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.init_resource::<Events<SpawnParticlesEvent>>();
+        let mut status = StatusEffects::default();
+        status.apply(StatusEffectKind::Poison, 10.0, 4.0);
+        status.apply(StatusEffectKind::Poison, 10.0, 4.0);
+        let enemy = world.spawn((Enemy, Transform::default(), status, Stats::default())).id();
+
+        // 2.25s de passos a 60 Hz: 4 ticks de meio segundo
+        for _ in 0..135 {
+            world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(1.0 / 60.0));
+            world.run_system_once(damage_over_time_system);
+        }
+
+        let taken = 100.0 - world.get::<Stats>(enemy).unwrap().current_health;
+        assert!((taken - 4.0 * DOT_TICK_INTERVAL * 2.0 * 4.0).abs() < 1e-3, "dano total: {taken}");
+    }
+}
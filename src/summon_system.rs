@@ -0,0 +1,200 @@
+/*!
+🐺 SUMMON SYSTEM
+Aliados invocados por boons (ex: Chacal de Anúbis) que caçam inimigos
+*/
+
+use bevy::prelude::*;
+use crate::boons::{ActiveBoons, BoonEffect};
+use crate::ui::AppState;
+use crate::{Enemy, Player, PlayerId, Stats, HitEffect, SimulationSet, SpawnParticlesEvent, AudioEvent, ShieldedEnemy, Spawning, apply_enemy_damage};
+
+#[derive(Component)]
+pub struct Summon {
+    pub lifetime: f32,
+    pub damage: f32,
+    pub attack_range: f32,
+    pub attack_cooldown: f32,
+    pub attack_timer: f32,
+}
+
+// Tempo até a próxima invocação automática
+#[derive(Resource, Default)]
+pub struct SummonCooldown {
+    pub timer: f32,
+}
+
+pub struct SummonPlugin;
+
+impl Plugin for SummonPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SummonCooldown>()
+            // Invocações causam dano: simulação em passo fixo, antes dos projéteis e das mortes
+            .add_systems(FixedUpdate, (
+                summon_spawn_system,
+                summon_ai_system,
+                summon_lifetime_system,
+            ).chain()
+                .after(crate::hades_combat_system)
+                .before(crate::projectile_movement_system)
+                .in_set(SimulationSet)
+                .run_if(in_state(AppState::InGame)))
+            .add_systems(OnEnter(AppState::Loading), cleanup_summons);
+    }
+}
+
+// Procura o primeiro boon de invocação ativo: (duração, dano, recarga)
+fn active_summon_boon(active_boons: &ActiveBoons) -> Option<(f32, f32, f32)> {
+    active_boons.player_boons.iter()
+        .flat_map(|boon| boon.effects.iter().map(move |effect| (boon.level, effect)))
+        .find_map(|(level, effect)| match effect {
+            BoonEffect::SummonAlly { duration, damage, cooldown } => {
+                // Cada nível extra aumenta o dano do aliado em 50%
+                Some((*duration, damage * (1.0 + 0.5 * (level - 1) as f32), *cooldown))
+            }
+            _ => None,
+        })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn summon_spawn_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    time: Res<Time>,
+    active_boons: Option<Res<ActiveBoons>>,
+    mut cooldown: ResMut<SummonCooldown>,
+    player_query: Query<(&PlayerId, &Transform), With<Player>>,
+    summons: Query<&Summon>,
+) {
+    let Some((duration, damage, recharge)) = active_boons.as_deref().and_then(active_summon_boon) else {
+        return;
+    };
+
+    cooldown.timer = (cooldown.timer - time.delta_seconds()).max(0.0);
+    if cooldown.timer > 0.0 || !summons.is_empty() {
+        return;
+    }
+
+    let Some((_, player_transform)) = player_query.iter().find(|(id, _)| id.is_primary()) else {
+        return;
+    };
+
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Capsule3d::new(0.3, 0.6)),
+            material: materials.add(StandardMaterial {
+                base_color: Color::rgb(0.15, 0.1, 0.2),
+                emissive: Color::rgb(0.8, 0.6, 0.1),
+                ..default()
+            }),
+            transform: Transform::from_translation(player_transform.translation + Vec3::new(1.0, 0.0, 1.0)),
+            ..default()
+        },
+        Summon {
+            lifetime: duration,
+            damage,
+            attack_range: 1.2,
+            attack_cooldown: 0.6,
+            attack_timer: 0.0,
+        },
+        Stats {
+            max_health: 50.0,
+            current_health: 50.0,
+            max_stamina: 0.0,
+            current_stamina: 0.0,
+            speed: 7.0,
+            stamina_regen_rate: 0.0,
+            poise: 0.0,
+            knockback_multiplier: 1.0,
+        },
+        Name::new("Summon: Anubis Jackal"),
+    ));
+
+    cooldown.timer = recharge;
+    info!("🐺 Anubis jackal summoned for {:.0}s", duration);
+}
+
+// Versão simplificada do ai_system: persegue o inimigo mais próximo e ataca em alcance
+#[allow(clippy::type_complexity)]
+pub(crate) fn summon_ai_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut summons: Query<(&mut Transform, &mut Summon, &Stats), Without<Enemy>>,
+    mut enemies: Query<(Entity, &Transform, &mut Stats, Option<&mut ShieldedEnemy>), (With<Enemy>, Without<Summon>, Without<Spawning>)>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+    mut audio_events: EventWriter<AudioEvent>,
+) {
+    let dt = time.delta_seconds();
+
+    for (mut summon_transform, mut summon, summon_stats) in &mut summons {
+        summon.attack_timer = (summon.attack_timer - dt).max(0.0);
+
+        let target = enemies.iter_mut()
+            .filter(|(_, _, stats, _)| stats.current_health > 0.0)
+            .min_by(|(_, a, _, _), (_, b, _, _)| {
+                let da = a.translation.distance_squared(summon_transform.translation);
+                let db = b.translation.distance_squared(summon_transform.translation);
+                da.total_cmp(&db)
+            });
+
+        let Some((enemy_entity, enemy_transform, mut enemy_stats, shield)) = target else {
+            continue;
+        };
+
+        let offset = enemy_transform.translation - summon_transform.translation;
+        let distance = Vec3::new(offset.x, 0.0, offset.z).length();
+
+        if distance > summon.attack_range {
+            let direction = Vec3::new(offset.x, 0.0, offset.z).normalize_or_zero();
+            summon_transform.translation += direction * summon_stats.speed * dt;
+            summon_transform.translation.y = 0.5;
+        } else if summon.attack_timer <= 0.0 {
+            // Conta como dano do jogador - mortes passam pelo enemy_death_system
+            apply_enemy_damage(&mut enemy_stats, shield, summon.damage);
+            summon.attack_timer = summon.attack_cooldown;
+
+            commands.entity(enemy_entity).insert(HitEffect {
+                timer: 0.0,
+                duration: 0.3,
+                original_scale: enemy_transform.scale,
+            });
+            particle_events.send(SpawnParticlesEvent {
+                position: enemy_transform.translation,
+                color: Color::rgb(0.9, 0.7, 0.2),
+                count: 6,
+            });
+            if enemy_stats.current_health > 0.0 {
+                audio_events.send(AudioEvent::EnemyHit);
+            }
+        }
+    }
+}
+
+fn summon_lifetime_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut summons: Query<(Entity, &mut Summon)>,
+    player_query: Query<&Stats, With<Player>>,
+) {
+    // Aliados somem quando todos os jogadores caem
+    let player_dead = player_query.iter().all(|stats| stats.current_health <= 0.0);
+
+    for (entity, mut summon) in &mut summons {
+        summon.lifetime -= time.delta_seconds();
+        if summon.lifetime <= 0.0 || player_dead {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+// Run nova: invocações da run anterior somem e a recarga recomeça
+fn cleanup_summons(
+    mut commands: Commands,
+    mut cooldown: ResMut<SummonCooldown>,
+    summons: Query<Entity, With<Summon>>,
+) {
+    for entity in &summons {
+        commands.entity(entity).despawn_recursive();
+    }
+    *cooldown = SummonCooldown::default();
+}
@@ -0,0 +1,238 @@
+/*!
+🗿 TOTEM SYSTEM
+Obelisco Solar de Rá: o jogador ergue um obelisco parado (T) que dispara raios de sol
+no inimigo mais próximo. Os tiros são projéteis do jogador - passam pelo
+projectile_collision_system, aplicam os venenos/sangramentos dos boons e as mortes
+contam para o enemy_death_system (XP, on-kill)
+*/
+
+use bevy::prelude::*;
+use crate::boons::{ActiveBoons, BoonEffect};
+use crate::projectile_pool::{fire_projectile, ProjectilePool, ProjectileVisual};
+use crate::status_effects::attack_on_hit_statuses;
+use crate::ui::AppState;
+use crate::{fan_directions, Combat, Enemy, FixedInput, Player, PlayerId, Projectile, RoomEnteredEvent, SimulationSet, SpawnParticlesEvent, Spawning, Stats, Q_FAN_SPREAD};
+
+// Alcance de mira e balística dos raios (versão simplificada do Shooter)
+const TOTEM_RANGE: f32 = 10.0;
+const TOTEM_SHOT_SPEED: f32 = 14.0;
+const TOTEM_SHOT_TTL: f32 = 1.2;
+const TOTEM_SHOT_HEIGHT: f32 = 1.2;
+
+#[derive(Component)]
+pub struct Totem {
+    pub lifetime: f32,
+    pub damage: f32,
+    pub fire_interval: f32,
+    pub fire_timer: f32,
+}
+
+// Obeliscos já erguidos na sala atual
+#[derive(Resource, Default)]
+struct TotemPlacements {
+    used: u32,
+}
+
+pub struct TotemPlugin;
+
+impl Plugin for TotemPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TotemPlacements>()
+            // Erguer lê a entrada da simulação (replays); disparo e duração seguem o mesmo passo fixo
+            .add_systems(FixedUpdate, (
+                reset_totems_on_room_enter,
+                place_totem,
+                totem_fire_system,
+                totem_lifetime_system,
+            ).chain()
+                .after(crate::hades_combat_system)
+                // Mira só em quem sobreviveu aos aliados invocados neste passo
+                .after(crate::summon_system::summon_ai_system)
+                .before(crate::projectile_movement_system)
+                .in_set(SimulationSet)
+                .run_if(in_state(AppState::InGame)))
+            .add_systems(OnEnter(AppState::Loading), cleanup_totems);
+    }
+}
+
+struct TotemBoon {
+    duration: f32,
+    damage: f32,
+    fire_interval: f32,
+    charges: u32,
+}
+
+fn active_totem_boon(active_boons: &ActiveBoons) -> Option<TotemBoon> {
+    active_boons.player_boons.iter()
+        .flat_map(|boon| boon.effects.iter().map(move |effect| (boon.level, effect)))
+        .find_map(|(level, effect)| match effect {
+            BoonEffect::SummonTotem { duration, damage, fire_interval, charges } => Some(TotemBoon {
+                duration: *duration,
+                // Cada nível extra aumenta o dano dos raios em 50%
+                damage: damage * (1.0 + 0.5 * (level - 1) as f32),
+                fire_interval: *fire_interval,
+                charges: *charges,
+            }),
+            _ => None,
+        })
+}
+
+// Cada sala nova devolve as cargas; obeliscos não acompanham o grupo
+fn reset_totems_on_room_enter(
+    mut commands: Commands,
+    mut entered_events: EventReader<RoomEnteredEvent>,
+    mut placements: ResMut<TotemPlacements>,
+    totems: Query<Entity, With<Totem>>,
+) {
+    if entered_events.read().count() == 0 {
+        return;
+    }
+    placements.used = 0;
+    for entity in &totems {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn place_totem(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    fixed_input: Res<FixedInput>,
+    active_boons: Res<ActiveBoons>,
+    mut placements: ResMut<TotemPlacements>,
+    player_query: Query<(&PlayerId, &Transform, &Stats), With<Player>>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+) {
+    if !fixed_input.get(PlayerId::P1).place_totem {
+        return;
+    }
+    let Some(boon) = active_totem_boon(&active_boons) else {
+        return;
+    };
+    if placements.used >= boon.charges {
+        info!("🗿 No obelisk charges left in this room");
+        return;
+    }
+    let Some((_, player_transform, stats)) = player_query.iter().find(|(id, ..)| id.is_primary()) else {
+        return;
+    };
+    if stats.current_health <= 0.0 {
+        return;
+    }
+
+    let position = Vec3::new(player_transform.translation.x, 0.0, player_transform.translation.z);
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Cuboid::new(0.4, 1.6, 0.4)),
+            material: materials.add(StandardMaterial {
+                base_color: Color::rgb(0.75, 0.6, 0.3),
+                emissive: Color::rgb(0.9, 0.5, 0.1),
+                ..default()
+            }),
+            transform: Transform::from_translation(position + Vec3::Y * 0.8),
+            ..default()
+        },
+        Totem {
+            lifetime: boon.duration,
+            damage: boon.damage,
+            fire_interval: boon.fire_interval,
+            fire_timer: 0.0,
+        },
+        Name::new("Totem: Ra Obelisk"),
+    ));
+    particle_events.send(SpawnParticlesEvent {
+        position,
+        color: Color::rgb(1.0, 0.8, 0.3),
+        count: 14,
+    });
+    placements.used += 1;
+    info!("🗿 Ra obelisk raised ({}/{} this room)", placements.used, boon.charges);
+}
+
+// Mira no inimigo vivo mais próximo dentro do alcance e atira quando a recarga zera
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn totem_fire_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    time: Res<Time>,
+    active_boons: Res<ActiveBoons>,
+    mut totems: Query<(&Transform, &mut Totem)>,
+    enemies: Query<(&Transform, &Stats), (With<Enemy>, Without<Spawning>)>,
+    players: Query<(&PlayerId, &Combat), With<Player>>,
+    mut projectile_pool: ResMut<ProjectilePool>,
+) {
+    let on_hit = attack_on_hit_statuses(&active_boons).first().copied();
+    // Bênçãos de múltiplos projéteis também abrem os raios do obelisco em leque
+    let shots = players.iter()
+        .find(|(id, _)| id.is_primary())
+        .map_or(1, |(_, combat)| combat.projectile_count);
+
+    for (totem_transform, mut totem) in &mut totems {
+        totem.fire_timer = (totem.fire_timer - time.delta_seconds()).max(0.0);
+        if totem.fire_timer > 0.0 {
+            continue;
+        }
+
+        let muzzle = Vec3::new(totem_transform.translation.x, TOTEM_SHOT_HEIGHT, totem_transform.translation.z);
+        let target = enemies.iter()
+            .filter(|(_, stats)| stats.current_health > 0.0)
+            .map(|(transform, _)| transform.translation)
+            .filter(|position| position.distance(muzzle) <= TOTEM_RANGE)
+            .min_by(|a, b| a.distance_squared(muzzle).total_cmp(&b.distance_squared(muzzle)));
+        let Some(target) = target else {
+            continue;
+        };
+
+        let aim = (target - muzzle).normalize_or_zero();
+        for direction in fan_directions(aim, shots, Q_FAN_SPREAD) {
+            let projectile = fire_projectile(
+                &mut commands,
+                &mut projectile_pool,
+                &mut meshes,
+                &mut materials,
+                ProjectileVisual::TotemShot,
+                Transform::from_translation(muzzle + direction * 0.4),
+                Projectile {
+                    damage: totem.damage.round() as i32,
+                    velocity: direction * TOTEM_SHOT_SPEED,
+                    ttl: TOTEM_SHOT_TTL,
+                    from_enemy: false,
+                },
+            );
+            if let Some(on_hit) = on_hit {
+                commands.entity(projectile).insert(on_hit);
+            }
+        }
+        totem.fire_timer = totem.fire_interval;
+    }
+}
+
+fn totem_lifetime_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut totems: Query<(Entity, &Transform, &mut Totem)>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+) {
+    for (entity, transform, mut totem) in &mut totems {
+        totem.lifetime -= time.delta_seconds();
+        if totem.lifetime > 0.0 {
+            continue;
+        }
+        // Desmorona em areia
+        particle_events.send(SpawnParticlesEvent {
+            position: transform.translation,
+            color: Color::rgb(0.8, 0.65, 0.35),
+            count: 12,
+        });
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// Run nova: obeliscos da run anterior não sobrevivem
+fn cleanup_totems(mut commands: Commands, totems: Query<Entity, With<Totem>>) {
+    for entity in &totems {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,272 @@
+/*!
+🪤 TRAP SYSTEM
+Armadilhas das salas do tesouro/secretas (mecânicas Trapped_Chest e Legendary_Loot):
+placas de dardos, fossos de espinhos e piso que desaba. Cada uma é um volume de gatilho
+no chão, levemente mais escuro que o piso; jogadores e inimigos disparam ao pisar
+*/
+
+use bevy::prelude::*;
+use rand::Rng;
+use crate::coop_system::Downed;
+use crate::damage_indicator::PlayerDamagedEvent;
+use crate::debug_tools::{godmode_enabled, DebugSettings};
+use crate::projectile_pool::{fire_projectile, ProjectilePool, ProjectileVisual};
+use crate::ui::AppState;
+use crate::{
+    apply_enemy_damage, AudioEvent, Dash, Enemy, EnemyProjectile, HitEffect, Player, Projectile, ShieldedEnemy,
+    SimulationSet, SpawnParticlesEvent, Spawning, Stats,
+};
+
+// Mecânicas de template que trazem armadilhas
+const TRAPPED_MECHANICS: [&str; 2] = ["Trapped_Chest", "Legendary_Loot"];
+
+const SPIKE_RADIUS: f32 = 1.2;
+const SPIKE_ARM_TIME: f32 = 0.5;
+const SPIKE_DAMAGE: f32 = 18.0;
+const SPIKE_RESET_TIME: f32 = 2.0;
+
+const COLLAPSE_HALF_SIZE: f32 = 1.5;
+const COLLAPSE_CRACK_TIME: f32 = 0.8;
+const COLLAPSE_DAMAGE: f32 = 25.0;
+
+const DART_PLATE_RADIUS: f32 = 0.7;
+const DART_DELAY: f32 = 0.25;
+const DART_DAMAGE: i32 = 12;
+const DART_SPEED: f32 = 14.0;
+const DART_RESET_TIME: f32 = 1.5;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TrapKind {
+    SpikePit,
+    CollapsingFloor,
+    DartPlate { launcher: Vec3 }, // Lançador na parede, atira através da placa
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TrapState {
+    Idle,
+    Armed(f32),     // Disparada, tempo até o efeito
+    Cooldown(f32),  // Efeito aplicado, tempo até rearmar
+    Spent,          // Piso que já desabou
+}
+
+#[derive(Component)]
+pub struct Trap {
+    kind: TrapKind,
+    state: TrapState,
+}
+
+impl Trap {
+    // Volume de gatilho no plano XZ
+    fn contains(&self, center: Vec3, point: Vec3) -> bool {
+        let offset = Vec3::new(point.x - center.x, 0.0, point.z - center.z);
+        match self.kind {
+            TrapKind::SpikePit => offset.length() <= SPIKE_RADIUS,
+            TrapKind::CollapsingFloor => offset.x.abs() <= COLLAPSE_HALF_SIZE && offset.z.abs() <= COLLAPSE_HALF_SIZE,
+            TrapKind::DartPlate { .. } => offset.length() <= DART_PLATE_RADIUS,
+        }
+    }
+
+    fn delay(&self) -> f32 {
+        match self.kind {
+            TrapKind::SpikePit => SPIKE_ARM_TIME,
+            TrapKind::CollapsingFloor => COLLAPSE_CRACK_TIME,
+            TrapKind::DartPlate { .. } => DART_DELAY,
+        }
+    }
+}
+
+pub struct TrapPlugin;
+
+impl Plugin for TrapPlugin {
+    fn build(&self, app: &mut App) {
+        // Dano no passo fixo, junto com o resto da simulação: depois de jogador e inimigos
+        // se moverem, antes dos golpes e da checagem de mortes
+        app.add_systems(FixedUpdate, update_traps
+            .after(crate::player_movement_system)
+            .after(crate::ai_system)
+            .before(crate::hades_combat_system)
+            .in_set(SimulationSet)
+            .run_if(in_state(AppState::InGame)));
+    }
+}
+
+pub fn room_has_traps(special_mechanics: &[String]) -> bool {
+    special_mechanics.iter().any(|mechanic| TRAPPED_MECHANICS.contains(&mechanic.as_str()))
+}
+
+/// Espalha as armadilhas de uma sala do tesouro em volta do centro (o baú fica no meio)
+pub fn spawn_room_traps(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    room_center: Vec2,
+    rng: &mut impl Rng,
+) {
+    let center = Vec3::new(room_center.x, 0.0, room_center.y);
+    let mut jitter = || Vec3::new(rng.gen_range(-1.0..1.0), 0.0, rng.gen_range(-1.0..1.0));
+
+    let traps = [
+        (center + Vec3::new(-4.0, 0.0, 3.0) + jitter(), TrapKind::SpikePit),
+        (center + Vec3::new(4.0, 0.0, -3.0) + jitter(), TrapKind::SpikePit),
+        (center + Vec3::new(0.0, 0.0, 5.0) + jitter(), TrapKind::CollapsingFloor),
+        // Placa na frente do baú; o lançador fica na parede norte, alinhado com ela
+        (center + Vec3::new(-2.5, 0.0, 0.0), TrapKind::DartPlate { launcher: center + Vec3::new(-2.5, 1.0, -9.5) }),
+    ];
+    for (position, kind) in traps {
+        spawn_trap(commands, meshes, materials, position, kind);
+    }
+}
+
+fn spawn_trap(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    position: Vec3,
+    kind: TrapKind,
+) {
+    // Telegraph sutil: só um pouco mais escuro que o chão
+    let mesh = match kind {
+        TrapKind::SpikePit => meshes.add(Circle::new(SPIKE_RADIUS)),
+        TrapKind::CollapsingFloor => meshes.add(Rectangle::new(COLLAPSE_HALF_SIZE * 2.0, COLLAPSE_HALF_SIZE * 2.0)),
+        TrapKind::DartPlate { .. } => meshes.add(Rectangle::new(DART_PLATE_RADIUS * 1.6, DART_PLATE_RADIUS * 1.6)),
+    };
+    commands.spawn((
+        PbrBundle {
+            mesh,
+            material: materials.add(StandardMaterial {
+                base_color: Color::rgba(0.0, 0.0, 0.0, 0.15),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            }),
+            transform: Transform::from_xyz(position.x, 0.02, position.z)
+                .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+            ..default()
+        },
+        Trap { kind, state: TrapState::Idle },
+    ));
+
+    if let TrapKind::DartPlate { launcher } = kind {
+        commands.spawn(PbrBundle {
+            mesh: meshes.add(Cuboid::new(0.4, 0.4, 0.3)),
+            material: materials.add(StandardMaterial {
+                base_color: Color::rgb(0.35, 0.3, 0.25),
+                ..default()
+            }),
+            transform: Transform::from_translation(launcher),
+            ..default()
+        });
+    }
+}
+
+// Dispara, conta o atraso, aplica o efeito e rearma
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn update_traps(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut traps: Query<(&Transform, &mut Trap, &Handle<StandardMaterial>)>,
+    mut players: Query<(Entity, &Transform, &mut Stats, &Dash), (With<Player>, Without<Downed>, Without<Trap>)>,
+    mut enemies: Query<(&Transform, &mut Stats, Option<&mut ShieldedEnemy>), (With<Enemy>, Without<Player>, Without<Spawning>, Without<Trap>)>,
+    mut damaged_events: EventWriter<PlayerDamagedEvent>,
+    mut particle_events: EventWriter<SpawnParticlesEvent>,
+    mut audio_events: EventWriter<AudioEvent>,
+    debug: Option<Res<DebugSettings>>,
+    mut projectile_pool: ResMut<ProjectilePool>,
+) {
+    let dt = time.delta_seconds();
+    let godmode = godmode_enabled(debug.as_deref());
+
+    for (transform, mut trap, material) in &mut traps {
+        let center = transform.translation;
+        match trap.state {
+            TrapState::Spent => {}
+            TrapState::Cooldown(remaining) => {
+                trap.state = if remaining > dt { TrapState::Cooldown(remaining - dt) } else { TrapState::Idle };
+                if trap.state == TrapState::Idle {
+                    if let Some(material) = materials.get_mut(material) {
+                        material.base_color = Color::rgba(0.0, 0.0, 0.0, 0.15);
+                    }
+                }
+            }
+            TrapState::Idle => {
+                let stepped = players.iter().any(|(_, player, ..)| trap.contains(center, player.translation))
+                    || enemies.iter().any(|(enemy, ..)| trap.contains(center, enemy.translation));
+                if stepped {
+                    trap.state = TrapState::Armed(trap.delay());
+                    // Clique da placa: o telegraph fica evidente depois de pisar
+                    if let Some(material) = materials.get_mut(material) {
+                        material.base_color = Color::rgba(0.8, 0.3, 0.1, 0.45);
+                    }
+                    audio_events.send(AudioEvent::Parry);
+                }
+            }
+            TrapState::Armed(remaining) if remaining > dt => {
+                trap.state = TrapState::Armed(remaining - dt);
+            }
+            TrapState::Armed(_) => {
+                match trap.kind {
+                    TrapKind::DartPlate { launcher } => {
+                        let target = Vec3::new(center.x, launcher.y, center.z);
+                        let direction = (target - launcher).normalize_or_zero();
+                        let dart = fire_projectile(
+                            &mut commands,
+                            &mut projectile_pool,
+                            &mut meshes,
+                            &mut materials,
+                            ProjectileVisual::Dart,
+                            Transform::from_translation(launcher).looking_to(direction, Vec3::Y),
+                            Projectile {
+                                damage: DART_DAMAGE,
+                                velocity: direction * DART_SPEED,
+                                ttl: 2.0,
+                                from_enemy: true,
+                            },
+                        );
+                        commands.entity(dart).insert(EnemyProjectile);
+                        trap.state = TrapState::Cooldown(DART_RESET_TIME);
+                    }
+                    TrapKind::SpikePit | TrapKind::CollapsingFloor => {
+                        let damage = if trap.kind == TrapKind::SpikePit { SPIKE_DAMAGE } else { COLLAPSE_DAMAGE };
+                        for (player_entity, player_transform, mut stats, dash) in &mut players {
+                            if !trap.contains(center, player_transform.translation) || dash.i_timer > 0.0 || godmode {
+                                continue;
+                            }
+                            stats.current_health = (stats.current_health - damage).max(0.0);
+                            damaged_events.send(PlayerDamagedEvent { target: player_entity, source: center });
+                            commands.entity(player_entity).insert(HitEffect {
+                                timer: 0.0,
+                                duration: 0.2,
+                                original_scale: player_transform.scale,
+                            });
+                        }
+                        // Inimigos atraídos para a armadilha também sofrem
+                        for (enemy_transform, mut stats, shield) in &mut enemies {
+                            if trap.contains(center, enemy_transform.translation) {
+                                apply_enemy_damage(&mut stats, shield, damage);
+                            }
+                        }
+                        particle_events.send(SpawnParticlesEvent {
+                            position: center + Vec3::Y * 0.3,
+                            color: Color::rgb(0.6, 0.5, 0.35),
+                            count: 14,
+                        });
+                        audio_events.send(AudioEvent::EnemyHit);
+
+                        if trap.kind == TrapKind::CollapsingFloor {
+                            // Vira um buraco escuro e inerte
+                            if let Some(material) = materials.get_mut(material) {
+                                material.base_color = Color::rgba(0.02, 0.01, 0.0, 0.9);
+                            }
+                            trap.state = TrapState::Spent;
+                        } else {
+                            trap.state = TrapState::Cooldown(SPIKE_RESET_TIME);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
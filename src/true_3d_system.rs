@@ -162,8 +162,10 @@ fn spawn_3d_hero(
         },
         Hero3D,
         crate::Player, // Add Player component for gameplay systems
+        crate::PlayerId::P1,
         crate::Stats::default(),
         crate::Dash::default(), 
+        crate::Velocity::default(),
         crate::Combat::default(),
         AnimationController3D {
             current_animation: "idle".to_string(),
@@ -2,10 +2,12 @@ use bevy::prelude::*;
 use super::menu_system::AppState;
 use super::hud_system::BoonData;
 use crate::boons::{
-    BoonRegistry, BoonSelectedEvent, 
+    ActiveBoons, BoonRegistry, BoonSelectedEvent, 
     EgyptianGod, BoonRarity, Boon
 };
 use crate::hades_assets::HadesEgyptianAssets;
+use crate::procedural::{RunRng, RunRngStream};
+use super::color_palette::UiPalette;
 
 #[derive(Component)]
 pub struct BoonSelectionUI;
@@ -36,14 +38,21 @@ pub struct CurrentBoonOffer {
     pub selected: bool,
 }
 
+/// Deus que apareceu pessoalmente para esta oferta (retrato + fala no topo da tela)
+#[derive(Resource, Default)]
+pub struct GodEncounter {
+    pub god: Option<EgyptianGod>,
+}
+
 pub struct BoonSelectionPlugin;
 
 impl Plugin for BoonSelectionPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<CurrentBoonOffer>()
+            .init_resource::<GodEncounter>()
             .add_systems(Startup, load_boon_selection_assets)
             .add_systems(OnEnter(AppState::BoonSelection), setup_boon_selection)
-            .add_systems(OnExit(AppState::BoonSelection), cleanup_boon_selection)
+            .add_systems(OnExit(AppState::BoonSelection), (cleanup_boon_selection, end_god_encounter))
             .add_systems(Update, (
                 handle_boon_selection,
                 animate_boon_options,
@@ -88,13 +97,15 @@ fn load_boon_selection_assets(
 
 fn generate_boon_offer_on_enter(
     boon_registry: Option<Res<BoonRegistry>>,
+    active_boons: Res<ActiveBoons>,
     mut current_offer: ResMut<CurrentBoonOffer>,
+    mut run_rng: ResMut<RunRng>,
     _commands: Commands,
 ) {
     if current_offer.boons.is_empty() && !current_offer.selected {
         if let Some(registry) = boon_registry {
             info!("🎯 Generating new boon offer...");
-            let offer = registry.generate_offer(None, 3);
+            let offer = registry.generate_offer(None, 3, &active_boons.player_boons, run_rng.stream(RunRngStream::Rewards));
             current_offer.boons = offer.boons;
             info!("✨ Generated {} boon options", current_offer.boons.len());
         } else {
@@ -108,6 +119,9 @@ fn setup_boon_selection(
     boon_assets: Res<BoonSelectionAssets>,
     hades_assets: Option<Res<HadesEgyptianAssets>>,
     current_offer: Res<CurrentBoonOffer>,
+    encounter: Res<GodEncounter>,
+    active_boons: Res<ActiveBoons>,
+    palette: Res<UiPalette>,
 ) {
     info!("Setting up boon selection screen...");
     
@@ -145,18 +159,22 @@ fn setup_boon_selection(
             ..default()
         });
         
-        // Title
-        parent.spawn(TextBundle::from_section(
-            "Escolha uma Bênção dos Deuses",
-            TextStyle {
-                font: boon_assets.font.clone(),
-                font_size: 42.0,
-                color: Color::rgb(0.9, 0.8, 0.4),
-            },
-        ).with_style(Style {
-            margin: UiRect::bottom(Val::Px(50.0)),
-            ..default()
-        }));
+        // Encontro: o deus se apresenta no lugar do título genérico
+        if let Some(god) = encounter.god {
+            spawn_god_encounter_header(parent, god, &boon_assets, hades_assets.as_deref());
+        } else {
+            parent.spawn(TextBundle::from_section(
+                "Escolha uma Bênção dos Deuses",
+                TextStyle {
+                    font: boon_assets.font.clone(),
+                    font_size: 42.0,
+                    color: Color::rgb(0.9, 0.8, 0.4),
+                },
+            ).with_style(Style {
+                margin: UiRect::bottom(Val::Px(50.0)),
+                ..default()
+            }));
+        }
         
         // Boon options container (3 options side by side)
         parent.spawn(NodeBundle {
@@ -171,7 +189,13 @@ fn setup_boon_selection(
         }).with_children(|parent| {
             // Create 3 boon option cards
             for (index, boon) in current_offer.boons.iter().take(3).enumerate() {
-                create_boon_option_card(parent, index, boon, &boon_assets, hades_assets.as_ref().map(|v| &**v));
+                // Avisa quando pegar esta bênção tira outra incompatível
+                let replaced: Vec<&str> = active_boons.player_boons.iter()
+                    .filter(|owned| owned.conflicts(boon))
+                    .map(|owned| owned.name.as_str())
+                    .collect();
+                let footer = (!replaced.is_empty()).then(|| format!("Substitui: {}", replaced.join(", ")));
+                create_boon_option_card(parent, BoonOption { option_index: index }, boon, &boon_assets, hades_assets.as_ref().map(|v| &**v), &palette, footer);
             }
         });
         
@@ -190,13 +214,98 @@ fn setup_boon_selection(
     });
 }
 
-fn create_boon_option_card(
+fn god_portrait(god: EgyptianGod, boon_assets: &BoonSelectionAssets, hades_assets: Option<&HadesEgyptianAssets>) -> Handle<Image> {
+    // Use Hades-style god portraits if available
+    if let Some(hades) = hades_assets {
+        hades.get_god_portrait(&god)
+    } else {
+        match god {
+            EgyptianGod::Ra => boon_assets.god_portrait_ra.clone(),
+            EgyptianGod::Anubis => boon_assets.god_portrait_anubis.clone(),
+            EgyptianGod::Isis => boon_assets.god_portrait_isis.clone(),
+            EgyptianGod::Set => boon_assets.god_portrait_set.clone(),
+            EgyptianGod::Thoth => boon_assets.god_portrait_thoth.clone(),
+        }
+    }
+}
+
+// Retrato grande ao lado do nome e da fala do deus
+fn spawn_god_encounter_header(
+    parent: &mut ChildBuilder,
+    god: EgyptianGod,
+    boon_assets: &BoonSelectionAssets,
+    hades_assets: Option<&HadesEgyptianAssets>,
+) {
+    parent.spawn(NodeBundle {
+        style: Style {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(24.0),
+            margin: UiRect::bottom(Val::Px(40.0)),
+            ..default()
+        },
+        ..default()
+    }).with_children(|parent| {
+        parent.spawn(NodeBundle {
+            style: Style {
+                border: UiRect::all(Val::Px(3.0)),
+                ..default()
+            },
+            border_color: god.get_theme_color().into(),
+            ..default()
+        }).with_children(|parent| {
+            parent.spawn(ImageBundle {
+                style: Style {
+                    width: Val::Px(140.0),
+                    height: Val::Px(140.0),
+                    ..default()
+                },
+                image: UiImage::new(god_portrait(god, boon_assets, hades_assets)),
+                ..default()
+            });
+        });
+        parent.spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(10.0),
+                max_width: Val::Px(620.0),
+                ..default()
+            },
+            ..default()
+        }).with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                god.get_display_name(),
+                TextStyle {
+                    font: boon_assets.font.clone(),
+                    font_size: 40.0,
+                    color: god.get_theme_color(),
+                },
+            ));
+            parent.spawn(TextBundle::from_section(
+                format!("\"{}\"", god.encounter_line()),
+                TextStyle {
+                    font: boon_assets.font.clone(),
+                    font_size: 22.0,
+                    color: Color::rgb(0.85, 0.78, 0.6),
+                },
+            ));
+        });
+    });
+}
+
+/// Carta de bênção clicável; `marker` identifica a carta e `footer` vai embaixo da descrição
+/// (a tela de upgrade usa para mostrar nível e custo)
+pub(super) fn create_boon_option_card(
     parent: &mut ChildBuilder,
-    option_index: usize,
+    marker: impl Bundle,
     boon: &Boon,
     boon_assets: &BoonSelectionAssets,
     hades_assets: Option<&HadesEgyptianAssets>,
+    palette: &UiPalette,
+    footer: Option<String>,
 ) {
+    let rarity_color = palette.rarity_color(boon.rarity.get_color(), boon.rarity.tier());
+    
     // Use Hades-style assets if available, fallback to originals
     let card_bg = if let Some(hades) = hades_assets {
         hades.get_boon_frame(&boon.rarity)
@@ -209,18 +318,7 @@ fn create_boon_option_card(
         }
     };
     
-    // Use Hades-style god portraits if available
-    let god_portrait = if let Some(hades) = hades_assets {
-        hades.get_god_portrait(&boon.god)
-    } else {
-        match boon.god {
-            EgyptianGod::Ra => boon_assets.god_portrait_ra.clone(),
-            EgyptianGod::Anubis => boon_assets.god_portrait_anubis.clone(),
-            EgyptianGod::Isis => boon_assets.god_portrait_isis.clone(),
-            EgyptianGod::Set => boon_assets.god_portrait_set.clone(),
-            EgyptianGod::Thoth => boon_assets.god_portrait_thoth.clone(),
-        }
-    };
+    let god_portrait = god_portrait(boon.god, boon_assets, hades_assets);
     
     parent.spawn((
         ButtonBundle {
@@ -234,12 +332,12 @@ fn create_boon_option_card(
                 border: UiRect::all(Val::Px(4.0)),
                 ..default()
             },
-            border_color: boon.rarity.get_color().into(),
+            border_color: rarity_color.into(),
             background_color: Color::rgba(0.1, 0.08, 0.05, 0.9).into(),
             image: UiImage::new(card_bg),
             ..default()
         },
-        BoonOption { option_index },
+        marker,
     )).with_children(|parent| {
         // God name
         parent.spawn(TextBundle::from_section(
@@ -293,13 +391,19 @@ fn create_boon_option_card(
             ..default()
         }));
         
-        // Rarity indicator
+        // Rarity indicator (com pips no modo daltônico)
+        let pips = palette.rarity_pips(boon.rarity.tier());
+        let rarity_label = if pips.is_empty() {
+            boon.rarity.get_display_name().to_string()
+        } else {
+            format!("{} {}", pips, boon.rarity.get_display_name())
+        };
         parent.spawn(TextBundle::from_section(
-            boon.rarity.get_display_name(),
+            rarity_label,
             TextStyle {
                 font: boon_assets.font.clone(),
                 font_size: 20.0,
-                color: boon.rarity.get_color(),
+                color: rarity_color,
             },
         ).with_style(Style {
             margin: UiRect::bottom(Val::Px(15.0)),
@@ -318,9 +422,24 @@ fn create_boon_option_card(
             max_width: Val::Px(300.0),
             ..default()
         }));
+
+        if let Some(footer) = footer {
+            parent.spawn(TextBundle::from_section(
+                footer,
+                TextStyle {
+                    font: boon_assets.font.clone(),
+                    font_size: 22.0,
+                    color: Color::rgb(0.95, 0.5, 0.55),
+                },
+            ).with_style(Style {
+                margin: UiRect::top(Val::Px(20.0)),
+                ..default()
+            }));
+        }
     });
 }
 
+#[allow(clippy::type_complexity)]
 fn handle_boon_selection(
     mut interaction_query: Query<
         (&Interaction, &BoonOption, &mut BorderColor),
@@ -330,6 +449,7 @@ fn handle_boon_selection(
     mut boon_selection_events: EventWriter<BoonSelectedEvent>,
     mut boon_data: ResMut<BoonData>,
     mut app_state: ResMut<NextState<AppState>>,
+    palette: Res<UiPalette>,
 ) {
     for (interaction, boon_option, mut border_color) in &mut interaction_query {
         match *interaction {
@@ -366,7 +486,7 @@ fn handle_boon_selection(
             }
             Interaction::None => {
                 if let Some(chosen_boon) = current_offer.boons.get(boon_option.option_index) {
-                    *border_color = chosen_boon.rarity.get_color().into();
+                    *border_color = palette.rarity_color(chosen_boon.rarity.get_color(), chosen_boon.rarity.tier()).into();
                 }
             }
         }
@@ -386,6 +506,7 @@ fn animate_boon_options(
     }
 }
 
+#[allow(clippy::type_complexity)]
 fn handle_boon_hover_effects(
     mut query: Query<(&Interaction, &mut BackgroundColor), (With<BoonOption>, Changed<Interaction>)>,
 ) {
@@ -409,4 +530,8 @@ fn cleanup_boon_selection(
     for entity in query.iter() {
         commands.entity(entity).despawn_recursive();
     }
-}
\ No newline at end of file
+}
+
+fn end_god_encounter(mut encounter: ResMut<GodEncounter>) {
+    encounter.god = None;
+}
@@ -0,0 +1,202 @@
+use bevy::prelude::*;
+use super::menu_system::AppState;
+use super::hud_system::BoonData;
+use super::boon_selection::{create_boon_option_card, BoonSelectionAssets};
+use super::color_palette::UiPalette;
+use crate::boons::{ActiveBoons, Boon};
+use crate::hades_assets::HadesEgyptianAssets;
+
+// Tecla que abre o altar de upgrade durante a run
+const UPGRADE_MENU_KEY: KeyCode = KeyCode::KeyU;
+
+#[derive(Component)]
+struct BoonUpgradeUI;
+
+#[derive(Component)]
+struct BoonUpgradeOption {
+    boon_index: usize,
+}
+
+pub struct BoonUpgradePlugin;
+
+impl Plugin for BoonUpgradePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, toggle_boon_upgrade_menu)
+            .add_systems(OnEnter(AppState::BoonUpgrade), setup_boon_upgrade_menu)
+            .add_systems(OnExit(AppState::BoonUpgrade), cleanup_boon_upgrade_menu)
+            .add_systems(Update, handle_boon_upgrade.run_if(in_state(AppState::BoonUpgrade)));
+    }
+}
+
+/// Romãs para subir a bênção do nível atual para o próximo (mais caro a cada nível)
+pub fn pom_upgrade_cost(boon: &Boon) -> u32 {
+    boon.level
+}
+
+fn toggle_boon_upgrade_menu(
+    keys: Res<ButtonInput<KeyCode>>,
+    current_state: Res<State<AppState>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if !keys.just_pressed(UPGRADE_MENU_KEY) {
+        return;
+    }
+    match current_state.get() {
+        AppState::InGame => next_state.set(AppState::BoonUpgrade),
+        AppState::BoonUpgrade => next_state.set(AppState::InGame),
+        _ => {}
+    }
+}
+
+fn setup_boon_upgrade_menu(
+    mut commands: Commands,
+    boon_assets: Res<BoonSelectionAssets>,
+    hades_assets: Option<Res<HadesEgyptianAssets>>,
+    palette: Res<UiPalette>,
+    active_boons: Res<ActiveBoons>,
+    boon_data: Res<BoonData>,
+) {
+    spawn_boon_upgrade_menu(&mut commands, &boon_assets, hades_assets.as_deref(), &palette, &active_boons, &boon_data);
+}
+
+// Mesma carta da seleção de bênçãos, com nível e custo no rodapé
+fn spawn_boon_upgrade_menu(
+    commands: &mut Commands,
+    boon_assets: &BoonSelectionAssets,
+    hades_assets: Option<&HadesEgyptianAssets>,
+    palette: &UiPalette,
+    active_boons: &ActiveBoons,
+    boon_data: &BoonData,
+) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            background_color: Color::rgba(0.08, 0.03, 0.04, 0.92).into(),
+            ..default()
+        },
+        BoonUpgradeUI,
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            format!("Altar das Romãs - {} disponíveis", boon_data.pom),
+            TextStyle {
+                font: boon_assets.font.clone(),
+                font_size: 42.0,
+                color: Color::rgb(0.95, 0.5, 0.55),
+            },
+        ).with_style(Style {
+            margin: UiRect::bottom(Val::Px(40.0)),
+            ..default()
+        }));
+
+        if active_boons.player_boons.is_empty() {
+            parent.spawn(TextBundle::from_section(
+                "Nenhuma bênção para fortalecer ainda",
+                TextStyle {
+                    font: boon_assets.font.clone(),
+                    font_size: 24.0,
+                    color: Color::rgb(0.6, 0.5, 0.3),
+                },
+            ));
+        }
+
+        parent.spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                flex_wrap: FlexWrap::Wrap,
+                justify_content: JustifyContent::Center,
+                column_gap: Val::Px(30.0),
+                row_gap: Val::Px(30.0),
+                max_width: Val::Percent(95.0),
+                ..default()
+            },
+            ..default()
+        }).with_children(|parent| {
+            for (index, boon) in active_boons.player_boons.iter().enumerate() {
+                let footer = if boon.level >= boon.max_level {
+                    format!("Nível {} (máximo)", boon.level)
+                } else {
+                    format!("Nível {} → {}  |  Custo: {} romã(s)", boon.level, boon.level + 1, pom_upgrade_cost(boon))
+                };
+                create_boon_option_card(
+                    parent,
+                    BoonUpgradeOption { boon_index: index },
+                    boon,
+                    boon_assets,
+                    hades_assets,
+                    palette,
+                    Some(footer),
+                );
+            }
+        });
+
+        parent.spawn(TextBundle::from_section(
+            "Clique em uma bênção para subir seu nível (U / Esc para voltar)",
+            TextStyle {
+                font: boon_assets.font.clone(),
+                font_size: 24.0,
+                color: Color::rgb(0.6, 0.5, 0.3),
+            },
+        ).with_style(Style {
+            margin: UiRect::top(Val::Px(40.0)),
+            ..default()
+        }));
+    });
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn handle_boon_upgrade(
+    mut commands: Commands,
+    mut interactions: Query<(&Interaction, &BoonUpgradeOption, &mut BorderColor), (Changed<Interaction>, With<Button>)>,
+    ui: Query<Entity, With<BoonUpgradeUI>>,
+    mut active_boons: ResMut<ActiveBoons>,
+    mut boon_data: ResMut<BoonData>,
+    boon_assets: Res<BoonSelectionAssets>,
+    hades_assets: Option<Res<HadesEgyptianAssets>>,
+    palette: Res<UiPalette>,
+) {
+    for (interaction, option, mut border_color) in &mut interactions {
+        let Some(boon) = active_boons.player_boons.get(option.boon_index) else {
+            continue;
+        };
+        match *interaction {
+            Interaction::Pressed => {
+                let cost = pom_upgrade_cost(boon);
+                if boon.level >= boon.max_level || boon_data.pom < cost {
+                    info!("🍎 Cannot upgrade {} (level {}/{}, {} pom)", boon.name, boon.level, boon.max_level, boon_data.pom);
+                    continue;
+                }
+                if active_boons.upgrade_boon(option.boon_index) {
+                    boon_data.pom -= cost;
+                    // Reconstrói as cartas com os níveis novos
+                    for entity in &ui {
+                        commands.entity(entity).despawn_recursive();
+                    }
+                    spawn_boon_upgrade_menu(&mut commands, &boon_assets, hades_assets.as_deref(), &palette, &active_boons, &boon_data);
+                }
+                return;
+            }
+            Interaction::Hovered => {
+                *border_color = Color::rgb(0.95, 0.5, 0.55).into();
+            }
+            Interaction::None => {
+                *border_color = palette.rarity_color(boon.rarity.get_color(), boon.rarity.tier()).into();
+            }
+        }
+    }
+}
+
+fn cleanup_boon_upgrade_menu(
+    mut commands: Commands,
+    query: Query<Entity, With<BoonUpgradeUI>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
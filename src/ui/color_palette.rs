@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+use crate::settings::GameSettings;
+use super::combat_feedback::DamageType;
+
+/// Paleta da UI para raridade de bênçãos e tipos de dano.
+/// No modo daltônico troca as cores pela paleta Okabe-Ito e liga as marcas
+/// de forma (pips de raridade, símbolos nos números de dano)
+#[derive(Resource, Default, Clone, Copy)]
+pub struct UiPalette {
+    pub colorblind: bool,
+}
+
+// Comum, raro, épico, lendário: claro/escuro distintos além do tom
+const COLORBLIND_RARITY: [Color; 4] = [
+    Color::rgb(0.85, 0.85, 0.85), // Cinza claro
+    Color::rgb(0.0, 0.45, 0.7),   // Azul
+    Color::rgb(0.8, 0.6, 0.7),    // Roxo-avermelhado
+    Color::rgb(0.95, 0.9, 0.25),  // Amarelo
+];
+
+const RARITY_PIPS: [&str; 4] = ["I", "II", "III", "IV"];
+
+impl UiPalette {
+    /// Cada tela mantém suas cores de raridade; no modo daltônico todas usam a mesma paleta
+    pub fn rarity_color(&self, standard: Color, tier: usize) -> Color {
+        if self.colorblind {
+            COLORBLIND_RARITY[tier.min(COLORBLIND_RARITY.len() - 1)]
+        } else {
+            standard
+        }
+    }
+
+    /// Marca de raridade pelo nível (0 = comum), vazia fora do modo daltônico
+    pub fn rarity_pips(&self, tier: usize) -> &'static str {
+        if self.colorblind {
+            RARITY_PIPS[tier.min(RARITY_PIPS.len() - 1)]
+        } else {
+            ""
+        }
+    }
+
+    pub fn damage_color(&self, damage_type: DamageType) -> Color {
+        match (damage_type, self.colorblind) {
+            (DamageType::Player, false) => Color::rgb(1.0, 0.3, 0.3),
+            (DamageType::Enemy, false) => Color::rgb(1.0, 1.0, 0.4),
+            (DamageType::Critical, false) => Color::rgb(1.0, 0.8, 0.2),
+            (DamageType::Heal, false) => Color::rgb(0.3, 1.0, 0.3),
+            // Vermelhão / branco / amarelo / azul-céu: nada depende de vermelho x verde
+            (DamageType::Player, true) => Color::rgb(0.85, 0.4, 0.0),
+            (DamageType::Enemy, true) => Color::rgb(1.0, 1.0, 1.0),
+            (DamageType::Critical, true) => Color::rgb(0.95, 0.9, 0.25),
+            (DamageType::Heal, true) => Color::rgb(0.35, 0.7, 0.9),
+        }
+    }
+
+    /// Texto do número de dano; no modo daltônico cada tipo ganha seu símbolo
+    pub fn damage_label(&self, damage_type: DamageType, amount: i32) -> String {
+        match (damage_type, self.colorblind) {
+            (DamageType::Heal, _) => format!("+{}", amount),
+            (DamageType::Player, true) => format!("-{}", amount),
+            (DamageType::Critical, true) => format!("!{}!", amount),
+            _ => amount.to_string(),
+        }
+    }
+}
+
+pub struct UiPalettePlugin;
+
+impl Plugin for UiPalettePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiPalette>()
+            .add_systems(PreUpdate, sync_palette_with_settings);
+    }
+}
+
+fn sync_palette_with_settings(settings: Res<GameSettings>, mut palette: ResMut<UiPalette>) {
+    if settings.is_changed() && palette.colorblind != settings.colorblind_mode {
+        palette.colorblind = settings.colorblind_mode;
+    }
+}
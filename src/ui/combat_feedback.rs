@@ -1,5 +1,9 @@
 use bevy::prelude::*;
 use crate::{Player, Combat, Stats};
+use super::color_palette::UiPalette;
+
+// Números e efeitos saem acima do ponto atingido (altura em unidades do mundo)
+const DAMAGE_NUMBER_HEIGHT: f32 = 1.0;
 
 #[derive(Component)]
 pub struct CombatFeedbackUI;
@@ -23,6 +27,13 @@ pub struct ScreenShake {
     pub duration: Timer,
 }
 
+// Posição da câmera sem tremor e o deslocamento aplicado no último quadro
+#[derive(Component)]
+pub struct CameraBase {
+    pub translation: Vec3,
+    applied: Vec3,
+}
+
 #[derive(Clone, Copy, PartialEq)]
 pub enum DamageType {
     Player,
@@ -53,6 +64,15 @@ pub struct HitStopEvent {
     pub duration: f32,
 }
 
+// Hit stop em andamento: tempo real que falta até o tempo virtual voltar a correr
+#[derive(Resource, Default)]
+pub struct HitStop {
+    remaining: f32,
+}
+
+// Teto de um congelamento (pedidos maiores são cortados)
+const MAX_HIT_STOP: f32 = 0.15;
+
 #[derive(Event)]
 pub struct ScreenShakeEvent {
     pub intensity: f32,
@@ -63,7 +83,8 @@ pub struct CombatFeedbackPlugin;
 
 impl Plugin for CombatFeedbackPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<DamageEvent>()
+        app.init_resource::<HitStop>()
+            .add_event::<DamageEvent>()
             .add_event::<HitStopEvent>()
             .add_event::<ScreenShakeEvent>()
             .add_systems(Startup, load_combat_feedback_assets)
@@ -73,7 +94,7 @@ impl Plugin for CombatFeedbackPlugin {
                 update_damage_numbers,
                 handle_hit_effects,
                 apply_screen_shake,
-                handle_hit_stop,
+                (tick_hit_stop, handle_hit_stop).chain(),
                 cleanup_expired_effects,
                 create_hit_particles,
             ));
@@ -116,16 +137,23 @@ fn setup_combat_feedback_ui(
     ));
 }
 
+#[allow(clippy::too_many_arguments)]
 fn handle_damage_events(
     mut commands: Commands,
     mut damage_events: EventReader<DamageEvent>,
     mut screen_shake_events: EventWriter<ScreenShakeEvent>,
     mut hit_stop_events: EventWriter<HitStopEvent>,
     feedback_assets: Res<CombatFeedbackAssets>,
+    palette: Res<UiPalette>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    ui_scale: Res<UiScale>,
 ) {
     for event in damage_events.read() {
-        // Create floating damage number
-        spawn_damage_number(&mut commands, event, &feedback_assets);
+        // Create floating damage number (golpes fora da tela não geram número nem efeito)
+        let screen_pos = world_to_screen(&cameras, &ui_scale, event.position);
+        if let Some(screen_pos) = screen_pos {
+            spawn_damage_number(&mut commands, event, screen_pos, &feedback_assets, &palette);
+        }
         
         // Screen shake based on damage type
         let shake_intensity = match event.damage_type {
@@ -150,30 +178,29 @@ fn handle_damage_events(
         }
         
         // Create hit effect
-        spawn_hit_effect(&mut commands, event, &feedback_assets);
+        if let Some(screen_pos) = screen_pos {
+            spawn_hit_effect(&mut commands, event, screen_pos, &feedback_assets);
+        }
     }
 }
 
 fn spawn_damage_number(
     commands: &mut Commands,
     event: &DamageEvent,
+    screen_pos: Vec2,
     feedback_assets: &CombatFeedbackAssets,
+    palette: &UiPalette,
 ) {
-    let (color, font_size) = match event.damage_type {
-        DamageType::Player => (Color::rgb(1.0, 0.3, 0.3), 32.0),
-        DamageType::Enemy => (Color::rgb(1.0, 1.0, 0.4), 28.0),
-        DamageType::Critical => (Color::rgb(1.0, 0.8, 0.2), 42.0),
-        DamageType::Heal => (Color::rgb(0.3, 1.0, 0.3), 30.0),
-    };
-    
-    let damage_text = if event.damage_type == DamageType::Heal {
-        format!("+{}", event.damage)
-    } else {
-        event.damage.to_string()
+    // Golpe crítico usa o estilo de crítico qualquer que seja o tipo informado
+    let damage_type = if event.is_critical { DamageType::Critical } else { event.damage_type };
+    let font_size = match damage_type {
+        DamageType::Player => 32.0,
+        DamageType::Enemy => 28.0,
+        DamageType::Critical => 42.0,
+        DamageType::Heal => 30.0,
     };
-    
-    // Convert world position to screen position (simplified)
-    let screen_pos = world_to_screen(event.position);
+    let color = palette.damage_color(damage_type);
+    let damage_text = palette.damage_label(damage_type, event.damage);
     
     commands.spawn((
         TextBundle::from_section(
@@ -192,7 +219,7 @@ fn spawn_damage_number(
         DamageNumber {
             lifetime: Timer::from_seconds(2.0, TimerMode::Once),
             velocity: Vec3::new(0.0, -50.0, 0.0), // Float upward
-            damage_type: event.damage_type,
+            damage_type,
         },
     ));
 }
@@ -200,6 +227,7 @@ fn spawn_damage_number(
 fn spawn_hit_effect(
     commands: &mut Commands,
     event: &DamageEvent,
+    screen_pos: Vec2,
     feedback_assets: &CombatFeedbackAssets,
 ) {
     let effect_image = match event.damage_type {
@@ -208,8 +236,6 @@ fn spawn_hit_effect(
         _ => feedback_assets.hit_particle.clone(),
     };
     
-    let screen_pos = world_to_screen(event.position);
-    
     commands.spawn((
         ImageBundle {
             style: Style {
@@ -297,10 +323,10 @@ fn handle_hit_effects(
     }
 }
 
-fn apply_screen_shake(
+pub fn apply_screen_shake(
     mut screen_shake_events: EventReader<ScreenShakeEvent>,
     mut commands: Commands,
-    mut camera_query: Query<&mut Transform, With<Camera>>,
+    mut camera_query: Query<(Entity, &mut Transform, Option<&mut CameraBase>), With<Camera3d>>,
     time: Res<Time>,
     mut shake_query: Query<(Entity, &mut ScreenShake)>,
 ) {
@@ -333,27 +359,57 @@ fn apply_screen_shake(
         total_shake += Vec3::new(shake_x, shake_y, 0.0);
     }
     
-    // Apply to camera
-    for mut camera_transform in camera_query.iter_mut() {
-        // Store original position and apply shake offset
-        // This is simplified - in practice you'd store the base position
-        camera_transform.translation += total_shake * 0.01; // Scale down the effect
+    // Apply to camera: o tremor é um deslocamento sobre a posição base, nunca acumulado
+    for (entity, mut camera_transform, base) in camera_query.iter_mut() {
+        // No plano da tela (eixos da própria câmera)
+        let offset = camera_transform.rotation * total_shake * 0.01; // Scale down the effect
+        let Some(mut base) = base else {
+            commands.entity(entity).insert(CameraBase {
+                translation: camera_transform.translation,
+                applied: offset,
+            });
+            camera_transform.translation += offset;
+            continue;
+        };
+        // Posição intocada desde o último quadro: a base continua valendo. Se o seguimento
+        // da câmera (coop_camera_system) reposicionou, a posição nova é a base
+        if camera_transform.translation != base.translation + base.applied {
+            base.translation = camera_transform.translation;
+        }
+        base.applied = offset;
+        camera_transform.translation = base.translation + offset;
     }
 }
 
+// Vários pedidos (no mesmo quadro ou durante um congelamento) não somam: vale o maior
 fn handle_hit_stop(
     mut hit_stop_events: EventReader<HitStopEvent>,
+    mut hit_stop: ResMut<HitStop>,
     mut time: ResMut<Time<Virtual>>,
 ) {
-    for event in hit_stop_events.read() {
-        // Pause virtual time for hit stop effect
-        // This is a simplified implementation
-        // In practice, you'd use a more sophisticated time scaling system
-        time.pause();
-        
-        // You would typically use a timer system to unpause after the duration
-        // For now, we'll just create a very short pause effect
-        info!("Hit stop for {:.2}s", event.duration);
+    let requested = hit_stop_events.read()
+        .map(|event| event.duration.min(MAX_HIT_STOP))
+        .fold(0.0, f32::max);
+    if requested <= 0.0 {
+        return;
+    }
+    hit_stop.remaining = hit_stop.remaining.max(requested);
+    time.pause();
+}
+
+// Conta em tempo real (o virtual está parado) e retoma quando o congelamento acaba
+fn tick_hit_stop(
+    real_time: Res<Time<Real>>,
+    mut hit_stop: ResMut<HitStop>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    if hit_stop.remaining <= 0.0 {
+        return;
+    }
+    hit_stop.remaining -= real_time.delta_seconds();
+    if hit_stop.remaining <= 0.0 {
+        hit_stop.remaining = 0.0;
+        time.unpause();
     }
 }
 
@@ -381,15 +437,18 @@ fn create_hit_particles(
     mut commands: Commands,
     mut damage_events: EventReader<DamageEvent>,
     feedback_assets: Res<CombatFeedbackAssets>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    ui_scale: Res<UiScale>,
 ) {
     for event in damage_events.read() {
+        let Some(screen_pos) = world_to_screen(&cameras, &ui_scale, event.position) else {
+            continue;
+        };
         // Create particle burst at hit location
         for i in 0..5 {
             let angle = (i as f32 / 5.0) * std::f32::consts::TAU;
             let velocity = Vec3::new(angle.cos(), angle.sin(), 0.0) * 100.0;
             
-            let screen_pos = world_to_screen(event.position);
-            
             commands.spawn((
                 ImageBundle {
                     style: Style {
@@ -414,14 +473,18 @@ fn create_hit_particles(
     }
 }
 
-// Helper function to convert world position to screen position
-fn world_to_screen(world_pos: Vec3) -> Vec2 {
-    // This is a simplified conversion
-    // In practice, you'd use the camera's view-projection matrix
-    Vec2::new(
-        400.0 + world_pos.x * 10.0, // Rough conversion
-        300.0 - world_pos.z * 10.0, // Flip Y for screen coords
-    )
+// Projeção pela câmera 3D ativa (a de maior ordem), um pouco acima do ponto atingido.
+// Devolve unidades da UI (pixels lógicos / UiScale); None atrás da câmera ou sem câmera
+fn world_to_screen(
+    cameras: &Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    ui_scale: &UiScale,
+    world_pos: Vec3,
+) -> Option<Vec2> {
+    let (camera, camera_transform) = cameras.iter()
+        .filter(|(camera, _)| camera.is_active)
+        .max_by_key(|(camera, _)| camera.order)?;
+    camera.world_to_viewport(camera_transform, world_pos + Vec3::Y * DAMAGE_NUMBER_HEIGHT)
+        .map(|viewport_pos| viewport_pos / ui_scale.0)
 }
 
 impl DamageType {
@@ -1,5 +1,14 @@
 use bevy::prelude::*;
-use crate::{Player, Stats, Dash, Combat};
+use std::collections::HashSet;
+use crate::{Player, PlayerId, Stats, Dash, Combat};
+use crate::meta_progression::HeatModifiers;
+use crate::inventory::Inventory;
+use crate::input_bindings::{InputAction, InputBindings};
+use crate::procedural::{GeneratedDungeon, RoomType};
+use super::color_palette::UiPalette;
+use super::menu_system::RequiredAssets;
+use super::level_up::RunExperience;
+use super::AppState;
 
 #[derive(Component)]
 pub struct HudUI;
@@ -10,29 +19,101 @@ pub struct HealthBar;
 #[derive(Component)]
 pub struct EnergyBar;
 
+#[derive(Component)]
+pub struct XpBar;
+
+#[derive(Component)]
+pub struct XpLevelText;
+
 #[derive(Component)]
 pub struct AbilityIcon {
     pub ability_type: AbilityType,
+    pub flash_timer: f32, // brilho ao voltar a ficar pronta
+    pub cooling_down: bool,
+}
+
+// Véu escuro sobre o ícone: a altura é a fração de recarga que falta
+#[derive(Component)]
+pub struct AbilityCooldownOverlay {
+    pub ability_type: AbilityType,
 }
 
 #[derive(Clone, Copy)]
 pub enum AbilityType {
     Dash,      // Space
-    Primary,   // Q
-    Secondary, // R
+    Primary,   // Mouse esq
+    Secondary, // Mouse dir
+    Cast,      // Q
+    Ultimate,  // R
+}
+
+impl AbilityType {
+    // Fração da recarga que ainda falta (1 = acabou de usar, 0 = pronta)
+    fn cooldown_fraction(self, dash: &Dash, combat: &Combat) -> f32 {
+        let (timer, cooldown) = match self {
+            AbilityType::Dash => (dash.cooldown_timer, dash.cooldown),
+            AbilityType::Primary => (combat.atk_timer, combat.atk_cd),
+            AbilityType::Secondary => (combat.special_timer, combat.special_cd),
+            AbilityType::Cast => (combat.q_timer, combat.q_cd),
+            AbilityType::Ultimate => (combat.r_timer, combat.r_cd),
+        };
+        if cooldown <= 0.0 { 0.0 } else { (timer / cooldown).clamp(0.0, 1.0) }
+    }
 }
 
+const ABILITY_READY_FLASH: f32 = 0.35;
+
 #[derive(Component)]
 pub struct BoonSlot {
     pub slot_index: usize,
 }
 
+// Ícone e marca de raridade dentro de um BoonSlot
+#[derive(Component)]
+pub struct BoonSlotIcon {
+    pub slot_index: usize,
+}
+
+#[derive(Component)]
+pub struct BoonSlotPips {
+    pub slot_index: usize,
+}
+
 #[derive(Component)]
 pub struct MiniMap;
 
+// Salas e conexões desenhadas dentro do minimapa (refeitas quando a dungeon muda)
+#[derive(Component)]
+pub struct MiniMapElement;
+
+// Área útil dentro da moldura de 200x150 (borda de 3px) e margem até as salas da ponta
+const MINIMAP_INNER: Vec2 = Vec2::new(194.0, 144.0);
+const MINIMAP_MARGIN: f32 = 12.0;
+const MINIMAP_ROOM_SIZE: f32 = 10.0;
+const MINIMAP_CURRENT_SIZE: f32 = 14.0;
+const MINIMAP_LINK_THICKNESS: f32 = 2.0;
+
+const MINIMAP_CURRENT: Color = Color::rgb(1.0, 0.85, 0.3);
+const MINIMAP_CLEARED: Color = Color::rgb(0.35, 0.65, 0.35);
+const MINIMAP_OPEN: Color = Color::rgb(0.75, 0.6, 0.4);
+const MINIMAP_LOCKED: Color = Color::rgba(0.3, 0.25, 0.2, 0.8);
+const MINIMAP_SECRET: Color = Color::rgba(0.45, 0.25, 0.6, 0.8);
+const MINIMAP_BOSS_BORDER: Color = Color::rgb(0.9, 0.15, 0.1);
+const MINIMAP_LINK: Color = Color::rgba(0.6, 0.45, 0.25, 0.8);
+const MINIMAP_LOCKED_LINK: Color = Color::rgba(0.5, 0.2, 0.15, 0.6);
+
 #[derive(Component)]
 pub struct CoinCounter;
 
+#[derive(Component)]
+pub struct PomCounter;
+
+#[derive(Component)]
+pub struct HeatIndicator;
+
+#[derive(Component)]
+pub struct ConsumableText;
+
 #[derive(Resource)]
 pub struct HudAssets {
     pub font: Handle<Font>,
@@ -53,6 +134,7 @@ pub struct HudAssets {
 pub struct BoonData {
     pub active_boons: Vec<ActiveBoon>,
     pub coins: u32,
+    pub pom: u32, // Romãs do Poder: sobem o nível de uma bênção que já temos
 }
 
 impl Default for BoonData {
@@ -60,6 +142,7 @@ impl Default for BoonData {
         Self {
             active_boons: Vec::new(),
             coins: 0,
+            pom: 0,
         }
     }
 }
@@ -100,17 +183,29 @@ impl Plugin for HudSystemPlugin {
             .add_systems(Update, (
                 update_health_bar,
                 update_energy_bar,
+                update_xp_bar,
                 update_ability_cooldowns,
                 update_boon_display,
                 update_coin_counter,
+                update_pom_counter,
+                update_heat_indicator,
+                update_consumable_display,
+                minimap_render_system,
                 animate_hud_elements,
-            ));
+            ))
+            // Run nova: moedas e romãs não passam de uma run para a outra
+            .add_systems(OnEnter(AppState::Loading), reset_boon_data);
     }
 }
 
+fn reset_boon_data(mut boon_data: ResMut<BoonData>) {
+    *boon_data = BoonData::default();
+}
+
 fn load_hud_assets(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    mut required: ResMut<RequiredAssets>,
 ) {
     info!("Loading HUD assets for 21:9 ultrawide...");
     
@@ -129,6 +224,22 @@ fn load_hud_assets(
         minimap_bg: asset_server.load("ui/minimap_papyrus_frame.png"),
     };
     
+    required.track(&hud_assets.font);
+    for image in [
+        &hud_assets.health_bar_bg,
+        &hud_assets.health_bar_fill,
+        &hud_assets.energy_bar_bg,
+        &hud_assets.energy_bar_fill,
+        &hud_assets.ability_frame,
+        &hud_assets.boon_frame_common,
+        &hud_assets.boon_frame_rare,
+        &hud_assets.boon_frame_epic,
+        &hud_assets.boon_frame_legendary,
+        &hud_assets.coin_icon,
+        &hud_assets.minimap_bg,
+    ] {
+        required.track(image);
+    }
     commands.insert_resource(hud_assets);
     info!("✅ HUD assets loaded");
 }
@@ -191,6 +302,41 @@ fn setup_hud(
                 EnergyBar,
                 &hud_assets,
             );
+            
+            // XP da run: barra até o próximo nível
+            create_resource_bar(
+                parent,
+                "Experiência",
+                &hud_assets.energy_bar_bg,
+                &hud_assets.energy_bar_fill,
+                Color::rgb(0.5, 0.85, 0.3),
+                XpBar,
+                &hud_assets,
+            );
+            parent.spawn((
+                TextBundle::from_section(
+                    "Nv. 1",
+                    TextStyle {
+                        font: hud_assets.font.clone(),
+                        font_size: 18.0,
+                        color: Color::rgb(0.6, 0.9, 0.4),
+                    },
+                ),
+                XpLevelText,
+            ));
+            
+            // Active heat (Pacto) - vazio quando nenhum modificador está ativo
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: hud_assets.font.clone(),
+                        font_size: 22.0,
+                        color: Color::rgb(1.0, 0.45, 0.15),
+                    },
+                ),
+                HeatIndicator,
+            ));
         });
         
         // Top-right: Active boons
@@ -231,21 +377,53 @@ fn setup_hud(
                 &hud_assets,
             );
             
-            // Primary ability (Q)
+            // Primary attack (mouse esq)
             create_ability_icon(
                 parent,
                 AbilityType::Primary,
-                "Q",
+                "MOUSE ESQ",
                 &hud_assets,
             );
             
-            // Secondary ability (R)
+            // Secondary / special (mouse dir)
             create_ability_icon(
                 parent,
                 AbilityType::Secondary,
+                "MOUSE DIR",
+                &hud_assets,
+            );
+            
+            // Cast (Q)
+            create_ability_icon(
+                parent,
+                AbilityType::Cast,
+                "Q",
+                &hud_assets,
+            );
+            
+            // Ultimate AoE (R)
+            create_ability_icon(
+                parent,
+                AbilityType::Ultimate,
                 "R",
                 &hud_assets,
             );
+            
+            // Consumables (F)
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: hud_assets.font.clone(),
+                        font_size: 20.0,
+                        color: Color::rgb(0.9, 0.8, 0.4),
+                    },
+                ).with_style(Style {
+                    align_self: AlignSelf::End,
+                    ..default()
+                }),
+                ConsumableText,
+            ));
         });
         
         // Bottom-right: Coin counter and minimap
@@ -297,6 +475,44 @@ fn setup_hud(
                     CoinCounter,
                 ));
             });
+
+            // Pom counter (moeda de upgrade de bênção)
+            parent.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    padding: UiRect::all(Val::Px(10.0)),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                border_color: Color::rgb(0.75, 0.15, 0.25).into(),
+                background_color: Color::rgba(0.1, 0.04, 0.05, 0.8).into(),
+                ..default()
+            }).with_children(|parent| {
+                parent.spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(18.0),
+                        height: Val::Px(18.0),
+                        margin: UiRect::all(Val::Px(3.0)),
+                        ..default()
+                    },
+                    background_color: Color::rgb(0.8, 0.15, 0.25).into(),
+                    ..default()
+                });
+
+                parent.spawn((
+                    TextBundle::from_section(
+                        "0",
+                        TextStyle {
+                            font: hud_assets.font.clone(),
+                            font_size: 24.0,
+                            color: Color::rgb(0.95, 0.5, 0.55),
+                        },
+                    ),
+                    PomCounter,
+                ));
+            });
             
             // Minimap
             parent.spawn((
@@ -317,8 +533,8 @@ fn setup_hud(
             )).with_children(|parent| {
                 parent.spawn(ImageBundle {
                     style: Style {
-                        width: Val::Px(194.0),
-                        height: Val::Px(144.0),
+                        width: Val::Px(MINIMAP_INNER.x),
+                        height: Val::Px(MINIMAP_INNER.y),
                         ..default()
                     },
                     image: UiImage::new(hud_assets.minimap_bg.clone()),
@@ -431,15 +647,36 @@ fn create_boon_slot(
         },
         BoonSlot { slot_index },
     )).with_children(|parent| {
-        parent.spawn(ImageBundle {
-            style: Style {
-                width: Val::Px(46.0),
-                height: Val::Px(46.0),
+        parent.spawn((
+            ImageBundle {
+                style: Style {
+                    width: Val::Px(46.0),
+                    height: Val::Px(46.0),
+                    ..default()
+                },
+                image: UiImage::new(hud_assets.boon_frame_common.clone()),
                 ..default()
             },
-            image: UiImage::new(hud_assets.boon_frame_common.clone()),
-            ..default()
-        });
+            BoonSlotIcon { slot_index },
+        ));
+        
+        // Pips de raridade no canto (só no modo daltônico)
+        parent.spawn((
+            TextBundle::from_section(
+                "",
+                TextStyle {
+                    font: hud_assets.font.clone(),
+                    font_size: 14.0,
+                    color: Color::WHITE,
+                },
+            ).with_style(Style {
+                position_type: PositionType::Absolute,
+                right: Val::Px(2.0),
+                bottom: Val::Px(0.0),
+                ..default()
+            }),
+            BoonSlotPips { slot_index },
+        ));
     });
 }
 
@@ -473,7 +710,7 @@ fn create_ability_icon(
                 background_color: Color::rgba(0.1, 0.08, 0.05, 0.9).into(),
                 ..default()
             },
-            AbilityIcon { ability_type },
+            AbilityIcon { ability_type, flash_timer: 0.0, cooling_down: false },
         )).with_children(|parent| {
             parent.spawn(ImageBundle {
                 style: Style {
@@ -484,6 +721,23 @@ fn create_ability_icon(
                 image: UiImage::new(hud_assets.ability_frame.clone()),
                 ..default()
             });
+            
+            // Cooldown wipe: desce de cima para baixo conforme a recarga anda
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(0.0),
+                        bottom: Val::Px(0.0),
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(0.0),
+                        ..default()
+                    },
+                    background_color: Color::rgba(0.0, 0.0, 0.0, 0.65).into(),
+                    ..default()
+                },
+                AbilityCooldownOverlay { ability_type },
+            ));
         });
         
         // Key binding
@@ -499,10 +753,10 @@ fn create_ability_icon(
 }
 
 fn update_health_bar(
-    player_query: Query<&Stats, With<Player>>,
+    player_query: Query<(&PlayerId, &Stats), With<Player>>,
     mut health_bar_query: Query<&mut Style, With<HealthBar>>,
 ) {
-    if let Ok(stats) = player_query.get_single() {
+    if let Some((_, stats)) = player_query.iter().find(|(id, _)| id.is_primary()) {
         if let Ok(mut style) = health_bar_query.get_single_mut() {
             let health_percent = (stats.current_health as f32 / stats.max_health as f32) * 100.0;
             style.width = Val::Percent(health_percent);
@@ -511,10 +765,10 @@ fn update_health_bar(
 }
 
 fn update_energy_bar(
-    player_query: Query<&Stats, With<Player>>,
+    player_query: Query<(&PlayerId, &Stats), With<Player>>,
     mut energy_bar_query: Query<&mut Style, With<EnergyBar>>,
 ) {
-    if let Ok(stats) = player_query.get_single() {
+    if let Some((_, stats)) = player_query.iter().find(|(id, _)| id.is_primary()) {
         if let Ok(mut style) = energy_bar_query.get_single_mut() {
             // Using stamina as energy for now
             let energy_percent = (stats.current_stamina as f32 / stats.max_stamina as f32) * 100.0;
@@ -523,61 +777,102 @@ fn update_energy_bar(
     }
 }
 
+fn update_xp_bar(
+    experience: Res<RunExperience>,
+    mut xp_bar_query: Query<&mut Style, With<XpBar>>,
+    mut level_text_query: Query<&mut Text, With<XpLevelText>>,
+) {
+    if !experience.is_changed() {
+        return;
+    }
+    if let Ok(mut style) = xp_bar_query.get_single_mut() {
+        style.width = Val::Percent(experience.progress().min(1.0) * 100.0);
+    }
+    if let Ok(mut text) = level_text_query.get_single_mut() {
+        text.sections[0].value = format!("Nv. {}", experience.level);
+    }
+}
+
 fn update_ability_cooldowns(
-    player_query: Query<(&Dash, &Combat), With<Player>>,
-    mut ability_query: Query<(&mut BackgroundColor, &AbilityIcon)>,
+    time: Res<Time>,
+    player_query: Query<(&PlayerId, &Dash, &Combat), With<Player>>,
+    mut ability_query: Query<(&mut BackgroundColor, &mut BorderColor, &mut AbilityIcon)>,
+    mut overlay_query: Query<(&mut Style, &AbilityCooldownOverlay)>,
 ) {
-    if let Ok((dash, combat)) = player_query.get_single() {
-        for (mut bg_color, ability_icon) in ability_query.iter_mut() {
-            match ability_icon.ability_type {
-                AbilityType::Dash => {
-                    if dash.cooldown_timer > 0.0 {
-                        *bg_color = Color::rgba(0.5, 0.3, 0.3, 0.8).into();
-                    } else {
-                        *bg_color = Color::rgba(0.1, 0.08, 0.05, 0.9).into();
-                    }
-                }
-                AbilityType::Primary => {
-                    if combat.atk_timer > 0.0 {
-                        *bg_color = Color::rgba(0.5, 0.3, 0.3, 0.8).into();
-                    } else {
-                        *bg_color = Color::rgba(0.1, 0.08, 0.05, 0.9).into();
-                    }
-                }
-                AbilityType::Secondary => {
-                    if combat.special_cd > 0.0 {
-                        *bg_color = Color::rgba(0.5, 0.3, 0.3, 0.8).into();
-                    } else {
-                        *bg_color = Color::rgba(0.1, 0.08, 0.05, 0.9).into();
-                    }
-                }
-            }
+    let Some((_, dash, combat)) = player_query.iter().find(|(id, ..)| id.is_primary()) else {
+        return;
+    };
+    
+    for (mut style, overlay) in overlay_query.iter_mut() {
+        let fraction = overlay.ability_type.cooldown_fraction(dash, combat);
+        style.height = Val::Percent(fraction * 100.0);
+    }
+    
+    for (mut bg_color, mut border_color, mut ability_icon) in ability_query.iter_mut() {
+        let cooling_down = ability_icon.ability_type.cooldown_fraction(dash, combat) > 0.0;
+        // Voltou a ficar pronta: um brilho rápido na moldura
+        if ability_icon.cooling_down && !cooling_down {
+            ability_icon.flash_timer = ABILITY_READY_FLASH;
         }
+        ability_icon.cooling_down = cooling_down;
+        ability_icon.flash_timer = (ability_icon.flash_timer - time.delta_seconds()).max(0.0);
+        
+        let flash = ability_icon.flash_timer / ABILITY_READY_FLASH;
+        let idle = Color::rgba(0.1, 0.08, 0.05, 0.9);
+        let bright = Color::rgba(1.0, 0.9, 0.5, 0.9);
+        *bg_color = mix_color(idle, bright, flash * 0.6).into();
+        *border_color = mix_color(Color::rgb(0.8, 0.6, 0.2), Color::rgb(1.0, 1.0, 0.8), flash).into();
     }
 }
 
+fn mix_color(from: Color, to: Color, t: f32) -> Color {
+    let (a, b) = (from.as_rgba_f32(), to.as_rgba_f32());
+    Color::rgba(
+        a[0] + (b[0] - a[0]) * t,
+        a[1] + (b[1] - a[1]) * t,
+        a[2] + (b[2] - a[2]) * t,
+        a[3] + (b[3] - a[3]) * t,
+    )
+}
+
 fn update_boon_display(
     boon_data: Res<BoonData>,
-    mut boon_slot_query: Query<(&mut UiImage, &mut BorderColor, &BoonSlot)>,
+    palette: Res<UiPalette>,
+    mut boon_slot_query: Query<(&mut BorderColor, &BoonSlot)>,
+    mut icon_query: Query<(&mut UiImage, &BoonSlotIcon)>,
+    mut pips_query: Query<(&mut Text, &BoonSlotPips)>,
     hud_assets: Res<HudAssets>,
 ) {
-    for (mut image, mut border_color, boon_slot) in boon_slot_query.iter_mut() {
-        if let Some(boon) = boon_data.active_boons.get(boon_slot.slot_index) {
-            // Update boon icon
-            *image = UiImage::new(boon.icon.clone());
-            
-            // Update border color based on rarity
-            *border_color = match boon.rarity {
-                BoonRarity::Common => Color::rgb(0.6, 0.6, 0.6),
-                BoonRarity::Rare => Color::rgb(0.2, 0.6, 1.0),
-                BoonRarity::Epic => Color::rgb(0.8, 0.3, 1.0),
-                BoonRarity::Legendary => Color::rgb(1.0, 0.8, 0.2),
-            }.into();
-        } else {
-            // Empty slot
-            *image = UiImage::new(hud_assets.boon_frame_common.clone());
-            *border_color = Color::rgb(0.3, 0.25, 0.2).into();
+    for (mut border_color, boon_slot) in boon_slot_query.iter_mut() {
+        // Update border color based on rarity
+        *border_color = match boon_data.active_boons.get(boon_slot.slot_index) {
+            Some(boon) => palette.rarity_color(boon.rarity.get_color(), boon.rarity.tier()),
+            None => Color::rgb(0.3, 0.25, 0.2), // Empty slot
+        }.into();
+    }
+    
+    for (mut image, icon) in icon_query.iter_mut() {
+        let handle = match boon_data.active_boons.get(icon.slot_index) {
+            Some(boon) => boon.icon.clone(),
+            None => hud_assets.boon_frame_common.clone(),
+        };
+        if image.texture != handle {
+            *image = UiImage::new(handle);
+        }
+    }
+    
+    for (mut text, pips) in pips_query.iter_mut() {
+        let (value, color) = match boon_data.active_boons.get(pips.slot_index) {
+            Some(boon) => (
+                palette.rarity_pips(boon.rarity.tier()),
+                palette.rarity_color(boon.rarity.get_color(), boon.rarity.tier()),
+            ),
+            None => ("", Color::WHITE),
+        };
+        if text.sections[0].value != value {
+            text.sections[0].value = value.to_string();
         }
+        text.sections[0].style.color = color;
     }
 }
 
@@ -590,6 +885,52 @@ fn update_coin_counter(
     }
 }
 
+fn update_pom_counter(
+    boon_data: Res<BoonData>,
+    mut pom_text_query: Query<&mut Text, With<PomCounter>>,
+) {
+    if let Ok(mut text) = pom_text_query.get_single_mut() {
+        text.sections[0].value = boon_data.pom.to_string();
+    }
+}
+
+fn update_heat_indicator(
+    heat: Res<HeatModifiers>,
+    mut heat_text_query: Query<&mut Text, With<HeatIndicator>>,
+) {
+    if !heat.is_changed() {
+        return;
+    }
+    
+    if let Ok(mut text) = heat_text_query.get_single_mut() {
+        let total_heat = heat.total_heat();
+        text.sections[0].value = if total_heat > 0 {
+            format!("Calor: {}", total_heat)
+        } else {
+            String::new()
+        };
+    }
+}
+
+fn update_consumable_display(
+    inventory: Res<Inventory>,
+    bindings: Res<InputBindings>,
+    mut consumable_text_query: Query<&mut Text, With<ConsumableText>>,
+) {
+    if !inventory.is_changed() && !bindings.is_changed() {
+        return;
+    }
+    
+    if let Ok(mut text) = consumable_text_query.get_single_mut() {
+        text.sections[0].value = if inventory.items.is_empty() {
+            String::new()
+        } else {
+            let names: Vec<&str> = inventory.items.iter().map(|item| item.get_display_name()).collect();
+            format!("[{}] {}", bindings.binding(InputAction::UseItem).label(), names.join(" | "))
+        };
+    }
+}
+
 fn animate_hud_elements(
     time: Res<Time>,
     mut query: Query<&mut BackgroundColor, With<HealthBar>>,
@@ -607,6 +948,16 @@ fn animate_hud_elements(
 }
 
 impl BoonRarity {
+    // Nível 0 (comum) a 3 (lendária), usado pela paleta daltônica
+    pub fn tier(&self) -> usize {
+        match self {
+            BoonRarity::Common => 0,
+            BoonRarity::Rare => 1,
+            BoonRarity::Epic => 2,
+            BoonRarity::Legendary => 3,
+        }
+    }
+    
     pub fn get_color(&self) -> Color {
         match self {
             BoonRarity::Common => Color::rgb(0.6, 0.6, 0.6),
@@ -646,4 +997,107 @@ impl EgyptianGod {
             EgyptianGod::Thoth => "Thoth",
         }
     }
-}
\ No newline at end of file
+}
+
+/// Desenha a dungeon gerada dentro do minimapa: um ponto por sala (posição do gerador encaixada
+/// na moldura) e uma linha por conexão. Só refaz quando a dungeon muda (sala limpa, salas
+/// desbloqueadas, sala atual, run nova)
+fn minimap_render_system(
+    mut commands: Commands,
+    dungeon: Option<Res<GeneratedDungeon>>,
+    minimaps: Query<Entity, With<MiniMap>>,
+    elements: Query<Entity, With<MiniMapElement>>,
+) {
+    let Some(dungeon) = dungeon else {
+        return;
+    };
+    let Ok(minimap) = minimaps.get_single() else {
+        return;
+    };
+    if !dungeon.is_changed() && !elements.is_empty() {
+        return;
+    }
+    for element in &elements {
+        commands.entity(element).despawn_recursive();
+    }
+
+    let layout = &dungeon.layout;
+    let positions: Vec<Vec2> = layout.rooms.values().map(|room| room.position).collect();
+    let (Some(min), Some(max)) = (positions.iter().copied().reduce(Vec2::min), positions.iter().copied().reduce(Vec2::max)) else {
+        return;
+    };
+    // Mesma escala nos dois eixos; y do gerador cresce para baixo, como no mapa completo
+    let available = MINIMAP_INNER - Vec2::splat(MINIMAP_MARGIN * 2.0);
+    let scale = (available / (max - min).max(Vec2::ONE)).min_element();
+    let to_minimap = |position: Vec2| MINIMAP_INNER / 2.0 + (position - (min + max) / 2.0) * scale;
+
+    commands.entity(minimap).with_children(|parent| {
+        // Conexões primeiro (ficam atrás das salas), uma linha por par
+        let mut drawn = HashSet::new();
+        for connection in layout.connections.values().flatten() {
+            let key = (connection.from_room.0.min(connection.to_room.0), connection.from_room.0.max(connection.to_room.0));
+            let (Some(from), Some(to)) = (layout.rooms.get(&connection.from_room), layout.rooms.get(&connection.to_room)) else {
+                continue;
+            };
+            if !drawn.insert(key) {
+                continue;
+            }
+            let (from, to) = (to_minimap(from.position), to_minimap(to.position));
+            let length = from.distance(to);
+            let middle = (from + to) / 2.0;
+            let offset = to - from;
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(middle.x - length / 2.0),
+                        top: Val::Px(middle.y - MINIMAP_LINK_THICKNESS / 2.0),
+                        width: Val::Px(length),
+                        height: Val::Px(MINIMAP_LINK_THICKNESS),
+                        ..default()
+                    },
+                    background_color: if connection.is_locked { MINIMAP_LOCKED_LINK } else { MINIMAP_LINK }.into(),
+                    transform: Transform::from_rotation(Quat::from_rotation_z(offset.y.atan2(offset.x))),
+                    ..default()
+                },
+                MiniMapElement,
+            ));
+        }
+
+        for room in layout.rooms.values() {
+            let current = room.id == dungeon.current_room;
+            let unlocked = dungeon.unlocked_rooms.contains(&room.id);
+            let color = if current {
+                MINIMAP_CURRENT
+            } else if dungeon.room_state(room.id).cleared {
+                MINIMAP_CLEARED
+            } else if room.template.room_type == RoomType::Secret && !unlocked {
+                MINIMAP_SECRET
+            } else if unlocked {
+                MINIMAP_OPEN
+            } else {
+                MINIMAP_LOCKED
+            };
+            let is_boss = layout.boss_rooms.contains(&room.id);
+            let size = if current { MINIMAP_CURRENT_SIZE } else { MINIMAP_ROOM_SIZE };
+            let corner = to_minimap(room.position) - Vec2::splat(size / 2.0);
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(corner.x),
+                        top: Val::Px(corner.y),
+                        width: Val::Px(size),
+                        height: Val::Px(size),
+                        border: UiRect::all(Val::Px(if is_boss { 2.0 } else { 0.0 })),
+                        ..default()
+                    },
+                    background_color: color.into(),
+                    border_color: MINIMAP_BOSS_BORDER.into(),
+                    ..default()
+                },
+                MiniMapElement,
+            ));
+        }
+    });
+}
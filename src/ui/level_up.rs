@@ -0,0 +1,264 @@
+use bevy::prelude::*;
+use super::menu_system::{AppState, MenuAssets};
+use crate::{Combat, Player, Stats};
+
+// XP para o nível 2; cada nível seguinte pede mais
+const BASE_XP_TO_LEVEL: u32 = 50;
+const XP_PER_LEVEL_INCREASE: u32 = 25;
+
+const HEALTH_BUMP: f32 = 15.0;
+const DAMAGE_BUMP: i32 = 2;
+const SPEED_BUMP: f32 = 0.4;
+const STAMINA_BUMP: f32 = 15.0;
+
+/// Experiência da run atual: abates enchem a barra, cada nível rende um bônus de atributo
+#[derive(Resource)]
+pub struct RunExperience {
+    pub xp: u32,
+    pub level: u32,
+    // Níveis ganhos que ainda esperam a escolha do bônus
+    pub pending_level_ups: u32,
+}
+
+impl Default for RunExperience {
+    fn default() -> Self {
+        Self {
+            xp: 0,
+            level: 1,
+            pending_level_ups: 0,
+        }
+    }
+}
+
+impl RunExperience {
+    pub fn xp_to_next_level(&self) -> u32 {
+        BASE_XP_TO_LEVEL + XP_PER_LEVEL_INCREASE * (self.level - 1)
+    }
+
+    /// Soma XP e sobe quantos níveis couberem; retorna os níveis ganhos
+    pub fn add_xp(&mut self, amount: u32) -> u32 {
+        self.xp += amount;
+        let mut gained = 0;
+        while self.xp >= self.xp_to_next_level() {
+            self.xp -= self.xp_to_next_level();
+            self.level += 1;
+            gained += 1;
+        }
+        self.pending_level_ups += gained;
+        gained
+    }
+
+    /// Fração da barra até o próximo nível (0..1)
+    pub fn progress(&self) -> f32 {
+        self.xp as f32 / self.xp_to_next_level() as f32
+    }
+}
+
+/// Bônus pequeno de atributo escolhido a cada nível
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatBump {
+    Health,
+    Damage,
+    Speed,
+    Stamina,
+}
+
+impl StatBump {
+    const ALL: [StatBump; 4] = [StatBump::Health, StatBump::Damage, StatBump::Speed, StatBump::Stamina];
+
+    fn title(&self) -> &'static str {
+        match self {
+            StatBump::Health => "Vigor",
+            StatBump::Damage => "Força",
+            StatBump::Speed => "Agilidade",
+            StatBump::Stamina => "Fôlego",
+        }
+    }
+
+    fn description(&self) -> String {
+        match self {
+            StatBump::Health => format!("+{:.0} de vida máxima", HEALTH_BUMP),
+            StatBump::Damage => format!("+{} de dano base", DAMAGE_BUMP),
+            StatBump::Speed => format!("+{:.1} de velocidade", SPEED_BUMP),
+            StatBump::Stamina => format!("+{:.0} de energia máxima", STAMINA_BUMP),
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            StatBump::Health => Color::rgb(0.9, 0.3, 0.3),
+            StatBump::Damage => Color::rgb(1.0, 0.65, 0.2),
+            StatBump::Speed => Color::rgb(0.9, 0.95, 0.4),
+            StatBump::Stamina => Color::rgb(0.3, 0.55, 1.0),
+        }
+    }
+
+    fn apply(&self, stats: &mut Stats, combat: &mut Combat) {
+        match self {
+            StatBump::Health => {
+                stats.max_health += HEALTH_BUMP;
+                stats.current_health += HEALTH_BUMP;
+            }
+            StatBump::Damage => combat.base_damage += DAMAGE_BUMP,
+            StatBump::Speed => stats.speed += SPEED_BUMP,
+            StatBump::Stamina => {
+                stats.max_stamina += STAMINA_BUMP;
+                stats.current_stamina += STAMINA_BUMP;
+            }
+        }
+    }
+}
+
+#[derive(Component)]
+struct LevelUpUI;
+
+#[derive(Component)]
+struct LevelUpOption(StatBump);
+
+pub struct LevelUpPlugin;
+
+impl Plugin for LevelUpPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunExperience>()
+            .add_systems(OnEnter(AppState::Loading), reset_run_experience)
+            .add_systems(Update, open_level_up.run_if(in_state(AppState::InGame)))
+            .add_systems(OnEnter(AppState::LevelUp), setup_level_up)
+            .add_systems(OnExit(AppState::LevelUp), cleanup_level_up)
+            .add_systems(Update, handle_level_up_choice.run_if(in_state(AppState::LevelUp)));
+    }
+}
+
+fn reset_run_experience(mut experience: ResMut<RunExperience>) {
+    *experience = RunExperience::default();
+}
+
+// Só abre se nenhuma outra tela (recompensa, bênção) já foi pedida neste quadro;
+// o nível fica pendente e abre na próxima volta ao jogo
+fn open_level_up(
+    experience: Res<RunExperience>,
+    mut app_state: ResMut<NextState<AppState>>,
+) {
+    if experience.pending_level_ups > 0 && app_state.0.is_none() {
+        app_state.set(AppState::LevelUp);
+    }
+}
+
+fn setup_level_up(
+    mut commands: Commands,
+    menu_assets: Res<MenuAssets>,
+    experience: Res<RunExperience>,
+) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            background_color: Color::rgba(0.03, 0.05, 0.02, 0.8).into(),
+            ..default()
+        },
+        LevelUpUI,
+    )).with_children(|parent| {
+        let reached = experience.level - experience.pending_level_ups + 1;
+        parent.spawn(TextBundle::from_section(
+            format!("Nível {} - Escolha um Bônus", reached),
+            TextStyle {
+                font: menu_assets.font.clone(),
+                font_size: 40.0,
+                color: Color::rgb(0.6, 0.9, 0.4),
+            },
+        ).with_style(Style {
+            margin: UiRect::bottom(Val::Px(40.0)),
+            ..default()
+        }));
+
+        parent.spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(30.0),
+                ..default()
+            },
+            ..default()
+        }).with_children(|parent| {
+            for bump in StatBump::ALL {
+                parent.spawn((
+                    ButtonBundle {
+                        style: Style {
+                            width: Val::Px(200.0),
+                            height: Val::Px(140.0),
+                            flex_direction: FlexDirection::Column,
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            row_gap: Val::Px(12.0),
+                            border: UiRect::all(Val::Px(3.0)),
+                            ..default()
+                        },
+                        background_color: Color::rgba(0.1, 0.08, 0.05, 0.9).into(),
+                        border_color: bump.color().into(),
+                        ..default()
+                    },
+                    LevelUpOption(bump),
+                )).with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(
+                        bump.title(),
+                        TextStyle {
+                            font: menu_assets.font.clone(),
+                            font_size: 28.0,
+                            color: bump.color(),
+                        },
+                    ));
+                    parent.spawn(TextBundle::from_section(
+                        bump.description(),
+                        TextStyle {
+                            font: menu_assets.font.clone(),
+                            font_size: 18.0,
+                            color: Color::rgb(0.8, 0.7, 0.5),
+                        },
+                    ));
+                });
+            }
+        });
+    });
+}
+
+// Vale para todos os jogadores (co-op sobe junto); com mais níveis pendentes a tela reabre
+#[allow(clippy::type_complexity)]
+fn handle_level_up_choice(
+    mut interactions: Query<(&Interaction, &LevelUpOption, &mut BackgroundColor), (Changed<Interaction>, With<Button>)>,
+    mut players: Query<(&mut Stats, &mut Combat), With<Player>>,
+    mut experience: ResMut<RunExperience>,
+    mut app_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, option, mut background) in &mut interactions {
+        match *interaction {
+            Interaction::Pressed => {
+                for (mut stats, mut combat) in &mut players {
+                    option.0.apply(&mut stats, &mut combat);
+                }
+                experience.pending_level_ups = experience.pending_level_ups.saturating_sub(1);
+                info!("⭐ Level up bonus: {}", option.0.title());
+                app_state.set(AppState::InGame);
+                return;
+            }
+            Interaction::Hovered => {
+                *background = Color::rgba(0.15, 0.12, 0.08, 0.95).into();
+            }
+            Interaction::None => {
+                *background = Color::rgba(0.1, 0.08, 0.05, 0.9).into();
+            }
+        }
+    }
+}
+
+fn cleanup_level_up(
+    mut commands: Commands,
+    query: Query<Entity, With<LevelUpUI>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -1,15 +1,26 @@
 use bevy::prelude::*;
+use bevy::asset::RecursiveDependencyLoadState;
+use crate::components::WeaponKind;
+use crate::meta_progression::{HeatModifier, HeatModifiers, MetaProgress, RunLoadout, RunStats};
+use crate::procedural::GeneratedDungeon;
+use crate::settings::{GameSettings, SettingToggle, BRIGHTNESS_STEP, VOLUME_STEP};
+use crate::input_bindings::{InputAction, InputBinding, InputBindings};
 
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, States)]
 pub enum AppState {
     #[default]
     MainMenu,
     Settings,
+    Controls, // Remapear teclas (a partir das configurações)
     Loading,
     InGame,
     Paused,
+    DungeonMap, // Mapa completo (Tab) - jogo pausado
     RoomTransition,
+    RewardChoice, // Recompensa da sala limpa (bênção, moedas ou cura)
     BoonSelection,
+    BoonUpgrade, // Gastar romãs para subir o nível de uma bênção (U)
+    LevelUp, // Subiu de nível: escolher um bônus de atributo
     Death,
     MetaProgression,
 }
@@ -20,9 +31,42 @@ pub struct MainMenuUI;
 #[derive(Component)]
 pub struct SettingsMenuUI;
 
+#[derive(Component)]
+pub struct ControlsMenuUI;
+
 #[derive(Component)]
 pub struct LoadingScreenUI;
 
+#[derive(Component)]
+pub struct LoadingBarFill;
+
+#[derive(Component)]
+pub struct LoadingProgressText;
+
+/// Assets que precisam terminar de carregar (ou falhar) antes de a run começar.
+/// Cada carregador registra os seus handles na inicialização
+#[derive(Resource, Default)]
+pub struct RequiredAssets {
+    handles: Vec<UntypedHandle>,
+}
+
+impl RequiredAssets {
+    pub fn track<A: Asset>(&mut self, handle: &Handle<A>) {
+        self.handles.push(handle.clone().untyped());
+    }
+}
+
+// Tempo na tela de carregamento atual (zerado ao entrar)
+#[derive(Resource, Default)]
+struct LoadingProgress {
+    elapsed: f32,
+}
+
+// Evita piscar a tela quando tudo já está em cache
+const LOADING_MIN_TIME: f32 = 0.75;
+// Sub-assets de arquivos ausentes (ex.: "#Scene0") podem nunca sair de Loading
+const LOADING_TIMEOUT: f32 = 15.0;
+
 #[derive(Component)]
 pub struct PauseMenuUI;
 
@@ -60,19 +104,117 @@ pub enum ButtonAction {
     ToggleFullscreen,
     VolumeUp,
     VolumeDown,
+    BrightnessUp,
+    BrightnessDown,
     ResetProgress,
+    ToggleHeat(HeatModifier),
+    SelectWeapon(WeaponKind),
+    ToggleSetting(SettingToggle),
+    SaveReplay,
+    WatchReplay,
+    Practice,
+    Controls,
+    Rebind(InputAction),
+    ResetBindings,
 }
 
+// Run de treino: sala inicial com boneco, sem progressão (montada fora da UI)
+#[derive(Resource, Default)]
+pub struct PracticeMode {
+    pub active: bool,
+}
+
+// Pedidos do menu ao sistema de replay (tratados fora da UI)
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayRequest {
+    SaveLastRun,
+    Watch,
+}
+
+// Texto de uma arma no seletor do menu principal
+#[derive(Component)]
+pub struct WeaponSelectText {
+    pub weapon: WeaponKind,
+}
+
+#[derive(Component)]
+pub struct EssenceText;
+
+// Texto de um modificador de calor no menu principal
+#[derive(Component)]
+pub struct HeatToggleText {
+    pub modifier: HeatModifier,
+}
+
+#[derive(Component)]
+pub struct HeatTotalText;
+
+// Texto de uma opção liga/desliga no menu de configurações
+#[derive(Component)]
+pub struct SettingToggleText {
+    pub toggle: SettingToggle,
+}
+
+// Texto de uma ação no menu de controles (nome + tecla atual)
+#[derive(Component)]
+pub struct RebindText {
+    pub action: InputAction,
+}
+
+// Aviso do menu de controles (tecla trocada, conflito resolvido, captura cancelada)
+#[derive(Component)]
+pub struct ControlsStatusText;
+
+// Ação esperando a próxima tecla ou botão do mouse. `armed` pula o quadro do clique que
+// abriu a captura (senão o próprio clique viraria a tecla nova)
+#[derive(Resource, Default)]
+pub struct RebindCapture {
+    pub action: Option<InputAction>,
+    armed: bool,
+}
+
+#[derive(Component)]
+pub struct BrightnessText;
+
+#[derive(Component)]
+pub struct VolumeText;
+
+// Sons de navegação dos menus (qualquer botão, em qualquer tela fora do jogo)
+#[derive(Resource)]
+pub struct MenuSfx {
+    pub hover: Handle<AudioSource>,
+    pub confirm: Handle<AudioSource>,
+    pub cancel: Handle<AudioSource>,
+}
+
+// Relativo ao volume geral (GlobalVolume)
+const MENU_SFX_VOLUME: f32 = 0.6;
+
+// Quadrado da prévia do brilho: tom escuro de referência (linear), clareado pelo gama atual
+#[derive(Component)]
+pub struct BrightnessPreviewSwatch {
+    pub luminance: f32,
+}
+
+const BRIGHTNESS_PREVIEW_LUMINANCES: [f32; 6] = [0.005, 0.015, 0.04, 0.1, 0.25, 0.6];
+
 pub struct MenuSystemPlugin;
 
 impl Plugin for MenuSystemPlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<AppState>()
+            .init_resource::<RequiredAssets>()
+            .init_resource::<LoadingProgress>()
+            .init_resource::<PracticeMode>()
+            .init_resource::<RebindCapture>()
+            .add_event::<ReplayRequest>()
             .add_systems(Startup, load_menu_assets)
             .add_systems(OnEnter(AppState::MainMenu), setup_main_menu)
             .add_systems(OnExit(AppState::MainMenu), cleanup_main_menu)
             .add_systems(OnEnter(AppState::Settings), setup_settings_menu)
             .add_systems(OnExit(AppState::Settings), cleanup_settings_menu)
+            .add_systems(OnEnter(AppState::Controls), setup_controls_menu)
+            .add_systems(OnExit(AppState::Controls), cleanup_controls_menu)
             .add_systems(OnEnter(AppState::Loading), setup_loading_screen)
             .add_systems(OnExit(AppState::Loading), cleanup_loading_screen)
             .add_systems(OnEnter(AppState::Death), setup_death_screen)
@@ -80,15 +222,23 @@ impl Plugin for MenuSystemPlugin {
             .add_systems(Update, (
                 button_interaction_system,
                 animate_menu_elements,
-                handle_menu_input,
+                capture_rebind_key,
+                handle_menu_input.after(capture_rebind_key),
+                update_rebind_texts.after(capture_rebind_key),
+                update_heat_toggle_texts,
+                update_weapon_select_texts,
+                update_setting_toggle_texts,
+                update_setting_values,
+                play_menu_sfx,
             ).run_if(not(in_state(AppState::InGame))))
-            .add_systems(Update, auto_complete_loading.run_if(in_state(AppState::Loading)));
+            .add_systems(Update, track_loading_progress.run_if(in_state(AppState::Loading)));
     }
 }
 
 fn load_menu_assets(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    mut required: ResMut<RequiredAssets>,
 ) {
     info!("Loading menu assets...");
     
@@ -102,7 +252,23 @@ fn load_menu_assets(
         logo: asset_server.load("ui/sands_of_duat_logo.png"),
     };
     
+    required.track(&menu_assets.font);
+    for image in [
+        &menu_assets.button_normal,
+        &menu_assets.button_hovered,
+        &menu_assets.button_pressed,
+        &menu_assets.background_main,
+        &menu_assets.background_settings,
+        &menu_assets.logo,
+    ] {
+        required.track(image);
+    }
     commands.insert_resource(menu_assets);
+    commands.insert_resource(MenuSfx {
+        hover: asset_server.load("audio/ui/menu_hover.ogg"),
+        confirm: asset_server.load("audio/ui/menu_confirm.ogg"),
+        cancel: asset_server.load("audio/ui/menu_cancel.ogg"),
+    });
     info!("✅ Menu assets loaded");
 }
 
@@ -171,6 +337,14 @@ fn setup_main_menu(
                 &menu_assets,
             );
             
+            // Sala de treino: testar builds e sinergias contra um boneco
+            create_menu_button(
+                parent,
+                "Sala de Treino",
+                ButtonAction::Practice,
+                &menu_assets,
+            );
+            
             // Settings button
             create_menu_button(
                 parent,
@@ -179,6 +353,21 @@ fn setup_main_menu(
                 &menu_assets,
             );
             
+            // Replays: salvar a última run / assistir a salva
+            create_menu_button(
+                parent,
+                "Salvar Replay",
+                ButtonAction::SaveReplay,
+                &menu_assets,
+            );
+            
+            create_menu_button(
+                parent,
+                "Assistir Replay",
+                ButtonAction::WatchReplay,
+                &menu_assets,
+            );
+            
             // Quit button
             create_menu_button(
                 parent,
@@ -188,6 +377,80 @@ fn setup_main_menu(
             );
         });
         
+        // Arma inicial - desbloqueadas com essência (meta-progressão)
+        parent.spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                margin: UiRect::top(Val::Px(40.0)),
+                ..default()
+            },
+            ..default()
+        }).with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    "Essência: 0",
+                    TextStyle {
+                        font: menu_assets.font.clone(),
+                        font_size: 28.0,
+                        color: Color::rgb(0.5, 0.8, 1.0),
+                    },
+                ),
+                EssenceText,
+            ));
+            
+            parent.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(12.0),
+                    ..default()
+                },
+                ..default()
+            }).with_children(|parent| {
+                for weapon in WeaponKind::get_all() {
+                    create_weapon_select(parent, weapon, &menu_assets);
+                }
+            });
+        });
+        
+        // Pacto do Duat - modificadores de calor escolhidos antes da run
+        parent.spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                margin: UiRect::top(Val::Px(40.0)),
+                ..default()
+            },
+            ..default()
+        }).with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    "Pacto do Duat - Calor: 0",
+                    TextStyle {
+                        font: menu_assets.font.clone(),
+                        font_size: 28.0,
+                        color: Color::rgb(1.0, 0.45, 0.15),
+                    },
+                ),
+                HeatTotalText,
+            ));
+            
+            parent.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(12.0),
+                    ..default()
+                },
+                ..default()
+            }).with_children(|parent| {
+                for modifier in HeatModifier::get_all() {
+                    create_heat_toggle(parent, modifier, &menu_assets);
+                }
+            });
+        });
+        
         // Version info (bottom corner)
         parent.spawn(TextBundle::from_section(
             "Sands of Duat v0.5.0 - Hades-like Egyptian Roguelike",
@@ -240,6 +503,110 @@ fn create_menu_button(
     });
 }
 
+fn create_setting_toggle(
+    parent: &mut ChildBuilder,
+    toggle: SettingToggle,
+    menu_assets: &MenuAssets,
+) {
+    parent.spawn((
+        ButtonBundle {
+            style: Style {
+                width: Val::Px(400.0),
+                height: Val::Px(60.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(5.0)),
+                border: UiRect::all(Val::Px(3.0)),
+                ..default()
+            },
+            border_color: Color::rgb(0.8, 0.6, 0.2).into(),
+            background_color: Color::rgb(0.2, 0.15, 0.1).into(),
+            ..default()
+        },
+        MenuButton { action: ButtonAction::ToggleSetting(toggle) },
+    )).with_children(|parent| {
+        parent.spawn((
+            TextBundle::from_section(
+                toggle.get_display_name(),
+                TextStyle {
+                    font: menu_assets.font.clone(),
+                    font_size: 26.0,
+                    color: Color::rgb(0.9, 0.8, 0.4),
+                },
+            ),
+            SettingToggleText { toggle },
+        ));
+    });
+}
+
+fn create_heat_toggle(
+    parent: &mut ChildBuilder,
+    modifier: HeatModifier,
+    menu_assets: &MenuAssets,
+) {
+    parent.spawn((
+        ButtonBundle {
+            style: Style {
+                padding: UiRect::axes(Val::Px(16.0), Val::Px(10.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            border_color: Color::rgb(0.8, 0.6, 0.2).into(),
+            background_color: Color::rgb(0.2, 0.15, 0.1).into(),
+            ..default()
+        },
+        MenuButton { action: ButtonAction::ToggleHeat(modifier) },
+    )).with_children(|parent| {
+        parent.spawn((
+            TextBundle::from_section(
+                format!("{} [{}]", modifier.get_display_name(), modifier.get_heat()),
+                TextStyle {
+                    font: menu_assets.font.clone(),
+                    font_size: 20.0,
+                    color: Color::rgb(0.6, 0.55, 0.45),
+                },
+            ),
+            HeatToggleText { modifier },
+        ));
+    });
+}
+
+fn create_weapon_select(
+    parent: &mut ChildBuilder,
+    weapon: WeaponKind,
+    menu_assets: &MenuAssets,
+) {
+    parent.spawn((
+        ButtonBundle {
+            style: Style {
+                padding: UiRect::axes(Val::Px(16.0), Val::Px(10.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            border_color: Color::rgb(0.8, 0.6, 0.2).into(),
+            background_color: Color::rgb(0.2, 0.15, 0.1).into(),
+            ..default()
+        },
+        MenuButton { action: ButtonAction::SelectWeapon(weapon) },
+    )).with_children(|parent| {
+        parent.spawn((
+            TextBundle::from_section(
+                weapon.get_display_name(),
+                TextStyle {
+                    font: menu_assets.font.clone(),
+                    font_size: 22.0,
+                    color: Color::rgb(0.6, 0.55, 0.45),
+                },
+            ),
+            WeaponSelectText { weapon },
+        ));
+    });
+}
+
 fn setup_settings_menu(
     mut commands: Commands,
     menu_assets: Res<MenuAssets>,
@@ -323,25 +690,107 @@ fn setup_settings_menu(
                     &menu_assets,
                 );
                 
-                // Volume indicator (placeholder)
+                // Volume indicator
+                parent.spawn((
+                    TextBundle::from_section(
+                        "70%",
+                        TextStyle {
+                            font: menu_assets.font.clone(),
+                            font_size: 28.0,
+                            color: Color::rgb(0.9, 0.8, 0.4),
+                        },
+                    ),
+                    VolumeText,
+                ));
+                
+                // Volume up
+                create_menu_button(
+                    parent,
+                    "+",
+                    ButtonAction::VolumeUp,
+                    &menu_assets,
+                );
+            });
+            
+            // Brilho: - / valor / + e a prévia logo abaixo
+            parent.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(20.0),
+                    ..default()
+                },
+                ..default()
+            }).with_children(|parent| {
                 parent.spawn(TextBundle::from_section(
-                    "70%",
+                    "Brilho:",
                     TextStyle {
                         font: menu_assets.font.clone(),
                         font_size: 28.0,
-                        color: Color::rgb(0.9, 0.8, 0.4),
+                        color: Color::rgb(0.8, 0.7, 0.5),
                     },
                 ));
                 
-                // Volume up
+                create_menu_button(
+                    parent,
+                    "-",
+                    ButtonAction::BrightnessDown,
+                    &menu_assets,
+                );
+                
+                parent.spawn((
+                    TextBundle::from_section(
+                        "100%",
+                        TextStyle {
+                            font: menu_assets.font.clone(),
+                            font_size: 28.0,
+                            color: Color::rgb(0.9, 0.8, 0.4),
+                        },
+                    ),
+                    BrightnessText,
+                ));
+                
                 create_menu_button(
                     parent,
                     "+",
-                    ButtonAction::VolumeUp,
+                    ButtonAction::BrightnessUp,
                     &menu_assets,
                 );
             });
             
+            parent.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(4.0),
+                    ..default()
+                },
+                ..default()
+            }).with_children(|parent| {
+                for luminance in BRIGHTNESS_PREVIEW_LUMINANCES {
+                    parent.spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Px(60.0),
+                                height: Val::Px(40.0),
+                                ..default()
+                            },
+                            background_color: Color::rgb_linear(luminance, luminance, luminance).into(),
+                            ..default()
+                        },
+                        BrightnessPreviewSwatch { luminance },
+                    ));
+                }
+            });
+            
+            parent.spawn(TextBundle::from_section(
+                "Ajuste até o primeiro quadrado ficar quase invisível",
+                TextStyle {
+                    font: menu_assets.font.clone(),
+                    font_size: 20.0,
+                    color: Color::rgb(0.7, 0.6, 0.4),
+                },
+            ));
+            
             // Fullscreen toggle
             create_menu_button(
                 parent,
@@ -350,6 +799,29 @@ fn setup_settings_menu(
                 &menu_assets,
             );
             
+            // Gameplay toggles (bússola, ...) em duas colunas para caber com a interface ampliada
+            parent.spawn(NodeBundle {
+                style: Style {
+                    width: Val::Px(840.0),
+                    flex_direction: FlexDirection::Row,
+                    flex_wrap: FlexWrap::Wrap,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                ..default()
+            }).with_children(|parent| {
+                for toggle in SettingToggle::get_all() {
+                    create_setting_toggle(parent, toggle, &menu_assets);
+                }
+            });
+            
+            create_menu_button(
+                parent,
+                "Controles",
+                ButtonAction::Controls,
+                &menu_assets,
+            );
+            
             // Resolution info
             parent.spawn(TextBundle::from_section(
                 "Resolução: 3440x1440 (21:9 Ultrawide)",
@@ -371,10 +843,143 @@ fn setup_settings_menu(
     });
 }
 
+fn setup_controls_menu(
+    mut commands: Commands,
+    menu_assets: Res<MenuAssets>,
+    mut capture: ResMut<RebindCapture>,
+) {
+    capture.action = None;
+    
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            background_color: Color::rgb(0.05, 0.05, 0.1).into(),
+            ..default()
+        },
+        ControlsMenuUI,
+    )).with_children(|parent| {
+        parent.spawn(ImageBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            image: UiImage::new(menu_assets.background_settings.clone()),
+            ..default()
+        });
+        
+        parent.spawn(TextBundle::from_section(
+            "Controles",
+            TextStyle {
+                font: menu_assets.font.clone(),
+                font_size: 48.0,
+                color: Color::rgb(0.9, 0.8, 0.4),
+            },
+        ).with_style(Style {
+            margin: UiRect::bottom(Val::Px(20.0)),
+            ..default()
+        }));
+        
+        parent.spawn((
+            TextBundle::from_section(
+                "Clique numa ação e aperte a nova tecla (Esc cancela)",
+                TextStyle {
+                    font: menu_assets.font.clone(),
+                    font_size: 22.0,
+                    color: Color::rgb(0.7, 0.6, 0.4),
+                },
+            ).with_style(Style {
+                margin: UiRect::bottom(Val::Px(20.0)),
+                ..default()
+            }),
+            ControlsStatusText,
+        ));
+        
+        // Uma ação por botão, em duas colunas
+        parent.spawn(NodeBundle {
+            style: Style {
+                width: Val::Px(840.0),
+                flex_direction: FlexDirection::Row,
+                flex_wrap: FlexWrap::Wrap,
+                justify_content: JustifyContent::Center,
+                margin: UiRect::bottom(Val::Px(20.0)),
+                ..default()
+            },
+            ..default()
+        }).with_children(|parent| {
+            for action in InputAction::get_all() {
+                parent.spawn((
+                    ButtonBundle {
+                        style: Style {
+                            width: Val::Px(400.0),
+                            height: Val::Px(56.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            margin: UiRect::all(Val::Px(5.0)),
+                            border: UiRect::all(Val::Px(3.0)),
+                            ..default()
+                        },
+                        border_color: Color::rgb(0.8, 0.6, 0.2).into(),
+                        background_color: Color::rgb(0.2, 0.15, 0.1).into(),
+                        ..default()
+                    },
+                    MenuButton { action: ButtonAction::Rebind(action) },
+                )).with_children(|parent| {
+                    parent.spawn((
+                        TextBundle::from_section(
+                            action.get_display_name(),
+                            TextStyle {
+                                font: menu_assets.font.clone(),
+                                font_size: 24.0,
+                                color: Color::rgb(0.9, 0.8, 0.4),
+                            },
+                        ),
+                        RebindText { action },
+                    ));
+                });
+            }
+        });
+        
+        parent.spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(30.0),
+                ..default()
+            },
+            ..default()
+        }).with_children(|parent| {
+            create_menu_button(
+                parent,
+                "Restaurar Padrão",
+                ButtonAction::ResetBindings,
+                &menu_assets,
+            );
+            
+            create_menu_button(
+                parent,
+                "Voltar",
+                ButtonAction::Settings,
+                &menu_assets,
+            );
+        });
+    });
+}
+
 fn setup_loading_screen(
     mut commands: Commands,
     menu_assets: Res<MenuAssets>,
+    mut progress: ResMut<LoadingProgress>,
 ) {
+    progress.elapsed = 0.0;
+    
     commands.spawn((
         NodeBundle {
             style: Style {
@@ -400,7 +1005,7 @@ fn setup_loading_screen(
             },
         ));
         
-        // Loading bar (placeholder)
+        // Loading bar
         parent.spawn(NodeBundle {
             style: Style {
                 width: Val::Px(600.0),
@@ -412,14 +1017,45 @@ fn setup_loading_screen(
             border_color: Color::rgb(0.8, 0.6, 0.2).into(),
             background_color: Color::rgb(0.2, 0.15, 0.1).into(),
             ..default()
+        }).with_children(|parent| {
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    background_color: Color::rgb(0.9, 0.7, 0.25).into(),
+                    ..default()
+                },
+                LoadingBarFill,
+            ));
         });
+        
+        parent.spawn((
+            TextBundle::from_section(
+                "0%",
+                TextStyle {
+                    font: menu_assets.font.clone(),
+                    font_size: 22.0,
+                    color: Color::rgb(0.7, 0.6, 0.4),
+                },
+            ).with_style(Style {
+                margin: UiRect::top(Val::Px(10.0)),
+                ..default()
+            }),
+            LoadingProgressText,
+        ));
     });
 }
 
 fn setup_death_screen(
     mut commands: Commands,
     menu_assets: Res<MenuAssets>,
+    dungeon: Option<Res<GeneratedDungeon>>,
+    run_stats: Res<RunStats>,
 ) {
+    let seed = dungeon.map_or(0, |dungeon| dungeon.generation_seed);
     commands.spawn((
         NodeBundle {
             style: Style {
@@ -450,12 +1086,28 @@ fn setup_death_screen(
         
         // Run stats
         parent.spawn(TextBundle::from_section(
-            "Salas Completadas: 5\nInimigos Derrotados: 23\nBoons Coletados: 7",
+            format!(
+                "Salas Completadas: {}\nInimigos Derrotados: {}\nBoons Coletados: {}",
+                run_stats.rooms_cleared, run_stats.enemies_killed, run_stats.boons_collected,
+            ),
             TextStyle {
                 font: menu_assets.font.clone(),
                 font_size: 24.0,
                 color: Color::rgb(0.8, 0.7, 0.5),
             },
+        ).with_style(Style {
+            margin: UiRect::bottom(Val::Px(20.0)),
+            ..default()
+        }));
+        
+        // Semente da dungeon (para repetir a run ou anexar a um relato de bug)
+        parent.spawn(TextBundle::from_section(
+            format!("Semente: {}", seed),
+            TextStyle {
+                font: menu_assets.font.clone(),
+                font_size: 18.0,
+                color: Color::rgb(0.6, 0.55, 0.45),
+            },
         ).with_style(Style {
             margin: UiRect::bottom(Val::Px(40.0)),
             ..default()
@@ -477,6 +1129,13 @@ fn setup_death_screen(
                 &menu_assets,
             );
             
+            create_menu_button(
+                parent,
+                "Salvar Replay",
+                ButtonAction::SaveReplay,
+                &menu_assets,
+            );
+            
             create_menu_button(
                 parent,
                 "Menu Principal",
@@ -487,6 +1146,7 @@ fn setup_death_screen(
     });
 }
 
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 fn button_interaction_system(
     mut interaction_query: Query<
         (&Interaction, &mut BackgroundColor, &MenuButton, &mut BorderColor),
@@ -494,6 +1154,14 @@ fn button_interaction_system(
     >,
     mut app_state: ResMut<NextState<AppState>>,
     mut exit: EventWriter<bevy::app::AppExit>,
+    mut heat: ResMut<HeatModifiers>,
+    mut meta_progress: ResMut<MetaProgress>,
+    mut loadout: ResMut<RunLoadout>,
+    mut settings: ResMut<GameSettings>,
+    mut replay_requests: EventWriter<ReplayRequest>,
+    mut practice: ResMut<PracticeMode>,
+    mut capture: ResMut<RebindCapture>,
+    mut bindings: ResMut<InputBindings>,
 ) {
     for (interaction, mut color, menu_button, mut border_color) in &mut interaction_query {
         match *interaction {
@@ -504,6 +1172,12 @@ fn button_interaction_system(
                 match menu_button.action {
                     ButtonAction::NewGame => {
                         info!("Starting new game...");
+                        practice.active = false;
+                        app_state.set(AppState::Loading);
+                    },
+                    ButtonAction::Practice => {
+                        info!("🎯 Entering practice room...");
+                        practice.active = true;
                         app_state.set(AppState::Loading);
                     },
                     ButtonAction::Settings => {
@@ -522,6 +1196,57 @@ fn button_interaction_system(
                         info!("Returning to main menu...");
                         app_state.set(AppState::MainMenu);
                     },
+                    ButtonAction::ToggleHeat(modifier) => {
+                        heat.toggle(modifier);
+                        info!("🔥 Heat modifier {:?} toggled (total heat: {})", modifier, heat.total_heat());
+                    },
+                    ButtonAction::SelectWeapon(weapon) => {
+                        if meta_progress.try_unlock_weapon(weapon) {
+                            loadout.weapon = weapon;
+                            info!("⚔️ Weapon selected: {}", weapon.get_display_name());
+                        } else {
+                            info!("🔒 Not enough essence to unlock {} ({} needed)",
+                                  weapon.get_display_name(), weapon.unlock_cost());
+                        }
+                    },
+                    ButtonAction::ToggleSetting(toggle) => {
+                        settings.toggle(toggle);
+                        info!("⚙️ {}: {}", toggle.get_display_name(), settings.value_label(toggle));
+                    },
+                    ButtonAction::VolumeUp => {
+                        settings.adjust_master_volume(VOLUME_STEP);
+                        info!("🔊 Volume: {:.0}%", settings.master_volume() * 100.0);
+                    },
+                    ButtonAction::VolumeDown => {
+                        settings.adjust_master_volume(-VOLUME_STEP);
+                        info!("🔊 Volume: {:.0}%", settings.master_volume() * 100.0);
+                    },
+                    ButtonAction::BrightnessUp => {
+                        settings.adjust_brightness(BRIGHTNESS_STEP);
+                        info!("🔆 Brightness: {:.0}%", settings.brightness() * 100.0);
+                    },
+                    ButtonAction::BrightnessDown => {
+                        settings.adjust_brightness(-BRIGHTNESS_STEP);
+                        info!("🔆 Brightness: {:.0}%", settings.brightness() * 100.0);
+                    },
+                    ButtonAction::SaveReplay => {
+                        replay_requests.send(ReplayRequest::SaveLastRun);
+                    },
+                    ButtonAction::WatchReplay => {
+                        replay_requests.send(ReplayRequest::Watch);
+                    },
+                    ButtonAction::Controls => {
+                        app_state.set(AppState::Controls);
+                    },
+                    ButtonAction::Rebind(action) => {
+                        capture.action = Some(action);
+                        capture.armed = false;
+                    },
+                    ButtonAction::ResetBindings => {
+                        capture.action = None;
+                        bindings.reset();
+                        info!("🎮 Controls reset to defaults");
+                    },
                     _ => {
                         info!("Button action not implemented: {:?}", menu_button.action);
                     }
@@ -539,6 +1264,171 @@ fn button_interaction_system(
     }
 }
 
+fn update_heat_toggle_texts(
+    heat: Res<HeatModifiers>,
+    mut toggle_query: Query<(&mut Text, &HeatToggleText), Without<HeatTotalText>>,
+    mut total_query: Query<&mut Text, With<HeatTotalText>>,
+) {
+    for (mut text, toggle) in toggle_query.iter_mut() {
+        text.sections[0].style.color = if heat.is_active(toggle.modifier) {
+            Color::rgb(1.0, 0.45, 0.15)
+        } else {
+            Color::rgb(0.6, 0.55, 0.45)
+        };
+    }
+    
+    if let Ok(mut text) = total_query.get_single_mut() {
+        text.sections[0].value = format!("Pacto do Duat - Calor: {}", heat.total_heat());
+    }
+}
+
+// Próxima tecla (ou botão do mouse) apertada vira a tecla da ação em captura. Tecla já usada
+// por outra ação: as duas trocam. Esc cancela e não chega ao handle_menu_input
+fn capture_rebind_key(
+    mut capture: ResMut<RebindCapture>,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut bindings: ResMut<InputBindings>,
+    mut status_query: Query<&mut Text, With<ControlsStatusText>>,
+) {
+    let Some(action) = capture.action else {
+        return;
+    };
+    if !capture.armed {
+        capture.armed = true;
+        return;
+    }
+
+    let message = if keys.just_pressed(KeyCode::Escape) {
+        keys.clear_just_pressed(KeyCode::Escape);
+        format!("{}: sem mudança", action.get_display_name())
+    } else {
+        let binding = keys.get_just_pressed().next().map(|key| InputBinding::Key(*key))
+            .or_else(|| mouse.get_just_pressed().next().map(|button| InputBinding::Mouse(*button)));
+        let Some(binding) = binding else {
+            return;
+        };
+        match bindings.rebind(action, binding) {
+            Some(other) => format!(
+                "{}: {} (já era de {}, que passou para {})",
+                action.get_display_name(),
+                binding.label(),
+                other.get_display_name(),
+                bindings.binding(other).label(),
+            ),
+            None => format!("{}: {}", action.get_display_name(), binding.label()),
+        }
+    };
+    capture.action = None;
+    info!("🎮 {}", message);
+    if let Ok(mut text) = status_query.get_single_mut() {
+        text.sections[0].value = message;
+    }
+}
+
+fn update_rebind_texts(
+    bindings: Res<InputBindings>,
+    capture: Res<RebindCapture>,
+    mut rebind_query: Query<(&mut Text, &RebindText)>,
+) {
+    if !bindings.is_changed() && !capture.is_changed() {
+        return;
+    }
+    for (mut text, rebind) in rebind_query.iter_mut() {
+        text.sections[0].value = if capture.action == Some(rebind.action) {
+            format!("{}: ...", rebind.action.get_display_name())
+        } else {
+            format!("{}: {}", rebind.action.get_display_name(), bindings.binding(rebind.action).label())
+        };
+    }
+}
+
+fn update_setting_toggle_texts(
+    settings: Res<GameSettings>,
+    mut toggle_query: Query<(&mut Text, &SettingToggleText)>,
+) {
+    for (mut text, setting) in toggle_query.iter_mut() {
+        text.sections[0].value = format!("{}: {}", setting.toggle.get_display_name(), settings.value_label(setting.toggle));
+    }
+}
+
+// Passar o mouse: hover; clicar: confirmar, ou cancelar nos botões que voltam/saem.
+// Vale para todos os botões (cartas de bênção, tela de morte...), não só os MenuButton
+#[allow(clippy::type_complexity)]
+fn play_menu_sfx(
+    mut commands: Commands,
+    menu_sfx: Option<Res<MenuSfx>>,
+    audio_sources: Res<Assets<AudioSource>>,
+    interaction_query: Query<(&Interaction, Option<&MenuButton>), (Changed<Interaction>, With<Button>)>,
+) {
+    let Some(menu_sfx) = menu_sfx else {
+        return;
+    };
+    for (interaction, menu_button) in &interaction_query {
+        let sound = match interaction {
+            Interaction::Hovered => &menu_sfx.hover,
+            Interaction::Pressed => match menu_button.map(|button| button.action) {
+                Some(ButtonAction::Back | ButtonAction::MainMenu | ButtonAction::Quit) => &menu_sfx.cancel,
+                _ => &menu_sfx.confirm,
+            },
+            Interaction::None => continue,
+        };
+        // Arquivo ausente ou ainda carregando: silêncio em vez de erro por clique
+        if audio_sources.contains(sound) {
+            commands.spawn(AudioBundle {
+                source: sound.clone(),
+                settings: PlaybackSettings::DESPAWN.with_volume(bevy::audio::Volume::new(MENU_SFX_VOLUME)),
+            });
+        }
+    }
+}
+
+// Volume e brilho em %, e a prévia com o mesmo gama das câmeras 3D (efeito visível antes da run)
+fn update_setting_values(
+    settings: Res<GameSettings>,
+    mut volume_query: Query<&mut Text, (With<VolumeText>, Without<BrightnessText>)>,
+    mut text_query: Query<&mut Text, With<BrightnessText>>,
+    mut swatch_query: Query<(&mut BackgroundColor, &BrightnessPreviewSwatch)>,
+) {
+    for mut text in text_query.iter_mut() {
+        text.sections[0].value = format!("{:.0}%", settings.brightness() * 100.0);
+    }
+    for mut text in volume_query.iter_mut() {
+        text.sections[0].value = format!("{:.0}%", settings.master_volume() * 100.0);
+    }
+    let gamma = settings.brightness_gamma();
+    for (mut color, swatch) in swatch_query.iter_mut() {
+        let value = swatch.luminance.powf(gamma);
+        *color = Color::rgb_linear(value, value, value).into();
+    }
+}
+
+fn update_weapon_select_texts(
+    meta_progress: Res<MetaProgress>,
+    loadout: Res<RunLoadout>,
+    mut weapon_query: Query<(&mut Text, &WeaponSelectText), Without<EssenceText>>,
+    mut essence_query: Query<&mut Text, With<EssenceText>>,
+) {
+    for (mut text, select) in weapon_query.iter_mut() {
+        let weapon = select.weapon;
+        if meta_progress.is_weapon_unlocked(weapon) {
+            text.sections[0].value = weapon.get_display_name().to_string();
+            text.sections[0].style.color = if loadout.weapon == weapon {
+                Color::rgb(1.0, 0.85, 0.3)
+            } else {
+                Color::rgb(0.6, 0.55, 0.45)
+            };
+        } else {
+            text.sections[0].value = format!("{} ({} essência)", weapon.get_display_name(), weapon.unlock_cost());
+            text.sections[0].style.color = Color::rgb(0.4, 0.4, 0.45);
+        }
+    }
+    
+    if let Ok(mut text) = essence_query.get_single_mut() {
+        text.sections[0].value = format!("Essência: {}", meta_progress.essence);
+    }
+}
+
 fn animate_menu_elements(
     time: Res<Time>,
     mut query: Query<&mut Style, With<MainMenuUI>>,
@@ -560,9 +1450,12 @@ fn handle_menu_input(
     if keys.just_pressed(KeyCode::Escape) {
         match current_state.get() {
             AppState::Settings => app_state.set(AppState::MainMenu),
+            AppState::Controls => app_state.set(AppState::Settings),
             AppState::InGame => app_state.set(AppState::Paused),
             AppState::Paused => app_state.set(AppState::InGame),
+            AppState::DungeonMap => app_state.set(AppState::InGame),
             AppState::BoonSelection => app_state.set(AppState::InGame),
+            AppState::BoonUpgrade => app_state.set(AppState::InGame),
             _ => {}
         }
     }
@@ -593,6 +1486,17 @@ fn cleanup_settings_menu(
     }
 }
 
+fn cleanup_controls_menu(
+    mut commands: Commands,
+    query: Query<Entity, With<ControlsMenuUI>>,
+    mut capture: ResMut<RebindCapture>,
+) {
+    capture.action = None;
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
 fn cleanup_loading_screen(
     mut commands: Commands,
     query: Query<Entity, With<LoadingScreenUI>>,
@@ -602,18 +1506,51 @@ fn cleanup_loading_screen(
     }
 }
 
-fn auto_complete_loading(
+// Progresso real: assets carregados ou que falharam sobre o total registrado
+fn track_loading_progress(
     time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    required: Res<RequiredAssets>,
+    mut progress: ResMut<LoadingProgress>,
     mut app_state: ResMut<NextState<AppState>>,
-    mut timer: Local<f32>,
+    mut bar_query: Query<&mut Style, With<LoadingBarFill>>,
+    mut text_query: Query<&mut Text, With<LoadingProgressText>>,
 ) {
-    *timer += time.delta_seconds();
+    progress.elapsed += time.delta_seconds();
     
-    // Transition to game after 2 seconds of loading
-    if *timer >= 2.0 {
-        info!("Loading complete, transitioning to game...");
-        app_state.set(AppState::InGame);
+    // Handles criados em memória (Assets::add) não passam pelo AssetServer: já estão prontos
+    let state = |handle: &UntypedHandle| asset_server.get_recursive_dependency_load_state(handle.id());
+    let settled = required.handles.iter()
+        .filter(|handle| matches!(state(handle), None | Some(RecursiveDependencyLoadState::Loaded | RecursiveDependencyLoadState::Failed)))
+        .count();
+    let fraction = if required.handles.is_empty() { 1.0 } else { settled as f32 / required.handles.len() as f32 };
+    
+    for mut style in bar_query.iter_mut() {
+        style.width = Val::Percent(fraction * 100.0);
+    }
+    for mut text in text_query.iter_mut() {
+        text.sections[0].value = format!("{:.0}%", fraction * 100.0);
+    }
+    
+    let finished = settled == required.handles.len();
+    let ready = finished && progress.elapsed >= LOADING_MIN_TIME;
+    let timed_out = progress.elapsed >= LOADING_TIMEOUT;
+    if !(ready || timed_out) {
+        return;
+    }
+    
+    for handle in &required.handles {
+        let path = handle.path().map(|path| path.to_string()).unwrap_or_default();
+        match state(handle) {
+            Some(RecursiveDependencyLoadState::Failed) => warn!("⚠️ Asset failed to load: {}", path),
+            Some(RecursiveDependencyLoadState::NotLoaded | RecursiveDependencyLoadState::Loading) => {
+                warn!("⚠️ Asset still loading after {:.0}s: {}", LOADING_TIMEOUT, path);
+            }
+            _ => {}
+        }
     }
+    info!("Loading complete ({}/{} assets), transitioning to game...", settled, required.handles.len());
+    app_state.set(AppState::InGame);
 }
 
 fn cleanup_death_screen(
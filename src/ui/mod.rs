@@ -4,10 +4,18 @@ pub mod transition_system;
 pub mod boon_selection;
 pub mod combat_feedback;
 pub mod hades_ui_system;
+pub mod color_palette;
+pub mod reward_choice;
+pub mod boon_upgrade;
+pub mod level_up;
 
 pub use menu_system::*;
 pub use hud_system::*;
 pub use transition_system::*;
 pub use boon_selection::*;
 pub use combat_feedback::*;
-pub use hades_ui_system::*;
\ No newline at end of file
+pub use hades_ui_system::*;
+pub use color_palette::*;
+pub use reward_choice::*;
+pub use boon_upgrade::*;
+pub use level_up::*;
\ No newline at end of file
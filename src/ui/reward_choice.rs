@@ -0,0 +1,265 @@
+use bevy::prelude::*;
+use rand::seq::SliceRandom;
+use super::menu_system::{AppState, MenuAssets};
+use super::hud_system::BoonData;
+use super::boon_selection::{CurrentBoonOffer, GodEncounter};
+use crate::boons::{ActiveBoons, BoonRegistry};
+use crate::meta_progression::HeatModifiers;
+use crate::procedural::{RunRng, RunRngStream};
+use crate::{Downed, Player, Stats};
+
+// Quantas recompensas aparecem para escolher depois de limpar a sala
+const REWARD_OPTION_COUNT: usize = 2;
+const COIN_REWARD: u32 = 40;
+const POM_REWARD: u32 = 1;
+const HEAL_FRACTION: f32 = 0.3;
+// Favor ganho com o deus só por ele aparecer
+const GOD_ENCOUNTER_FAVOR: f32 = 0.05;
+
+/// Recompensa de uma sala limpa (o "símbolo da porta")
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoomReward {
+    Boon,
+    Coins,
+    Heal,
+    Pom,
+}
+
+impl RoomReward {
+    const ALL: [RoomReward; 4] = [RoomReward::Boon, RoomReward::Coins, RoomReward::Heal, RoomReward::Pom];
+
+    fn title(&self) -> &'static str {
+        match self {
+            RoomReward::Boon => "Bênção",
+            RoomReward::Coins => "Oferenda de Ouro",
+            RoomReward::Heal => "Água do Nilo",
+            RoomReward::Pom => "Romã do Poder",
+        }
+    }
+
+    fn description(&self) -> String {
+        match self {
+            RoomReward::Boon => "Um deus aparece e oferece três de suas bênçãos".to_string(),
+            RoomReward::Coins => format!("+{} moedas", COIN_REWARD),
+            RoomReward::Heal => format!("Recupera {:.0}% da vida", HEAL_FRACTION * 100.0),
+            RoomReward::Pom => format!("+{} romã para subir o nível de uma bênção (U)", POM_REWARD),
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            RoomReward::Boon => Color::rgb(0.9, 0.8, 0.4),
+            RoomReward::Coins => Color::rgb(1.0, 0.65, 0.2),
+            RoomReward::Heal => Color::rgb(0.4, 0.8, 0.9),
+            RoomReward::Pom => Color::rgb(0.95, 0.5, 0.55),
+        }
+    }
+
+    // Cura aparece mais quanto mais vida falta ao jogador mais ferido
+    fn weight(&self, missing_health: f32) -> f32 {
+        match self {
+            RoomReward::Boon => 1.0,
+            RoomReward::Coins => 0.7,
+            RoomReward::Heal => 0.15 + 2.0 * missing_health,
+            RoomReward::Pom => 0.5,
+        }
+    }
+}
+
+/// Opções sorteadas para a sala que acabou de ser limpa
+#[derive(Resource, Default)]
+pub struct PendingRewardChoice {
+    pub options: Vec<RoomReward>,
+}
+
+#[derive(Component)]
+struct RewardChoiceUI;
+
+#[derive(Component)]
+struct RewardOption(RoomReward);
+
+pub struct RewardChoicePlugin;
+
+impl Plugin for RewardChoicePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PendingRewardChoice>()
+            .add_systems(OnEnter(AppState::RewardChoice), (roll_reward_options, setup_reward_choice).chain())
+            .add_systems(OnExit(AppState::RewardChoice), cleanup_reward_choice)
+            .add_systems(Update, handle_reward_choice.run_if(in_state(AppState::RewardChoice)));
+    }
+}
+
+fn roll_reward_options(
+    mut pending: ResMut<PendingRewardChoice>,
+    players: Query<&Stats, With<Player>>,
+    mut run_rng: ResMut<RunRng>,
+) {
+    let missing_health = players.iter()
+        .map(|stats| 1.0 - (stats.current_health / stats.max_health.max(1.0)).clamp(0.0, 1.0))
+        .fold(0.0, f32::max);
+
+    let rng = run_rng.stream(RunRngStream::Rewards);
+    let mut pool = RoomReward::ALL.to_vec();
+    pending.options.clear();
+    while pending.options.len() < REWARD_OPTION_COUNT {
+        let Ok(&reward) = pool.choose_weighted(rng, |reward| reward.weight(missing_health)) else {
+            break;
+        };
+        pool.retain(|other| *other != reward);
+        pending.options.push(reward);
+    }
+}
+
+fn setup_reward_choice(
+    mut commands: Commands,
+    menu_assets: Res<MenuAssets>,
+    pending: Res<PendingRewardChoice>,
+) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            background_color: Color::rgba(0.05, 0.04, 0.02, 0.85).into(),
+            ..default()
+        },
+        RewardChoiceUI,
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            "Sala Purificada - Escolha sua Recompensa",
+            TextStyle {
+                font: menu_assets.font.clone(),
+                font_size: 40.0,
+                color: Color::rgb(0.9, 0.8, 0.4),
+            },
+        ).with_style(Style {
+            margin: UiRect::bottom(Val::Px(40.0)),
+            ..default()
+        }));
+
+        parent.spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(40.0),
+                ..default()
+            },
+            ..default()
+        }).with_children(|parent| {
+            for reward in &pending.options {
+                parent.spawn((
+                    ButtonBundle {
+                        style: Style {
+                            width: Val::Px(260.0),
+                            height: Val::Px(180.0),
+                            flex_direction: FlexDirection::Column,
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            row_gap: Val::Px(16.0),
+                            border: UiRect::all(Val::Px(3.0)),
+                            ..default()
+                        },
+                        background_color: Color::rgba(0.1, 0.08, 0.05, 0.9).into(),
+                        border_color: reward.color().into(),
+                        ..default()
+                    },
+                    RewardOption(*reward),
+                )).with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(
+                        reward.title(),
+                        TextStyle {
+                            font: menu_assets.font.clone(),
+                            font_size: 30.0,
+                            color: reward.color(),
+                        },
+                    ));
+                    parent.spawn(TextBundle::from_section(
+                        reward.description(),
+                        TextStyle {
+                            font: menu_assets.font.clone(),
+                            font_size: 18.0,
+                            color: Color::rgb(0.8, 0.7, 0.5),
+                        },
+                    ).with_style(Style {
+                        max_width: Val::Px(220.0),
+                        ..default()
+                    }));
+                });
+            }
+        });
+    });
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn handle_reward_choice(
+    mut interactions: Query<(&Interaction, &RewardOption, &mut BackgroundColor), (Changed<Interaction>, With<Button>)>,
+    mut boon_data: ResMut<BoonData>,
+    mut current_offer: ResMut<CurrentBoonOffer>,
+    mut encounter: ResMut<GodEncounter>,
+    mut boon_registry: ResMut<BoonRegistry>,
+    active_boons: Res<ActiveBoons>,
+    // Caídos no co-op não recebem a cura: só voltam revividos
+    mut players: Query<&mut Stats, (With<Player>, Without<Downed>)>,
+    mut app_state: ResMut<NextState<AppState>>,
+    mut run_rng: ResMut<RunRng>,
+    heat: Res<HeatModifiers>,
+) {
+    for (interaction, option, mut background) in &mut interactions {
+        match *interaction {
+            Interaction::Pressed => {
+                match option.0 {
+                    RoomReward::Boon => {
+                        // Encontro com um deus (mais favor = aparece mais); a oferta já vai pronta
+                        let rng = run_rng.stream(RunRngStream::Rewards);
+                        let god = boon_registry.choose_encounter_god(rng);
+                        boon_registry.increase_god_favor(god, GOD_ENCOUNTER_FAVOR);
+                        current_offer.boons = boon_registry.generate_offer(Some(vec![god]), 3, &active_boons.player_boons, rng).boons;
+                        encounter.god = Some(god);
+                        info!("🌟 God encounter: {}", god.get_display_name());
+                        current_offer.selected = false;
+                        app_state.set(AppState::BoonSelection);
+                    }
+                    RoomReward::Coins => {
+                        boon_data.coins += COIN_REWARD;
+                        info!("💰 Room reward: {} coins", COIN_REWARD);
+                        app_state.set(AppState::InGame);
+                    }
+                    RoomReward::Heal => {
+                        // Cura reduzida pelo calor, como poções e orbes de vida
+                        let heal_fraction = HEAL_FRACTION * heat.healing_multiplier();
+                        for mut stats in &mut players {
+                            stats.current_health = (stats.current_health + stats.max_health * heal_fraction).min(stats.max_health);
+                        }
+                        info!("💧 Room reward: healed {:.0}%", heal_fraction * 100.0);
+                        app_state.set(AppState::InGame);
+                    }
+                    RoomReward::Pom => {
+                        boon_data.pom += POM_REWARD;
+                        info!("🍎 Room reward: {} pom", POM_REWARD);
+                        app_state.set(AppState::InGame);
+                    }
+                }
+                return;
+            }
+            Interaction::Hovered => {
+                *background = Color::rgba(0.15, 0.12, 0.08, 0.95).into();
+            }
+            Interaction::None => {
+                *background = Color::rgba(0.1, 0.08, 0.05, 0.9).into();
+            }
+        }
+    }
+}
+
+fn cleanup_reward_choice(
+    mut commands: Commands,
+    query: Query<Entity, With<RewardChoiceUI>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}